@@ -25,6 +25,8 @@ use thiserror::Error;
 /// - `InvalidBase` is thrown if the provided base to call a function is not valid
 /// - `InvalidExponent` is thrown if an invalid exponent is used for a `pow` function
 /// - `InvalidIntToModulus` is thrown if an integer is provided, which is not greater than `0`
+/// - `InvalidInterpolationPoints` is thrown if the points provided for an
+/// interpolation do not have pairwise distinct x-coordinates
 /// - `InvalidMatrix` is thrown if an invalid string input of a matrix is given
 /// - `InvalidStringToCStringInput` is thrown if an invalid string is given to
 /// construct a [`CString`](std::ffi::CString)
@@ -51,9 +53,23 @@ use thiserror::Error;
 /// objects with different modulus where equal modulus is required
 /// - `MismatchingVectorDimensions` is thrown if an operation of two vectors is
 /// called for which their dimensions do not match
+/// - `NoPrimitiveRoot` is thrown if a generator of the multiplicative group
+/// modulo a modulus or of one of its subgroups is required, but does not exist
+/// - `NoSolution` is thrown if a system of equations has no solution
+/// in the required domain
+/// - `NonPositive` is thrown if the function expects a positive value,
+/// but a value smaller than or equal to `0` is provided
+/// - `NotCoprime` is thrown if values are required to be coprime,
+/// but share a common divisor
+/// - `NotInvertible` is thrown if the inverse of a value is required,
+/// but the value has no multiplicative inverse
 /// - `NotNaturalNumber` is thrown if the function expects a natural number,
 /// but a number smaller than `1` is provided
 /// - `NotPrime` is thrown if a provided integer is not prime
+/// - `NotQuadraticResidue` is thrown if the square root of a value
+/// is required, but the value is not a square
+/// - `NttNotSupported` is thrown if the number theoretic transform is
+/// required, but not supported for the provided modulus
 /// - `OutOfBounds` is thrown if a provided index is not in a desired range
 /// - `VectorFunctionCalledOnNonVector` is thrown if a function defined
 /// on vectors was called on a matrix instance that is not a vector
@@ -93,6 +109,10 @@ pub enum MathError {
     )]
     InvalidIntToModulus(String),
 
+    /// invalid points for an interpolation
+    #[error("invalid interpolation points. {0}")]
+    InvalidInterpolationPoints(String),
+
     /// invalid Matrix input error
     #[error("invalid Matrix. {0}")]
     InvalidMatrix(String),
@@ -162,6 +182,26 @@ pub enum MathError {
     #[error("mismatching vector dimensions. {0}")]
     MismatchingVectorDimensions(String),
 
+    /// no primitive root exists modulo the modulus
+    #[error("no primitive root exists. {0}")]
+    NoPrimitiveRoot(String),
+
+    /// if a system of equations has no solution
+    #[error("the system has no solution. {0}")]
+    NoSolution(String),
+
+    /// if a value is not positive
+    #[error("invalid value. The provided value needs to be positive and is {0}")]
+    NonPositive(String),
+
+    /// if values are not coprime
+    #[error("the values are not coprime. {0}")]
+    NotCoprime(String),
+
+    /// if a value has no multiplicative inverse
+    #[error("the value {0} is not invertible")]
+    NotInvertible(String),
+
     /// if an integer is not a natural number (excluding the `´0`)
     #[error("invalid integer. The provided value needs to be a natural number and is {0}")]
     NotNaturalNumber(String),
@@ -170,6 +210,14 @@ pub enum MathError {
     #[error("invalid integer. The integer has to be prime and the provided value is {0}")]
     NotPrime(String),
 
+    /// if a value is not a quadratic residue
+    #[error("the value {0} is not a quadratic residue")]
+    NotQuadraticResidue(String),
+
+    /// if the number theoretic transform is not supported for a modulus
+    #[error("the number theoretic transform is not supported. {0}")]
+    NttNotSupported(String),
+
     /// if a provided index is out of bounds
     #[error(
         "invalid index submitted. The index is out of bounds.
@@ -17,6 +17,7 @@ mod concat;
 mod evaluate;
 mod from;
 mod get;
+mod inplace;
 mod ownership;
 mod properties;
 mod serialize;
@@ -0,0 +1,145 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains in-place variants of the transposition, negation and
+//! scalar multiplication of [`MatPolyOverZ`], which reuse the memory of the matrix.
+
+use super::MatPolyOverZ;
+use crate::integer::PolyOverZ;
+use crate::traits::{GetNumColumns, GetNumRows};
+use flint_sys::fmpz_poly_mat::{
+    fmpz_poly_mat_neg, fmpz_poly_mat_scalar_mul_fmpz_poly, fmpz_poly_mat_struct,
+    fmpz_poly_mat_transpose,
+};
+
+impl MatPolyOverZ {
+    /// Transposes the matrix in place, i.e. rows get transformed to columns
+    /// and vice versa. Square matrices are transposed without allocating
+    /// new memory, all others are replaced by their transposed form.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatPolyOverZ, PolyOverZ};
+    /// use std::str::FromStr;
+    ///
+    /// let mut mat = MatPolyOverZ::from_str("[[0, 1  42],[2  1 2, 1  -3]]").unwrap();
+    ///
+    /// mat.transpose_inplace();
+    ///
+    /// assert_eq!(MatPolyOverZ::from_str("[[0, 2  1 2],[1  42, 1  -3]]").unwrap(), mat);
+    /// ```
+    pub fn transpose_inplace(&mut self) {
+        if self.get_num_rows() == self.get_num_columns() {
+            let matrix: *mut fmpz_poly_mat_struct = &mut self.matrix;
+            unsafe { fmpz_poly_mat_transpose(matrix, matrix) };
+        } else {
+            *self = self.transpose();
+        }
+    }
+
+    /// Negates every entry of the matrix in place.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatPolyOverZ, PolyOverZ};
+    /// use std::str::FromStr;
+    ///
+    /// let mut mat = MatPolyOverZ::from_str("[[0, 1  42],[2  1 2, 1  -3]]").unwrap();
+    ///
+    /// mat.negate_inplace();
+    ///
+    /// assert_eq!(MatPolyOverZ::from_str("[[0, 1  -42],[2  -1 -2, 1  3]]").unwrap(), mat);
+    /// ```
+    pub fn negate_inplace(&mut self) {
+        let matrix: *mut fmpz_poly_mat_struct = &mut self.matrix;
+        unsafe { fmpz_poly_mat_neg(matrix, matrix) };
+    }
+
+    /// Multiplies every entry of the matrix in place by the polynomial `scalar`.
+    ///
+    /// Parameters:
+    /// - `scalar`: specifies the scalar by which the matrix is multiplied
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatPolyOverZ, PolyOverZ};
+    /// use std::str::FromStr;
+    ///
+    /// let mut mat = MatPolyOverZ::from_str("[[0, 1  42],[2  1 2, 1  -3]]").unwrap();
+    ///
+    /// mat.scalar_mul_assign(&PolyOverZ::from_str("2  0 2").unwrap());
+    ///
+    /// assert_eq!(MatPolyOverZ::from_str("[[0, 2  0 84],[3  0 2 4, 2  0 -6]]").unwrap(), mat);
+    /// ```
+    pub fn scalar_mul_assign(&mut self, scalar: &PolyOverZ) {
+        let matrix: *mut fmpz_poly_mat_struct = &mut self.matrix;
+        unsafe { fmpz_poly_mat_scalar_mul_fmpz_poly(matrix, matrix, &scalar.poly) };
+    }
+}
+
+#[cfg(test)]
+mod test_transpose_inplace {
+    use super::MatPolyOverZ;
+    use std::str::FromStr;
+
+    /// Ensure that square and non-square matrices are transposed correctly
+    #[test]
+    fn correct_transpose() {
+        let mut square =
+            MatPolyOverZ::from_str(&format!("[[0, 1  {}],[2  1 2, 1  -3]]", i64::MAX)).unwrap();
+        let mut row = MatPolyOverZ::from_str("[[1  1, 0, 3  1 2 3]]").unwrap();
+        let cmp_square = square.transpose();
+        let cmp_row = row.transpose();
+
+        square.transpose_inplace();
+        row.transpose_inplace();
+
+        assert_eq!(cmp_square, square);
+        assert_eq!(cmp_row, row);
+    }
+}
+
+#[cfg(test)]
+mod test_negate_inplace {
+    use super::MatPolyOverZ;
+    use std::str::FromStr;
+
+    /// Ensure that all coefficients are negated
+    #[test]
+    fn correct_negation() {
+        let mut mat = MatPolyOverZ::from_str(&format!("[[0, 2  {} -1]]", u64::MAX)).unwrap();
+
+        mat.negate_inplace();
+
+        assert_eq!(
+            MatPolyOverZ::from_str(&format!("[[0, 2  -{} 1]]", u64::MAX)).unwrap(),
+            mat
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_scalar_mul_assign {
+    use super::{MatPolyOverZ, PolyOverZ};
+    use std::str::FromStr;
+
+    /// Ensure that constant and zero polynomials are handled correctly
+    #[test]
+    fn constant_and_zero_scalar() {
+        let mut mat = MatPolyOverZ::from_str("[[1  3, 2  1 -1],[0, 3  0 0 5]]").unwrap();
+
+        mat.scalar_mul_assign(&PolyOverZ::from_str("1  -2").unwrap());
+        assert_eq!(
+            MatPolyOverZ::from_str("[[1  -6, 2  -2 2],[0, 3  0 0 -10]]").unwrap(),
+            mat
+        );
+
+        mat.scalar_mul_assign(&PolyOverZ::default());
+        assert_eq!(MatPolyOverZ::new(2, 2).unwrap(), mat);
+    }
+}
@@ -12,15 +12,32 @@
 use flint_sys::fmpz_mat::fmpz_mat_struct;
 
 mod arithmetic;
+mod block;
 mod cmp;
 mod concat;
 mod from;
+mod gadget;
 mod get;
+mod gram;
+mod hnf;
+mod inplace;
+#[cfg(any(feature = "nalgebra", feature = "ndarray"))]
+mod interop;
+mod kernel;
+mod manipulation;
+mod map;
+mod nearest_plane;
+mod norm;
 mod ownership;
+mod rank;
+mod sample;
 mod serialize;
 mod set;
+mod solve;
+mod submatrix;
 mod tensor;
 mod to_string;
+mod trace;
 mod transpose;
 mod vector;
 
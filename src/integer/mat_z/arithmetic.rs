@@ -10,6 +10,8 @@
 //! such as addition or subtraction.
 
 mod add;
+mod hadamard;
 mod mul;
 mod mul_scalar;
+mod pow;
 mod sub;
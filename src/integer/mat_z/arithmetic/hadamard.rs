@@ -0,0 +1,115 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementation of the entrywise (Hadamard) product for [`MatZ`] values.
+
+use super::super::MatZ;
+use crate::error::MathError;
+use crate::traits::{GetNumColumns, GetNumRows};
+use flint_sys::{fmpz::fmpz_mul, fmpz_mat::fmpz_mat_entry};
+
+impl MatZ {
+    /// Computes the entrywise (Hadamard) product of `self` and `other`,
+    /// i.e. the matrix whose entry `(i, j)` is `self[i][j] * other[i][j]`.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the matrix to multiply entrywise with `self`
+    ///
+    /// Returns the entrywise product of both matrices as a [`MatZ`] or an
+    /// error if the matrix dimensions mismatch.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let a = MatZ::from_str("[[1, 2, 3],[3, 4, 5]]").unwrap();
+    /// let b = MatZ::from_str("[[1, 9, 3],[-1, 0, 5]]").unwrap();
+    ///
+    /// let c = a.hadamard(&b).unwrap();
+    ///
+    /// assert_eq!(MatZ::from_str("[[1, 18, 9],[-3, 0, 25]]").unwrap(), c);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the matrix dimensions mismatch.
+    pub fn hadamard(&self, other: &Self) -> Result<Self, MathError> {
+        if self.get_num_rows() != other.get_num_rows()
+            || self.get_num_columns() != other.get_num_columns()
+        {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to compute the Hadamard product of a '{}x{}' matrix and a '{}x{}' matrix.",
+                self.get_num_rows(),
+                self.get_num_columns(),
+                other.get_num_rows(),
+                other.get_num_columns()
+            )));
+        }
+
+        let mut out = MatZ::new(self.get_num_rows(), self.get_num_columns()).unwrap();
+        for row in 0..self.get_num_rows() {
+            for column in 0..self.get_num_columns() {
+                unsafe {
+                    fmpz_mul(
+                        fmpz_mat_entry(&out.matrix, row, column),
+                        fmpz_mat_entry(&self.matrix, row, column),
+                        fmpz_mat_entry(&other.matrix, row, column),
+                    )
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_hadamard {
+    use super::MatZ;
+    use crate::{error::MathError, integer::Z, traits::GetEntry};
+    use std::str::FromStr;
+
+    /// Ensure that the entrywise product is computed correctly
+    #[test]
+    fn correct_result() {
+        let a = MatZ::from_str("[[1, -2],[0, 7],[3, 3]]").unwrap();
+        let b = MatZ::from_str("[[5, 4],[9, -1],[-3, 0]]").unwrap();
+
+        let c = a.hadamard(&b).unwrap();
+
+        assert_eq!(MatZ::from_str("[[5, -8],[0, -7],[-9, 0]]").unwrap(), c);
+        assert_eq!(c, b.hadamard(&a).unwrap());
+    }
+
+    /// Ensure that large entries are multiplied correctly
+    #[test]
+    fn large_entries() {
+        let a = MatZ::from_str(&format!("[[{}, 2]]", i64::MAX)).unwrap();
+        let b = MatZ::from_str(&format!("[[{}, {}]]", i64::MIN, u64::MAX)).unwrap();
+
+        let c = a.hadamard(&b).unwrap();
+
+        let entry_0: Z = c.get_entry(0, 0).unwrap();
+        let entry_1: Z = c.get_entry(0, 1).unwrap();
+        assert_eq!(Z::from(i64::MAX) * Z::from(i64::MIN), entry_0);
+        assert_eq!(Z::from(u64::MAX) + Z::from(u64::MAX), entry_1);
+    }
+
+    /// Ensure that an error is returned if the dimensions mismatch
+    #[test]
+    fn mismatching_dimensions() {
+        let a = MatZ::new(2, 3).unwrap();
+        let b = MatZ::new(3, 2).unwrap();
+
+        assert!(matches!(
+            a.hadamard(&b),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module provides an implementation of the [`Pow`] trait for [`MatZ`].
+
+use crate::{
+    error::MathError,
+    integer::{MatZ, Z},
+    macros::for_others::{implement_for_others, implement_for_owned},
+    traits::{GetNumColumns, GetNumRows, Pow},
+};
+use flint_sys::fmpz::{fmpz_bits, fmpz_tstbit};
+
+impl Pow<&Z> for MatZ {
+    type Output = MatZ;
+
+    /// Raises the square matrix `self` to the power of a non-negative integer `exp`
+    /// using binary exponentiation.
+    ///
+    /// Parameters:
+    /// - `exp`: specifies the exponent to which the matrix is raised
+    ///
+    /// Returns the matrix `self` powered by `exp` as a new [`MatZ`] instance.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use qfall_math::traits::*;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZ::from_str("[[1,1],[1,0]]").unwrap();
+    ///
+    /// let powered_value = matrix.pow(5).unwrap();
+    ///
+    /// assert_eq!(MatZ::from_str("[[8,5],[5,3]]").unwrap(), powered_value);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidExponent`](MathError::InvalidExponent)
+    /// if the provided exponent is negative.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the matrix is not square.
+    fn pow(&self, exp: &Z) -> Result<Self::Output, MathError> {
+        if exp < &Z::ZERO {
+            return Err(MathError::InvalidExponent(format!(
+                "A negative exponent {} was used for a matrix.",
+                exp
+            )));
+        }
+        if self.get_num_rows() != self.get_num_columns() {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Only square matrices can be powered, but the matrix is '{}x{}'.",
+                self.get_num_rows(),
+                self.get_num_columns()
+            )));
+        }
+
+        // square-and-multiply from the most significant bit of `exp`
+        let mut out = MatZ::identity(self.get_num_rows(), self.get_num_rows()).unwrap();
+        for i in (0..unsafe { fmpz_bits(&exp.value) }).rev() {
+            out = &out * &out;
+            if 1 == unsafe { fmpz_tstbit(&exp.value, i) } {
+                out = &out * self;
+            }
+        }
+        Ok(out)
+    }
+}
+
+implement_for_owned!(Z, MatZ, Pow);
+implement_for_others!(Z, MatZ, Pow for u8 u16 u32 u64 i8 i16 i32 i64);
+
+#[cfg(test)]
+mod test_pow {
+    use super::*;
+    use std::str::FromStr;
+
+    /// Ensure that `pow` works for small exponents including `0` and `1`
+    #[test]
+    fn small() {
+        let matrix = MatZ::from_str("[[1,1],[1,0]]").unwrap();
+
+        let res_0 = matrix.pow(0).unwrap();
+        let res_1 = matrix.pow(1).unwrap();
+        let res_2 = matrix.pow(&Z::from(10)).unwrap();
+
+        assert_eq!(MatZ::identity(2, 2).unwrap(), res_0);
+        assert_eq!(matrix, res_1);
+        assert_eq!(MatZ::from_str("[[89,55],[55,34]]").unwrap(), res_2);
+    }
+
+    /// Ensure that `pow` works for large entries in the result
+    #[test]
+    fn large_entries() {
+        let matrix = MatZ::from_str("[[2,0],[0,-1]]").unwrap();
+
+        let res = matrix.pow(101).unwrap();
+
+        let cmp =
+            MatZ::from_str(&format!("[[{},0],[0,-1]]", Z::from(2).pow(101).unwrap())).unwrap();
+        assert_eq!(cmp, res);
+    }
+
+    /// Ensure that `pow` works for exponents that do not fit into an [`u64`]
+    #[test]
+    fn large_exponent() {
+        let matrix = MatZ::from_str("[[1,0],[0,-1]]").unwrap();
+        let nilpotent = MatZ::from_str("[[0,1],[0,0]]").unwrap();
+        let exp = Z::from(2).pow(70).unwrap();
+
+        assert_eq!(MatZ::identity(2, 2).unwrap(), matrix.pow(&exp).unwrap());
+        assert_eq!(MatZ::new(2, 2).unwrap(), nilpotent.pow(&exp).unwrap());
+    }
+
+    /// Ensure that `pow` returns an error for negative exponents
+    #[test]
+    fn negative_exponent() {
+        let matrix = MatZ::identity(2, 2).unwrap();
+
+        assert!(matches!(matrix.pow(-1), Err(MathError::InvalidExponent(_))));
+    }
+
+    /// Ensure that `pow` returns an error for non-square matrices
+    #[test]
+    fn non_square() {
+        let matrix = MatZ::new(2, 3).unwrap();
+
+        assert!(matches!(
+            matrix.pow(2),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains constructors of block matrices from several [`MatZ`] instances.
+
+use super::MatZ;
+use crate::{
+    error::MathError,
+    traits::{GetNumColumns, GetNumRows},
+};
+
+impl MatZ {
+    /// Creates the block diagonal matrix with the provided `blocks` on its
+    /// diagonal, starting at the top left, and `0` anywhere else.
+    ///
+    /// Parameters:
+    /// - `blocks`: the blocks on the diagonal, which do not have to be square
+    ///
+    /// Returns the block diagonal matrix or an error, if `blocks` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let block_1 = MatZ::from_str("[[1, 2],[3, 4]]").unwrap();
+    /// let block_2 = MatZ::from_str("[[5, 6]]").unwrap();
+    ///
+    /// let matrix = MatZ::block_diagonal(&[&block_1, &block_2]).unwrap();
+    ///
+    /// let cmp = MatZ::from_str("[[1, 2, 0, 0],[3, 4, 0, 0],[0, 0, 5, 6]]").unwrap();
+    /// assert_eq!(cmp, matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `blocks` is empty.
+    pub fn block_diagonal(blocks: &[&MatZ]) -> Result<Self, MathError> {
+        let num_rows = blocks.iter().map(|block| block.get_num_rows()).sum::<i64>();
+        let num_cols = blocks
+            .iter()
+            .map(|block| block.get_num_columns())
+            .sum::<i64>();
+        let mut out = MatZ::new(num_rows, num_cols)?;
+
+        let (mut row, mut column) = (0, 0);
+        for block in blocks {
+            out.set_submatrix(row, column, block).unwrap();
+            row += block.get_num_rows();
+            column += block.get_num_columns();
+        }
+        Ok(out)
+    }
+
+    /// Creates a block matrix from a grid of `blocks`, i.e. `blocks[i][j]`
+    /// is placed in the `i`-th row and `j`-th column of blocks.
+    /// All blocks in a row of blocks need to have the same number of rows
+    /// and all blocks in a column of blocks the same number of columns.
+    ///
+    /// Parameters:
+    /// - `blocks`: the rows of blocks, starting with the top row
+    ///
+    /// Returns the block matrix or an error, if `blocks` is empty,
+    /// its rows contain different numbers of blocks, the dimensions
+    /// of the blocks do not fit together.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let a = MatZ::from_str("[[1, 2],[3, 4]]").unwrap();
+    /// let b = MatZ::from_str("[[5, 6],[7, 8]]").unwrap();
+    /// let identity = MatZ::identity(2, 2).unwrap();
+    /// let zero = MatZ::new(2, 2).unwrap();
+    ///
+    /// // [[A, I],[0, B]]
+    /// let matrix = MatZ::from_blocks(&[&[&a, &identity], &[&zero, &b]]).unwrap();
+    ///
+    /// let cmp = MatZ::from_str("[[1, 2, 1, 0],[3, 4, 0, 1],[0, 0, 5, 6],[0, 0, 7, 8]]").unwrap();
+    /// assert_eq!(cmp, matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `blocks` is empty or its rows contain different numbers of blocks.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the dimensions of the blocks do not fit together.
+    pub fn from_blocks(blocks: &[&[&MatZ]]) -> Result<Self, MathError> {
+        let block_rows: Vec<i64> = blocks
+            .iter()
+            .map(|row| row.first().map_or(0, |block| block.get_num_rows()))
+            .collect();
+        let block_cols: Vec<i64> = blocks.first().map_or(vec![], |row| {
+            row.iter().map(|block| block.get_num_columns()).collect()
+        });
+
+        for (i, row) in blocks.iter().enumerate() {
+            if row.len() != block_cols.len() {
+                return Err(MathError::InvalidMatrix(format!(
+                    "Each row of blocks has to contain {} blocks, but row {} contains {}.",
+                    block_cols.len(),
+                    i,
+                    row.len()
+                )));
+            }
+            for (j, block) in row.iter().enumerate() {
+                if block.get_num_rows() != block_rows[i] || block.get_num_columns() != block_cols[j]
+                {
+                    return Err(MathError::MismatchingMatrixDimension(format!(
+                        "The block at ({}, {}) has to be a '{}x{}' matrix, but is a '{}x{}' matrix.",
+                        i,
+                        j,
+                        block_rows[i],
+                        block_cols[j],
+                        block.get_num_rows(),
+                        block.get_num_columns()
+                    )));
+                }
+            }
+        }
+
+        let mut out = MatZ::new(
+            block_rows.iter().sum::<i64>(),
+            block_cols.iter().sum::<i64>(),
+        )?;
+        let mut row = 0;
+        for (i, blocks_row) in blocks.iter().enumerate() {
+            let mut column = 0;
+            for (j, block) in blocks_row.iter().enumerate() {
+                out.set_submatrix(row, column, block).unwrap();
+                column += block_cols[j];
+            }
+            row += block_rows[i];
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_block_diagonal {
+    use super::MatZ;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that square and non-square blocks are placed correctly
+    #[test]
+    fn correct_placement() {
+        let block_1 = MatZ::from_str(&format!("[[{}],[2]]", i64::MAX)).unwrap();
+        let block_2 = MatZ::from_str("[[3, 4, 5]]").unwrap();
+        let block_3 = MatZ::from_str(&format!("[[{}]]", i64::MIN)).unwrap();
+
+        let matrix = MatZ::block_diagonal(&[&block_1, &block_2, &block_3]).unwrap();
+
+        let cmp = MatZ::from_str(&format!(
+            "[[{}, 0, 0, 0, 0],[2, 0, 0, 0, 0],[0, 3, 4, 5, 0],[0, 0, 0, 0, {}]]",
+            i64::MAX,
+            i64::MIN
+        ))
+        .unwrap();
+        assert_eq!(cmp, matrix);
+    }
+
+    /// Ensure that a single block results in the block itself
+    #[test]
+    fn single_block() {
+        let block = MatZ::from_str("[[1, 2],[3, 4]]").unwrap();
+
+        assert_eq!(block, MatZ::block_diagonal(&[&block]).unwrap());
+    }
+
+    /// Ensure that an empty slice of blocks results in an error
+    #[test]
+    fn no_blocks() {
+        assert!(matches!(
+            MatZ::block_diagonal(&[]),
+            Err(MathError::InvalidMatrix(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_from_blocks {
+    use super::MatZ;
+    use crate::{error::MathError, traits::Concatenate};
+    use std::str::FromStr;
+
+    /// Ensure that the result equals the concatenation of the blocks
+    #[test]
+    fn same_as_concatenation() {
+        let a = MatZ::from_str(&format!("[[1, {}, 3],[4, 5, 6]]", i64::MAX)).unwrap();
+        let b = MatZ::from_str("[[7],[8]]").unwrap();
+        let c = MatZ::from_str(&format!("[[{}, 2, 3]]", i64::MIN)).unwrap();
+        let d = MatZ::from_str("[[-1]]").unwrap();
+
+        let matrix = MatZ::from_blocks(&[&[&a, &b], &[&c, &d]]).unwrap();
+
+        let top = a.concat_horizontal(&b).unwrap();
+        let bottom = c.concat_horizontal(&d).unwrap();
+        assert_eq!(top.concat_vertical(&bottom).unwrap(), matrix);
+    }
+
+    /// Ensure that rows with different numbers of blocks and empty grids
+    /// result in an error
+    #[test]
+    fn invalid_grid() {
+        let a = MatZ::identity(2, 2).unwrap();
+
+        assert!(matches!(
+            MatZ::from_blocks(&[&[&a, &a], &[&a]]),
+            Err(MathError::InvalidMatrix(_))
+        ));
+        assert!(matches!(
+            MatZ::from_blocks(&[]),
+            Err(MathError::InvalidMatrix(_))
+        ));
+        assert!(matches!(
+            MatZ::from_blocks(&[&[]]),
+            Err(MathError::InvalidMatrix(_))
+        ));
+    }
+
+    /// Ensure that blocks with mismatching dimensions result in an error
+    #[test]
+    fn mismatching_dimensions() {
+        let a = MatZ::new(2, 2).unwrap();
+        let b = MatZ::new(2, 3).unwrap();
+        let c = MatZ::new(3, 2).unwrap();
+
+        assert!(matches!(
+            MatZ::from_blocks(&[&[&a, &c]]),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+        assert!(matches!(
+            MatZ::from_blocks(&[&[&a], &[&b]]),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
@@ -19,7 +19,9 @@ use crate::{
     integer::Z,
     traits::SetEntry,
     utils::{
-        dimensions::find_matrix_dimensions, index::evaluate_index, parse::parse_matrix_string,
+        dimensions::{find_matrix_dimensions, find_slice_matrix_dimensions},
+        index::evaluate_index,
+        parse::parse_matrix_string,
     },
 };
 use flint_sys::fmpz_mat::{fmpz_mat_init, fmpz_mat_one};
@@ -105,6 +107,131 @@ impl MatZ {
         unsafe { fmpz_mat_one(&mut out.matrix) };
         Ok(out)
     }
+
+    /// Generate a square matrix with the provided values on the diagonal
+    /// and `0` anywhere else.
+    ///
+    /// Parameters:
+    /// - `diagonal`: the entries of the diagonal, starting at the top left
+    ///
+    /// Returns a square matrix with `diagonal` as diagonal or an error,
+    /// if `diagonal` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZ::from_diagonal(&[Z::from(2), Z::from(-3)]).unwrap();
+    ///
+    /// assert_eq!(MatZ::from_str("[[2,0],[0,-3]]").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `diagonal` is empty.
+    pub fn from_diagonal(diagonal: &[Z]) -> Result<Self, MathError> {
+        let mut out = MatZ::new(diagonal.len(), diagonal.len())?;
+        for (i, entry) in diagonal.iter().enumerate() {
+            out.set_entry(i, i, entry).unwrap();
+        }
+        Ok(out)
+    }
+
+    /// Creates a matrix from a slice of rows, where each row is a slice of
+    /// entries, which can be converted into [`Z`].
+    ///
+    /// Parameters:
+    /// - `rows`: the rows of the matrix, starting with the top row
+    ///
+    /// Returns a [`MatZ`] or an error, if `rows` is empty, contains empty rows
+    /// or rows of different length.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZ::from_slices(&[[1, 2, 3].as_slice(), &[4, 5, 6]]).unwrap();
+    ///
+    /// assert_eq!(MatZ::from_str("[[1, 2, 3],[4, 5, 6]]").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `rows` is empty, contains empty rows or the number of entries in rows is unequal.
+    pub fn from_slices<T: Into<Z> + Clone>(rows: &[&[T]]) -> Result<Self, MathError> {
+        let (num_rows, num_cols) = find_slice_matrix_dimensions(rows)?;
+        let mut matrix = MatZ::new(num_rows, num_cols)?;
+
+        for (row_num, row) in rows.iter().enumerate() {
+            for (col_num, entry) in row.iter().enumerate() {
+                let entry: Z = entry.clone().into();
+                matrix.set_entry(row_num, col_num, entry).unwrap();
+            }
+        }
+        Ok(matrix)
+    }
+}
+
+impl TryFrom<&[&[Z]]> for MatZ {
+    type Error = MathError;
+    /// Create a [`MatZ`] from a slice of rows using [`from_slices`](MatZ::from_slices)
+    fn try_from(rows: &[&[Z]]) -> Result<Self, Self::Error> {
+        MatZ::from_slices(rows)
+    }
+}
+
+impl From<Vec<Vec<i64>>> for MatZ {
+    /// Creates a matrix from a vector of rows, where each row is a vector of entries.
+    ///
+    /// Parameters:
+    /// - `rows`: the rows of the matrix, starting with the top row
+    ///
+    /// Returns a [`MatZ`] with the given entries.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZ::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    ///
+    /// assert_eq!(MatZ::from_str("[[1, 2, 3],[4, 5, 6]]").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if `rows` is empty, contains empty rows or rows of different length.
+    fn from(rows: Vec<Vec<i64>>) -> Self {
+        let rows: Vec<&[i64]> = rows.iter().map(Vec::as_slice).collect();
+        MatZ::from_slices(&rows).unwrap()
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> From<[[i64; COLS]; ROWS]> for MatZ {
+    /// Creates a `ROWS x COLS` matrix from an array of rows.
+    ///
+    /// Parameters:
+    /// - `rows`: the rows of the matrix, starting with the top row
+    ///
+    /// Returns a [`MatZ`] with the given entries.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZ::from([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(MatZ::from_str("[[1, 2, 3],[4, 5, 6]]").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if `ROWS` or `COLS` is `0`.
+    fn from(rows: [[i64; COLS]; ROWS]) -> Self {
+        let rows: Vec<&[i64]> = rows.iter().map(|row| row.as_slice()).collect();
+        MatZ::from_slices(&rows).unwrap()
+    }
 }
 
 impl FromStr for MatZ {
@@ -246,6 +373,44 @@ mod test_set_one {
     }
 }
 
+#[cfg(test)]
+mod test_from_diagonal {
+    use crate::{
+        error::MathError,
+        integer::{MatZ, Z},
+    };
+    use std::str::FromStr;
+
+    /// Tests if the diagonal is correctly set
+    #[test]
+    fn correct_diagonal() {
+        let matrix = MatZ::from_diagonal(&[Z::from(1), Z::from(-2), Z::ZERO]).unwrap();
+
+        assert_eq!(
+            MatZ::from_str("[[1,0,0],[0,-2,0],[0,0,0]]").unwrap(),
+            matrix
+        );
+    }
+
+    /// Tests if large entries are correctly set
+    #[test]
+    fn large_entries() {
+        let matrix = MatZ::from_diagonal(&[Z::from(i64::MIN), Z::from(u64::MAX)]).unwrap();
+        let cmp = MatZ::from_str(&format!("[[{},0],[0,{}]]", i64::MIN, u64::MAX)).unwrap();
+
+        assert_eq!(cmp, matrix);
+    }
+
+    /// Tests if an empty diagonal returns an error
+    #[test]
+    fn empty() {
+        assert!(matches!(
+            MatZ::from_diagonal(&[]),
+            Err(MathError::InvalidMatrix(_))
+        ));
+    }
+}
+
 #[cfg(test)]
 mod test_from_str {
     use crate::{
@@ -336,3 +501,67 @@ mod test_from_str {
         assert!(MatZ::from_str(&matrix_string9).is_err());
     }
 }
+
+#[cfg(test)]
+mod test_from_slices {
+    use crate::{
+        error::MathError,
+        integer::{MatZ, Z},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that matrices are built correctly from slices of [`Z`] and primitive integers
+    #[test]
+    fn correct_entries() {
+        let row_0 = [Z::from(u64::MAX), Z::ZERO];
+        let row_1 = [Z::from(-3), Z::ONE];
+
+        let matrix_z = MatZ::try_from([row_0.as_slice(), &row_1].as_slice()).unwrap();
+        let matrix_u8 = MatZ::from_slices(&[[17_u8].as_slice(), &[42]]).unwrap();
+
+        assert_eq!(
+            MatZ::from_str(&format!("[[{}, 0],[-3, 1]]", u64::MAX)).unwrap(),
+            matrix_z
+        );
+        assert_eq!(MatZ::from_str("[[17],[42]]").unwrap(), matrix_u8);
+    }
+
+    /// Ensure that vectors and arrays of [`i64`] are converted correctly
+    #[test]
+    fn from_vec_and_array() {
+        let cmp = MatZ::from_str(&format!("[[{}, 0, -1],[2, {}, 3]]", i64::MIN, i64::MAX)).unwrap();
+
+        let from_vec = MatZ::from(vec![vec![i64::MIN, 0, -1], vec![2, i64::MAX, 3]]);
+        let from_array = MatZ::from([[i64::MIN, 0, -1], [2, i64::MAX, 3]]);
+
+        assert_eq!(cmp, from_vec);
+        assert_eq!(cmp, from_array);
+    }
+
+    /// Ensure that rows of different length and empty matrices result in an error
+    #[test]
+    fn invalid_dimensions() {
+        let empty: [i64; 0] = [];
+
+        assert!(matches!(
+            MatZ::from_slices(&[[1, 2].as_slice(), &[3]]),
+            Err(MathError::InvalidMatrix(_))
+        ));
+        assert!(MatZ::from_slices::<i64>(&[]).is_err());
+        assert!(MatZ::from_slices(&[empty.as_slice()]).is_err());
+    }
+
+    /// Ensure that rows of different length result in a panic
+    #[test]
+    #[should_panic]
+    fn unequal_rows_panic() {
+        let _ = MatZ::from(vec![vec![1, 2], vec![3]]);
+    }
+
+    /// Ensure that an array without entries results in a panic
+    #[test]
+    #[should_panic]
+    fn empty_array_panic() {
+        let _ = MatZ::from([[0_i64; 0]; 2]);
+    }
+}
@@ -0,0 +1,286 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the construction of gadget matrices and
+//! the corresponding base-`b` digit decomposition `G^{-1}(·)`.
+
+use super::MatZ;
+use crate::{
+    error::MathError,
+    integer::Z,
+    traits::{GetEntry, GetNumColumns, GetNumRows},
+    utils::index::evaluate_index,
+};
+use flint_sys::{
+    fmpz::{fmpz_is_zero, fmpz_set, fmpz_swap, fmpz_tdiv_qr},
+    fmpz_mat::fmpz_mat_entry,
+};
+use std::fmt::Display;
+
+impl MatZ {
+    /// Generates the gadget matrix `G = I_n ⊗ (1, b, ..., b^{k-1})`,
+    /// i.e. the `n x (n * k)` matrix whose `i`-th row contains the powers
+    /// `b^0, ..., b^{k-1}` of the `base` in the columns `i * k, ..., (i + 1) * k - 1`.
+    ///
+    /// Parameters:
+    /// - `base`: the base `b` of the gadget vector
+    /// - `k`: the number of powers of `base` in the gadget vector
+    /// - `n`: the number of rows of the gadget matrix
+    ///
+    /// Returns the gadget matrix or an error if the base is not greater than `1`
+    /// or `k` and `n` are not suited to create a matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let gadget = MatZ::gadget(2, 3, 2).unwrap();
+    ///
+    /// assert_eq!(MatZ::from_str("[[1, 2, 4, 0, 0, 0],[0, 0, 0, 1, 2, 4]]").unwrap(), gadget);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidBase`](MathError::InvalidBase)
+    /// if the `base` is not greater than `1`.
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix) or
+    /// [`OutOfBounds`](MathError::OutOfBounds) if `k` or `n` are not suited
+    /// to create a matrix. For further information see [`MatZ::new`].
+    pub fn gadget(
+        base: impl Into<Z>,
+        k: impl TryInto<i64> + Display + Copy,
+        n: impl TryInto<i64> + Display + Copy,
+    ) -> Result<Self, MathError> {
+        let base = check_gadget_base(base.into())?;
+        let k = evaluate_index(k)?;
+        let n = evaluate_index(n)?;
+
+        let out = MatZ::new(n, n * k)?;
+        let mut power = Z::ONE;
+        for j in 0..k {
+            for i in 0..n {
+                unsafe { fmpz_set(fmpz_mat_entry(&out.matrix, i, i * k + j), &power.value) };
+            }
+            power = power * &base;
+        }
+        Ok(out)
+    }
+
+    /// Computes the base-`b` digit decomposition `G^{-1}(self)` with respect to
+    /// the gadget matrix `G = MatZ::gadget(base, k, m)`, where `m` is the number
+    /// of rows of `self`. The resulting `(m * k) x n` matrix `X` satisfies `G * X = self`
+    /// and all its entries are smaller than `base` in absolute value.
+    ///
+    /// Each entry is decomposed into its `k` least significant digits w.r.t. `base`,
+    /// where the digits of negative entries are negative.
+    ///
+    /// Parameters:
+    /// - `base`: the base `b` of the gadget vector
+    /// - `k`: the number of digits every entry is decomposed into
+    ///
+    /// Returns the decomposition of `self` or an error if the base is not
+    /// greater than `1`, `k` is not positive, or an entry can not be represented
+    /// with `k` digits.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatZ::from_str("[[5, -3],[2, 7]]").unwrap();
+    ///
+    /// let decomposition = mat.decompose(2, 3).unwrap();
+    ///
+    /// let expected = MatZ::from_str("[[1, -1],[0, -1],[1, 0],[0, 1],[1, 1],[0, 1]]").unwrap();
+    /// assert_eq!(expected, decomposition);
+    /// assert_eq!(mat, MatZ::gadget(2, 3, 2).unwrap() * decomposition);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidBase`](MathError::InvalidBase)
+    /// if the `base` is not greater than `1`.
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix) or
+    /// [`OutOfBounds`](MathError::OutOfBounds) if `k` is not suited to create a matrix.
+    /// For further information see [`MatZ::new`].
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the absolute value of an entry is not smaller than `base^k`.
+    pub fn decompose(
+        &self,
+        base: impl Into<Z>,
+        k: impl TryInto<i64> + Display + Copy,
+    ) -> Result<Self, MathError> {
+        let base = check_gadget_base(base.into())?;
+        let k = evaluate_index(k)?;
+
+        let out = MatZ::new(self.get_num_rows() * k, self.get_num_columns())?;
+        let mut quotient = Z::default();
+        let mut remainder = Z::default();
+        for row in 0..self.get_num_rows() {
+            for column in 0..self.get_num_columns() {
+                let mut value = Z::default();
+                unsafe { fmpz_set(&mut value.value, fmpz_mat_entry(&self.matrix, row, column)) };
+
+                // truncated division keeps the sign of `value` for every digit
+                for j in 0..k {
+                    unsafe {
+                        fmpz_tdiv_qr(
+                            &mut quotient.value,
+                            &mut remainder.value,
+                            &value.value,
+                            &base.value,
+                        );
+                        fmpz_set(
+                            fmpz_mat_entry(&out.matrix, row * k + j, column),
+                            &remainder.value,
+                        );
+                        fmpz_swap(&mut value.value, &mut quotient.value);
+                    }
+                }
+
+                if unsafe { 1 != fmpz_is_zero(&value.value) } {
+                    let entry: Z = self.get_entry(row, column).unwrap();
+                    return Err(MathError::OutOfBounds(
+                        format!("be smaller than {}^{} in absolute value", base, k),
+                        entry.to_string(),
+                    ));
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Checks whether the provided `base` is suited for a gadget matrix,
+/// i.e. whether it is greater than `1`.
+///
+/// Parameters:
+/// - `base`: the base of the gadget vector
+///
+/// Returns the `base` or an error if it is not greater than `1`.
+///
+/// # Errors and Failures
+/// - Returns a [`MathError`] of type [`InvalidBase`](MathError::InvalidBase)
+/// if the `base` is not greater than `1`.
+fn check_gadget_base(base: Z) -> Result<Z, MathError> {
+    if base <= Z::ONE {
+        return Err(MathError::InvalidBase(format!(
+            "The base of a gadget matrix must be greater than 1, but is {}.",
+            base
+        )));
+    }
+    Ok(base)
+}
+
+#[cfg(test)]
+mod test_gadget {
+    use super::MatZ;
+    use crate::{error::MathError, integer::Z, traits::GetEntry};
+    use std::str::FromStr;
+
+    /// Ensure that the gadget matrix has the correct structure
+    #[test]
+    fn correct_structure() {
+        let gadget = MatZ::gadget(3, 4, 3).unwrap();
+
+        let gadget_vector = MatZ::from_str("[[1, 3, 9, 27]]").unwrap();
+        let zero = MatZ::new(1, 4).unwrap();
+        assert_eq!(gadget_vector, gadget.get_submatrix(0..1, 0..4).unwrap());
+        assert_eq!(gadget_vector, gadget.get_submatrix(1..2, 4..8).unwrap());
+        assert_eq!(gadget_vector, gadget.get_submatrix(2..3, 8..12).unwrap());
+        assert_eq!(zero, gadget.get_submatrix(0..1, 4..8).unwrap());
+        assert_eq!(zero, gadget.get_submatrix(2..3, 0..4).unwrap());
+    }
+
+    /// Ensure that large bases are supported
+    #[test]
+    fn large_base() {
+        let gadget = MatZ::gadget(u64::MAX, 3, 1).unwrap();
+
+        let entry: Z = gadget.get_entry(0, 2).unwrap();
+        assert_eq!(Z::from(u64::MAX) * Z::from(u64::MAX), entry);
+    }
+
+    /// Ensure that invalid bases result in an error
+    #[test]
+    fn invalid_base() {
+        assert!(matches!(
+            MatZ::gadget(1, 2, 2),
+            Err(MathError::InvalidBase(_))
+        ));
+        assert!(matches!(
+            MatZ::gadget(-2, 2, 2),
+            Err(MathError::InvalidBase(_))
+        ));
+    }
+
+    /// Ensure that invalid dimensions result in an error
+    #[test]
+    fn invalid_dimensions() {
+        assert!(MatZ::gadget(2, 0, 2).is_err());
+        assert!(MatZ::gadget(2, 2, 0).is_err());
+        assert!(MatZ::gadget(2, -1, 2).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_decompose {
+    use super::MatZ;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that the decomposition is correct for small entries
+    #[test]
+    fn correct_digits() {
+        let mat = MatZ::from_str("[[26, 0, -8]]").unwrap();
+
+        let decomposition = mat.decompose(3, 3).unwrap();
+
+        let expected = MatZ::from_str("[[2, 0, -2],[2, 0, -2],[2, 0, 0]]").unwrap();
+        assert_eq!(expected, decomposition);
+    }
+
+    /// Ensure that `G * G^{-1}(A) = A` holds
+    #[test]
+    fn inverse_of_gadget() {
+        let mat = MatZ::from_str(&format!(
+            "[[{}, -17, 1],[0, {}, 255],[-1, 1024, 3]]",
+            i64::MAX,
+            i64::MIN
+        ))
+        .unwrap();
+
+        let decomposition = mat.decompose(2, 64).unwrap();
+
+        assert_eq!(mat, MatZ::gadget(2, 64, 3).unwrap() * &decomposition);
+        assert!(decomposition.max_abs_entry() <= 1.into());
+    }
+
+    /// Ensure that entries that can not be represented with `k` digits
+    /// result in an error
+    #[test]
+    fn too_large_entry() {
+        let mat = MatZ::from_str("[[7, 8]]").unwrap();
+
+        assert!(mat.decompose(2, 3).is_err());
+        assert!(matches!(
+            MatZ::from_str("[[-8]]").unwrap().decompose(2, 3),
+            Err(MathError::OutOfBounds(_, _))
+        ));
+    }
+
+    /// Ensure that invalid bases result in an error
+    #[test]
+    fn invalid_base() {
+        let mat = MatZ::from_str("[[7, 8]]").unwrap();
+
+        assert!(matches!(
+            mat.decompose(0, 3),
+            Err(MathError::InvalidBase(_))
+        ));
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the `gram` function.
+
+use super::MatZ;
+use crate::traits::GetNumRows;
+use flint_sys::fmpz_mat::fmpz_mat_gram;
+
+impl MatZ {
+    /// Returns the Gram matrix `B * B^T` of the given matrix `B`,
+    /// i.e. the matrix of the pairwise inner products of the rows of `B`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let basis = MatZ::from_str("[[1, 2, 0],[3, -1, 4]]").unwrap();
+    ///
+    /// let gram = basis.gram();
+    ///
+    /// assert_eq!(MatZ::from_str("[[5, 1],[1, 26]]").unwrap(), gram);
+    /// ```
+    pub fn gram(&self) -> Self {
+        let mut out = Self::new(self.get_num_rows(), self.get_num_rows()).unwrap();
+        unsafe { fmpz_mat_gram(&mut out.matrix, &self.matrix) };
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_gram {
+    use super::MatZ;
+    use std::str::FromStr;
+
+    /// Ensure that the Gram matrix equals `B * B^T`
+    #[test]
+    fn same_as_mul_transpose() {
+        let basis = MatZ::from_str(&format!(
+            "[[1, {}, 0, -3],[3, -1, 4, {}],[0, 0, 7, 1]]",
+            i64::MAX,
+            i64::MIN
+        ))
+        .unwrap();
+
+        let gram = basis.gram();
+
+        assert_eq!(&basis * &basis.transpose(), gram);
+        assert_eq!(gram, gram.transpose());
+    }
+
+    /// Ensure that row and column vectors result in the squared norm
+    /// and the outer product respectively
+    #[test]
+    fn vectors() {
+        let row = MatZ::from_str("[[1, -2, 3]]").unwrap();
+
+        assert_eq!(MatZ::from_str("[[14]]").unwrap(), row.gram());
+        assert_eq!(
+            MatZ::from_str("[[1, -2, 3],[-2, 4, -6],[3, -6, 9]]").unwrap(),
+            row.transpose().gram()
+        );
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the computation of the Hermite normal form of a [`MatZ`].
+
+use super::MatZ;
+use crate::traits::{GetNumColumns, GetNumRows};
+use flint_sys::fmpz_mat::{fmpz_mat_hnf, fmpz_mat_hnf_transform};
+
+impl MatZ {
+    /// Computes the row-style Hermite normal form `H` of the matrix.
+    ///
+    /// `H` is upper triangular, its zero rows are at the bottom,
+    /// the pivot of each non-zero row is positive and lies strictly to the right
+    /// of the pivot of the row above, and all entries above a pivot are
+    /// non-negative and smaller than the pivot.
+    /// In particular, `H` is a canonical basis of the lattice spanned by the rows of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatZ::from_str("[[2,3],[4,5]]").unwrap();
+    ///
+    /// let hnf = mat.hnf();
+    ///
+    /// assert_eq!(MatZ::from_str("[[2,1],[0,1]]").unwrap(), hnf);
+    /// ```
+    pub fn hnf(&self) -> Self {
+        let mut out = MatZ::new(self.get_num_rows(), self.get_num_columns()).unwrap();
+        unsafe { fmpz_mat_hnf(&mut out.matrix, &self.matrix) };
+        out
+    }
+
+    /// Computes the row-style Hermite normal form `H` of the matrix
+    /// together with a unimodular transformation `U` s.t. `U * self = H`.
+    /// See [`MatZ::hnf`] for the properties of `H`.
+    ///
+    /// Returns a tuple `(H, U)`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatZ::from_str("[[2,3],[4,5]]").unwrap();
+    ///
+    /// let (hnf, transform) = mat.hnf_transform();
+    ///
+    /// assert_eq!(mat.hnf(), hnf);
+    /// assert_eq!(hnf, &transform * &mat);
+    /// ```
+    pub fn hnf_transform(&self) -> (Self, Self) {
+        let mut hnf = MatZ::new(self.get_num_rows(), self.get_num_columns()).unwrap();
+        let mut transform = MatZ::new(self.get_num_rows(), self.get_num_rows()).unwrap();
+        unsafe { fmpz_mat_hnf_transform(&mut hnf.matrix, &mut transform.matrix, &self.matrix) };
+        (hnf, transform)
+    }
+}
+
+#[cfg(test)]
+mod test_hnf {
+    use super::MatZ;
+    use std::str::FromStr;
+
+    /// Checks if the HNF is correctly computed for a square matrix
+    #[test]
+    fn square() {
+        let mat = MatZ::from_str("[[2,3,6],[3,4,8],[4,5,9]]").unwrap();
+        let cmp = MatZ::from_str("[[1,0,0],[0,1,0],[0,0,1]]").unwrap();
+
+        assert_eq!(cmp, mat.hnf());
+    }
+
+    /// Checks if zero rows are moved to the bottom
+    #[test]
+    fn dependent_rows() {
+        let mat = MatZ::from_str("[[0,0],[3,6],[2,4]]").unwrap();
+        let cmp = MatZ::from_str("[[1,2],[0,0],[0,0]]").unwrap();
+
+        assert_eq!(cmp, mat.hnf());
+    }
+
+    /// Checks if bases of the same lattice have the same HNF
+    #[test]
+    fn canonical() {
+        let mat = MatZ::from_str("[[1,7],[0,3]]").unwrap();
+        let other_basis = MatZ::from_str("[[1,10],[1,7]]").unwrap();
+
+        assert_eq!(mat.hnf(), other_basis.hnf());
+        assert_eq!(MatZ::from_str("[[1,1],[0,3]]").unwrap(), mat.hnf());
+    }
+
+    /// Checks if the HNF is correctly computed for large entries
+    #[test]
+    fn large_entries() {
+        let mat = MatZ::from_str(&format!("[[{},0],[0,1]]", i64::MIN)).unwrap();
+        let cmp = MatZ::from_str(&format!("[[{},0],[0,1]]", i64::MAX as u64 + 1)).unwrap();
+
+        assert_eq!(cmp, mat.hnf());
+    }
+}
+
+#[cfg(test)]
+mod test_hnf_transform {
+    use super::MatZ;
+    use std::str::FromStr;
+
+    /// Checks if the transformation maps the matrix to its HNF
+    #[test]
+    fn correct_transformation() {
+        let mat = MatZ::from_str("[[2,3,6],[3,4,8],[4,5,9],[1,1,1]]").unwrap();
+
+        let (hnf, transform) = mat.hnf_transform();
+
+        assert_eq!(mat.hnf(), hnf);
+        assert_eq!(hnf, &transform * &mat);
+    }
+
+    /// Checks if the transformation is unimodular
+    #[test]
+    fn unimodular() {
+        let mat = MatZ::from_str("[[0,0],[3,6],[2,4]]").unwrap();
+
+        let (_, transform) = mat.hnf_transform();
+
+        assert_eq!(3, transform.rank());
+        assert_eq!(transform.hnf(), MatZ::identity(3, 3).unwrap());
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains in-place variants of the transposition, negation and
+//! scalar multiplication of [`MatZ`], which reuse the memory of the matrix.
+
+use super::MatZ;
+use crate::integer::Z;
+use crate::traits::{GetNumColumns, GetNumRows};
+use flint_sys::fmpz_mat::{
+    fmpz_mat_neg, fmpz_mat_scalar_mul_fmpz, fmpz_mat_struct, fmpz_mat_transpose,
+};
+
+impl MatZ {
+    /// Transposes the matrix in place, i.e. rows get transformed to columns
+    /// and vice versa. Square matrices are transposed without allocating
+    /// new memory, all others are replaced by their transposed form.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let mut mat = MatZ::from_str("[[1, 2, 3],[4, 5, 6]]").unwrap();
+    ///
+    /// mat.transpose_inplace();
+    ///
+    /// assert_eq!(MatZ::from_str("[[1, 4],[2, 5],[3, 6]]").unwrap(), mat);
+    /// ```
+    pub fn transpose_inplace(&mut self) {
+        if self.get_num_rows() == self.get_num_columns() {
+            let matrix: *mut fmpz_mat_struct = &mut self.matrix;
+            unsafe { fmpz_mat_transpose(matrix, matrix) };
+        } else {
+            *self = self.transpose();
+        }
+    }
+
+    /// Negates every entry of the matrix in place.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let mut mat = MatZ::from_str("[[1, 2, 3],[4, 5, 6]]").unwrap();
+    ///
+    /// mat.negate_inplace();
+    ///
+    /// assert_eq!(MatZ::from_str("[[-1, -2, -3],[-4, -5, -6]]").unwrap(), mat);
+    /// ```
+    pub fn negate_inplace(&mut self) {
+        let matrix: *mut fmpz_mat_struct = &mut self.matrix;
+        unsafe { fmpz_mat_neg(matrix, matrix) };
+    }
+
+    /// Multiplies every entry of the matrix in place by the integer `scalar`.
+    ///
+    /// Parameters:
+    /// - `scalar`: specifies the scalar by which the matrix is multiplied
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let mut mat = MatZ::from_str("[[1, 2, 3],[4, 5, 6]]").unwrap();
+    ///
+    /// mat.scalar_mul_assign(&Z::from(-2));
+    ///
+    /// assert_eq!(MatZ::from_str("[[-2, -4, -6],[-8, -10, -12]]").unwrap(), mat);
+    /// ```
+    pub fn scalar_mul_assign(&mut self, scalar: &Z) {
+        let matrix: *mut fmpz_mat_struct = &mut self.matrix;
+        unsafe { fmpz_mat_scalar_mul_fmpz(matrix, matrix, &scalar.value) };
+    }
+}
+
+#[cfg(test)]
+mod test_transpose_inplace {
+    use super::MatZ;
+    use std::str::FromStr;
+
+    /// Ensure that square and non-square matrices are transposed correctly
+    #[test]
+    fn correct_transpose() {
+        let mut square = MatZ::from_str(&format!("[[1, {}],[{}, 4]]", i64::MAX, u64::MAX)).unwrap();
+        let mut row = MatZ::from_str(&format!("[[1, 2, {}]]", i64::MIN)).unwrap();
+        let cmp_square = square.transpose();
+        let cmp_row = row.transpose();
+
+        square.transpose_inplace();
+        row.transpose_inplace();
+
+        assert_eq!(cmp_square, square);
+        assert_eq!(cmp_row, row);
+    }
+}
+
+#[cfg(test)]
+mod test_negate_inplace {
+    use super::MatZ;
+    use std::str::FromStr;
+
+    /// Ensure that all entries are negated
+    #[test]
+    fn correct_negation() {
+        let mut mat = MatZ::from_str(&format!("[[1, 0],[{}, -4]]", u64::MAX)).unwrap();
+
+        mat.negate_inplace();
+
+        assert_eq!(
+            MatZ::from_str(&format!("[[-1, 0],[-{}, 4]]", u64::MAX)).unwrap(),
+            mat
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_scalar_mul_assign {
+    use super::{MatZ, Z};
+    use std::str::FromStr;
+
+    /// Ensure that the result equals the out-of-place scalar multiplication
+    #[test]
+    fn same_as_mul() {
+        let mut mat = MatZ::from_str(&format!("[[1, 0, -7],[{}, -4, 2]]", i64::MAX)).unwrap();
+        let scalar = Z::from(u64::MAX);
+        let cmp = &mat * &scalar;
+
+        mat.scalar_mul_assign(&scalar);
+
+        assert_eq!(cmp, mat);
+    }
+}
@@ -0,0 +1,289 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains conversions between [`MatZ`] and the matrix types of
+//! [`nalgebra`](https://docs.rs/nalgebra) and [`ndarray`](https://docs.rs/ndarray),
+//! which are available behind the features `nalgebra` and `ndarray` respectively.
+
+use super::MatZ;
+use crate::{
+    error::MathError,
+    integer::Z,
+    traits::{GetEntry, GetNumColumns, GetNumRows, SetEntry},
+};
+
+/// Collects the entries of `matrix` in row-major order as [`i64`] values.
+///
+/// Parameters:
+/// - `matrix`: the matrix whose entries are collected
+///
+/// Returns the entries of `matrix` or an error if an entry does not fit into an [`i64`].
+///
+/// # Errors and Failures
+/// - Returns a [`MathError`] of type [`ConversionError`](MathError::ConversionError)
+/// if an entry of `matrix` does not fit into an [`i64`].
+fn collect_i64_entries(matrix: &MatZ) -> Result<Vec<i64>, MathError> {
+    let mut entries =
+        Vec::with_capacity((matrix.get_num_rows() * matrix.get_num_columns()) as usize);
+    for row in 0..matrix.get_num_rows() {
+        for column in 0..matrix.get_num_columns() {
+            let entry: Z = matrix.get_entry(row, column).unwrap();
+            entries.push(i64::try_from(&entry)?);
+        }
+    }
+    Ok(entries)
+}
+
+/// Creates a [`MatZ`] with `num_rows` rows and `num_cols` columns
+/// whose entry `(i, j)` is given by `entry(i, j)`.
+///
+/// Returns the matrix or an error if `num_rows` or `num_cols` is `0`.
+///
+/// # Errors and Failures
+/// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+/// if the number of rows or columns is `0`.
+fn mat_z_from_fn(
+    num_rows: usize,
+    num_cols: usize,
+    entry: impl Fn(usize, usize) -> i64,
+) -> Result<MatZ, MathError> {
+    let mut out = MatZ::new(num_rows, num_cols)?;
+    for row in 0..num_rows {
+        for column in 0..num_cols {
+            out.set_entry(row, column, entry(row, column)).unwrap();
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(feature = "nalgebra")]
+impl TryFrom<&MatZ> for nalgebra::DMatrix<i64> {
+    type Error = MathError;
+
+    /// Converts a [`MatZ`] into a [`DMatrix`](nalgebra::DMatrix) with [`i64`] entries.
+    ///
+    /// Parameters:
+    /// - `matrix`: the matrix that is converted
+    ///
+    /// Returns the converted matrix or an error if an entry does not fit into an [`i64`].
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::DMatrix;
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZ::from_str("[[1, 2, 3],[4, 5, 6]]").unwrap();
+    ///
+    /// let converted = DMatrix::<i64>::try_from(&matrix).unwrap();
+    ///
+    /// assert_eq!(DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]), converted);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`ConversionError`](MathError::ConversionError)
+    /// if an entry of `matrix` does not fit into an [`i64`].
+    fn try_from(matrix: &MatZ) -> Result<Self, Self::Error> {
+        Ok(nalgebra::DMatrix::from_row_slice(
+            matrix.get_num_rows() as usize,
+            matrix.get_num_columns() as usize,
+            &collect_i64_entries(matrix)?,
+        ))
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl TryFrom<&nalgebra::DMatrix<i64>> for MatZ {
+    type Error = MathError;
+
+    /// Converts a [`DMatrix`](nalgebra::DMatrix) with [`i64`] entries into a [`MatZ`].
+    ///
+    /// Parameters:
+    /// - `matrix`: the matrix that is converted
+    ///
+    /// Returns the converted matrix or an error if `matrix` has no rows or columns.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::DMatrix;
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = DMatrix::from_row_slice(2, 3, &[1_i64, 2, 3, 4, 5, 6]);
+    ///
+    /// let converted = MatZ::try_from(&matrix).unwrap();
+    ///
+    /// assert_eq!(MatZ::from_str("[[1, 2, 3],[4, 5, 6]]").unwrap(), converted);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `matrix` has no rows or columns.
+    fn try_from(matrix: &nalgebra::DMatrix<i64>) -> Result<Self, Self::Error> {
+        mat_z_from_fn(matrix.nrows(), matrix.ncols(), |row, column| {
+            matrix[(row, column)]
+        })
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl TryFrom<&MatZ> for ndarray::Array2<i64> {
+    type Error = MathError;
+
+    /// Converts a [`MatZ`] into an [`Array2`](ndarray::Array2) with [`i64`] entries.
+    ///
+    /// Parameters:
+    /// - `matrix`: the matrix that is converted
+    ///
+    /// Returns the converted matrix or an error if an entry does not fit into an [`i64`].
+    ///
+    /// # Example
+    /// ```
+    /// use ndarray::{array, Array2};
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZ::from_str("[[1, 2, 3],[4, 5, 6]]").unwrap();
+    ///
+    /// let converted = Array2::<i64>::try_from(&matrix).unwrap();
+    ///
+    /// assert_eq!(array![[1, 2, 3], [4, 5, 6]], converted);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`ConversionError`](MathError::ConversionError)
+    /// if an entry of `matrix` does not fit into an [`i64`].
+    fn try_from(matrix: &MatZ) -> Result<Self, Self::Error> {
+        let shape = (
+            matrix.get_num_rows() as usize,
+            matrix.get_num_columns() as usize,
+        );
+        // the number of collected entries always matches the shape
+        Ok(ndarray::Array2::from_shape_vec(shape, collect_i64_entries(matrix)?).unwrap())
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl TryFrom<&ndarray::Array2<i64>> for MatZ {
+    type Error = MathError;
+
+    /// Converts an [`Array2`](ndarray::Array2) with [`i64`] entries into a [`MatZ`].
+    ///
+    /// Parameters:
+    /// - `matrix`: the matrix that is converted
+    ///
+    /// Returns the converted matrix or an error if `matrix` has no rows or columns.
+    ///
+    /// # Example
+    /// ```
+    /// use ndarray::array;
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = array![[1_i64, 2, 3], [4, 5, 6]];
+    ///
+    /// let converted = MatZ::try_from(&matrix).unwrap();
+    ///
+    /// assert_eq!(MatZ::from_str("[[1, 2, 3],[4, 5, 6]]").unwrap(), converted);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `matrix` has no rows or columns.
+    fn try_from(matrix: &ndarray::Array2<i64>) -> Result<Self, Self::Error> {
+        mat_z_from_fn(matrix.nrows(), matrix.ncols(), |row, column| {
+            matrix[[row, column]]
+        })
+    }
+}
+
+#[cfg(all(test, feature = "nalgebra"))]
+mod test_nalgebra {
+    use super::MatZ;
+    use crate::error::MathError;
+    use nalgebra::DMatrix;
+    use std::str::FromStr;
+
+    /// Ensure that converting back and forth results in the same matrix
+    #[test]
+    fn round_trip() {
+        let matrix =
+            MatZ::from_str(&format!("[[{}, 0],[-1, {}],[3, 4]]", i64::MAX, i64::MIN)).unwrap();
+
+        let converted = DMatrix::<i64>::try_from(&matrix).unwrap();
+
+        assert_eq!((3, 2), converted.shape());
+        assert_eq!(i64::MIN, converted[(1, 1)]);
+        assert_eq!(matrix, MatZ::try_from(&converted).unwrap());
+    }
+
+    /// Ensure that entries not fitting into an [`i64`] result in an error
+    #[test]
+    fn large_entry() {
+        let matrix = MatZ::from_str(&format!("[[1, {}]]", u64::MAX)).unwrap();
+
+        assert!(matches!(
+            DMatrix::<i64>::try_from(&matrix),
+            Err(MathError::ConversionError(_))
+        ));
+    }
+
+    /// Ensure that empty matrices can not be converted into a [`MatZ`]
+    #[test]
+    fn empty_matrix() {
+        let matrix = DMatrix::<i64>::zeros(0, 3);
+
+        assert!(matches!(
+            MatZ::try_from(&matrix),
+            Err(MathError::InvalidMatrix(_))
+        ));
+    }
+}
+
+#[cfg(all(test, feature = "ndarray"))]
+mod test_ndarray {
+    use super::MatZ;
+    use crate::error::MathError;
+    use ndarray::Array2;
+    use std::str::FromStr;
+
+    /// Ensure that converting back and forth results in the same matrix
+    #[test]
+    fn round_trip() {
+        let matrix =
+            MatZ::from_str(&format!("[[{}, 0],[-1, {}],[3, 4]]", i64::MAX, i64::MIN)).unwrap();
+
+        let converted = Array2::<i64>::try_from(&matrix).unwrap();
+
+        assert_eq!(&[3, 2], converted.shape());
+        assert_eq!(i64::MIN, converted[[1, 1]]);
+        assert_eq!(matrix, MatZ::try_from(&converted).unwrap());
+    }
+
+    /// Ensure that entries not fitting into an [`i64`] result in an error
+    #[test]
+    fn large_entry() {
+        let matrix = MatZ::from_str(&format!("[[1, {}]]", u64::MAX)).unwrap();
+
+        assert!(matches!(
+            Array2::<i64>::try_from(&matrix),
+            Err(MathError::ConversionError(_))
+        ));
+    }
+
+    /// Ensure that empty matrices can not be converted into a [`MatZ`]
+    #[test]
+    fn empty_matrix() {
+        let matrix = Array2::<i64>::zeros((2, 0));
+
+        assert!(matches!(
+            MatZ::try_from(&matrix),
+            Err(MathError::InvalidMatrix(_))
+        ));
+    }
+}
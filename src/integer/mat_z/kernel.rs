@@ -0,0 +1,128 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the `kernel` function.
+
+use super::MatZ;
+use crate::traits::GetNumColumns;
+use flint_sys::{fmpz::fmpz_set, fmpz_mat::fmpz_mat_entry};
+
+impl MatZ {
+    /// Computes an integral basis of the right kernel of the matrix,
+    /// i.e. of the lattice of all integer vectors `x` with `self * x = 0`.
+    ///
+    /// The basis is obtained from the unimodular transformation `U` of the
+    /// Hermite normal form `U * self^T = H` (see [`MatZ::hnf_transform`]),
+    /// where the rows of `U` corresponding to the zero rows of `H` form
+    /// a basis of the kernel.
+    ///
+    /// Returns a matrix whose columns form a basis of the right kernel.
+    /// If the kernel is trivial, the zero column vector is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatZ::from_str("[[1,2,3],[2,4,7]]").unwrap();
+    ///
+    /// let kernel = mat.kernel();
+    ///
+    /// assert_eq!(MatZ::new(2, 1).unwrap(), &mat * &kernel);
+    /// assert_eq!(1, kernel.rank());
+    /// ```
+    pub fn kernel(&self) -> Self {
+        let num_columns = self.get_num_columns();
+        let rank = self.rank();
+        if rank == num_columns {
+            return MatZ::new(num_columns, 1).unwrap();
+        }
+
+        let (_, transform) = self.transpose().hnf_transform();
+
+        // the zero rows of the HNF are its last `num_columns - rank` rows
+        let out = MatZ::new(num_columns, num_columns - rank).unwrap();
+        for column in 0..num_columns - rank {
+            for row in 0..num_columns {
+                unsafe {
+                    fmpz_set(
+                        fmpz_mat_entry(&out.matrix, row, column),
+                        fmpz_mat_entry(&transform.matrix, rank + column, row),
+                    )
+                };
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_kernel {
+    use super::MatZ;
+    use crate::traits::{GetNumColumns, GetNumRows};
+    use std::str::FromStr;
+
+    /// Checks if the kernel vectors are mapped to zero and span a
+    /// space of the correct dimension
+    #[test]
+    fn correct_dimension() {
+        let mat = MatZ::from_str("[[1,2,3,4],[2,4,6,8],[0,1,1,0]]").unwrap();
+
+        let kernel = mat.kernel();
+
+        assert_eq!(4, kernel.get_num_rows());
+        assert_eq!(2, kernel.get_num_columns());
+        assert_eq!(2, kernel.rank());
+        assert_eq!(MatZ::new(3, 2).unwrap(), &mat * &kernel);
+    }
+
+    /// Checks if the returned basis generates all integral kernel vectors
+    /// and not only a sublattice
+    #[test]
+    fn integral_basis() {
+        let mat = MatZ::from_str("[[2,4]]").unwrap();
+
+        let kernel = mat.kernel();
+
+        let positive = MatZ::from_str("[[2],[-1]]").unwrap();
+        let negative = MatZ::from_str("[[-2],[1]]").unwrap();
+        assert!(kernel == positive || kernel == negative);
+    }
+
+    /// Checks if the zero vector is returned for a trivial kernel
+    #[test]
+    fn trivial_kernel() {
+        let mat = MatZ::from_str("[[1,2],[3,4],[5,6]]").unwrap();
+
+        let kernel = mat.kernel();
+
+        assert_eq!(MatZ::new(2, 1).unwrap(), kernel);
+    }
+
+    /// Checks if the kernel of the zero matrix is the whole space
+    #[test]
+    fn zero_matrix() {
+        let mat = MatZ::new(2, 3).unwrap();
+
+        let kernel = mat.kernel();
+
+        assert_eq!(3, kernel.get_num_columns());
+        assert_eq!(3, kernel.rank());
+    }
+
+    /// Checks if the kernel is correctly computed for large entries
+    #[test]
+    fn large_entries() {
+        let mat = MatZ::from_str(&format!("[[{},{},1]]", i64::MAX, i64::MIN)).unwrap();
+
+        let kernel = mat.kernel();
+
+        assert_eq!(2, kernel.get_num_columns());
+        assert_eq!(MatZ::new(1, 2).unwrap(), &mat * &kernel);
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains functions to apply a closure to every entry of a [`MatZ`].
+
+use super::MatZ;
+use crate::{
+    integer::Z,
+    traits::{GetEntry, GetNumColumns, GetNumRows},
+};
+use flint_sys::{fmpz::fmpz_swap, fmpz_mat::fmpz_mat_entry};
+use std::convert::Infallible;
+
+impl MatZ {
+    /// Applies `f` to every entry of `self` and returns the matrix of results.
+    ///
+    /// Parameters:
+    /// - `f`: the function that is applied to each entry
+    ///
+    /// Returns a [`MatZ`] with the same dimensions as `self`, where
+    /// each entry is `f` applied to the corresponding entry of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZ::from_str("[[1, -5],[7, 3]]").unwrap();
+    ///
+    /// let clamped = matrix.map(|entry| match entry {
+    ///     entry if entry < &Z::ZERO => Z::ZERO,
+    ///     entry if entry > &Z::from(4) => Z::from(4),
+    ///     entry => entry.clone(),
+    /// });
+    ///
+    /// assert_eq!(MatZ::from_str("[[1, 0],[4, 3]]").unwrap(), clamped);
+    /// ```
+    pub fn map(&self, mut f: impl FnMut(&Z) -> Z) -> Self {
+        self.try_map(|entry| Ok::<Z, Infallible>(f(entry))).unwrap()
+    }
+
+    /// Applies the fallible function `f` to every entry of `self`
+    /// in row-major order and returns the matrix of results.
+    ///
+    /// Parameters:
+    /// - `f`: the function that is applied to each entry
+    ///
+    /// Returns a [`MatZ`] with the same dimensions as `self`, where
+    /// each entry is `f` applied to the corresponding entry of `self`,
+    /// or the first error returned by `f`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZ::from_str("[[4, 6],[8, 2]]").unwrap();
+    ///
+    /// let result = matrix.try_map(|entry| match entry < &Z::ZERO {
+    ///     true => Err(format!("The entry {} is negative.", entry)),
+    ///     false => Ok(entry - Z::ONE),
+    /// });
+    ///
+    /// assert_eq!(MatZ::from_str("[[3, 5],[7, 1]]").unwrap(), result.unwrap());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns the first error returned by `f`.
+    pub fn try_map<E>(&self, mut f: impl FnMut(&Z) -> Result<Z, E>) -> Result<Self, E> {
+        let mut out = MatZ::new(self.get_num_rows(), self.get_num_columns()).unwrap();
+        for row in 0..self.get_num_rows() {
+            for column in 0..self.get_num_columns() {
+                let entry: Z = self.get_entry(row, column).unwrap();
+                let mut value = f(&entry)?;
+                unsafe { fmpz_swap(fmpz_mat_entry(&out.matrix, row, column), &mut value.value) };
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_map {
+    use super::MatZ;
+    use crate::integer::Z;
+    use std::str::FromStr;
+
+    /// Ensure that the function is applied to small and large entries
+    #[test]
+    fn correct_entries() {
+        let matrix = MatZ::from_str(&format!("[[1, {}],[{}, 0]]", i64::MAX, i64::MIN)).unwrap();
+
+        let doubled = matrix.map(|entry| entry * Z::from(2));
+
+        let cmp = MatZ::from_str(&format!(
+            "[[2, {}],[{}, 0]]",
+            2 * i64::MAX as i128,
+            2 * i64::MIN as i128
+        ))
+        .unwrap();
+        assert_eq!(cmp, doubled);
+    }
+
+    /// Ensure that the entries are visited in row-major order
+    #[test]
+    fn row_major_order() {
+        let matrix = MatZ::new(2, 3).unwrap();
+        let mut counter = 0;
+
+        let enumerated = matrix.map(|_| {
+            counter += 1;
+            Z::from(counter)
+        });
+
+        assert_eq!(MatZ::from_str("[[1, 2, 3],[4, 5, 6]]").unwrap(), enumerated);
+    }
+}
+
+#[cfg(test)]
+mod test_try_map {
+    use super::MatZ;
+    use crate::integer::Z;
+    use std::str::FromStr;
+
+    /// Ensure that the mapped matrix is returned if no error occurs
+    #[test]
+    fn success() {
+        let matrix = MatZ::from_str("[[1, -2],[3, 4]]").unwrap();
+
+        let squared = matrix.try_map(|entry| Ok::<Z, ()>(entry * entry));
+
+        assert_eq!(
+            MatZ::from_str("[[1, 4],[9, 16]]").unwrap(),
+            squared.unwrap()
+        );
+    }
+
+    /// Ensure that the first error is returned
+    #[test]
+    fn first_error() {
+        let matrix = MatZ::from_str("[[1, -2],[-3, 4]]").unwrap();
+
+        let result = matrix.try_map(|entry| {
+            if entry < &Z::ZERO {
+                Err(entry.clone())
+            } else {
+                Ok(entry.clone())
+            }
+        });
+
+        assert_eq!(Err(Z::from(-2)), result);
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains Babai's nearest plane algorithm to solve the
+//! closest vector problem approximately.
+
+use super::MatZ;
+use crate::{
+    error::MathError,
+    rational::{MatQ, Q},
+    traits::{GetNumColumns, GetNumRows},
+};
+
+impl MatZ {
+    /// Computes a lattice vector close to `target` using Babai's nearest plane
+    /// algorithm, where the columns of `self` form the basis of the lattice.
+    /// The returned vector `v` satisfies `|target - v| <= 1/2 * sqrt(sum |b*_i|^2)`,
+    /// where `b*_i` are the Gram-Schmidt vectors of the basis.
+    ///
+    /// Parameters:
+    /// - `gso`: the Gram-Schmidt orthogonalization of the columns of `self`
+    /// - `target`: the column vector to which a close lattice vector is searched
+    ///
+    /// Returns a close lattice vector as a column vector or an error if the
+    /// dimensions do not match or `gso` contains a zero column.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::MatZ, rational::MatQ};
+    /// use std::str::FromStr;
+    ///
+    /// let basis = MatZ::from_str("[[2,1],[0,2]]").unwrap();
+    /// let gso = MatQ::from_str("[[2,0],[0,2]]").unwrap();
+    /// let target = MatQ::from_str("[[13/4],[-3/2]]").unwrap();
+    ///
+    /// let closest = basis.nearest_plane(&gso, &target).unwrap();
+    ///
+    /// assert_eq!(MatZ::from_str("[[3],[-2]]").unwrap(), closest);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if `gso` and `self` have different dimensions or `target` is not
+    /// a column vector with as many rows as `self`.
+    /// - Returns a [`MathError`] of type
+    /// [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if a column of `gso` is zero, i.e. the basis is linearly dependent.
+    pub fn nearest_plane(&self, gso: &MatQ, target: &MatQ) -> Result<MatZ, MathError> {
+        if self.get_num_rows() != gso.get_num_rows()
+            || self.get_num_columns() != gso.get_num_columns()
+            || !target.is_column_vector()
+            || self.get_num_rows() != target.get_num_rows()
+        {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to run nearest plane with a '{}x{}' basis, a '{}x{}' GSO and a '{}x{}' target.",
+                self.get_num_rows(),
+                self.get_num_columns(),
+                gso.get_num_rows(),
+                gso.get_num_columns(),
+                target.get_num_rows(),
+                target.get_num_columns()
+            )));
+        }
+
+        // project the remaining difference onto each Gram-Schmidt vector,
+        // starting with the last one, and subtract the closest multiple
+        // of the corresponding basis vector
+        let mut difference = target.clone();
+        let mut out = MatZ::new(self.get_num_rows(), 1).unwrap();
+        for column in (0..self.get_num_columns()).rev() {
+            let gso_vector = gso.get_column(column).unwrap();
+            let norm = gso_vector.norm_eucl_sqrd().unwrap();
+            if norm == Q::default() {
+                return Err(MathError::InvalidMatrix(format!(
+                    "The GSO {} contains a zero column and thus the basis is linearly dependent.",
+                    gso
+                )));
+            }
+            let coefficient = difference
+                .dot_product(&gso_vector)
+                .unwrap()
+                .div_safe(&norm)
+                .unwrap()
+                .round();
+
+            let step = &self.get_column(column).unwrap() * &coefficient;
+            difference = difference - MatQ::from(&step);
+            out = out + step;
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_nearest_plane {
+    use super::MatZ;
+    use crate::{error::MathError, rational::MatQ};
+    use std::str::FromStr;
+
+    /// Ensure that lattice vectors are returned unchanged
+    #[test]
+    fn lattice_vector() {
+        let basis = MatZ::from_str("[[2,1],[0,2]]").unwrap();
+        let gso = MatQ::from_str("[[2,0],[0,2]]").unwrap();
+        let target = MatQ::from_str("[[-3],[4]]").unwrap();
+
+        let closest = basis.nearest_plane(&gso, &target).unwrap();
+
+        assert_eq!(MatZ::from_str("[[-3],[4]]").unwrap(), closest);
+    }
+
+    /// Ensure that the result is correct for a non-orthogonal basis
+    #[test]
+    fn non_orthogonal() {
+        // b_0 = (1, 1, 0), b_1 = (1, 0, 1) with b*_1 = (1/2, -1/2, 1)
+        let basis = MatZ::from_str("[[1,1],[1,0],[0,1]]").unwrap();
+        let gso = MatQ::from_str("[[1,1/2],[1,-1/2],[0,1]]").unwrap();
+        let target = MatQ::from_str("[[3/2],[1/3],[9/10]]").unwrap();
+
+        let closest = basis.nearest_plane(&gso, &target).unwrap();
+
+        assert_eq!(MatZ::from_str("[[1],[0],[1]]").unwrap(), closest);
+    }
+
+    /// Ensure that large entries are handled correctly
+    #[test]
+    fn large_entries() {
+        let basis = MatZ::from_str(&format!("[[{},0],[0,1]]", i64::MAX)).unwrap();
+        let gso = MatQ::from_str(&format!("[[{},0],[0,1]]", i64::MAX)).unwrap();
+        let target =
+            MatQ::from_str(&format!("[[{}/2],[{}/3]]", 3 * i64::MAX as i128, i64::MIN)).unwrap();
+
+        let closest = basis.nearest_plane(&gso, &target).unwrap();
+
+        let cmp = MatZ::from_str(&format!(
+            "[[{}],[{}]]",
+            2 * i64::MAX as i128,
+            i64::MIN / 3 - 1
+        ))
+        .unwrap();
+        assert_eq!(cmp, closest);
+    }
+
+    /// Ensure that mismatching dimensions and zero GSO vectors result in an error
+    #[test]
+    fn invalid_input() {
+        let basis = MatZ::from_str("[[1,2],[1,2]]").unwrap();
+        let gso = MatQ::from_str("[[1,0],[1,0]]").unwrap();
+        let target = MatQ::from_str("[[1],[1]]").unwrap();
+
+        assert!(matches!(
+            basis.nearest_plane(&gso, &target),
+            Err(MathError::InvalidMatrix(_))
+        ));
+        assert!(matches!(
+            basis.nearest_plane(&MatQ::new(2, 3).unwrap(), &target),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+        assert!(matches!(
+            basis.nearest_plane(&gso, &MatQ::new(1, 2).unwrap()),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
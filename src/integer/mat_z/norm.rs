@@ -0,0 +1,244 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module includes functionality to compute several norms
+//! defined on matrices.
+//! The norms defined on vectors can be found in the `vector` module.
+
+use super::MatZ;
+use crate::{
+    integer::{fmpz_helpers::find_max_abs, Z},
+    traits::{GetEntry, GetNumColumns, GetNumRows},
+};
+use flint_sys::fmpz::fmpz_addmul;
+
+impl MatZ {
+    /// Returns the 1-norm of the matrix, i.e. the operator norm induced
+    /// by the vector 1-norm, which is the maximum absolute column sum.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatZ::from_str("[[1,-2],[3,4]]").unwrap();
+    ///
+    /// let norm = mat.matrix_norm_1();
+    ///
+    /// // max{|1| + |3|, |-2| + |4|} = 6
+    /// assert_eq!(Z::from(6), norm);
+    /// ```
+    pub fn matrix_norm_1(&self) -> Z {
+        let mut max = Z::ZERO;
+        for column in 0..self.get_num_columns() {
+            let mut sum = Z::ZERO;
+            for row in 0..self.get_num_rows() {
+                let entry: Z = self.get_entry(row, column).unwrap();
+                sum = sum + entry.abs();
+            }
+            if sum > max {
+                max = sum;
+            }
+        }
+        max
+    }
+
+    /// Returns the ∞-norm of the matrix, i.e. the operator norm induced
+    /// by the vector ∞-norm, which is the maximum absolute row sum.
+    ///
+    /// For the ∞-norm of a vector, see [`MatZ::norm_infty`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatZ::from_str("[[1,-2],[3,4]]").unwrap();
+    ///
+    /// let norm = mat.matrix_norm_infty();
+    ///
+    /// // max{|1| + |-2|, |3| + |4|} = 7
+    /// assert_eq!(Z::from(7), norm);
+    /// ```
+    pub fn matrix_norm_infty(&self) -> Z {
+        self.transpose().matrix_norm_1()
+    }
+
+    /// Returns the squared Frobenius norm of the matrix,
+    /// i.e. the sum of all squared entries.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatZ::from_str("[[1,-2],[3,4]]").unwrap();
+    ///
+    /// let norm = mat.norm_frobenius_sqrd();
+    ///
+    /// // 1*1 + 2*2 + 3*3 + 4*4 = 30
+    /// assert_eq!(Z::from(30), norm);
+    /// ```
+    pub fn norm_frobenius_sqrd(&self) -> Z {
+        let entries = self.collect_entries();
+
+        // sum squared entries in result
+        let mut result = Z::ZERO;
+        for entry in entries {
+            // sets result = result + entry * entry without cloned Z element
+            unsafe { fmpz_addmul(&mut result.value, &entry, &entry) }
+        }
+
+        result
+    }
+
+    /// Returns the maximum absolute value of all entries of the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatZ::from_str("[[1,-5],[3,4]]").unwrap();
+    ///
+    /// let max = mat.max_abs_entry();
+    ///
+    /// assert_eq!(Z::from(5), max);
+    /// ```
+    pub fn max_abs_entry(&self) -> Z {
+        let entries = self.collect_entries();
+
+        find_max_abs(&entries)
+    }
+}
+
+#[cfg(test)]
+mod test_matrix_norm_1 {
+    use super::{MatZ, Z};
+    use std::str::FromStr;
+
+    /// Check whether the maximum absolute column sum is computed
+    #[test]
+    fn correct_norm() {
+        let mat = MatZ::from_str("[[1,-2,0],[-3,4,1],[5,0,-1]]").unwrap();
+
+        assert_eq!(Z::from(9), mat.matrix_norm_1());
+    }
+
+    /// Check whether large entries are summed up correctly
+    #[test]
+    fn large_entries() {
+        let mat = MatZ::from_str(&format!("[[{},1],[{},1]]", i64::MIN, i64::MAX)).unwrap();
+
+        let cmp = Z::from(i64::MAX) + Z::from(i64::MAX) + Z::ONE;
+        assert_eq!(cmp, mat.matrix_norm_1());
+    }
+
+    /// Check whether the norm of the zero matrix is `0`
+    #[test]
+    fn zero() {
+        let mat = MatZ::new(2, 3).unwrap();
+
+        assert_eq!(Z::ZERO, mat.matrix_norm_1());
+    }
+}
+
+#[cfg(test)]
+mod test_matrix_norm_infty {
+    use super::{MatZ, Z};
+    use std::str::FromStr;
+
+    /// Check whether the maximum absolute row sum is computed
+    #[test]
+    fn correct_norm() {
+        let mat = MatZ::from_str("[[1,-2,0],[-3,4,1],[5,0,-1]]").unwrap();
+
+        assert_eq!(Z::from(8), mat.matrix_norm_infty());
+    }
+
+    /// Check whether large entries are summed up correctly
+    #[test]
+    fn large_entries() {
+        let mat = MatZ::from_str(&format!("[[{},{}],[1,1]]", i64::MIN, i64::MAX)).unwrap();
+
+        let cmp = Z::from(i64::MAX) + Z::from(i64::MAX) + Z::ONE;
+        assert_eq!(cmp, mat.matrix_norm_infty());
+    }
+
+    /// Check whether the matrix norm of a row vector differs from its vector norm
+    #[test]
+    fn row_vector() {
+        let vec = MatZ::from_str("[[1,-2,3]]").unwrap();
+
+        assert_eq!(Z::from(6), vec.matrix_norm_infty());
+        assert_eq!(Z::from(3), vec.norm_infty().unwrap());
+    }
+}
+
+#[cfg(test)]
+mod test_norm_frobenius_sqrd {
+    use super::{MatZ, Z};
+    use std::str::FromStr;
+
+    /// Check whether all squared entries are summed up
+    #[test]
+    fn correct_norm() {
+        let mat = MatZ::from_str("[[1,-2,0],[-3,4,1]]").unwrap();
+
+        assert_eq!(Z::from(31), mat.norm_frobenius_sqrd());
+    }
+
+    /// Check whether large entries are squared correctly
+    #[test]
+    fn large_entries() {
+        let mat = MatZ::from_str(&format!("[[{},0],[0,{}]]", i64::MIN, i64::MAX)).unwrap();
+
+        let min = Z::from(i64::MIN);
+        let max = Z::from(i64::MAX);
+        assert_eq!(&min * &min + &max * &max, mat.norm_frobenius_sqrd());
+    }
+
+    /// Check whether the squared Frobenius norm of a vector
+    /// equals its squared Euclidean norm
+    #[test]
+    fn vector() {
+        let vec = MatZ::from_str("[[1],[-2],[3]]").unwrap();
+
+        assert_eq!(vec.norm_eucl_sqrd().unwrap(), vec.norm_frobenius_sqrd());
+    }
+}
+
+#[cfg(test)]
+mod test_max_abs_entry {
+    use super::{MatZ, Z};
+    use std::str::FromStr;
+
+    /// Check whether the maximum absolute entry is found
+    #[test]
+    fn correct_entry() {
+        let mat = MatZ::from_str("[[1,-7,0],[-3,4,6]]").unwrap();
+
+        assert_eq!(Z::from(7), mat.max_abs_entry());
+    }
+
+    /// Check whether large entries are correctly handled
+    #[test]
+    fn large_entries() {
+        let mat = MatZ::from_str(&format!("[[{},0],[0,{}]]", i64::MIN, i64::MAX)).unwrap();
+
+        assert_eq!(Z::from(i64::MAX) + Z::ONE, mat.max_abs_entry());
+    }
+
+    /// Check whether the zero matrix has maximum absolute entry `0`
+    #[test]
+    fn zero() {
+        let mat = MatZ::new(3, 3).unwrap();
+
+        assert_eq!(Z::ZERO, mat.max_abs_entry());
+    }
+}
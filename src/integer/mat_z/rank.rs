@@ -0,0 +1,76 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the `rank` function.
+
+use super::MatZ;
+use flint_sys::fmpz_mat::fmpz_mat_rank;
+
+impl MatZ {
+    /// Returns the rank of the matrix, i.e. the maximal number of linearly
+    /// independent rows (or columns) over `Q`.
+    /// The rank is computed using fraction-free Gaussian elimination.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatZ::from_str("[[1,2,3],[2,4,6],[0,1,1]]").unwrap();
+    ///
+    /// assert_eq!(2, mat.rank());
+    /// ```
+    pub fn rank(&self) -> i64 {
+        unsafe { fmpz_mat_rank(&self.matrix) }
+    }
+}
+
+#[cfg(test)]
+mod test_rank {
+    use super::MatZ;
+    use std::str::FromStr;
+
+    /// Checks if matrices of full rank are correctly recognized
+    #[test]
+    fn full_rank() {
+        let square = MatZ::from_str("[[2,1],[1,2]]").unwrap();
+        let wide = MatZ::from_str("[[1,0,5],[0,1,7]]").unwrap();
+        let tall = MatZ::from_str("[[1,0],[0,1],[5,7]]").unwrap();
+
+        assert_eq!(2, square.rank());
+        assert_eq!(2, wide.rank());
+        assert_eq!(2, tall.rank());
+    }
+
+    /// Checks if linearly dependent rows reduce the rank
+    #[test]
+    fn dependent_rows() {
+        let mat = MatZ::from_str("[[1,2,3],[4,5,6],[7,8,9]]").unwrap();
+
+        assert_eq!(2, mat.rank());
+    }
+
+    /// Checks if the rank is correctly computed for large entries
+    #[test]
+    fn large_entries() {
+        let mat =
+            MatZ::from_str(&format!("[[{},{}],[{},1]]", i64::MAX, i64::MIN, i64::MIN)).unwrap();
+        let dependent = MatZ::from_str(&format!("[[{},1],[{},1]]", u64::MAX, u64::MAX)).unwrap();
+
+        assert_eq!(2, mat.rank());
+        assert_eq!(1, dependent.rank());
+    }
+
+    /// Checks if the zero matrix has rank `0`
+    #[test]
+    fn zero() {
+        let mat = MatZ::new(3, 4).unwrap();
+
+        assert_eq!(0, mat.rank());
+    }
+}
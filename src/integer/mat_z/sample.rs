@@ -0,0 +1,312 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains algorithms for sampling [`MatZ`] instances
+//! with entries chosen uniformly at random.
+
+use super::MatZ;
+use crate::{
+    error::MathError,
+    integer::Z,
+    traits::{GetNumColumns, GetNumRows, SetEntry},
+    utils::sample::sample_uniform_rejection_with_rng,
+};
+use rand::{CryptoRng, RngCore};
+use std::fmt::Display;
+
+impl MatZ {
+    /// Samples a `num_rows x num_cols` matrix, whose entries are chosen
+    /// independently and uniformly at random from `[lower_bound, upper_bound)`
+    /// using a cryptographically secure pseudorandom number generator.
+    ///
+    /// Parameters:
+    /// - `num_rows`: specifies the number of rows of the sampled matrix
+    /// - `num_cols`: specifies the number of columns of the sampled matrix
+    /// - `lower_bound`: specifies the included lower bound of the entries
+    /// - `upper_bound`: specifies the excluded upper bound of the entries
+    ///
+    /// Returns a fresh [`MatZ`] with uniformly sampled entries or an error
+    /// if the interval is empty or the dimensions are not suited to create a matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    ///
+    /// let mat = MatZ::sample_uniform(3, 4, -5, 5).unwrap();
+    ///
+    /// assert!(mat.max_abs_entry() <= 5.into());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NonPositive`](MathError::NonPositive)
+    /// if `upper_bound` is not larger than `lower_bound`.
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix) or
+    /// [`OutOfBounds`](MathError::OutOfBounds) if the provided number of rows and columns
+    /// are not suited to create a matrix. For further information see [`MatZ::new`].
+    pub fn sample_uniform(
+        num_rows: impl TryInto<i64> + Display + Copy,
+        num_cols: impl TryInto<i64> + Display + Copy,
+        lower_bound: impl Into<Z>,
+        upper_bound: impl Into<Z>,
+    ) -> Result<Self, MathError> {
+        Self::sample_uniform_with_rng(
+            num_rows,
+            num_cols,
+            lower_bound,
+            upper_bound,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Samples a `num_rows x num_cols` matrix, whose entries are chosen
+    /// independently and uniformly at random from `[lower_bound, upper_bound)`
+    /// using the provided cryptographically secure pseudorandom number generator.
+    /// Hence, a seeded generator yields reproducible matrices.
+    ///
+    /// Parameters:
+    /// - `num_rows`: specifies the number of rows of the sampled matrix
+    /// - `num_cols`: specifies the number of columns of the sampled matrix
+    /// - `lower_bound`: specifies the included lower bound of the entries
+    /// - `upper_bound`: specifies the excluded upper bound of the entries
+    /// - `rng`: specifies the generator the randomness is drawn from
+    ///
+    /// Returns a fresh [`MatZ`] with uniformly sampled entries or an error
+    /// if the interval is empty or the dimensions are not suited to create a matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let mat_1 = MatZ::sample_uniform_with_rng(3, 4, -5, 5, &mut StdRng::seed_from_u64(42)).unwrap();
+    /// let mat_2 = MatZ::sample_uniform_with_rng(3, 4, -5, 5, &mut StdRng::seed_from_u64(42)).unwrap();
+    ///
+    /// assert_eq!(mat_1, mat_2);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NonPositive`](MathError::NonPositive)
+    /// if `upper_bound` is not larger than `lower_bound`.
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix) or
+    /// [`OutOfBounds`](MathError::OutOfBounds) if the provided number of rows and columns
+    /// are not suited to create a matrix. For further information see [`MatZ::new`].
+    pub fn sample_uniform_with_rng<R: CryptoRng + RngCore>(
+        num_rows: impl TryInto<i64> + Display + Copy,
+        num_cols: impl TryInto<i64> + Display + Copy,
+        lower_bound: impl Into<Z>,
+        upper_bound: impl Into<Z>,
+        rng: &mut R,
+    ) -> Result<Self, MathError> {
+        let lower_bound: Z = lower_bound.into();
+        let interval_size = upper_bound.into() - &lower_bound;
+
+        let mut matrix = MatZ::new(num_rows, num_cols)?;
+        for row in 0..matrix.get_num_rows() {
+            for column in 0..matrix.get_num_columns() {
+                let sample = sample_uniform_rejection_with_rng(&interval_size, rng)?;
+                matrix.set_entry(row, column, &sample + &lower_bound)?;
+            }
+        }
+        Ok(matrix)
+    }
+
+    /// Samples a `num_rows x num_cols` matrix, whose entries are chosen
+    /// independently and uniformly at random from `{-1, 0, 1}` using
+    /// a cryptographically secure pseudorandom number generator.
+    ///
+    /// Parameters:
+    /// - `num_rows`: specifies the number of rows of the sampled matrix
+    /// - `num_cols`: specifies the number of columns of the sampled matrix
+    ///
+    /// Returns a fresh ternary [`MatZ`] with uniformly sampled entries
+    /// or an error if the dimensions are not suited to create a matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    ///
+    /// let mat = MatZ::sample_ternary(16, 16).unwrap();
+    ///
+    /// assert!(mat.max_abs_entry() <= 1.into());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix) or
+    /// [`OutOfBounds`](MathError::OutOfBounds) if the provided number of rows and columns
+    /// are not suited to create a matrix. For further information see [`MatZ::new`].
+    pub fn sample_ternary(
+        num_rows: impl TryInto<i64> + Display + Copy,
+        num_cols: impl TryInto<i64> + Display + Copy,
+    ) -> Result<Self, MathError> {
+        Self::sample_uniform(num_rows, num_cols, -1, 2)
+    }
+
+    /// Samples a `num_rows x num_cols` matrix, whose entries are chosen
+    /// independently and uniformly at random from `{-1, 0, 1}` using
+    /// the provided cryptographically secure pseudorandom number generator.
+    /// Hence, a seeded generator yields reproducible matrices.
+    ///
+    /// Parameters:
+    /// - `num_rows`: specifies the number of rows of the sampled matrix
+    /// - `num_cols`: specifies the number of columns of the sampled matrix
+    /// - `rng`: specifies the generator the randomness is drawn from
+    ///
+    /// Returns a fresh ternary [`MatZ`] with uniformly sampled entries
+    /// or an error if the dimensions are not suited to create a matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let mat = MatZ::sample_ternary_with_rng(16, 16, &mut rng).unwrap();
+    ///
+    /// assert!(mat.max_abs_entry() <= 1.into());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix) or
+    /// [`OutOfBounds`](MathError::OutOfBounds) if the provided number of rows and columns
+    /// are not suited to create a matrix. For further information see [`MatZ::new`].
+    pub fn sample_ternary_with_rng<R: CryptoRng + RngCore>(
+        num_rows: impl TryInto<i64> + Display + Copy,
+        num_cols: impl TryInto<i64> + Display + Copy,
+        rng: &mut R,
+    ) -> Result<Self, MathError> {
+        Self::sample_uniform_with_rng(num_rows, num_cols, -1, 2, rng)
+    }
+}
+
+#[cfg(test)]
+mod test_sample_uniform {
+    use super::MatZ;
+    use crate::{
+        error::MathError,
+        integer::Z,
+        traits::{GetEntry, GetNumColumns, GetNumRows},
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// Ensure that all entries lie in the interval and the dimensions are correct
+    #[test]
+    fn entries_in_interval() {
+        let lower_bound = Z::from(i64::MIN);
+        let upper_bound = Z::from(u64::MAX);
+
+        let mat = MatZ::sample_uniform(5, 7, &lower_bound, &upper_bound).unwrap();
+
+        assert_eq!(5, mat.get_num_rows());
+        assert_eq!(7, mat.get_num_columns());
+        for row in 0..5 {
+            for column in 0..7 {
+                let entry: Z = mat.get_entry(row, column).unwrap();
+                assert!(lower_bound <= entry);
+                assert!(entry < upper_bound);
+            }
+        }
+    }
+
+    /// Ensure that every value of a small interval is sampled
+    #[test]
+    fn all_values_sampled() {
+        let mat = MatZ::sample_uniform(20, 15, 7, 10).unwrap();
+        let entries: Vec<Z> = (0..20)
+            .flat_map(|row| (0..15).map(move |column| (row, column)))
+            .map(|(row, column)| mat.get_entry(row, column).unwrap())
+            .collect();
+
+        for value in 7..10 {
+            assert!(entries.contains(&Z::from(value)));
+        }
+    }
+
+    /// Ensure that an interval of size `1` yields a fixed matrix
+    #[test]
+    fn single_value() {
+        let mat = MatZ::sample_uniform(2, 3, -3, -2).unwrap();
+
+        assert_eq!(MatZ::from([[-3, -3, -3], [-3, -3, -3]]), mat);
+    }
+
+    /// Ensure that equally seeded generators yield the same matrix
+    #[test]
+    fn seeded_rng() {
+        let mut rng_1 = StdRng::seed_from_u64(42);
+        let mut rng_2 = StdRng::seed_from_u64(42);
+
+        let mat_1 = MatZ::sample_uniform_with_rng(4, 6, i64::MIN, u64::MAX, &mut rng_1).unwrap();
+        let mat_2 = MatZ::sample_uniform_with_rng(4, 6, i64::MIN, u64::MAX, &mut rng_2).unwrap();
+
+        assert_eq!(mat_1, mat_2);
+    }
+
+    /// Ensure that an error is returned for empty intervals and invalid dimensions
+    #[test]
+    fn invalid_parameters() {
+        assert!(matches!(
+            MatZ::sample_uniform(3, 3, 5, 5),
+            Err(MathError::NonPositive(_))
+        ));
+        assert!(matches!(
+            MatZ::sample_uniform(3, 3, 5, -5),
+            Err(MathError::NonPositive(_))
+        ));
+        assert!(matches!(
+            MatZ::sample_uniform(0, 3, 0, 5),
+            Err(MathError::InvalidMatrix(_))
+        ));
+        assert!(matches!(
+            MatZ::sample_uniform(3, -1, 0, 5),
+            Err(MathError::OutOfBounds(_, _))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_sample_ternary {
+    use super::MatZ;
+    use crate::{integer::Z, traits::GetEntry};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// Ensure that all entries are ternary and each value is sampled
+    #[test]
+    fn ternary_entries() {
+        let mat = MatZ::sample_ternary(20, 15).unwrap();
+        let entries: Vec<Z> = (0..20)
+            .flat_map(|row| (0..15).map(move |column| (row, column)))
+            .map(|(row, column)| mat.get_entry(row, column).unwrap())
+            .collect();
+
+        for entry in entries.iter() {
+            assert!(&Z::MINUS_ONE <= entry && entry <= &Z::ONE);
+        }
+        for value in -1..=1 {
+            assert!(entries.contains(&Z::from(value)));
+        }
+    }
+
+    /// Ensure that equally seeded generators yield the same ternary matrix
+    #[test]
+    fn seeded_rng() {
+        let mut rng_1 = StdRng::seed_from_u64(42);
+        let mut rng_2 = StdRng::seed_from_u64(42);
+
+        assert_eq!(
+            MatZ::sample_ternary_with_rng(10, 10, &mut rng_1).unwrap(),
+            MatZ::sample_ternary_with_rng(10, 10, &mut rng_2).unwrap()
+        );
+    }
+
+    /// Ensure that an error is returned for invalid dimensions
+    #[test]
+    fn invalid_dimensions() {
+        assert!(MatZ::sample_ternary(0, 2).is_err());
+        assert!(MatZ::sample_ternary(2, -2).is_err());
+    }
+}
@@ -10,8 +10,12 @@
 
 use super::MatZ;
 use crate::macros::for_others::{implement_for_others, implement_for_owned};
-use crate::traits::SetEntry;
-use crate::{error::MathError, integer::Z, utils::index::evaluate_indices};
+use crate::traits::{GetNumColumns, GetNumRows, SetEntry};
+use crate::{
+    error::MathError,
+    integer::Z,
+    utils::index::{evaluate_index, evaluate_indices},
+};
 use flint_sys::{fmpz::fmpz_set, fmpz_mat::fmpz_mat_entry};
 use std::fmt::Display;
 
@@ -62,6 +66,135 @@ implement_for_owned!(Z, MatZ, SetEntry);
 
 implement_for_others!(Z, MatZ, SetEntry for i8 i16 i32 i64 u8 u16 u32 u64);
 
+impl MatZ {
+    /// Sets the specified row of the matrix to the given row vector.
+    ///
+    /// Parameters:
+    /// - `row`: specifies the row of the matrix that is set
+    /// - `row_vector`: the row vector with as many entries as `self` has columns
+    ///
+    /// Returns an empty `Ok` if the action could be performed successfully.
+    /// Otherwise, a [`MathError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let mut matrix = MatZ::from_str("[[1,2,3],[3,4,5]]").unwrap();
+    /// let row_vector = MatZ::from_str("[[6,7,8]]").unwrap();
+    ///
+    /// matrix.set_row(1, &row_vector).unwrap();
+    ///
+    /// assert_eq!(row_vector, matrix.get_row(1).unwrap());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the number of the row is greater than the matrix or negative.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if `row_vector` is not a row vector with as many entries as `self` has columns.
+    pub fn set_row(
+        &mut self,
+        row: impl TryInto<i64> + Display + Copy,
+        row_vector: &Self,
+    ) -> Result<(), MathError> {
+        let row_i64 = evaluate_index(row)?;
+
+        if self.get_num_rows() <= row_i64 {
+            return Err(MathError::OutOfBounds(
+                format!("be smaller than {}", self.get_num_rows()),
+                format!("{}", row_i64),
+            ));
+        }
+        if row_vector.get_num_rows() != 1 || row_vector.get_num_columns() != self.get_num_columns()
+        {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to set a row of a '{}x{}' matrix to a '{}x{}' matrix.",
+                self.get_num_rows(),
+                self.get_num_columns(),
+                row_vector.get_num_rows(),
+                row_vector.get_num_columns()
+            )));
+        }
+
+        for column in 0..self.get_num_columns() {
+            unsafe {
+                fmpz_set(
+                    fmpz_mat_entry(&self.matrix, row_i64, column),
+                    fmpz_mat_entry(&row_vector.matrix, 0, column),
+                )
+            };
+        }
+        Ok(())
+    }
+
+    /// Sets the specified column of the matrix to the given column vector.
+    ///
+    /// Parameters:
+    /// - `column`: specifies the column of the matrix that is set
+    /// - `column_vector`: the column vector with as many entries as `self` has rows
+    ///
+    /// Returns an empty `Ok` if the action could be performed successfully.
+    /// Otherwise, a [`MathError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let mut matrix = MatZ::from_str("[[1,2,3],[3,4,5]]").unwrap();
+    /// let column_vector = MatZ::from_str("[[9],[10]]").unwrap();
+    ///
+    /// matrix.set_column(2, &column_vector).unwrap();
+    ///
+    /// assert_eq!(column_vector, matrix.get_column(2).unwrap());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the number of the column is greater than the matrix or negative.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if `column_vector` is not a column vector with as many entries as `self` has rows.
+    pub fn set_column(
+        &mut self,
+        column: impl TryInto<i64> + Display + Copy,
+        column_vector: &Self,
+    ) -> Result<(), MathError> {
+        let column_i64 = evaluate_index(column)?;
+
+        if self.get_num_columns() <= column_i64 {
+            return Err(MathError::OutOfBounds(
+                format!("be smaller than {}", self.get_num_columns()),
+                format!("{}", column_i64),
+            ));
+        }
+        if column_vector.get_num_columns() != 1
+            || column_vector.get_num_rows() != self.get_num_rows()
+        {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to set a column of a '{}x{}' matrix to a '{}x{}' matrix.",
+                self.get_num_rows(),
+                self.get_num_columns(),
+                column_vector.get_num_rows(),
+                column_vector.get_num_columns()
+            )));
+        }
+
+        for row in 0..self.get_num_rows() {
+            unsafe {
+                fmpz_set(
+                    fmpz_mat_entry(&self.matrix, row, column_i64),
+                    fmpz_mat_entry(&column_vector.matrix, row, 0),
+                )
+            };
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test_setter {
     use super::Z;
@@ -177,3 +310,76 @@ mod test_setter {
         assert!(matrix.set_entry(1, 100, value).is_err());
     }
 }
+
+#[cfg(test)]
+mod test_set_vec {
+    use crate::{error::MathError, integer::MatZ};
+    use std::str::FromStr;
+
+    /// Ensure that a row is correctly set
+    #[test]
+    fn set_row_works() {
+        let mut matrix = MatZ::new(3, 3).unwrap();
+        let row = MatZ::from_str(&format!("[[1,{},{}]]", i64::MAX, i64::MIN)).unwrap();
+
+        matrix.set_row(1, &row).unwrap();
+
+        let cmp =
+            MatZ::from_str(&format!("[[0,0,0],[1,{},{}],[0,0,0]]", i64::MAX, i64::MIN)).unwrap();
+        assert_eq!(cmp, matrix);
+    }
+
+    /// Ensure that a column is correctly set
+    #[test]
+    fn set_column_works() {
+        let mut matrix = MatZ::from_str("[[1,2],[3,4],[5,6]]").unwrap();
+        let column = MatZ::from_str(&format!("[[{}],[0],[-1]]", u64::MAX)).unwrap();
+
+        matrix.set_column(0, &column).unwrap();
+
+        let cmp = MatZ::from_str(&format!("[[{},2],[0,4],[-1,6]]", u64::MAX)).unwrap();
+        assert_eq!(cmp, matrix);
+    }
+
+    /// Ensure that the setters are inverse to the getters
+    #[test]
+    fn get_set_roundtrip() {
+        let matrix = MatZ::from_str("[[1,2,3],[4,5,6]]").unwrap();
+        let mut copy = MatZ::new(2, 3).unwrap();
+
+        copy.set_row(0, &matrix.get_row(0).unwrap()).unwrap();
+        copy.set_row(1, &matrix.get_row(1).unwrap()).unwrap();
+
+        assert_eq!(matrix, copy);
+    }
+
+    /// Ensure that vectors of wrong dimensions are rejected
+    #[test]
+    fn wrong_dimensions() {
+        let mut matrix = MatZ::new(2, 3).unwrap();
+
+        assert!(matches!(
+            matrix.set_row(0, &MatZ::new(1, 2).unwrap()),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+        assert!(matches!(
+            matrix.set_row(0, &MatZ::new(3, 1).unwrap()),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+        assert!(matches!(
+            matrix.set_column(0, &MatZ::new(1, 2).unwrap()),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+
+    /// Ensure that indices out of bounds are rejected
+    #[test]
+    fn out_of_bounds() {
+        let mut matrix = MatZ::new(2, 3).unwrap();
+
+        assert!(matrix.set_row(2, &MatZ::new(1, 3).unwrap()).is_err());
+        assert!(matrix.set_row(-1, &MatZ::new(1, 3).unwrap()).is_err());
+        assert!(matrix.set_column(3, &MatZ::new(2, 1).unwrap()).is_err());
+        assert!(matrix.set_column(-1, &MatZ::new(2, 1).unwrap()).is_err());
+    }
+}
@@ -0,0 +1,350 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains functions to solve systems of linear equations
+//! given by a [`MatZ`] exactly over [`Q`](crate::rational::Q) and [`Z`].
+
+use super::MatZ;
+use crate::{
+    error::MathError,
+    integer::Z,
+    rational::MatQ,
+    traits::{Concatenate, GetNumColumns, GetNumRows},
+};
+use flint_sys::{
+    fmpq::fmpq_set_fmpz_frac,
+    fmpq_mat::fmpq_mat_entry,
+    fmpz::{fmpz_divexact, fmpz_divisible, fmpz_is_zero, fmpz_set, fmpz_submul},
+    fmpz_mat::{fmpz_mat_entry, fmpz_mat_rref},
+};
+
+impl MatZ {
+    /// Computes a rational solution `x` of the system `self * x = b`.
+    /// If the system has several solutions, all free variables are set to `0`.
+    /// If `b` has several columns, each column of `x` solves the system
+    /// for the corresponding column of `b`.
+    ///
+    /// Parameters:
+    /// - `b`: the right-hand side of the system
+    ///
+    /// Returns a matrix `x` with `self * x = b` or an error if the system
+    /// has no solution or the dimensions do not match.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::MatZ, rational::MatQ};
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatZ::from_str("[[2,0],[1,4]]").unwrap();
+    /// let b = MatZ::from_str("[[1],[1]]").unwrap();
+    ///
+    /// let x = mat.solve(&b).unwrap();
+    ///
+    /// assert_eq!(MatQ::from_str("[[1/2],[1/8]]").unwrap(), x);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the number of rows of `self` and `b` differ.
+    /// - Returns a [`MathError`] of type [`NoSolution`](MathError::NoSolution)
+    /// if the system has no rational solution.
+    pub fn solve(&self, b: &MatZ) -> Result<MatQ, MathError> {
+        self.check_solve_dimensions(b)?;
+        let num_columns = self.get_num_columns();
+        let augmented = self.concat_horizontal(b)?;
+
+        let mut rref = MatZ::new(augmented.get_num_rows(), augmented.get_num_columns()).unwrap();
+        let mut den = Z::ZERO;
+        let rank = unsafe { fmpz_mat_rref(&mut rref.matrix, &mut den.value, &augmented.matrix) };
+
+        // every non-zero row of the reduced row echelon form determines
+        // the variable at its pivot, all other variables are free
+        let out = MatQ::new(num_columns, b.get_num_columns()).unwrap();
+        for row in 0..rank {
+            let pivot = (0..augmented.get_num_columns())
+                .find(|column| unsafe {
+                    fmpz_is_zero(fmpz_mat_entry(&rref.matrix, row, *column)) == 0
+                })
+                .unwrap();
+            if pivot >= num_columns {
+                return Err(MathError::NoSolution(format!(
+                    "The system given by {} and {} is inconsistent.",
+                    self, b
+                )));
+            }
+            for column in 0..b.get_num_columns() {
+                unsafe {
+                    fmpq_set_fmpz_frac(
+                        fmpq_mat_entry(&out.matrix, pivot, column),
+                        fmpz_mat_entry(&rref.matrix, row, num_columns + column),
+                        &den.value,
+                    )
+                };
+            }
+        }
+        Ok(out)
+    }
+
+    /// Computes an integral solution `x` of the system `self * x = b`.
+    /// If `b` has several columns, each column of `x` solves the system
+    /// for the corresponding column of `b`.
+    ///
+    /// The solution is computed using the Hermite normal form `U * self^T = H`,
+    /// i.e. by solving `H^T * y = b` by forward substitution and setting `x = U^T * y`.
+    ///
+    /// Parameters:
+    /// - `b`: the right-hand side of the system
+    ///
+    /// Returns a matrix `x` with `self * x = b` or an error if the system
+    /// has no integral solution or the dimensions do not match.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatZ::from_str("[[2,3]]").unwrap();
+    /// let b = MatZ::from_str("[[1]]").unwrap();
+    ///
+    /// let x = mat.solve_integer(&b).unwrap();
+    ///
+    /// assert_eq!(b, &mat * &x);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the number of rows of `self` and `b` differ.
+    /// - Returns a [`MathError`] of type [`NoSolution`](MathError::NoSolution)
+    /// if the system has no integral solution.
+    pub fn solve_integer(&self, b: &MatZ) -> Result<MatZ, MathError> {
+        self.check_solve_dimensions(b)?;
+        let (hnf, transform) = self.transpose().hnf_transform();
+
+        let y = MatZ::new(self.get_num_columns(), b.get_num_columns()).unwrap();
+        for row in 0..self.rank() {
+            let pivot = (0..hnf.get_num_columns())
+                .find(|column| unsafe {
+                    fmpz_is_zero(fmpz_mat_entry(&hnf.matrix, row, *column)) == 0
+                })
+                .unwrap();
+            for column in 0..b.get_num_columns() {
+                let mut value = Z::ZERO;
+                unsafe {
+                    fmpz_set(&mut value.value, fmpz_mat_entry(&b.matrix, pivot, column));
+                    for previous in 0..row {
+                        fmpz_submul(
+                            &mut value.value,
+                            fmpz_mat_entry(&hnf.matrix, previous, pivot),
+                            fmpz_mat_entry(&y.matrix, previous, column),
+                        );
+                    }
+
+                    let pivot_entry = fmpz_mat_entry(&hnf.matrix, row, pivot);
+                    if fmpz_divisible(&value.value, pivot_entry) == 0 {
+                        return Err(MathError::NoSolution(format!(
+                            "The system given by {} and {} has no integral solution.",
+                            self, b
+                        )));
+                    }
+                    fmpz_divexact(
+                        fmpz_mat_entry(&y.matrix, row, column),
+                        &value.value,
+                        pivot_entry,
+                    );
+                }
+            }
+        }
+
+        // the rows of `b` that are not pivots of `H^T` are not covered by the substitution
+        let out = &transform.transpose() * &y;
+        if &(self * &out) != b {
+            return Err(MathError::NoSolution(format!(
+                "The system given by {} and {} is inconsistent.",
+                self, b
+            )));
+        }
+        Ok(out)
+    }
+
+    /// Checks if `self` and `b` have the same number of rows.
+    ///
+    /// Parameters:
+    /// - `b`: the right-hand side of the system
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the number of rows of `self` and `b` differ.
+    fn check_solve_dimensions(&self, b: &MatZ) -> Result<(), MathError> {
+        if self.get_num_rows() != b.get_num_rows() {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to solve a system with a '{}x{}' matrix and a '{}x{}' right-hand side.",
+                self.get_num_rows(),
+                self.get_num_columns(),
+                b.get_num_rows(),
+                b.get_num_columns()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_solve {
+    use super::MatZ;
+    use crate::{error::MathError, rational::MatQ};
+    use std::str::FromStr;
+
+    /// Checks if a regular system is correctly solved
+    #[test]
+    fn regular() {
+        let mat = MatZ::from_str("[[2,0],[0,4]]").unwrap();
+        let b = MatZ::from_str("[[1],[-1]]").unwrap();
+
+        let x = mat.solve(&b).unwrap();
+
+        assert_eq!(MatQ::from_str("[[1/2],[-1/4]]").unwrap(), x);
+    }
+
+    /// Checks if free variables are set to `0`
+    #[test]
+    fn underdetermined() {
+        let mat = MatZ::from_str("[[1,2,3],[0,0,2]]").unwrap();
+        let b = MatZ::from_str("[[6],[1]]").unwrap();
+
+        let x = mat.solve(&b).unwrap();
+
+        assert_eq!(MatQ::from_str("[[9/2],[0],[1/2]]").unwrap(), x);
+    }
+
+    /// Checks if each column of the right-hand side is solved
+    #[test]
+    fn several_columns() {
+        let mat = MatZ::from_str("[[1,1],[1,-1]]").unwrap();
+        let b = MatZ::from_str("[[2,3],[0,1]]").unwrap();
+
+        let x = mat.solve(&b).unwrap();
+
+        assert_eq!(MatQ::from_str("[[1,2],[1,1]]").unwrap(), x);
+    }
+
+    /// Checks if large entries are correctly handled
+    #[test]
+    fn large_entries() {
+        let mat = MatZ::from_str(&format!("[[{},0],[0,1]]", i64::MAX)).unwrap();
+        let b = MatZ::from_str(&format!("[[1],[{}]]", i64::MIN)).unwrap();
+
+        let x = mat.solve(&b).unwrap();
+
+        let cmp = MatQ::from_str(&format!("[[1/{}],[{}]]", i64::MAX, i64::MIN)).unwrap();
+        assert_eq!(cmp, x);
+    }
+
+    /// Checks if an error is returned for inconsistent systems
+    #[test]
+    fn inconsistent() {
+        let mat = MatZ::from_str("[[1,1],[2,2]]").unwrap();
+        let b = MatZ::from_str("[[1],[3]]").unwrap();
+
+        assert!(matches!(mat.solve(&b), Err(MathError::NoSolution(_))));
+    }
+
+    /// Checks if an error is returned for mismatching dimensions
+    #[test]
+    fn mismatching_dimensions() {
+        let mat = MatZ::from_str("[[1,1],[2,2]]").unwrap();
+        let b = MatZ::new(3, 1).unwrap();
+
+        assert!(matches!(
+            mat.solve(&b),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_solve_integer {
+    use super::MatZ;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Checks if a regular system is correctly solved
+    #[test]
+    fn regular() {
+        let mat = MatZ::from_str("[[2,0],[1,4]]").unwrap();
+        let b = MatZ::from_str("[[2],[9]]").unwrap();
+
+        let x = mat.solve_integer(&b).unwrap();
+
+        assert_eq!(MatZ::from_str("[[1],[2]]").unwrap(), x);
+    }
+
+    /// Checks if underdetermined systems with several columns are solved
+    #[test]
+    fn underdetermined() {
+        let mat = MatZ::from_str("[[6,10,15],[1,1,1]]").unwrap();
+        let b = MatZ::from_str("[[1,-7],[0,5]]").unwrap();
+
+        let x = mat.solve_integer(&b).unwrap();
+
+        assert_eq!(b, &mat * &x);
+    }
+
+    /// Checks if large entries are correctly handled
+    #[test]
+    fn large_entries() {
+        let mat = MatZ::from_str(&format!("[[{},{}]]", i64::MAX, i64::MIN)).unwrap();
+        let b = MatZ::from_str("[[1]]").unwrap();
+
+        let x = mat.solve_integer(&b).unwrap();
+
+        assert_eq!(b, &mat * &x);
+    }
+
+    /// Checks if an error is returned if only rational solutions exist
+    #[test]
+    fn no_integral_solution() {
+        let regular = MatZ::from_str("[[2,0],[0,4]]").unwrap();
+        let underdetermined = MatZ::from_str("[[2,4]]").unwrap();
+
+        assert!(matches!(
+            regular.solve_integer(&MatZ::from_str("[[2],[2]]").unwrap()),
+            Err(MathError::NoSolution(_))
+        ));
+        assert!(matches!(
+            underdetermined.solve_integer(&MatZ::from_str("[[3]]").unwrap()),
+            Err(MathError::NoSolution(_))
+        ));
+    }
+
+    /// Checks if an error is returned for inconsistent systems
+    #[test]
+    fn inconsistent() {
+        let mat = MatZ::from_str("[[1],[1]]").unwrap();
+        let b = MatZ::from_str("[[1],[2]]").unwrap();
+
+        assert!(matches!(
+            mat.solve_integer(&b),
+            Err(MathError::NoSolution(_))
+        ));
+    }
+
+    /// Checks if an error is returned for mismatching dimensions
+    #[test]
+    fn mismatching_dimensions() {
+        let mat = MatZ::from_str("[[1,1],[2,2]]").unwrap();
+        let b = MatZ::new(1, 1).unwrap();
+
+        assert!(matches!(
+            mat.solve_integer(&b),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
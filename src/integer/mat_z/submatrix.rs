@@ -0,0 +1,255 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains functions to extract a submatrix of a [`MatZ`]
+//! and to embed a matrix into a [`MatZ`].
+
+use super::MatZ;
+use crate::{
+    error::MathError,
+    traits::{GetNumColumns, GetNumRows},
+    utils::index::{evaluate_bounded_index, evaluate_index},
+};
+use flint_sys::{fmpz::fmpz_set, fmpz_mat::fmpz_mat_entry};
+use std::{fmt::Display, ops::Range};
+
+impl MatZ {
+    /// Returns the submatrix consisting of the entries in the rows `rows`
+    /// and the columns `columns` of the matrix.
+    ///
+    /// Parameters:
+    /// - `rows`: the range of rows of the submatrix, excluding its end
+    /// - `columns`: the range of columns of the submatrix, excluding its end
+    ///
+    /// Returns the submatrix or an error if the ranges are empty
+    /// or not within the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZ::from_str("[[1,2,3],[4,5,6],[7,8,9]]").unwrap();
+    ///
+    /// let submatrix = matrix.get_submatrix(1..3, 0..2).unwrap();
+    ///
+    /// assert_eq!(MatZ::from_str("[[4,5],[7,8]]").unwrap(), submatrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if one of the ranges is not within the matrix.
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if one of the ranges is empty.
+    pub fn get_submatrix(&self, rows: Range<i64>, columns: Range<i64>) -> Result<Self, MathError> {
+        let row_start = evaluate_index(rows.start)?;
+        let column_start = evaluate_index(columns.start)?;
+        if rows.end > self.get_num_rows() || columns.end > self.get_num_columns() {
+            return Err(MathError::OutOfBounds(
+                format!(
+                    "be within ({},{})",
+                    self.get_num_rows(),
+                    self.get_num_columns()
+                ),
+                format!("({:?},{:?})", rows, columns),
+            ));
+        }
+
+        let out = MatZ::new(
+            (rows.end - row_start).max(0),
+            (columns.end - column_start).max(0),
+        )?;
+        for row in 0..out.get_num_rows() {
+            for column in 0..out.get_num_columns() {
+                unsafe {
+                    fmpz_set(
+                        fmpz_mat_entry(&out.matrix, row, column),
+                        fmpz_mat_entry(&self.matrix, row_start + row, column_start + column),
+                    )
+                };
+            }
+        }
+        Ok(out)
+    }
+
+    /// Overwrites the entries of the matrix with the entries of `other`,
+    /// s.t. the top left entry of `other` is placed at position (`row`, `column`).
+    ///
+    /// Parameters:
+    /// - `row`: the row at which the top row of `other` is placed
+    /// - `column`: the column at which the leftmost column of `other` is placed
+    /// - `other`: the matrix that is embedded into `self`
+    ///
+    /// Returns an empty `Ok` if the action could be performed successfully.
+    /// Otherwise, a [`MathError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use std::str::FromStr;
+    ///
+    /// let mut matrix = MatZ::from_str("[[1,2,3],[4,5,6],[7,8,9]]").unwrap();
+    /// let other = MatZ::from_str("[[0,0],[0,0]]").unwrap();
+    ///
+    /// matrix.set_submatrix(0, 1, &other).unwrap();
+    ///
+    /// assert_eq!(MatZ::from_str("[[1,0,0],[4,0,0],[7,8,9]]").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the position is not within the matrix.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if `other` does not fit into `self` at the provided position.
+    pub fn set_submatrix(
+        &mut self,
+        row: impl TryInto<i64> + Display + Copy,
+        column: impl TryInto<i64> + Display + Copy,
+        other: &Self,
+    ) -> Result<(), MathError> {
+        let row = evaluate_bounded_index(row, self.get_num_rows())?;
+        let column = evaluate_bounded_index(column, self.get_num_columns())?;
+        if row + other.get_num_rows() > self.get_num_rows()
+            || column + other.get_num_columns() > self.get_num_columns()
+        {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to embed a '{}x{}' matrix at position ({},{}) of a '{}x{}' matrix.",
+                other.get_num_rows(),
+                other.get_num_columns(),
+                row,
+                column,
+                self.get_num_rows(),
+                self.get_num_columns()
+            )));
+        }
+
+        for other_row in 0..other.get_num_rows() {
+            for other_column in 0..other.get_num_columns() {
+                unsafe {
+                    fmpz_set(
+                        fmpz_mat_entry(&self.matrix, row + other_row, column + other_column),
+                        fmpz_mat_entry(&other.matrix, other_row, other_column),
+                    )
+                };
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_get_submatrix {
+    use crate::{error::MathError, integer::MatZ};
+    use std::str::FromStr;
+
+    /// Ensure that the correct entries are extracted
+    #[test]
+    fn correct_entries() {
+        let matrix = MatZ::from_str(&format!(
+            "[[1,2,3,4],[5,{},{},8],[9,10,11,12]]",
+            i64::MAX,
+            i64::MIN
+        ))
+        .unwrap();
+
+        let submatrix = matrix.get_submatrix(1..3, 1..4).unwrap();
+
+        let cmp = MatZ::from_str(&format!("[[{},{},8],[10,11,12]]", i64::MAX, i64::MIN)).unwrap();
+        assert_eq!(cmp, submatrix);
+    }
+
+    /// Ensure that the full range returns the matrix itself
+    #[test]
+    fn full_range() {
+        let matrix = MatZ::from_str("[[1,2],[3,4],[5,6]]").unwrap();
+
+        assert_eq!(matrix, matrix.get_submatrix(0..3, 0..2).unwrap());
+    }
+
+    /// Ensure that ranges outside of the matrix are rejected
+    #[test]
+    fn out_of_bounds() {
+        let matrix = MatZ::new(2, 3).unwrap();
+
+        assert!(matches!(
+            matrix.get_submatrix(0..3, 0..1),
+            Err(MathError::OutOfBounds(_, _))
+        ));
+        assert!(matches!(
+            matrix.get_submatrix(0..1, 2..4),
+            Err(MathError::OutOfBounds(_, _))
+        ));
+        assert!(matches!(
+            matrix.get_submatrix(-1..1, 0..1),
+            Err(MathError::OutOfBounds(_, _))
+        ));
+    }
+
+    /// Ensure that empty ranges are rejected
+    #[test]
+    fn empty_range() {
+        let matrix = MatZ::new(2, 3).unwrap();
+
+        assert!(matches!(
+            matrix.get_submatrix(1..1, 0..3),
+            Err(MathError::InvalidMatrix(_))
+        ));
+        assert!(matches!(
+            matrix.get_submatrix(0..2, 2..1),
+            Err(MathError::InvalidMatrix(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_set_submatrix {
+    use crate::{error::MathError, integer::MatZ};
+    use std::str::FromStr;
+
+    /// Ensure that the block structure `[A | I]` can be built
+    #[test]
+    fn block_matrix() {
+        let a = MatZ::from_str(&format!("[[1,{}],[{},4]]", i64::MAX, i64::MIN)).unwrap();
+        let mut matrix = MatZ::new(2, 4).unwrap();
+
+        matrix.set_submatrix(0, 0, &a).unwrap();
+        matrix
+            .set_submatrix(0, 2, &MatZ::identity(2, 2).unwrap())
+            .unwrap();
+
+        let cmp = MatZ::from_str(&format!("[[1,{},1,0],[{},4,0,1]]", i64::MAX, i64::MIN)).unwrap();
+        assert_eq!(cmp, matrix);
+        assert_eq!(a, matrix.get_submatrix(0..2, 0..2).unwrap());
+    }
+
+    /// Ensure that matrices which do not fit are rejected
+    #[test]
+    fn does_not_fit() {
+        let mut matrix = MatZ::new(3, 3).unwrap();
+
+        assert!(matches!(
+            matrix.set_submatrix(1, 0, &MatZ::new(3, 1).unwrap()),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+        assert!(matches!(
+            matrix.set_submatrix(0, 2, &MatZ::new(1, 2).unwrap()),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+
+    /// Ensure that positions outside of the matrix are rejected
+    #[test]
+    fn out_of_bounds() {
+        let mut matrix = MatZ::new(3, 3).unwrap();
+        let other = MatZ::new(1, 1).unwrap();
+
+        assert!(matrix.set_submatrix(3, 0, &other).is_err());
+        assert!(matrix.set_submatrix(0, -1, &other).is_err());
+    }
+}
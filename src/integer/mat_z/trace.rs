@@ -0,0 +1,89 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the `trace` function.
+
+use super::MatZ;
+use crate::{
+    error::MathError,
+    integer::Z,
+    traits::{GetNumColumns, GetNumRows},
+};
+use flint_sys::fmpz_mat::fmpz_mat_trace;
+
+impl MatZ {
+    /// Returns the trace of a square matrix, i.e. the sum of its diagonal entries.
+    ///
+    /// Returns the trace of the matrix or an error if the matrix is not square.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatZ::from_str("[[1,2],[3,4]]").unwrap();
+    ///
+    /// let trace = mat.trace().unwrap();
+    ///
+    /// assert_eq!(Z::from(5), trace);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the matrix is not square.
+    pub fn trace(&self) -> Result<Z, MathError> {
+        if self.get_num_rows() != self.get_num_columns() {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "The trace is only defined for square matrices, but the matrix is '{}x{}'.",
+                self.get_num_rows(),
+                self.get_num_columns()
+            )));
+        }
+
+        let mut out = Z::default();
+        unsafe { fmpz_mat_trace(&mut out.value, &self.matrix) };
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_trace {
+    use super::MatZ;
+    use crate::{error::MathError, integer::Z};
+    use std::str::FromStr;
+
+    /// Checks if the trace is correctly computed
+    #[test]
+    fn correct_trace() {
+        let mat = MatZ::from_str("[[1,2,3],[4,-5,6],[7,8,9]]").unwrap();
+        let single = MatZ::from_str("[[-3]]").unwrap();
+
+        assert_eq!(Z::from(5), mat.trace().unwrap());
+        assert_eq!(Z::from(-3), single.trace().unwrap());
+    }
+
+    /// Checks if the trace is correctly computed for large entries
+    #[test]
+    fn large_entries() {
+        let mat = MatZ::from_str(&format!("[[{},1],[1,{}]]", u64::MAX, u64::MAX)).unwrap();
+
+        assert_eq!(Z::from(u64::MAX) + Z::from(u64::MAX), mat.trace().unwrap());
+    }
+
+    /// Checks if an error is returned for non-square matrices
+    #[test]
+    fn non_square() {
+        let mat = MatZ::new(2, 3).unwrap();
+
+        assert!(matches!(
+            mat.trace(),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
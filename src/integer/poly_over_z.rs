@@ -14,13 +14,24 @@ use flint_sys::fmpz_poly::fmpz_poly_struct;
 
 mod arithmetic;
 mod cmp;
+mod compose;
+mod content;
 mod default;
+mod derivative;
 mod evaluate;
+mod factor;
 mod from;
+mod gcd;
 mod get;
+mod interpolate;
+mod modulo;
+mod norm;
 mod ownership;
+mod properties;
+mod sample;
 mod serialize;
 mod set;
+mod shift;
 mod to_string;
 
 /// [`PolyOverZ`] is a type of polynomial with arbitrarily many coefficients of type
@@ -0,0 +1,307 @@
+// Copyright © 2023 Phil Milewski
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementation of the [`Div`] trait for [`PolyOverZ`] values
+//! and of the division with remainder and pseudo-division.
+
+use super::super::PolyOverZ;
+use crate::{
+    error::MathError,
+    macros::arithmetics::{
+        arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
+    },
+};
+use flint_sys::fmpz_poly::{fmpz_poly_divrem, fmpz_poly_pseudo_divrem_divconquer};
+use std::ops::Div;
+
+impl Div for &PolyOverZ {
+    type Output = PolyOverZ;
+    /// Implements the [`Div`] trait for two [`PolyOverZ`] values.
+    /// [`Div`] is implemented for any combination of [`PolyOverZ`] and borrowed [`PolyOverZ`].
+    /// The quotient of [`PolyOverZ::div_rem`] is returned.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the polynomial `self` is divided by
+    ///
+    /// Returns the quotient of both polynomials as a [`PolyOverZ`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let a: PolyOverZ = PolyOverZ::from_str("3  -1 0 1").unwrap();
+    /// let b: PolyOverZ = PolyOverZ::from_str("2  1 1").unwrap();
+    ///
+    /// let c: PolyOverZ = &a / &b;
+    /// let d: PolyOverZ = a / b;
+    /// let e: PolyOverZ = &c / d;
+    /// let f: PolyOverZ = c / &e;
+    /// ```
+    ///
+    /// # Panics
+    /// - Panics if `other` is the zero polynomial.
+    fn div(self, other: Self) -> Self::Output {
+        self.div_rem(other).unwrap().0
+    }
+}
+
+arithmetic_trait_borrowed_to_owned!(Div, div, PolyOverZ, PolyOverZ, PolyOverZ);
+arithmetic_trait_mixed_borrowed_owned!(Div, div, PolyOverZ, PolyOverZ, PolyOverZ);
+
+impl PolyOverZ {
+    /// Divides `self` by `divisor` with remainder, i.e. computes `q` and `r`
+    /// s.t. `self = q * divisor + r`.
+    /// If the leading coefficient of `divisor` is `1` or `-1` or the division is exact,
+    /// this coincides with the division over the rationals and the degree of `r`
+    /// is smaller than the degree of `divisor`.
+    /// Otherwise, each coefficient of `r` of degree at least the degree of `divisor`
+    /// is reduced modulo the leading coefficient of `divisor`.
+    ///
+    /// Parameters:
+    /// - `divisor`: specifies the polynomial `self` is divided by
+    ///
+    /// Returns the quotient and the remainder as a tuple of [`PolyOverZ`]
+    /// or an error if `divisor` is the zero polynomial.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let a = PolyOverZ::from_str("4  1 0 2 1").unwrap();
+    /// let b = PolyOverZ::from_str("2  1 1").unwrap();
+    ///
+    /// let (quotient, remainder) = a.div_rem(&b).unwrap();
+    ///
+    /// assert_eq!(PolyOverZ::from_str("3  -1 1 1").unwrap(), quotient);
+    /// assert_eq!(PolyOverZ::from_str("1  2").unwrap(), remainder);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`DivisionByZeroError`](MathError::DivisionByZeroError)
+    /// if `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> Result<(Self, Self), MathError> {
+        divisor.check_nonzero_divisor(self)?;
+
+        let mut quotient = PolyOverZ::default();
+        let mut remainder = PolyOverZ::default();
+        unsafe {
+            fmpz_poly_divrem(
+                &mut quotient.poly,
+                &mut remainder.poly,
+                &self.poly,
+                &divisor.poly,
+            )
+        };
+        Ok((quotient, remainder))
+    }
+
+    /// Computes the pseudo-division of `self` by `divisor`, i.e. computes `q`, `r` and `d`
+    /// s.t. `l^d * self = q * divisor + r`, where `l` is the leading coefficient
+    /// of `divisor` and the degree of `r` is smaller than the degree of `divisor`.
+    /// In contrast to [`PolyOverZ::div_rem`], this division is possible for
+    /// arbitrary non-zero divisors without leaving the integers.
+    ///
+    /// Parameters:
+    /// - `divisor`: specifies the polynomial `self` is divided by
+    ///
+    /// Returns the quotient, the remainder, and the exponent `d` of the leading
+    /// coefficient as a tuple or an error if `divisor` is the zero polynomial.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let a = PolyOverZ::from_str("3  1 0 1").unwrap();
+    /// let b = PolyOverZ::from_str("2  1 2").unwrap();
+    ///
+    /// let (quotient, remainder, d) = a.pseudo_div_rem(&b).unwrap();
+    ///
+    /// assert_eq!(PolyOverZ::from_str("2  -1 2").unwrap(), quotient);
+    /// assert_eq!(PolyOverZ::from_str("1  5").unwrap(), remainder);
+    /// assert_eq!(2, d);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`DivisionByZeroError`](MathError::DivisionByZeroError)
+    /// if `divisor` is the zero polynomial.
+    pub fn pseudo_div_rem(&self, divisor: &Self) -> Result<(Self, Self, u64), MathError> {
+        divisor.check_nonzero_divisor(self)?;
+
+        let mut quotient = PolyOverZ::default();
+        let mut remainder = PolyOverZ::default();
+        let mut d = 0_u64;
+        unsafe {
+            fmpz_poly_pseudo_divrem_divconquer(
+                &mut quotient.poly,
+                &mut remainder.poly,
+                &mut d,
+                &self.poly,
+                &divisor.poly,
+            )
+        };
+        Ok((quotient, remainder, d))
+    }
+
+    /// Checks whether `self` can be used as a divisor, i.e. is not the zero polynomial.
+    ///
+    /// Parameters:
+    /// - `dividend`: the polynomial that is divided by `self`, used for the error message
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`DivisionByZeroError`](MathError::DivisionByZeroError)
+    /// if `self` is the zero polynomial.
+    fn check_nonzero_divisor(&self, dividend: &Self) -> Result<(), MathError> {
+        if self.is_zero() {
+            return Err(MathError::DivisionByZeroError(format!(
+                "tried to divide {} by zero",
+                dividend
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_div {
+    use super::PolyOverZ;
+    use std::str::FromStr;
+
+    /// testing division for two [`PolyOverZ`]
+    #[test]
+    fn div() {
+        let a: PolyOverZ = PolyOverZ::from_str("3  -1 0 1").unwrap();
+        let b: PolyOverZ = PolyOverZ::from_str("2  1 1").unwrap();
+        let c: PolyOverZ = a / b;
+        assert_eq!(c, PolyOverZ::from_str("2  -1 1").unwrap());
+    }
+
+    /// testing division for two borrowed [`PolyOverZ`]
+    #[test]
+    fn div_borrow() {
+        let a: PolyOverZ = PolyOverZ::from_str("3  -1 0 1").unwrap();
+        let b: PolyOverZ = PolyOverZ::from_str("2  1 1").unwrap();
+        let c: PolyOverZ = &a / &b;
+        assert_eq!(c, PolyOverZ::from_str("2  -1 1").unwrap());
+    }
+
+    /// testing division for borrowed and owned [`PolyOverZ`] in both orders
+    #[test]
+    fn div_mixed_borrowed() {
+        let a: PolyOverZ = PolyOverZ::from_str("3  -1 0 1").unwrap();
+        let b: PolyOverZ = PolyOverZ::from_str("2  1 1").unwrap();
+        let c: PolyOverZ = &a / b.clone();
+        let d: PolyOverZ = a / &b;
+        assert_eq!(c, PolyOverZ::from_str("2  -1 1").unwrap());
+        assert_eq!(c, d);
+    }
+
+    /// testing division with large coefficients
+    #[test]
+    fn div_large_numbers() {
+        let a: PolyOverZ = PolyOverZ::from_str(&format!("3  {} 0 {}", i64::MIN, u64::MAX)).unwrap();
+        let b: PolyOverZ = PolyOverZ::from_str(&format!("1  {}", u64::MAX)).unwrap();
+        let c: PolyOverZ = &a * &b;
+        assert_eq!(a, c / b);
+    }
+
+    /// testing whether division by the zero polynomial panics
+    #[test]
+    #[should_panic]
+    fn div_by_zero() {
+        let a: PolyOverZ = PolyOverZ::from_str("3  -1 0 1").unwrap();
+        let _ = a / PolyOverZ::default();
+    }
+}
+
+#[cfg(test)]
+mod test_div_rem {
+    use super::PolyOverZ;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that `self = q * divisor + r` holds for monic divisors
+    /// and the remainder has a smaller degree
+    #[test]
+    fn monic_divisor() {
+        let a = PolyOverZ::from_str(&format!("5  {} 3 -7 0 {}", u64::MAX, i64::MIN)).unwrap();
+        let b = PolyOverZ::from_str("3  5 -2 1").unwrap();
+
+        let (quotient, remainder) = a.div_rem(&b).unwrap();
+
+        assert_eq!(a, &quotient * &b + &remainder);
+        assert!(remainder.get_degree() < 2);
+    }
+
+    /// Ensure that exact divisions yield a zero remainder
+    #[test]
+    fn exact_division() {
+        let a = PolyOverZ::from_str("3  3 -5 2").unwrap();
+        let b = PolyOverZ::from_str("2  -3 2").unwrap();
+
+        let (quotient, remainder) = a.div_rem(&b).unwrap();
+
+        assert_eq!(PolyOverZ::from_str("2  -1 1").unwrap(), quotient);
+        assert_eq!(PolyOverZ::default(), remainder);
+    }
+
+    /// Ensure that `self = q * divisor + r` holds for non-monic divisors
+    #[test]
+    fn non_monic_divisor() {
+        let a = PolyOverZ::from_str("4  1 7 -3 5").unwrap();
+        let b = PolyOverZ::from_str("2  1 3").unwrap();
+
+        let (quotient, remainder) = a.div_rem(&b).unwrap();
+
+        assert_eq!(a, &quotient * &b + &remainder);
+    }
+
+    /// Ensure that an error is returned for the zero polynomial as divisor
+    #[test]
+    fn division_by_zero() {
+        let a = PolyOverZ::from_str("2  1 3").unwrap();
+
+        assert!(matches!(
+            a.div_rem(&PolyOverZ::default()),
+            Err(MathError::DivisionByZeroError(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_pseudo_div_rem {
+    use super::PolyOverZ;
+    use crate::{error::MathError, integer::Z, traits::Pow};
+    use std::str::FromStr;
+
+    /// Ensure that `l^d * self = q * divisor + r` holds for non-monic divisors
+    #[test]
+    fn non_monic_divisor() {
+        let a = PolyOverZ::from_str(&format!("5  {} 3 -7 0 {}", u64::MAX, i64::MIN)).unwrap();
+        let b = PolyOverZ::from_str("3  5 -2 3").unwrap();
+
+        let (quotient, remainder, d) = a.pseudo_div_rem(&b).unwrap();
+
+        let factor = PolyOverZ::from_str(&format!("1  {}", Z::from(3).pow(d).unwrap())).unwrap();
+        assert_eq!(&factor * &a, &quotient * &b + &remainder);
+        assert!(remainder.get_degree() < 2);
+    }
+
+    /// Ensure that an error is returned for the zero polynomial as divisor
+    #[test]
+    fn division_by_zero() {
+        let a = PolyOverZ::from_str("2  1 3").unwrap();
+
+        assert!(matches!(
+            a.pseudo_div_rem(&PolyOverZ::default()),
+            Err(MathError::DivisionByZeroError(_))
+        ));
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the composition of [`PolyOverZ`] instances.
+
+use super::PolyOverZ;
+use flint_sys::fmpz_poly::fmpz_poly_compose;
+
+impl PolyOverZ {
+    /// Composes `self` with `inner`, i.e. computes `self(inner(X))`.
+    ///
+    /// Parameters:
+    /// - `inner`: specifies the polynomial that is inserted into `self`
+    ///
+    /// Returns the composition `self ∘ inner` as a [`PolyOverZ`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("3  1 2 3").unwrap();
+    /// let inner = PolyOverZ::from_str("3  0 0 1").unwrap();
+    ///
+    /// let composition = poly.compose(&inner);
+    ///
+    /// assert_eq!(PolyOverZ::from_str("5  1 0 2 0 3").unwrap(), composition);
+    /// ```
+    pub fn compose(&self, inner: &Self) -> Self {
+        let mut out = PolyOverZ::default();
+        unsafe { fmpz_poly_compose(&mut out.poly, &self.poly, &inner.poly) };
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_compose {
+    use super::PolyOverZ;
+    use crate::{integer::Z, traits::Evaluate};
+    use std::str::FromStr;
+
+    /// Ensure that the automorphism `X -> X^k` maps coefficients correctly
+    #[test]
+    fn automorphism() {
+        let poly = PolyOverZ::from_str("4  1 -2 3 -4").unwrap();
+        let inner = PolyOverZ::from_str("4  0 0 0 1").unwrap();
+
+        assert_eq!(
+            PolyOverZ::from_str("10  1 0 0 -2 0 0 3 0 0 -4").unwrap(),
+            poly.compose(&inner)
+        );
+    }
+
+    /// Ensure that the evaluation of the composition is the composition
+    /// of the evaluations for large coefficients
+    #[test]
+    fn evaluation() {
+        let poly = PolyOverZ::from_str(&format!("3  {} -1 2", u64::MAX)).unwrap();
+        let inner = PolyOverZ::from_str(&format!("2  {} 3", i64::MIN)).unwrap();
+        let value = Z::from(-17);
+
+        let composition = poly.compose(&inner);
+
+        assert_eq!(
+            poly.evaluate(&inner.evaluate(&value)),
+            composition.evaluate(&value)
+        );
+    }
+
+    /// Ensure that composing with a constant polynomial yields a constant
+    /// and composing with `X` yields the same polynomial
+    #[test]
+    fn special_cases() {
+        let poly = PolyOverZ::from_str("3  1 2 3").unwrap();
+        let constant = PolyOverZ::from_str("1  2").unwrap();
+        let identity = PolyOverZ::from_str("2  0 1").unwrap();
+
+        assert_eq!(
+            PolyOverZ::from_str("1  17").unwrap(),
+            poly.compose(&constant)
+        );
+        assert_eq!(poly, poly.compose(&identity));
+        assert_eq!(PolyOverZ::default(), PolyOverZ::default().compose(&poly));
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the computation of the content
+//! and the primitive part of a [`PolyOverZ`].
+
+use super::PolyOverZ;
+use crate::integer::Z;
+use flint_sys::fmpz_poly::{fmpz_poly_content, fmpz_poly_primitive_part};
+
+impl PolyOverZ {
+    /// Returns the content of `self`, i.e. the non-negative greatest common divisor
+    /// of all coefficients of `self`.
+    /// The content of the zero polynomial is defined to be `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("3  -6 0 9").unwrap();
+    ///
+    /// let content = poly.content();
+    ///
+    /// assert_eq!(Z::from(3), content);
+    /// ```
+    pub fn content(&self) -> Z {
+        let mut out = Z::default();
+        unsafe { fmpz_poly_content(&mut out.value, &self.poly) };
+        out
+    }
+
+    /// Returns the primitive part of `self`, i.e. `self` divided by its content
+    /// and normalised to have a positive leading coefficient.
+    /// The primitive part of the zero polynomial is the zero polynomial.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("3  6 0 -9").unwrap();
+    ///
+    /// let primitive_part = poly.primitive_part();
+    ///
+    /// assert_eq!(PolyOverZ::from_str("3  -2 0 3").unwrap(), primitive_part);
+    /// ```
+    pub fn primitive_part(&self) -> Self {
+        let mut out = PolyOverZ::default();
+        unsafe { fmpz_poly_primitive_part(&mut out.poly, &self.poly) };
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_content {
+    use super::{PolyOverZ, Z};
+    use std::str::FromStr;
+
+    /// Ensure that the content is computed correctly for large coefficients
+    /// and is non-negative
+    #[test]
+    fn large_coefficients() {
+        let poly = PolyOverZ::from_str(&format!("3  -{} 0 -{}", u64::MAX, u64::MAX)).unwrap();
+        let multiple = PolyOverZ::from_str(&format!("2  {} {}", i64::MIN, i64::MIN)).unwrap();
+
+        assert_eq!(Z::from(u64::MAX), poly.content());
+        assert_eq!(Z::from(i64::MIN).abs(), multiple.content());
+    }
+
+    /// Ensure that the content of the zero polynomial is zero
+    /// and the content of constant polynomials is their absolute value
+    #[test]
+    fn special_cases() {
+        assert_eq!(Z::ZERO, PolyOverZ::default().content());
+        assert_eq!(Z::from(7), PolyOverZ::from_str("1  -7").unwrap().content());
+    }
+}
+
+#[cfg(test)]
+mod test_primitive_part {
+    use super::PolyOverZ;
+    use std::str::FromStr;
+
+    /// Ensure that `content * primitive_part = self` up to the sign
+    /// for large coefficients
+    #[test]
+    fn large_coefficients() {
+        let poly = PolyOverZ::from_str(&format!("3  {} 0 {}", u64::MAX, u64::MAX)).unwrap();
+
+        let primitive_part = poly.primitive_part();
+
+        assert_eq!(PolyOverZ::from_str("3  1 0 1").unwrap(), primitive_part);
+        assert_eq!(
+            poly,
+            primitive_part * PolyOverZ::from_str(&format!("1  {}", poly.content())).unwrap()
+        );
+    }
+
+    /// Ensure that the leading coefficient of the primitive part is positive
+    #[test]
+    fn positive_leading_coefficient() {
+        let poly = PolyOverZ::from_str("3  4 -2 -8").unwrap();
+
+        assert_eq!(
+            PolyOverZ::from_str("3  -2 1 4").unwrap(),
+            poly.primitive_part()
+        );
+    }
+
+    /// Ensure that the primitive part of the zero polynomial is zero
+    #[test]
+    fn zero() {
+        assert_eq!(PolyOverZ::default(), PolyOverZ::default().primitive_part());
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the formal derivative
+//! of a [`PolyOverZ`].
+
+use super::PolyOverZ;
+use flint_sys::fmpz_poly::fmpz_poly_derivative;
+
+impl PolyOverZ {
+    /// Computes the formal derivative of `self`, i.e. for
+    /// `a_0 + a_1 X + ... + a_n X^n` the polynomial
+    /// `a_1 + 2 a_2 X + ... + n a_n X^(n-1)` is returned.
+    ///
+    /// Returns the formal derivative of `self` as a [`PolyOverZ`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("4  -3 0 1 2").unwrap();
+    ///
+    /// let derivative = poly.derivative();
+    ///
+    /// assert_eq!(PolyOverZ::from_str("3  0 2 6").unwrap(), derivative);
+    /// ```
+    pub fn derivative(&self) -> Self {
+        let mut out = PolyOverZ::default();
+        unsafe { fmpz_poly_derivative(&mut out.poly, &self.poly) };
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_derivative {
+    use super::PolyOverZ;
+    use std::str::FromStr;
+
+    /// Ensure that the derivative of small polynomials is computed correctly
+    #[test]
+    fn small() {
+        let poly = PolyOverZ::from_str("5  1 -2 0 7 -1").unwrap();
+
+        assert_eq!(
+            PolyOverZ::from_str("4  -2 0 21 -4").unwrap(),
+            poly.derivative()
+        );
+    }
+
+    /// Ensure that the derivative of constant polynomials is zero
+    #[test]
+    fn constant() {
+        let poly = PolyOverZ::from_str("1  42").unwrap();
+
+        assert_eq!(PolyOverZ::default(), poly.derivative());
+        assert_eq!(PolyOverZ::default(), PolyOverZ::default().derivative());
+    }
+
+    /// Ensure that the derivative of polynomials with large coefficients
+    /// is computed correctly
+    #[test]
+    fn large_coefficients() {
+        let poly = PolyOverZ::from_str(&format!("3  1 {} {}", i64::MIN, u64::MAX)).unwrap();
+        let cmp =
+            PolyOverZ::from_str(&format!("2  {} {}", i64::MIN, 2 * u64::MAX as u128)).unwrap();
+
+        assert_eq!(cmp, poly.derivative());
+    }
+
+    /// Ensure that the product rule holds
+    #[test]
+    fn product_rule() {
+        let poly_1 = PolyOverZ::from_str("4  3 -1 0 5").unwrap();
+        let poly_2 = PolyOverZ::from_str("3  -7 2 1").unwrap();
+
+        assert_eq!(
+            &poly_1.derivative() * &poly_2 + &poly_1 * &poly_2.derivative(),
+            (&poly_1 * &poly_2).derivative()
+        );
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the factorization of a [`PolyOverZ`]
+//! into irreducible factors.
+
+use super::PolyOverZ;
+use crate::integer::Z;
+use flint_sys::fmpz_poly_factor::{
+    fmpz_poly_factor, fmpz_poly_factor_clear, fmpz_poly_factor_get_fmpz_poly,
+    fmpz_poly_factor_init, fmpz_poly_factor_struct,
+};
+use std::mem::MaybeUninit;
+
+impl PolyOverZ {
+    /// Factors `self` into its content and irreducible factors over the integers,
+    /// i.e. computes `c` and `f_1, ..., f_k` with multiplicities `e_1, ..., e_k`
+    /// s.t. `self = c * f_1^e_1 * ... * f_k^e_k`.
+    /// The content `c` is the gcd of the coefficients of `self` and carries the sign
+    /// of the leading coefficient. The irreducible factors are primitive
+    /// and have positive leading coefficients.
+    ///
+    /// Returns the content and a vector of all irreducible factors together
+    /// with their multiplicity. For the zero polynomial, the content is `0`
+    /// and the vector is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("4  -2 -2 2 2").unwrap();
+    ///
+    /// let (content, factors) = poly.factor();
+    ///
+    /// assert_eq!(Z::from(2), content);
+    /// assert!(factors.contains(&(PolyOverZ::from_str("2  -1 1").unwrap(), 1)));
+    /// assert!(factors.contains(&(PolyOverZ::from_str("2  1 1").unwrap(), 2)));
+    /// ```
+    pub fn factor(&self) -> (Z, Vec<(PolyOverZ, u64)>) {
+        if self.is_zero() {
+            return (Z::ZERO, vec![]);
+        }
+
+        let mut factors = MaybeUninit::<fmpz_poly_factor_struct>::uninit();
+        unsafe {
+            fmpz_poly_factor_init(factors.as_mut_ptr());
+            let mut factors = factors.assume_init();
+            fmpz_poly_factor(&mut factors, &self.poly);
+
+            let content = Z::from_fmpz(&factors.c);
+            let out = (0..factors.num)
+                .map(|i| {
+                    let mut factor = PolyOverZ::default();
+                    fmpz_poly_factor_get_fmpz_poly(&mut factor.poly, &factors, i);
+                    (factor, *factors.exp.add(i as usize) as u64)
+                })
+                .collect();
+
+            fmpz_poly_factor_clear(&mut factors);
+            (content, out)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_factor {
+    use super::PolyOverZ;
+    use crate::integer::Z;
+    use std::str::FromStr;
+
+    /// Multiplies the content and all factors with their multiplicities.
+    fn product(content: &Z, factors: &[(PolyOverZ, u64)]) -> PolyOverZ {
+        let mut out = PolyOverZ::from_str(&format!("1  {}", content)).unwrap();
+        for (factor, exponent) in factors {
+            for _ in 0..*exponent {
+                out = out * factor;
+            }
+        }
+        out
+    }
+
+    /// Ensure that the product of the factorization is the polynomial
+    /// and all factors have the expected form
+    #[test]
+    fn product_of_factors() {
+        let poly = PolyOverZ::from_str("6  -12 -18 0 6 6 18").unwrap();
+
+        let (content, factors) = poly.factor();
+
+        assert_eq!(Z::from(6), content);
+        assert_eq!(poly, product(&content, &factors));
+    }
+
+    /// Ensure that the sign of the leading coefficient is part of the content
+    /// and irreducible polynomials are not split
+    #[test]
+    fn irreducible() {
+        let poly = PolyOverZ::from_str("3  -1 0 -1").unwrap();
+
+        let (content, factors) = poly.factor();
+
+        assert_eq!(Z::MINUS_ONE, content);
+        assert_eq!(vec![(PolyOverZ::from_str("3  1 0 1").unwrap(), 1)], factors);
+    }
+
+    /// Ensure that multiplicities are detected for large coefficients
+    #[test]
+    fn large_coefficients() {
+        let factor_1 = PolyOverZ::from_str(&format!("2  {} 1", u64::MAX)).unwrap();
+        let factor_2 = PolyOverZ::from_str(&format!("3  {} 0 1", i64::MAX)).unwrap();
+        let poly = &factor_1 * &factor_1 * &factor_2;
+
+        let (content, factors) = poly.factor();
+
+        assert_eq!(Z::ONE, content);
+        assert_eq!(2, factors.len());
+        assert!(factors.contains(&(factor_1, 2)));
+        assert!(factors.contains(&(factor_2, 1)));
+    }
+
+    /// Ensure that constant polynomials only have a content
+    #[test]
+    fn constant() {
+        let (content, factors) = PolyOverZ::from_str("1  -12").unwrap().factor();
+        let (zero_content, zero_factors) = PolyOverZ::default().factor();
+
+        assert_eq!(Z::from(-12), content);
+        assert!(factors.is_empty());
+        assert_eq!(Z::ZERO, zero_content);
+        assert!(zero_factors.is_empty());
+    }
+
+    /// Ensure that cyclotomic polynomials of powers of two are irreducible,
+    /// while `X^n - 1` splits
+    #[test]
+    fn cyclotomic() {
+        let n = 2_u64.pow(4);
+        let power_of_two =
+            PolyOverZ::from_str(&format!("{}  1{} 1", n + 1, " 0".repeat(n as usize - 1))).unwrap();
+        let minus_one =
+            PolyOverZ::from_str(&format!("{}  -1{} 1", n + 1, " 0".repeat(n as usize - 1)))
+                .unwrap();
+
+        assert_eq!(1, power_of_two.factor().1.len());
+        assert_eq!(5, minus_one.factor().1.len());
+        assert_eq!(Z::ONE, minus_one.factor().0);
+    }
+}
@@ -13,10 +13,118 @@
 //! The explicit functions contain the documentation.
 
 use super::PolyOverZ;
-use crate::error::MathError;
-use flint_sys::fmpz_poly::fmpz_poly_set_str;
+use crate::{
+    error::MathError,
+    integer::{MatZ, Z},
+    traits::{GetNumColumns, GetNumRows},
+};
+use flint_sys::fmpz_poly::{fmpz_poly_set_coeff_fmpz, fmpz_poly_set_coeff_si, fmpz_poly_set_str};
 use std::{ffi::CString, str::FromStr};
 
+impl PolyOverZ {
+    /// Creates a polynomial from a (row or column) vector of coefficients,
+    /// i.e. the `i`-th entry of `vector` is the coefficient of `X^i`.
+    /// This is the inverse operation of [`PolyOverZ::to_vec`].
+    ///
+    /// Parameters:
+    /// - `vector`: the coefficient vector of the polynomial
+    ///
+    /// Returns a [`PolyOverZ`] or an error, if `vector` is not a (row or column) vector.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatZ, PolyOverZ};
+    /// use std::str::FromStr;
+    ///
+    /// let vector = MatZ::from_str("[[1],[0],[-3],[0]]").unwrap();
+    ///
+    /// let poly = PolyOverZ::from_vec(&vector).unwrap();
+    ///
+    /// assert_eq!(PolyOverZ::from_str("3  1 0 -3").unwrap(), poly);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MathError::VectorFunctionCalledOnNonVector`] if
+    /// the given [`MatZ`] instance is not a (row or column) vector.
+    pub fn from_vec(vector: &MatZ) -> Result<Self, MathError> {
+        if !vector.is_vector() {
+            return Err(MathError::VectorFunctionCalledOnNonVector(
+                String::from("from_vec"),
+                vector.get_num_rows(),
+                vector.get_num_columns(),
+            ));
+        }
+
+        let mut res = Self::default();
+        for (index, entry) in vector.collect_entries().iter().enumerate() {
+            unsafe { fmpz_poly_set_coeff_fmpz(&mut res.poly, index as i64, entry) };
+        }
+        Ok(res)
+    }
+}
+
+impl From<&[i64]> for PolyOverZ {
+    /// Creates a polynomial from a slice of coefficients,
+    /// i.e. the `i`-th entry of `coefficients` is the coefficient of `X^i`.
+    ///
+    /// Parameters:
+    /// - `coefficients`: the coefficients of the polynomial in ascending order
+    ///
+    /// Returns a [`PolyOverZ`] with the given coefficients.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from([0, 1, 2, 3].as_slice());
+    ///
+    /// assert_eq!(PolyOverZ::from_str("4  0 1 2 3").unwrap(), poly);
+    /// ```
+    fn from(coefficients: &[i64]) -> Self {
+        let mut res = Self::default();
+        for (index, coefficient) in coefficients.iter().enumerate() {
+            unsafe { fmpz_poly_set_coeff_si(&mut res.poly, index as i64, *coefficient) };
+        }
+        res
+    }
+}
+
+impl From<Vec<Z>> for PolyOverZ {
+    /// Creates a polynomial from a vector of coefficients,
+    /// i.e. the `i`-th entry of `coefficients` is the coefficient of `X^i`.
+    ///
+    /// Parameters:
+    /// - `coefficients`: the coefficients of the polynomial in ascending order
+    ///
+    /// Returns a [`PolyOverZ`] with the given coefficients.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from(vec![Z::ZERO, Z::ONE, Z::from(u64::MAX)]);
+    ///
+    /// assert_eq!(PolyOverZ::from_str(&format!("3  0 1 {}", u64::MAX)).unwrap(), poly);
+    /// ```
+    fn from(coefficients: Vec<Z>) -> Self {
+        let mut res = Self::default();
+        for (index, coefficient) in coefficients.iter().enumerate() {
+            unsafe { fmpz_poly_set_coeff_fmpz(&mut res.poly, index as i64, &coefficient.value) };
+        }
+        res
+    }
+}
+
+impl TryFrom<&MatZ> for PolyOverZ {
+    type Error = MathError;
+    /// Create a [`PolyOverZ`] from a [`MatZ`] vector using [`from_vec`](PolyOverZ::from_vec)
+    fn try_from(vector: &MatZ) -> Result<Self, Self::Error> {
+        PolyOverZ::from_vec(vector)
+    }
+}
+
 impl FromStr for PolyOverZ {
     type Err = MathError;
 
@@ -122,3 +230,122 @@ mod test_from_str {
         assert!(PolyOverZ::from_str("4  1 2 -3").is_err());
     }
 }
+
+#[cfg(test)]
+mod test_from_vec {
+    use crate::{
+        error::MathError,
+        integer::{MatZ, PolyOverZ},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that row and column vectors yield the same polynomial
+    #[test]
+    fn row_and_column_vector() {
+        let row = MatZ::from_str(&format!("[[{}, 0, 2, {}]]", u64::MAX, i64::MIN)).unwrap();
+        let column = MatZ::from_str(&format!("[[{}],[0],[2],[{}]]", u64::MAX, i64::MIN)).unwrap();
+        let cmp = PolyOverZ::from_str(&format!("4  {} 0 2 {}", u64::MAX, i64::MIN)).unwrap();
+
+        assert_eq!(cmp, PolyOverZ::from_vec(&row).unwrap());
+        assert_eq!(cmp, PolyOverZ::from_vec(&column).unwrap());
+    }
+
+    /// Ensure that trailing zeros are removed and zero vectors yield the zero polynomial
+    #[test]
+    fn zeros() {
+        let vector = MatZ::from_str("[[0, 1, 0, 0]]").unwrap();
+        let zero = MatZ::new(5, 1).unwrap();
+
+        assert_eq!(
+            PolyOverZ::from_str("2  0 1").unwrap(),
+            PolyOverZ::from_vec(&vector).unwrap()
+        );
+        assert_eq!(PolyOverZ::default(), PolyOverZ::from_vec(&zero).unwrap());
+    }
+
+    /// Ensure that an error is returned for matrices that are not vectors
+    #[test]
+    fn non_vector() {
+        let matrix = MatZ::from_str("[[1, 2],[3, 4]]").unwrap();
+
+        assert!(matches!(
+            PolyOverZ::from_vec(&matrix),
+            Err(MathError::VectorFunctionCalledOnNonVector(_, 2, 2))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_from_slice {
+    use super::PolyOverZ;
+    use std::str::FromStr;
+
+    /// Ensure that large coefficients are set correctly
+    #[test]
+    fn large_coefficients() {
+        let poly = PolyOverZ::from([i64::MIN, 0, i64::MAX].as_slice());
+
+        assert_eq!(
+            PolyOverZ::from_str(&format!("3  {} 0 {}", i64::MIN, i64::MAX)).unwrap(),
+            poly
+        );
+    }
+
+    /// Ensure that trailing zeros are removed and empty slices yield the zero polynomial
+    #[test]
+    fn zeros() {
+        let empty: &[i64] = &[];
+
+        assert_eq!(
+            PolyOverZ::from_str("2  0 1").unwrap(),
+            PolyOverZ::from([0, 1, 0, 0].as_slice())
+        );
+        assert_eq!(PolyOverZ::default(), PolyOverZ::from(empty));
+    }
+}
+
+#[cfg(test)]
+mod test_from_vec_z {
+    use crate::integer::{PolyOverZ, Z};
+    use std::str::FromStr;
+
+    /// Ensure that large coefficients are set correctly
+    #[test]
+    fn large_coefficients() {
+        let poly = PolyOverZ::from(vec![Z::from(u64::MAX), Z::ZERO, Z::from(i64::MIN)]);
+
+        assert_eq!(
+            PolyOverZ::from_str(&format!("3  {} 0 {}", u64::MAX, i64::MIN)).unwrap(),
+            poly
+        );
+    }
+
+    /// Ensure that trailing zeros are removed and empty vectors yield the zero polynomial
+    #[test]
+    fn zeros() {
+        assert_eq!(
+            PolyOverZ::from_str("2  0 1").unwrap(),
+            PolyOverZ::from(vec![Z::ZERO, Z::ONE, Z::ZERO])
+        );
+        assert_eq!(PolyOverZ::default(), PolyOverZ::from(Vec::<Z>::new()));
+    }
+}
+
+#[cfg(test)]
+mod test_try_from_mat_z {
+    use crate::integer::{MatZ, PolyOverZ};
+    use std::str::FromStr;
+
+    /// Ensure that vectors are converted and non-vectors return an error
+    #[test]
+    fn vector_and_matrix() {
+        let vector = MatZ::from_str(&format!("[[{}, 0, 2]]", u64::MAX)).unwrap();
+        let matrix = MatZ::from_str("[[1, 2],[3, 4]]").unwrap();
+
+        assert_eq!(
+            PolyOverZ::from_str(&format!("3  {} 0 2", u64::MAX)).unwrap(),
+            PolyOverZ::try_from(&vector).unwrap()
+        );
+        assert!(PolyOverZ::try_from(&matrix).is_err());
+    }
+}
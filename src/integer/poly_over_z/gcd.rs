@@ -0,0 +1,341 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the [`Gcd`]
+//! and [`Xgcd`] trait for [`PolyOverZ`].
+
+use super::PolyOverZ;
+use crate::{
+    integer::Z,
+    macros::for_others::implement_for_owned,
+    traits::{Gcd, GetCoefficient, Pow, Xgcd},
+};
+use flint_sys::{
+    fmpz::{fmpz_is_zero, fmpz_mul},
+    fmpz_poly::{
+        fmpz_poly_content, fmpz_poly_gcd, fmpz_poly_scalar_divexact_fmpz,
+        fmpz_poly_scalar_mul_fmpz, fmpz_poly_xgcd,
+    },
+};
+
+impl Gcd<&PolyOverZ> for PolyOverZ {
+    type Output = PolyOverZ;
+
+    /// Outputs the greatest common divisor (gcd) of the two given polynomials
+    /// with `gcd(a,0) = a` up to the sign of the leading coefficient.
+    /// The gcd includes the gcd of the contents, i.e. coefficients, of both
+    /// polynomials and its leading coefficient is positive.
+    ///
+    /// Paramters:
+    /// - `other`: specifies one of the polynomials of which the gcd is computed
+    ///
+    /// Returns the greatest common divisor of `self` and `other` as
+    /// a [`PolyOverZ`] instance.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::traits::*;
+    /// use std::str::FromStr;
+    ///
+    /// let poly_1 = PolyOverZ::from_str("3  -2 0 2").unwrap();
+    /// let poly_2 = PolyOverZ::from_str("3  4 8 4").unwrap();
+    ///
+    /// let gcd = poly_1.gcd(&poly_2);
+    ///
+    /// assert_eq!(PolyOverZ::from_str("2  2 2").unwrap(), gcd);
+    /// ```
+    fn gcd(&self, other: &Self) -> Self::Output {
+        let mut out = PolyOverZ::default();
+        unsafe { fmpz_poly_gcd(&mut out.poly, &self.poly, &other.poly) };
+        out
+    }
+}
+
+implement_for_owned!(PolyOverZ, PolyOverZ, Gcd);
+
+impl Xgcd<&PolyOverZ> for PolyOverZ {
+    type Output = (Z, PolyOverZ, PolyOverZ);
+
+    /// Outputs the extended greatest common divisor (xgcd) of the two given polynomials
+    /// over the integers. As not every gcd can be written as an integer
+    /// linear combination of `a` and `b` in `Z[X]`, the resultant `r` of `a` and `b`
+    /// is returned together with `x` and `y` s.t. `a*x + b*y = r`.
+    /// The resultant is `0` if and only if `a` and `b` have a non-constant common
+    /// factor or one of them is `0`. In this case, `x` and `y` are `0` as well.
+    /// If both polynomials are non-zero constants, their resultant `1` can not be
+    /// written as such a linear combination in general. Hence, the gcd of both
+    /// constants is returned as `r` instead.
+    ///
+    /// Paramters:
+    /// - `other`: specifies one of the polynomials of which the xgcd is computed
+    ///
+    /// Returns a triple `(r, x, y)` containing the resultant,
+    /// `x`, and `y` s.t. `r = a*x + b*y`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use qfall_math::traits::*;
+    /// use std::str::FromStr;
+    ///
+    /// let poly_1 = PolyOverZ::from_str("2  1 2").unwrap();
+    /// let poly_2 = PolyOverZ::from_str("3  1 0 1").unwrap();
+    ///
+    /// let (resultant, x, y) = poly_1.xgcd(&poly_2);
+    /// let cmp = &poly_1 * &x + &poly_2 * &y;
+    ///
+    /// assert_eq!(Z::from(5), resultant);
+    /// assert_eq!(PolyOverZ::from_str("1  5").unwrap(), cmp);
+    /// ```
+    fn xgcd(&self, other: &Self) -> Self::Output {
+        let degree_self = self.get_degree();
+        let degree_other = other.get_degree();
+        if degree_self < 0 || degree_other < 0 {
+            return (Z::ZERO, PolyOverZ::default(), PolyOverZ::default());
+        }
+
+        // the resultant of a polynomial `a` and a constant `c` is `c^deg(a)`
+        match (degree_self, degree_other) {
+            (0, 0) => {
+                let (gcd, x, y) = self.get_coeff(0).unwrap().xgcd(other.get_coeff(0).unwrap());
+                return (gcd, PolyOverZ::from(vec![x]), PolyOverZ::from(vec![y]));
+            }
+            (_, 0) => {
+                let constant: Z = other.get_coeff(0).unwrap();
+                let resultant = constant.pow(degree_self).unwrap();
+                let y = PolyOverZ::from(vec![constant.pow(degree_self - 1).unwrap()]);
+                return (resultant, PolyOverZ::default(), y);
+            }
+            (0, _) => {
+                let constant: Z = self.get_coeff(0).unwrap();
+                let resultant = constant.pow(degree_other).unwrap();
+                let x = PolyOverZ::from(vec![constant.pow(degree_other - 1).unwrap()]);
+                return (resultant, x, PolyOverZ::default());
+            }
+            _ => {}
+        }
+
+        // FLINT requires primitive polynomials, hence the contents `c_a` and `c_b`
+        // are split off s.t. `a = c_a * f` and `b = c_b * g`
+        let (content_self, primitive_self) = split_content(self);
+        let (content_other, primitive_other) = split_content(other);
+
+        let mut resultant = Z::ZERO;
+        let mut x = PolyOverZ::default();
+        let mut y = PolyOverZ::default();
+        unsafe {
+            fmpz_poly_xgcd(
+                &mut resultant.value,
+                &mut x.poly,
+                &mut y.poly,
+                &primitive_self.poly,
+                &primitive_other.poly,
+            )
+        };
+
+        // FLINT leaves `x` and `y` undefined if the resultant is zero
+        if 1 == unsafe { fmpz_is_zero(&resultant.value) } {
+            return (resultant, PolyOverZ::default(), PolyOverZ::default());
+        }
+
+        // `Res(a, b) = c_a^deg(b) * c_b^deg(a) * Res(f, g)`, hence
+        // `a * (x * c_a^(deg(b) - 1) * c_b^deg(a)) + b * (y * c_a^deg(b) * c_b^(deg(a) - 1))`
+        // equals `Res(a, b)`
+        let power_self = content_self.pow(degree_other - 1).unwrap();
+        let power_other = content_other.pow(degree_self - 1).unwrap();
+        let factor_x = &power_self * &power_other * &content_other;
+        let factor_y = &power_self * &power_other * &content_self;
+        let factor_resultant = &factor_x * &content_self;
+        unsafe {
+            fmpz_mul(
+                &mut resultant.value,
+                &resultant.value,
+                &factor_resultant.value,
+            );
+            fmpz_poly_scalar_mul_fmpz(&mut x.poly, &x.poly, &factor_x.value);
+            fmpz_poly_scalar_mul_fmpz(&mut y.poly, &y.poly, &factor_y.value);
+        }
+        (resultant, x, y)
+    }
+}
+
+/// Splits a non-zero polynomial into its positive content `c` and
+/// the primitive polynomial `f` s.t. `poly = c * f`.
+///
+/// Parameters:
+/// - `poly`: the polynomial whose content is split off
+///
+/// Returns the content of `poly` and the primitive part with
+/// the sign of the leading coefficient of `poly`.
+fn split_content(poly: &PolyOverZ) -> (Z, PolyOverZ) {
+    let mut content = Z::default();
+    let mut primitive = PolyOverZ::default();
+    unsafe {
+        fmpz_poly_content(&mut content.value, &poly.poly);
+        fmpz_poly_scalar_divexact_fmpz(&mut primitive.poly, &poly.poly, &content.value);
+    }
+    (content, primitive)
+}
+
+impl Xgcd<PolyOverZ> for PolyOverZ {
+    type Output = (Z, PolyOverZ, PolyOverZ);
+
+    /// Documentation can be found at [`PolyOverZ::xgcd`].
+    fn xgcd(&self, other: PolyOverZ) -> Self::Output {
+        self.xgcd(&other)
+    }
+}
+
+#[cfg(test)]
+mod test_gcd {
+    use super::{Gcd, PolyOverZ};
+    use std::str::FromStr;
+
+    /// Ensures that the gcd is correctly computed for small [`PolyOverZ`] instances
+    /// and ensures the property `gcd(a,b) == gcd(b,a)`
+    #[test]
+    fn small() {
+        let poly_1 = PolyOverZ::from_str("3  -1 0 1").unwrap();
+        let poly_2 = PolyOverZ::from_str("3  1 2 1").unwrap();
+        let poly_3 = PolyOverZ::from_str("2  2 4").unwrap();
+
+        assert_eq!(PolyOverZ::from_str("2  1 1").unwrap(), poly_1.gcd(&poly_2));
+        assert_eq!(PolyOverZ::from_str("2  1 1").unwrap(), poly_2.gcd(&poly_1));
+        assert_eq!(PolyOverZ::from_str("1  1").unwrap(), poly_1.gcd(&poly_3));
+    }
+
+    /// Ensures that the gcd contains the gcd of the contents
+    /// and that its leading coefficient is positive
+    #[test]
+    fn content_and_sign() {
+        let poly_1 = PolyOverZ::from_str("3  6 0 -6").unwrap();
+        let poly_2 = PolyOverZ::from_str("2  -4 -4").unwrap();
+
+        assert_eq!(PolyOverZ::from_str("2  2 2").unwrap(), poly_1.gcd(poly_2));
+    }
+
+    /// Ensures that `gcd(a,0) == a` up to the sign and `gcd(0,0) == 0`
+    #[test]
+    fn zero() {
+        let poly = PolyOverZ::from_str("3  1 0 -7").unwrap();
+        let zero = PolyOverZ::default();
+
+        assert_eq!(PolyOverZ::from_str("3  -1 0 7").unwrap(), poly.gcd(&zero));
+        assert_eq!(zero, zero.gcd(&zero));
+    }
+
+    /// Ensures that the gcd is correctly computed for large coefficients
+    #[test]
+    fn large() {
+        let factor = PolyOverZ::from_str(&format!("2  {} 1", i64::MIN)).unwrap();
+        let poly_1 = &factor * PolyOverZ::from_str(&format!("2  {} 3", u64::MAX)).unwrap();
+        let poly_2 = &factor * PolyOverZ::from_str("3  1 0 1").unwrap();
+
+        assert_eq!(factor, poly_1.gcd(&poly_2));
+    }
+}
+
+#[cfg(test)]
+mod test_xgcd {
+    use super::{PolyOverZ, Xgcd};
+    use crate::integer::Z;
+    use std::str::FromStr;
+
+    /// Ensures that `a*x + b*y = r` holds for coprime polynomials
+    /// and the order of the polynomials is irrelevant for the resultant up to the sign
+    #[test]
+    fn coprime() {
+        let poly_1 = PolyOverZ::from_str("3  1 -3 2").unwrap();
+        let poly_2 = PolyOverZ::from_str(&format!("4  {} 0 5 1", i64::MAX)).unwrap();
+
+        let (resultant_1, x_1, y_1) = poly_1.xgcd(&poly_2);
+        let (resultant_2, x_2, y_2) = poly_2.xgcd(poly_1.clone());
+
+        let cmp_1 = PolyOverZ::from_str(&format!("1  {}", resultant_1)).unwrap();
+        let cmp_2 = PolyOverZ::from_str(&format!("1  {}", resultant_2)).unwrap();
+        assert_ne!(Z::ZERO, resultant_1);
+        assert_eq!(cmp_1, &poly_1 * &x_1 + &poly_2 * &y_1);
+        assert_eq!(cmp_2, &poly_2 * &x_2 + &poly_1 * &y_2);
+    }
+
+    /// Ensures that the resultant is zero for polynomials with a common factor
+    #[test]
+    fn common_factor() {
+        let poly_1 = PolyOverZ::from_str("3  -1 0 1").unwrap();
+        let poly_2 = PolyOverZ::from_str("3  1 2 1").unwrap();
+
+        let (resultant, x, y) = poly_1.xgcd(&poly_2);
+
+        assert_eq!(Z::ZERO, resultant);
+        assert_eq!(PolyOverZ::default(), x);
+        assert_eq!(PolyOverZ::default(), y);
+    }
+
+    /// Ensures that the resultant and `a*x + b*y = r` are correct
+    /// for polynomials that are not primitive
+    #[test]
+    fn non_primitive() {
+        let poly_1 = PolyOverZ::from_str("2  2 4").unwrap();
+        let poly_2 = PolyOverZ::from_str("3  1 0 1").unwrap();
+        let poly_3 = PolyOverZ::from_str("3  3 0 3").unwrap();
+
+        let (resultant_1, x_1, y_1) = poly_1.xgcd(&poly_2);
+        let (resultant_2, x_2, y_2) = poly_1.xgcd(&poly_3);
+
+        // Res(2 + 4X, 1 + X^2) = 2^2 * Res(1 + 2X, 1 + X^2) = 4 * 5
+        assert_eq!(Z::from(20), resultant_1);
+        assert_eq!(
+            PolyOverZ::from_str("1  20").unwrap(),
+            &poly_1 * &x_1 + &poly_2 * &y_1
+        );
+        assert_eq!(Z::from(60), resultant_2);
+        assert_eq!(
+            PolyOverZ::from_str("1  60").unwrap(),
+            &poly_1 * &x_2 + &poly_3 * &y_2
+        );
+    }
+
+    /// Ensures that constant polynomials are handled correctly
+    #[test]
+    fn constant() {
+        let poly = PolyOverZ::from_str("3  1 2 1").unwrap();
+        let constant_1 = PolyOverZ::from_str("1  4").unwrap();
+        let constant_2 = PolyOverZ::from_str("1  -6").unwrap();
+
+        let (resultant_1, x_1, y_1) = poly.xgcd(&constant_1);
+        let (resultant_2, x_2, y_2) = constant_1.xgcd(&poly);
+        let (gcd, x_3, y_3) = constant_1.xgcd(&constant_2);
+
+        assert_eq!(Z::from(16), resultant_1);
+        assert_eq!(
+            PolyOverZ::from_str("1  16").unwrap(),
+            &poly * &x_1 + &constant_1 * &y_1
+        );
+        assert_eq!(Z::from(16), resultant_2);
+        assert_eq!(
+            PolyOverZ::from_str("1  16").unwrap(),
+            &constant_1 * &x_2 + &poly * &y_2
+        );
+        assert_eq!(Z::from(2), gcd);
+        assert_eq!(
+            PolyOverZ::from_str("1  2").unwrap(),
+            &constant_1 * &x_3 + &constant_2 * &y_3
+        );
+    }
+
+    /// Ensures that the resultant is zero if one of the polynomials is zero
+    #[test]
+    fn zero() {
+        let poly = PolyOverZ::from_str("3  1 0 -7").unwrap();
+
+        let (resultant, _, _) = poly.xgcd(&PolyOverZ::default());
+
+        assert_eq!(Z::ZERO, resultant);
+    }
+}
@@ -6,12 +6,21 @@
 // the terms of the Mozilla Public License Version 2.0 as published by the
 // Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
 
-//! Implementations to get coefficients of a [`PolyOverZ`].
+//! Implementations to get coefficients and the degree of a [`PolyOverZ`].
 //! Each reasonable type should be allowed as a index.
 
 use super::PolyOverZ;
-use crate::{error::MathError, integer::Z, traits::GetCoefficient, utils::index::evaluate_index};
-use flint_sys::fmpz_poly::fmpz_poly_get_coeff_fmpz;
+use crate::{
+    error::MathError,
+    integer::{MatZ, Z},
+    traits::GetCoefficient,
+    utils::index::evaluate_index,
+};
+use flint_sys::{
+    fmpz::fmpz_set,
+    fmpz_mat::fmpz_mat_entry,
+    fmpz_poly::{fmpz_poly_degree, fmpz_poly_get_coeff_fmpz},
+};
 use std::fmt::Display;
 
 impl GetCoefficient<Z> for PolyOverZ {
@@ -49,6 +58,99 @@ impl GetCoefficient<Z> for PolyOverZ {
     }
 }
 
+impl PolyOverZ {
+    /// Returns the degree of a polynomial [`PolyOverZ`] as a [`i64`].
+    /// The zero polynomial has degree `-1`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("4  0 1 2 3").unwrap();
+    ///
+    /// assert_eq!(3, poly.get_degree());
+    /// assert_eq!(-1, PolyOverZ::default().get_degree());
+    /// ```
+    pub fn get_degree(&self) -> i64 {
+        unsafe { fmpz_poly_degree(&self.poly) }
+    }
+
+    /// Returns the leading coefficient of a polynomial [`PolyOverZ`] as a [`Z`],
+    /// i.e. the coefficient of the highest power of `X` with a non-zero coefficient.
+    /// The leading coefficient of the zero polynomial is `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("4  0 1 2 -3").unwrap();
+    ///
+    /// assert_eq!(Z::from(-3), poly.get_leading_coeff());
+    /// ```
+    pub fn get_leading_coeff(&self) -> Z {
+        let mut out = Z::default();
+        if !self.is_zero() {
+            unsafe { fmpz_poly_get_coeff_fmpz(&mut out.value, &self.poly, self.get_degree()) }
+        }
+        out
+    }
+
+    /// Returns the coefficient vector of a polynomial [`PolyOverZ`] as a row vector
+    /// of type [`MatZ`], i.e. the `i`-th entry is the coefficient of `X^i`.
+    /// The vector is padded with zeros up to the given `length`.
+    /// A column vector can be obtained using [`MatZ::transpose`].
+    ///
+    /// Parameters:
+    /// - `length`: the number of entries of the vector, i.e. the number of coefficients
+    ///
+    /// Returns a row vector with `length` entries or an error if `length` is smaller
+    /// than the number of coefficients of `self`, i.e. its degree plus one.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatZ, PolyOverZ};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("3  1 0 -3").unwrap();
+    ///
+    /// let vector = poly.to_vec(5).unwrap();
+    ///
+    /// assert_eq!(MatZ::from_str("[[1, 0, -3, 0, 0]]").unwrap(), vector);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// `length` is smaller than the number of coefficients of `self`,
+    /// negative or does not fit into an [`i64`].
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `length` is `0`.
+    pub fn to_vec(&self, length: impl TryInto<i64> + Display + Copy) -> Result<MatZ, MathError> {
+        let length = evaluate_index(length)?;
+        if length < self.poly.length {
+            return Err(MathError::OutOfBounds(
+                format!(
+                    "be at least the number of coefficients {}",
+                    self.poly.length
+                ),
+                length.to_string(),
+            ));
+        }
+
+        let out = MatZ::new(1, length)?;
+        for index in 0..self.poly.length {
+            unsafe {
+                fmpz_set(
+                    fmpz_mat_entry(&out.matrix, 0, index),
+                    self.poly.coeffs.offset(index as isize),
+                )
+            };
+        }
+        Ok(out)
+    }
+}
+
 #[cfg(test)]
 mod test_get_coeff {
 
@@ -111,3 +213,108 @@ mod test_get_coeff {
         assert_eq!(Z::from(i64::MIN), poly.get_coeff(1).unwrap());
     }
 }
+
+#[cfg(test)]
+mod test_get_degree {
+    use crate::integer::PolyOverZ;
+    use std::str::FromStr;
+
+    /// Ensure that the degree is correct for polynomials with leading zeros
+    /// in their string representation and large coefficients
+    #[test]
+    fn degree() {
+        let poly_1 = PolyOverZ::from_str("5  1 2 3 0 0").unwrap();
+        let poly_2 = PolyOverZ::from_str(&format!("2  {} {}", u64::MAX, i64::MIN)).unwrap();
+        let poly_3 = PolyOverZ::from_str("1  -7").unwrap();
+
+        assert_eq!(2, poly_1.get_degree());
+        assert_eq!(1, poly_2.get_degree());
+        assert_eq!(0, poly_3.get_degree());
+    }
+
+    /// Ensure that the zero polynomial has degree `-1`
+    #[test]
+    fn zero() {
+        assert_eq!(-1, PolyOverZ::default().get_degree());
+        assert_eq!(-1, PolyOverZ::from_str("3  0 0 0").unwrap().get_degree());
+    }
+}
+
+#[cfg(test)]
+mod test_get_leading_coeff {
+    use crate::integer::{PolyOverZ, Z};
+    use std::str::FromStr;
+
+    /// Ensure that the leading coefficient is returned for small
+    /// and large coefficients
+    #[test]
+    fn leading_coeff() {
+        let poly_1 = PolyOverZ::from_str("5  1 2 -3 0 0").unwrap();
+        let poly_2 = PolyOverZ::from_str(&format!("2  {} {}", u64::MAX, i64::MIN)).unwrap();
+
+        assert_eq!(Z::from(-3), poly_1.get_leading_coeff());
+        assert_eq!(Z::from(i64::MIN), poly_2.get_leading_coeff());
+    }
+
+    /// Ensure that the leading coefficient of the zero polynomial is `0`
+    #[test]
+    fn zero() {
+        assert_eq!(Z::ZERO, PolyOverZ::default().get_leading_coeff());
+    }
+}
+
+#[cfg(test)]
+mod test_to_vec {
+    use crate::{
+        error::MathError,
+        integer::{MatZ, PolyOverZ},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that the coefficients are padded with zeros
+    #[test]
+    fn padding() {
+        let poly = PolyOverZ::from_str(&format!("3  {} 0 {}", u64::MAX, i64::MIN)).unwrap();
+
+        let vector = poly.to_vec(4).unwrap();
+
+        assert_eq!(
+            MatZ::from_str(&format!("[[{}, 0, {}, 0]]", u64::MAX, i64::MIN)).unwrap(),
+            vector
+        );
+    }
+
+    /// Ensure that converting to a vector and back yields the same polynomial
+    #[test]
+    fn round_trip() {
+        let poly = PolyOverZ::from_str("5  -1 2 0 -3 4").unwrap();
+
+        let row = poly.to_vec(5).unwrap();
+        let column = poly.to_vec(7).unwrap().transpose();
+
+        assert_eq!(poly, PolyOverZ::from_vec(&row).unwrap());
+        assert_eq!(poly, PolyOverZ::from_vec(&column).unwrap());
+    }
+
+    /// Ensure that the zero polynomial yields a zero vector
+    #[test]
+    fn zero() {
+        assert_eq!(
+            MatZ::new(1, 3).unwrap(),
+            PolyOverZ::default().to_vec(3).unwrap()
+        );
+    }
+
+    /// Ensure that an error is returned if the length is too small
+    #[test]
+    fn length_too_small() {
+        let poly = PolyOverZ::from_str("3  1 2 3").unwrap();
+
+        assert!(matches!(poly.to_vec(2), Err(MathError::OutOfBounds(_, _))));
+        assert!(matches!(poly.to_vec(-1), Err(MathError::OutOfBounds(_, _))));
+        assert!(matches!(
+            PolyOverZ::default().to_vec(0),
+            Err(MathError::InvalidMatrix(_))
+        ));
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the interpolation of a [`PolyOverZ`]
+//! through a given set of points.
+
+use super::PolyOverZ;
+use crate::{
+    error::MathError,
+    integer::Z,
+    rational::{PolyOverQ, Q},
+};
+use flint_sys::{fmpq_poly::fmpq_poly_get_numerator, fmpz::fmpz_is_one};
+
+impl PolyOverZ {
+    /// Computes the unique polynomial of degree smaller than the number of
+    /// points, which passes through all given points, i.e. the Lagrange interpolant.
+    /// The interpolant is computed over the rationals, see [`PolyOverQ::interpolate`],
+    /// and has to have integer coefficients.
+    ///
+    /// Parameters:
+    /// - `points`: the points `(x, y)` the polynomial passes through
+    ///
+    /// Returns the interpolating polynomial as a [`PolyOverZ`] or an error if
+    /// the x-coordinates of the points are not pairwise distinct or the
+    /// interpolant has non-integral coefficients.
+    /// For an empty slice, the zero polynomial is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let points = [
+    ///     (Z::from(0), Z::from(1)),
+    ///     (Z::from(1), Z::from(2)),
+    ///     (Z::from(-1), Z::from(4)),
+    /// ];
+    ///
+    /// let poly = PolyOverZ::interpolate(&points).unwrap();
+    ///
+    /// assert_eq!(PolyOverZ::from_str("3  1 -1 2").unwrap(), poly);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`InvalidInterpolationPoints`](MathError::InvalidInterpolationPoints)
+    /// if two points have the same x-coordinate.
+    /// - Returns a [`MathError`] of type [`ConversionError`](MathError::ConversionError)
+    /// if the interpolant does not have integer coefficients.
+    pub fn interpolate(points: &[(Z, Z)]) -> Result<Self, MathError> {
+        let points: Vec<(Q, Q)> = points
+            .iter()
+            .map(|(x, y)| (Q::from(x), Q::from(y)))
+            .collect();
+        let interpolant = PolyOverQ::interpolate(&points)?;
+
+        if 0 == unsafe { fmpz_is_one(&interpolant.poly.den[0]) } {
+            return Err(MathError::ConversionError(format!(
+                "The interpolant {} does not have integer coefficients.",
+                interpolant
+            )));
+        }
+        let mut out = PolyOverZ::default();
+        unsafe { fmpq_poly_get_numerator(&mut out.poly, &interpolant.poly) };
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_interpolate {
+    use super::PolyOverZ;
+    use crate::{error::MathError, integer::Z, traits::Evaluate};
+    use std::str::FromStr;
+
+    /// Ensure that a polynomial is recovered from sufficiently many evaluations
+    #[test]
+    fn recover_polynomial() {
+        let poly = PolyOverZ::from_str(&format!("4  {} -3 0 {}", i64::MIN, u64::MAX)).unwrap();
+        let points: Vec<(Z, Z)> = [-5, 0, 2, 17]
+            .iter()
+            .map(|x| (Z::from(*x), poly.evaluate(*x)))
+            .collect();
+
+        assert_eq!(poly, PolyOverZ::interpolate(&points).unwrap());
+    }
+
+    /// Ensure that a single point yields a constant polynomial
+    /// and no points yield the zero polynomial
+    #[test]
+    fn few_points() {
+        let points = [(Z::from(5), Z::from(-2))];
+
+        assert_eq!(
+            PolyOverZ::from_str("1  -2").unwrap(),
+            PolyOverZ::interpolate(&points).unwrap()
+        );
+        assert_eq!(PolyOverZ::default(), PolyOverZ::interpolate(&[]).unwrap());
+    }
+
+    /// Ensure that an error is returned if the interpolant is not integral
+    #[test]
+    fn not_integral() {
+        let points = [(Z::from(0), Z::from(0)), (Z::from(2), Z::from(1))];
+
+        assert!(matches!(
+            PolyOverZ::interpolate(&points),
+            Err(MathError::ConversionError(_))
+        ));
+    }
+
+    /// Ensure that an error is returned for duplicate x-coordinates
+    #[test]
+    fn duplicate_x() {
+        let points = [(Z::from(1), Z::from(0)), (Z::from(1), Z::from(0))];
+
+        assert!(matches!(
+            PolyOverZ::interpolate(&points),
+            Err(MathError::InvalidInterpolationPoints(_))
+        ));
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the coefficient-wise reduction of a [`PolyOverZ`]
+//! modulo a [`Modulus`].
+
+use super::PolyOverZ;
+use crate::integer_mod_q::{Modulus, PolyOverZq};
+
+impl PolyOverZ {
+    /// Reduces each coefficient of `self` modulo `modulus`.
+    /// The reverse operation is given by [`PolyOverZq::lift`]
+    /// and [`PolyOverZq::lift_symmetric`].
+    ///
+    /// Parameters:
+    /// - `modulus`: specifies the modulus by which the coefficients are reduced
+    ///
+    /// Returns the reduced polynomial as a [`PolyOverZq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, PolyOverZq};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("3  -1 5 9").unwrap();
+    /// let modulus = Modulus::from_str("7").unwrap();
+    ///
+    /// let reduced = poly.modulo(&modulus);
+    ///
+    /// assert_eq!(PolyOverZq::from_str("3  6 5 2 mod 7").unwrap(), reduced);
+    /// ```
+    pub fn modulo(&self, modulus: &Modulus) -> PolyOverZq {
+        PolyOverZq::from_poly_over_z(self, modulus)
+    }
+}
+
+#[cfg(test)]
+mod test_modulo {
+    use super::PolyOverZ;
+    use crate::integer_mod_q::{Modulus, PolyOverZq};
+    use std::str::FromStr;
+
+    /// Ensure that large and negative coefficients are reduced correctly
+    #[test]
+    fn large_coefficients() {
+        let poly = PolyOverZ::from_str(&format!("3  {} -1 {}", u64::MAX, i64::MIN)).unwrap();
+        let modulus = Modulus::from_str(&i64::MAX.to_string()).unwrap();
+
+        let reduced = poly.modulo(&modulus);
+
+        assert_eq!(
+            PolyOverZq::from_str(&format!("3  1 {} -1 mod {}", i64::MAX - 1, i64::MAX)).unwrap(),
+            reduced
+        );
+    }
+
+    /// Ensure that reducing and lifting yields the original polynomial
+    /// for coefficients in the respective intervals
+    #[test]
+    fn round_trip() {
+        let modulus = Modulus::from_str("17").unwrap();
+        let canonical = PolyOverZ::from_str("4  0 16 3 8").unwrap();
+        let symmetric = PolyOverZ::from_str("4  0 -8 3 8").unwrap();
+
+        assert_eq!(canonical, canonical.modulo(&modulus).lift());
+        assert_eq!(symmetric, symmetric.modulo(&modulus).lift_symmetric());
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module includes functionality to compute several norms
+//! defined on the coefficient vector of polynomials.
+
+use super::PolyOverZ;
+use crate::integer::Z;
+use flint_sys::{fmpz_poly::fmpz_poly_height, fmpz_vec::_fmpz_vec_dot};
+
+impl PolyOverZ {
+    /// Returns the squared Euclidean norm or 2-norm of the coefficient vector
+    /// of the given polynomial.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("3  1 2 -3").unwrap();
+    ///
+    /// let sqrd_2_norm = poly.norm_eucl_sqrd();
+    ///
+    /// // 1*1 + 2*2 + (-3)*(-3) = 14
+    /// assert_eq!(Z::from(14), sqrd_2_norm);
+    /// ```
+    pub fn norm_eucl_sqrd(&self) -> Z {
+        let mut result = Z::ZERO;
+        unsafe {
+            _fmpz_vec_dot(
+                &mut result.value,
+                self.poly.coeffs,
+                self.poly.coeffs,
+                self.poly.length,
+            )
+        };
+        result
+    }
+
+    /// Returns the infinity norm or ∞-norm of the coefficient vector
+    /// of the given polynomial.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("3  1 2 -3").unwrap();
+    ///
+    /// let infty_norm = poly.norm_infty();
+    ///
+    /// // max{1, 2, |-3|} = 3
+    /// assert_eq!(Z::from(3), infty_norm);
+    /// ```
+    pub fn norm_infty(&self) -> Z {
+        let mut result = Z::ZERO;
+        unsafe { fmpz_poly_height(&mut result.value, &self.poly) };
+        result
+    }
+}
+
+#[cfg(test)]
+mod test_norm_eucl_sqrd {
+    use super::{PolyOverZ, Z};
+    use std::str::FromStr;
+
+    /// Check whether the squared euclidean norm for polynomials
+    /// with small coefficients is calculated correctly
+    #[test]
+    fn small_coefficients() {
+        let poly_1 = PolyOverZ::from_str("1  1").unwrap();
+        let poly_2 = PolyOverZ::from_str("3  1 10 100").unwrap();
+        let poly_3 = PolyOverZ::from_str("4  1 -10 100 -1000").unwrap();
+
+        assert_eq!(poly_1.norm_eucl_sqrd(), Z::ONE);
+        assert_eq!(poly_2.norm_eucl_sqrd(), Z::from(10101));
+        assert_eq!(poly_3.norm_eucl_sqrd(), Z::from(1010101));
+    }
+
+    /// Check whether the squared euclidean norm for polynomials
+    /// with large coefficients is calculated correctly
+    #[test]
+    fn large_coefficients() {
+        let poly = PolyOverZ::from_str(&format!("3  {} {} 2", i64::MAX, i64::MIN)).unwrap();
+        let max = Z::from(i64::MAX);
+        let min = Z::from(i64::MIN);
+        let cmp = &min * &min + &max * &max + Z::from(4);
+
+        assert_eq!(poly.norm_eucl_sqrd(), cmp);
+    }
+
+    /// Check whether the squared euclidean norm of the zero polynomial is `0`
+    #[test]
+    fn zero() {
+        assert_eq!(PolyOverZ::default().norm_eucl_sqrd(), Z::ZERO);
+    }
+}
+
+#[cfg(test)]
+mod test_norm_infty {
+    use super::{PolyOverZ, Z};
+    use std::str::FromStr;
+
+    /// Check whether the infinity norm for polynomials
+    /// with small coefficients is calculated correctly
+    #[test]
+    fn small_coefficients() {
+        let poly_1 = PolyOverZ::from_str("1  1").unwrap();
+        let poly_2 = PolyOverZ::from_str("3  1 10 100").unwrap();
+        let poly_3 = PolyOverZ::from_str("4  1 10 -1000 100").unwrap();
+
+        assert_eq!(poly_1.norm_infty(), Z::ONE);
+        assert_eq!(poly_2.norm_infty(), Z::from(100));
+        assert_eq!(poly_3.norm_infty(), Z::from(1000));
+    }
+
+    /// Check whether the infinity norm for polynomials
+    /// with large coefficients is calculated correctly
+    #[test]
+    fn large_coefficients() {
+        let poly = PolyOverZ::from_str(&format!("3  {} {} 2", i64::MAX, i64::MIN)).unwrap();
+        let cmp = Z::from(-1) * Z::from(i64::MIN);
+
+        assert_eq!(poly.norm_infty(), cmp);
+    }
+
+    /// Check whether the infinity norm of the zero polynomial is `0`
+    #[test]
+    fn zero() {
+        assert_eq!(PolyOverZ::default().norm_infty(), Z::ZERO);
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module includes functionality about properties of [`PolyOverZ`] instances.
+
+use super::PolyOverZ;
+use flint_sys::fmpz_poly::fmpz_poly_is_one;
+
+impl PolyOverZ {
+    /// Checks if a [`PolyOverZ`] is the zero polynomial.
+    ///
+    /// Returns true if all coefficients are `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let value = PolyOverZ::from_str("3  0 0 0").unwrap();
+    /// assert!(value.is_zero())
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.get_degree() == -1
+    }
+
+    /// Checks if a [`PolyOverZ`] is the constant polynomial `1`.
+    ///
+    /// Returns true if the constant coefficient is `1` and all others are `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let value = PolyOverZ::from_str("3  1 0 0").unwrap();
+    /// assert!(value.is_one())
+    /// ```
+    pub fn is_one(&self) -> bool {
+        1 == unsafe { fmpz_poly_is_one(&self.poly) }
+    }
+}
+
+#[cfg(test)]
+mod test_is_zero {
+    use super::PolyOverZ;
+    use std::str::FromStr;
+
+    /// Ensure that is_zero returns `true` for the zero polynomial
+    #[test]
+    fn zero_detection() {
+        let zero_1 = PolyOverZ::default();
+        let zero_2 = PolyOverZ::from_str("2  0 0").unwrap();
+
+        assert!(zero_1.is_zero());
+        assert!(zero_2.is_zero());
+    }
+
+    /// Ensure that is_zero returns `false` for non-zero polynomials
+    #[test]
+    fn zero_rejection() {
+        let small = PolyOverZ::from_str("3  0 0 1").unwrap();
+        let large = PolyOverZ::from_str(&format!("1  {}", u64::MAX)).unwrap();
+
+        assert!(!small.is_zero());
+        assert!(!large.is_zero());
+    }
+}
+
+#[cfg(test)]
+mod test_is_one {
+    use super::PolyOverZ;
+    use std::str::FromStr;
+
+    /// Ensure that is_one returns `true` for the one polynomial
+    #[test]
+    fn one_detection() {
+        let one = PolyOverZ::from_str("3  1 0 0").unwrap();
+
+        assert!(one.is_one());
+    }
+
+    /// Ensure that is_one returns `false` for other polynomials
+    #[test]
+    fn one_rejection() {
+        let zero = PolyOverZ::default();
+        let minus_one = PolyOverZ::from_str("1  -1").unwrap();
+        let shifted_one = PolyOverZ::from_str("2  0 1").unwrap();
+        let large = PolyOverZ::from_str(&format!("2  1 {}", u64::MAX)).unwrap();
+
+        assert!(!zero.is_one());
+        assert!(!minus_one.is_one());
+        assert!(!shifted_one.is_one());
+        assert!(!large.is_one());
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains algorithms for sampling [`PolyOverZ`] instances
+//! with coefficients chosen uniformly at random.
+
+use super::PolyOverZ;
+use crate::{
+    error::MathError,
+    integer::Z,
+    traits::SetCoefficient,
+    utils::{index::evaluate_index, sample::sample_uniform_rejection},
+};
+use std::fmt::Display;
+
+impl PolyOverZ {
+    /// Samples a polynomial of degree at most `max_degree`, whose coefficients
+    /// are chosen uniformly at random from `[lower_bound, upper_bound)` using
+    /// a cryptographically secure pseudorandom number generator.
+    ///
+    /// Parameters:
+    /// - `max_degree`: specifies the maximal degree of the sampled polynomial
+    /// - `lower_bound`: specifies the included lower bound of the coefficients
+    /// - `upper_bound`: specifies the excluded upper bound of the coefficients
+    ///
+    /// Returns a fresh [`PolyOverZ`] with `max_degree + 1` uniformly sampled
+    /// coefficients or an error if the interval is empty or `max_degree` is negative.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    ///
+    /// let poly = PolyOverZ::sample_uniform(3, -5, 5).unwrap();
+    ///
+    /// assert!(poly.get_degree() <= 3);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NonPositive`](MathError::NonPositive)
+    /// if `upper_bound` is not larger than `lower_bound`.
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// `max_degree` is negative or it does not fit into an [`i64`].
+    pub fn sample_uniform(
+        max_degree: impl TryInto<i64> + Display + Copy,
+        lower_bound: impl Into<Z>,
+        upper_bound: impl Into<Z>,
+    ) -> Result<Self, MathError> {
+        let max_degree = evaluate_index(max_degree)?;
+        let lower_bound: Z = lower_bound.into();
+        let interval_size = upper_bound.into() - &lower_bound;
+
+        let mut poly = PolyOverZ::default();
+        for index in 0..=max_degree {
+            let sample = sample_uniform_rejection(&interval_size)?;
+            poly.set_coeff(index, &sample + &lower_bound)?;
+        }
+        Ok(poly)
+    }
+
+    /// Samples a polynomial of degree at most `max_degree`, whose coefficients
+    /// are chosen uniformly at random from `{-1, 0, 1}` using
+    /// a cryptographically secure pseudorandom number generator.
+    ///
+    /// Parameters:
+    /// - `max_degree`: specifies the maximal degree of the sampled polynomial
+    ///
+    /// Returns a fresh ternary [`PolyOverZ`] with `max_degree + 1` uniformly
+    /// sampled coefficients or an error if `max_degree` is negative.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    ///
+    /// let poly = PolyOverZ::sample_ternary(255).unwrap();
+    ///
+    /// assert!(poly.get_degree() <= 255);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// `max_degree` is negative or it does not fit into an [`i64`].
+    pub fn sample_ternary(
+        max_degree: impl TryInto<i64> + Display + Copy,
+    ) -> Result<Self, MathError> {
+        Self::sample_uniform(max_degree, -1, 2)
+    }
+}
+
+#[cfg(test)]
+mod test_sample_uniform {
+    use super::PolyOverZ;
+    use crate::{error::MathError, integer::Z, traits::GetCoefficient};
+    use std::str::FromStr;
+
+    /// Ensure that all coefficients lie in the interval and the degree is bounded
+    #[test]
+    fn coefficients_in_interval() {
+        let lower_bound = Z::from(i64::MIN);
+        let upper_bound = Z::from(u64::MAX);
+
+        let poly = PolyOverZ::sample_uniform(64, &lower_bound, &upper_bound).unwrap();
+
+        assert!(poly.get_degree() <= 64);
+        for index in 0..=64 {
+            let coeff = poly.get_coeff(index).unwrap();
+            assert!(lower_bound <= coeff);
+            assert!(coeff < upper_bound);
+        }
+    }
+
+    /// Ensure that every value of a small interval is sampled
+    #[test]
+    fn all_values_sampled() {
+        let poly = PolyOverZ::sample_uniform(300, 7, 10).unwrap();
+        let coeffs: Vec<Z> = (0..=300).map(|i| poly.get_coeff(i).unwrap()).collect();
+
+        for value in 7..10 {
+            assert!(coeffs.contains(&Z::from(value)));
+        }
+    }
+
+    /// Ensure that an interval of size `1` yields a fixed polynomial
+    #[test]
+    fn single_value() {
+        let poly = PolyOverZ::sample_uniform(2, -3, -2).unwrap();
+
+        assert_eq!(PolyOverZ::from_str("3  -3 -3 -3").unwrap(), poly);
+    }
+
+    /// Ensure that an error is returned for empty intervals and negative degrees
+    #[test]
+    fn invalid_parameters() {
+        assert!(matches!(
+            PolyOverZ::sample_uniform(3, 5, 5),
+            Err(MathError::NonPositive(_))
+        ));
+        assert!(matches!(
+            PolyOverZ::sample_uniform(3, 5, -5),
+            Err(MathError::NonPositive(_))
+        ));
+        assert!(matches!(
+            PolyOverZ::sample_uniform(-1, 0, 5),
+            Err(MathError::OutOfBounds(_, _))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_sample_ternary {
+    use super::PolyOverZ;
+    use crate::{integer::Z, traits::GetCoefficient};
+
+    /// Ensure that all coefficients are ternary and each value is sampled
+    #[test]
+    fn ternary_coefficients() {
+        let poly = PolyOverZ::sample_ternary(300).unwrap();
+        let coeffs: Vec<Z> = (0..=300).map(|i| poly.get_coeff(i).unwrap()).collect();
+
+        assert!(poly.get_degree() <= 300);
+        for coeff in coeffs.iter() {
+            assert!(&Z::MINUS_ONE <= coeff && coeff <= &Z::ONE);
+        }
+        for value in -1..=1 {
+            assert!(coeffs.contains(&Z::from(value)));
+        }
+    }
+
+    /// Ensure that an error is returned for negative degrees
+    #[test]
+    fn negative_degree() {
+        assert!(PolyOverZ::sample_ternary(-1).is_err());
+    }
+}
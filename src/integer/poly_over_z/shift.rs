@@ -0,0 +1,294 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains shifts of the coefficients of a [`PolyOverZ`]
+//! as well as the truncation and reversal of polynomials.
+
+use super::PolyOverZ;
+use crate::{error::MathError, utils::index::evaluate_index};
+use flint_sys::fmpz_poly::{
+    fmpz_poly_reverse, fmpz_poly_shift_left, fmpz_poly_shift_right, fmpz_poly_truncate,
+};
+use std::fmt::Display;
+
+impl PolyOverZ {
+    /// Multiplies `self` by `X^k`, i.e. shifts all coefficients `k` positions
+    /// to higher degrees.
+    ///
+    /// Parameters:
+    /// - `k`: specifies the exponent of `X` with which `self` is multiplied
+    ///
+    /// Returns `self * X^k` as a [`PolyOverZ`] or an error if `k` is negative.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("3  1 2 3").unwrap();
+    ///
+    /// let shifted = poly.mul_xk(2).unwrap();
+    ///
+    /// assert_eq!(PolyOverZ::from_str("5  0 0 1 2 3").unwrap(), shifted);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// `k` is negative or it does not fit into an [`i64`].
+    pub fn mul_xk(&self, k: impl TryInto<i64> + Display + Copy) -> Result<Self, MathError> {
+        let k = evaluate_index(k)?;
+
+        let mut out = PolyOverZ::default();
+        unsafe { fmpz_poly_shift_left(&mut out.poly, &self.poly, k) };
+        Ok(out)
+    }
+
+    /// Divides `self` by `X^k` discarding the remainder, i.e. shifts all coefficients
+    /// `k` positions to lower degrees and drops the `k` lowest coefficients.
+    ///
+    /// Parameters:
+    /// - `k`: specifies the exponent of `X` by which `self` is divided
+    ///
+    /// Returns the quotient of `self` and `X^k` as a [`PolyOverZ`]
+    /// or an error if `k` is negative.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("4  1 2 3 4").unwrap();
+    ///
+    /// let shifted = poly.div_xk(2).unwrap();
+    ///
+    /// assert_eq!(PolyOverZ::from_str("2  3 4").unwrap(), shifted);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// `k` is negative or it does not fit into an [`i64`].
+    pub fn div_xk(&self, k: impl TryInto<i64> + Display + Copy) -> Result<Self, MathError> {
+        let k = evaluate_index(k)?;
+
+        let mut out = PolyOverZ::default();
+        // FLINT requires the shift to be at most the length of the polynomial
+        if k < self.poly.length {
+            unsafe { fmpz_poly_shift_right(&mut out.poly, &self.poly, k) };
+        }
+        Ok(out)
+    }
+
+    /// Truncates `self` to its `length` lowest coefficients,
+    /// i.e. reduces `self` modulo `X^length`.
+    ///
+    /// Parameters:
+    /// - `length`: specifies the number of coefficients that are kept
+    ///
+    /// Returns an empty `Ok` if the truncation was successful
+    /// or an error if `length` is negative.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let mut poly = PolyOverZ::from_str("4  1 2 3 4").unwrap();
+    ///
+    /// poly.truncate(2).unwrap();
+    ///
+    /// assert_eq!(PolyOverZ::from_str("2  1 2").unwrap(), poly);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// `length` is negative or it does not fit into an [`i64`].
+    pub fn truncate(
+        &mut self,
+        length: impl TryInto<i64> + Display + Copy,
+    ) -> Result<(), MathError> {
+        let length = evaluate_index(length)?;
+
+        unsafe { fmpz_poly_truncate(&mut self.poly, length) };
+        Ok(())
+    }
+
+    /// Reverses the order of the coefficients of `self`,
+    /// i.e. computes `X^d * self(1/X)` for the degree `d` of `self`.
+    /// Trailing zero coefficients of `self` are dropped by the reversal.
+    ///
+    /// Returns the reversed polynomial as a [`PolyOverZ`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("4  0 1 2 3").unwrap();
+    ///
+    /// let reversed = poly.reverse();
+    ///
+    /// assert_eq!(PolyOverZ::from_str("3  3 2 1").unwrap(), reversed);
+    /// ```
+    pub fn reverse(&self) -> Self {
+        let mut out = PolyOverZ::default();
+        unsafe { fmpz_poly_reverse(&mut out.poly, &self.poly, self.poly.length) };
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_mul_xk {
+    use super::PolyOverZ;
+    use std::str::FromStr;
+
+    /// Ensure that shifting works for large coefficients and
+    /// coincides with the multiplication by `X^k`
+    #[test]
+    fn large_coefficients() {
+        let poly = PolyOverZ::from_str(&format!("3  {} 0 {}", i64::MIN, u64::MAX)).unwrap();
+        let x_k = PolyOverZ::from_str("4  0 0 0 1").unwrap();
+
+        assert_eq!(&poly * &x_k, poly.mul_xk(3).unwrap());
+    }
+
+    /// Ensure that shifting by zero and shifting the zero polynomial
+    /// does not change the polynomial
+    #[test]
+    fn trivial_shifts() {
+        let poly = PolyOverZ::from_str("3  1 2 3").unwrap();
+
+        assert_eq!(poly, poly.mul_xk(0).unwrap());
+        assert_eq!(
+            PolyOverZ::default(),
+            PolyOverZ::default().mul_xk(5).unwrap()
+        );
+    }
+
+    /// Ensure that an error is returned for negative shifts
+    #[test]
+    fn negative() {
+        let poly = PolyOverZ::from_str("3  1 2 3").unwrap();
+
+        assert!(poly.mul_xk(-1).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_div_xk {
+    use super::PolyOverZ;
+    use std::str::FromStr;
+
+    /// Ensure that shifting works for large coefficients and
+    /// reverts [`PolyOverZ::mul_xk`]
+    #[test]
+    fn large_coefficients() {
+        let poly = PolyOverZ::from_str(&format!("3  {} 0 {}", i64::MIN, u64::MAX)).unwrap();
+
+        assert_eq!(poly, poly.mul_xk(7).unwrap().div_xk(7).unwrap());
+    }
+
+    /// Ensure that shifting by at least the length of the polynomial
+    /// yields the zero polynomial
+    #[test]
+    fn beyond_degree() {
+        let poly = PolyOverZ::from_str("3  1 2 3").unwrap();
+
+        assert_eq!(
+            PolyOverZ::from_str("1  3").unwrap(),
+            poly.div_xk(2).unwrap()
+        );
+        assert_eq!(PolyOverZ::default(), poly.div_xk(3).unwrap());
+        assert_eq!(PolyOverZ::default(), poly.div_xk(u64::MAX >> 1).unwrap());
+        assert_eq!(
+            PolyOverZ::default(),
+            PolyOverZ::default().div_xk(0).unwrap()
+        );
+    }
+
+    /// Ensure that an error is returned for negative shifts
+    #[test]
+    fn negative() {
+        let poly = PolyOverZ::from_str("3  1 2 3").unwrap();
+
+        assert!(poly.div_xk(-1).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_truncate {
+    use super::PolyOverZ;
+    use std::str::FromStr;
+
+    /// Ensure that truncation works for large coefficients
+    /// and normalises the polynomial
+    #[test]
+    fn large_coefficients() {
+        let mut poly = PolyOverZ::from_str(&format!("5  {} 0 0 {} 1", i64::MIN, u64::MAX)).unwrap();
+
+        poly.truncate(4).unwrap();
+        assert_eq!(
+            PolyOverZ::from_str(&format!("4  {} 0 0 {}", i64::MIN, u64::MAX)).unwrap(),
+            poly
+        );
+
+        poly.truncate(3).unwrap();
+        assert_eq!(
+            PolyOverZ::from_str(&format!("1  {}", i64::MIN)).unwrap(),
+            poly
+        );
+        assert_eq!(0, poly.get_degree());
+    }
+
+    /// Ensure that truncating to a larger length does not change the polynomial
+    /// and truncating to zero yields the zero polynomial
+    #[test]
+    fn edge_cases() {
+        let mut poly = PolyOverZ::from_str("3  1 2 3").unwrap();
+
+        poly.truncate(10).unwrap();
+        assert_eq!(PolyOverZ::from_str("3  1 2 3").unwrap(), poly);
+
+        poly.truncate(0).unwrap();
+        assert_eq!(PolyOverZ::default(), poly);
+    }
+
+    /// Ensure that an error is returned for negative lengths
+    #[test]
+    fn negative() {
+        let mut poly = PolyOverZ::from_str("3  1 2 3").unwrap();
+
+        assert!(poly.truncate(-1).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_reverse {
+    use super::PolyOverZ;
+    use std::str::FromStr;
+
+    /// Ensure that reversing works for large coefficients
+    /// and reversing twice yields the original polynomial
+    #[test]
+    fn large_coefficients() {
+        let poly = PolyOverZ::from_str(&format!("3  {} 5 {}", i64::MIN, u64::MAX)).unwrap();
+
+        let reversed = poly.reverse();
+
+        assert_eq!(
+            PolyOverZ::from_str(&format!("3  {} 5 {}", u64::MAX, i64::MIN)).unwrap(),
+            reversed
+        );
+        assert_eq!(poly, reversed.reverse());
+    }
+
+    /// Ensure that the zero polynomial is reversed to itself
+    #[test]
+    fn zero() {
+        assert_eq!(PolyOverZ::default(), PolyOverZ::default().reverse());
+    }
+}
@@ -9,7 +9,14 @@
 //! This module contains helpful functions on [`fmpz`].
 
 use super::Z;
-use flint_sys::fmpz::{fmpz, fmpz_abs, fmpz_cmpabs, fmpz_sub};
+use flint_sys::{
+    fmpz::{
+        fmpz, fmpz_abs, fmpz_cmpabs, fmpz_get_ui_array, fmpz_neg, fmpz_set_ui_array, fmpz_sgn,
+        fmpz_size, fmpz_sub,
+    },
+    fmpz_factor::{fmpz_factor, fmpz_factor_clear, fmpz_factor_init, fmpz_factor_struct},
+};
+use std::mem::MaybeUninit;
 
 /// Efficiently finds maximum absolute value and returns
 /// a cloned [`Z`] instance out of a vector of [`fmpz`] instances.
@@ -74,6 +81,115 @@ pub(crate) fn distance(value_1: &fmpz, value_2: &fmpz) -> Z {
     out
 }
 
+/// Computes the prime factorization of the absolute value of an [`fmpz`].
+///
+/// Parameters:
+/// - `value`: the value that is factorized
+///
+/// Returns a vector of all prime factors of `|value|` together with their multiplicity
+/// in ascending order of the primes. For `0` and `1` the vector is empty.
+///
+/// # Example
+/// ```compile_fail
+/// use flint_sys::fmpz::fmpz;
+/// use qfall_math::integer::{fmpz_helpers::factor, Z};
+///
+/// assert_eq!(vec![(Z::from(2), 2), (Z::from(3), 1)], factor(&fmpz(-12)));
+/// ```
+pub(crate) fn factor(value: &fmpz) -> Vec<(Z, u64)> {
+    if unsafe { fmpz_sgn(value) } == 0 {
+        return vec![];
+    }
+
+    let mut factors = MaybeUninit::<fmpz_factor_struct>::uninit();
+    unsafe {
+        fmpz_factor_init(factors.as_mut_ptr());
+        let mut factors = factors.assume_init();
+        fmpz_factor(&mut factors, value);
+
+        let out = (0..factors.num as usize)
+            .map(|i| (Z::from_fmpz(&*factors.p.add(i)), *factors.exp.add(i)))
+            .collect();
+
+        fmpz_factor_clear(&mut factors);
+        out
+    }
+}
+
+/// Encodes an [`fmpz`] value as a compact sequence of bytes.
+/// The first byte stores the sign (`1` for negative values, `0` otherwise),
+/// the remaining bytes store the absolute value in little-endian order
+/// without trailing zero bytes.
+///
+/// Parameters:
+/// - `value`: the value that is encoded
+///
+/// Returns the byte encoding of `value`.
+///
+/// # Example
+/// ```compile_fail
+/// use flint_sys::fmpz::fmpz;
+/// use qfall_math::integer::fmpz_helpers::to_bytes;
+///
+/// assert_eq!(vec![1, 1, 1], to_bytes(&fmpz(-257)));
+/// ```
+pub(crate) fn to_bytes(value: &fmpz) -> Vec<u8> {
+    let mut abs = Z::default();
+    let (negative, limbs) = unsafe {
+        fmpz_abs(&mut abs.value, value);
+        let size = fmpz_size(&abs.value).max(1);
+        let mut limbs = vec![0_u64; size as usize];
+        fmpz_get_ui_array(limbs.as_mut_ptr(), size, &abs.value);
+        (fmpz_sgn(value) < 0, limbs)
+    };
+
+    let mut bytes = vec![u8::from(negative)];
+    bytes.extend(limbs.iter().flat_map(|limb| limb.to_le_bytes()));
+    while bytes.len() > 1 && bytes[bytes.len() - 1] == 0 {
+        bytes.pop();
+    }
+    bytes
+}
+
+/// Decodes a sequence of bytes created by [`to_bytes`] into a [`Z`].
+///
+/// Parameters:
+/// - `bytes`: the byte encoding of the value
+///
+/// Returns the decoded value as a [`Z`] or `None` if the first byte
+/// does not encode a sign.
+///
+/// # Example
+/// ```compile_fail
+/// use qfall_math::integer::{fmpz_helpers::from_bytes, Z};
+///
+/// assert_eq!(Some(Z::from(-257)), from_bytes(&[1, 1, 1]));
+/// ```
+pub(crate) fn from_bytes(bytes: &[u8]) -> Option<Z> {
+    let (sign, magnitude) = bytes.split_first()?;
+    if *sign > 1 {
+        return None;
+    }
+
+    let limbs: Vec<u64> = magnitude
+        .chunks(8)
+        .map(|chunk| {
+            let mut limb = [0_u8; 8];
+            limb[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(limb)
+        })
+        .collect();
+
+    let mut out = Z::default();
+    if !limbs.is_empty() {
+        unsafe { fmpz_set_ui_array(&mut out.value, limbs.as_ptr(), limbs.len() as i64) };
+    }
+    if *sign == 1 {
+        unsafe { fmpz_neg(&mut out.value, &out.value) };
+    }
+    Some(out)
+}
+
 #[cfg(test)]
 mod test_find_max_abs {
     use super::*;
@@ -173,3 +289,92 @@ mod test_distance {
         assert_eq!(zero, distance(&b.value, &b.value));
     }
 }
+
+#[cfg(test)]
+mod test_bytes {
+    use super::{from_bytes, to_bytes, Z};
+
+    /// Ensure that small values are encoded as expected
+    #[test]
+    fn small_values() {
+        assert_eq!(vec![0], to_bytes(&Z::ZERO.value));
+        assert_eq!(vec![0, 1], to_bytes(&Z::ONE.value));
+        assert_eq!(vec![1, 1], to_bytes(&Z::MINUS_ONE.value));
+        assert_eq!(vec![1, 1, 1], to_bytes(&Z::from(-257).value));
+    }
+
+    /// Ensure that decoding inverts the encoding for small and large values
+    #[test]
+    fn round_trip() {
+        let values = [
+            Z::ZERO,
+            Z::ONE,
+            Z::MINUS_ONE,
+            Z::from(u64::MAX),
+            Z::from(i64::MIN),
+            Z::from(u64::MAX) * Z::from(u64::MAX) * Z::from(17),
+            Z::from(i64::MIN) * Z::from(u64::MAX),
+        ];
+
+        for value in values {
+            assert_eq!(Some(value.clone()), from_bytes(&to_bytes(&value.value)));
+        }
+    }
+
+    /// Ensure that invalid encodings are rejected
+    #[test]
+    fn invalid_encoding() {
+        assert!(from_bytes(&[]).is_none());
+        assert!(from_bytes(&[2, 1]).is_none());
+    }
+
+    /// Ensure that non-canonical encodings are decoded correctly
+    #[test]
+    fn non_canonical_encoding() {
+        assert_eq!(Some(Z::ZERO), from_bytes(&[1]));
+        assert_eq!(
+            Some(Z::from(5)),
+            from_bytes(&[0, 5, 0, 0, 0, 0, 0, 0, 0, 0])
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_factor {
+    use super::{factor, Z};
+
+    /// Ensure that small values are factorized correctly
+    #[test]
+    fn small_values() {
+        assert!(factor(&Z::ZERO.value).is_empty());
+        assert!(factor(&Z::ONE.value).is_empty());
+        assert_eq!(vec![(Z::from(2), 1)], factor(&Z::from(2).value));
+        assert_eq!(
+            vec![(Z::from(2), 2), (Z::from(3), 1)],
+            factor(&Z::from(-12).value)
+        );
+        assert_eq!(
+            vec![(Z::from(3), 2), (Z::from(5), 1), (Z::from(17), 1)],
+            factor(&Z::from(765).value)
+        );
+    }
+
+    /// Ensure that large values are factorized correctly
+    #[test]
+    fn large_values() {
+        // 2^64 - 1 = 3 * 5 * 17 * 257 * 641 * 65537 * 6700417
+        let factors = factor(&Z::from(u64::MAX).value);
+        let primes: Vec<Z> = [3, 5, 17, 257, 641, 65537, 6700417]
+            .iter()
+            .map(|p| Z::from(*p))
+            .collect();
+
+        assert_eq!(
+            primes.into_iter().map(|p| (p, 1)).collect::<Vec<_>>(),
+            factors
+        );
+
+        let square = Z::from(u64::MAX - 58) * Z::from(u64::MAX - 58);
+        assert_eq!(vec![(Z::from(u64::MAX - 58), 2)], factor(&square.value));
+    }
+}
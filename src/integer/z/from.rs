@@ -207,6 +207,56 @@ from_trait!(u8, Z, Z::from_u8);
 from_trait!(Modulus, Z, Z::from_modulus);
 from_trait!(Zq, Z, Z::from_zq);
 
+impl From<&Z> for Z {
+    /// Convert a reference of a [`Z`] to [`Z`] by cloning it.
+    /// This allows to use borrowed values for functions with parameters
+    /// that implement [`Into<Z>`].
+    ///
+    /// Parameters:
+    /// - `value`: the value that is cloned
+    ///
+    /// Returns a clone of `value`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::Z;
+    ///
+    /// let value = Z::from(42);
+    ///
+    /// let clone = Z::from(&value);
+    ///
+    /// assert_eq!(value, clone);
+    /// ```
+    fn from(value: &Z) -> Self {
+        value.clone()
+    }
+}
+
+impl From<&Modulus> for Z {
+    /// Convert a reference of a [`Modulus`] to [`Z`] without consuming it.
+    ///
+    /// Parameters:
+    /// - `value`: the modulus whose value is returned
+    ///
+    /// Returns the value of the modulus as a [`Z`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::Z;
+    /// use qfall_math::integer_mod_q::Modulus;
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = Modulus::from_str("42").unwrap();
+    ///
+    /// let value = Z::from(&modulus);
+    ///
+    /// assert_eq!(Z::from(42), value);
+    /// ```
+    fn from(value: &Modulus) -> Self {
+        Z::from_fmpz(&value.get_fmpz_mod_ctx_struct().n[0])
+    }
+}
+
 impl FromStr for Z {
     type Err = MathError;
 
@@ -358,6 +408,14 @@ mod tests_from_int {
         let _ = Z::from(u32::MIN);
         let _ = Z::from(u64::MIN);
     }
+
+    /// Ensure that borrowed values are cloned
+    #[test]
+    fn from_z_reference() {
+        let value = Z::from(u64::MAX);
+
+        assert_eq!(value, Z::from(&value));
+    }
 }
 
 #[cfg(test)]
@@ -492,6 +550,18 @@ mod tests_from_modulus {
         let _ = Z::from(mod_1);
         let _ = Z::from(mod_2);
     }
+
+    /// Ensure that the value of a borrowed [`Modulus`] is returned
+    /// without consuming it
+    #[test]
+    fn from_reference() {
+        let mod_1 = Modulus::from_str(&"1".repeat(65)).unwrap();
+        let mod_2 = Modulus::from_str("10").unwrap();
+
+        assert_eq!(Z::from_str(&"1".repeat(65)).unwrap(), Z::from(&mod_1));
+        assert_eq!(Z::from(10), Z::from(&mod_2));
+        assert_eq!(Z::from(mod_2), Z::from(10));
+    }
 }
 
 #[cfg(test)]
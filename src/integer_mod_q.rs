@@ -11,15 +11,19 @@
 
 mod mat_zq;
 mod modulus;
+mod modulus_polynomial_ring_rns;
 mod modulus_polynomial_ring_zq;
 mod poly_over_zq;
+mod polynomial_ring_rns;
 mod polynomial_ring_zq;
 mod z_q;
 
 pub use mat_zq::MatZq;
 pub use modulus::Modulus;
+pub use modulus_polynomial_ring_rns::ModulusPolynomialRingRns;
 pub use modulus_polynomial_ring_zq::ModulusPolynomialRingZq;
 pub use poly_over_zq::PolyOverZq;
+pub use polynomial_ring_rns::PolynomialRingRns;
 pub use polynomial_ring_zq::PolynomialRingZq;
 pub(crate) use z_q::fmpz_mod_helpers;
 pub use z_q::Zq;
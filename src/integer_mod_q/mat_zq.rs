@@ -13,14 +13,21 @@ use crate::integer_mod_q::Modulus;
 use flint_sys::fmpz_mod_mat::fmpz_mod_mat_struct;
 
 mod arithmetic;
+mod block;
 mod cmp;
 mod concat;
 mod from;
+mod gadget;
 mod get;
+mod inplace;
+mod manipulation;
+mod map;
 mod ownership;
 mod serialize;
 mod set;
+mod submatrix;
 mod to_string;
+mod trace;
 mod transpose;
 mod vector;
 
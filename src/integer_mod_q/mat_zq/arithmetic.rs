@@ -10,5 +10,7 @@
 //! such as addition or subtraction.
 
 mod add;
+mod hadamard;
 mod mul;
+mod pow;
 mod sub;
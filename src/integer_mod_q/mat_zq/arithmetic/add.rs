@@ -10,6 +10,7 @@
 
 use super::super::MatZq;
 use crate::error::MathError;
+use crate::integer::MatZ;
 use crate::macros::arithmetics::{
     arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
 };
@@ -105,6 +106,54 @@ impl MatZq {
 arithmetic_trait_borrowed_to_owned!(Add, add, MatZq, MatZq, MatZq);
 arithmetic_trait_mixed_borrowed_owned!(Add, add, MatZq, MatZq, MatZq);
 
+impl Add<&MatZ> for &MatZq {
+    type Output = MatZq;
+    /// Implements the [`Add`] trait for a [`MatZq`] and a [`MatZ`].
+    /// [`Add`] is implemented for any combination of owned and borrowed
+    /// [`MatZq`] and [`MatZ`] in both orders.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the value to add to `self`
+    ///
+    /// Returns the sum of both matrices as a [`MatZq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::MatZ, integer_mod_q::MatZq};
+    /// use std::str::FromStr;
+    ///
+    /// let a = MatZq::from_str("[[1, 2],[3, 4]] mod 7").unwrap();
+    /// let b = MatZ::from_str("[[1, -2],[13, 4]]").unwrap();
+    ///
+    /// let c: MatZq = &a + &b;
+    /// let d: MatZq = &b + &a;
+    /// let e: MatZq = a + b;
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if the dimensions of both matrices mismatch.
+    fn add(self, other: &MatZ) -> Self::Output {
+        self.add_safe(&MatZq::from_mat_z_modulus(other, &self.modulus))
+            .unwrap()
+    }
+}
+
+#[doc(hidden)]
+impl Add<&MatZq> for &MatZ {
+    type Output = MatZq;
+    /// Documentation at [`MatZq::add`].
+    fn add(self, other: &MatZq) -> Self::Output {
+        MatZq::from_mat_z_modulus(self, &other.modulus)
+            .add_safe(other)
+            .unwrap()
+    }
+}
+
+arithmetic_trait_borrowed_to_owned!(Add, add, MatZq, MatZ, MatZq);
+arithmetic_trait_mixed_borrowed_owned!(Add, add, MatZq, MatZ, MatZq);
+arithmetic_trait_borrowed_to_owned!(Add, add, MatZ, MatZq, MatZq);
+arithmetic_trait_mixed_borrowed_owned!(Add, add, MatZ, MatZq, MatZq);
+
 #[cfg(test)]
 mod test_add {
 
@@ -201,3 +250,36 @@ mod test_add {
         assert!(a.add_safe(&d).is_err());
     }
 }
+
+#[cfg(test)]
+mod test_add_between_types {
+    use crate::{integer::MatZ, integer_mod_q::MatZq};
+    use std::str::FromStr;
+
+    /// Ensure that the [`MatZ`] is reduced by the modulus of the [`MatZq`]
+    #[test]
+    fn correct_result() {
+        let a = MatZq::from_str(&format!("[[1, 2],[3, {}]] mod {}", i64::MAX, u64::MAX)).unwrap();
+        let b = MatZ::from_str(&format!("[[-1, {}],[0, {}]]", u64::MAX, i64::MAX)).unwrap();
+        let cmp =
+            MatZq::from_str(&format!("[[0, 2],[3, {}]] mod {}", u64::MAX - 1, u64::MAX)).unwrap();
+
+        assert_eq!(cmp, &a + &b);
+        assert_eq!(cmp, &b + &a);
+        assert_eq!(cmp, a.clone() + &b);
+        assert_eq!(cmp, &b + a.clone());
+        assert_eq!(cmp, b.clone() + &a);
+        assert_eq!(cmp, &a + b.clone());
+        assert_eq!(cmp, a + b);
+    }
+
+    /// Ensure that mismatching dimensions result in a panic
+    #[test]
+    #[should_panic]
+    fn mismatching_dimensions() {
+        let a = MatZq::new(2, 3, 7).unwrap();
+        let b = MatZ::new(3, 2).unwrap();
+
+        let _ = &a + &b;
+    }
+}
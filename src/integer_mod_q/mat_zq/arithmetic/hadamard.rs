@@ -0,0 +1,148 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementation of the entrywise (Hadamard) product for [`MatZq`] values.
+
+use super::super::MatZq;
+use crate::error::MathError;
+use crate::integer::Z;
+use crate::traits::{GetNumColumns, GetNumRows};
+use flint_sys::{
+    fmpz_mat::fmpz_mat_entry, fmpz_mod::fmpz_mod_mul, fmpz_mod_mat::fmpz_mod_mat_entry,
+};
+
+impl MatZq {
+    /// Computes the entrywise (Hadamard) product of `self` and `other`,
+    /// i.e. the matrix whose entry `(i, j)` is `self[i][j] * other[i][j] mod q`.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the matrix to multiply entrywise with `self`
+    ///
+    /// Returns the entrywise product of both matrices as a [`MatZq`] or an
+    /// error if the matrix dimensions or the moduli mismatch.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let a = MatZq::from_str("[[1, 2, 3],[3, 4, 5]] mod 7").unwrap();
+    /// let b = MatZq::from_str("[[1, 9, 3],[1, 0, 5]] mod 7").unwrap();
+    ///
+    /// let c = a.hadamard(&b).unwrap();
+    ///
+    /// assert_eq!(MatZq::from_str("[[1, 4, 2],[3, 0, 4]] mod 7").unwrap(), c);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the matrix dimensions mismatch.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the moduli mismatch.
+    pub fn hadamard(&self, other: &Self) -> Result<Self, MathError> {
+        if self.get_mod() != other.get_mod() {
+            return Err(MathError::MismatchingModulus(format!(
+                "Tried to compute the Hadamard product of matrices with moduli '{}' and '{}'.",
+                self.get_mod(),
+                other.get_mod()
+            )));
+        }
+        if self.get_num_rows() != other.get_num_rows()
+            || self.get_num_columns() != other.get_num_columns()
+        {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to compute the Hadamard product of a '{}x{}' matrix and a '{}x{}' matrix.",
+                self.get_num_rows(),
+                self.get_num_columns(),
+                other.get_num_rows(),
+                other.get_num_columns()
+            )));
+        }
+
+        let out = MatZq::new(
+            self.get_num_rows(),
+            self.get_num_columns(),
+            Z::from(&self.modulus),
+        )
+        .unwrap();
+        let ctx = self.modulus.get_fmpz_mod_ctx_struct();
+        for row in 0..self.get_num_rows() {
+            for column in 0..self.get_num_columns() {
+                unsafe {
+                    fmpz_mod_mul(
+                        fmpz_mat_entry(&out.matrix.mat[0], row, column),
+                        fmpz_mod_mat_entry(&self.matrix, row, column),
+                        fmpz_mod_mat_entry(&other.matrix, row, column),
+                        ctx,
+                    )
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_hadamard {
+    use super::MatZq;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that the entrywise product is computed and reduced correctly
+    #[test]
+    fn correct_result() {
+        let a = MatZq::from_str("[[1, 5],[6, 3],[2, 0]] mod 11").unwrap();
+        let b = MatZq::from_str("[[7, 9],[6, 4],[10, 8]] mod 11").unwrap();
+
+        let c = a.hadamard(&b).unwrap();
+
+        assert_eq!(MatZq::from_str("[[7, 1],[3, 1],[9, 0]] mod 11").unwrap(), c);
+        assert_eq!(c, b.hadamard(&a).unwrap());
+    }
+
+    /// Ensure that large moduli are supported
+    #[test]
+    fn large_modulus() {
+        let modulus = u64::MAX;
+        let a = MatZq::from_str(&format!("[[{}, 2]] mod {}", u64::MAX - 1, modulus)).unwrap();
+        let b = MatZq::from_str(&format!("[[{}, 3]] mod {}", u64::MAX - 1, modulus)).unwrap();
+
+        let c = a.hadamard(&b).unwrap();
+
+        assert_eq!(
+            MatZq::from_str(&format!("[[1, 6]] mod {}", modulus)).unwrap(),
+            c
+        );
+    }
+
+    /// Ensure that an error is returned if the dimensions mismatch
+    #[test]
+    fn mismatching_dimensions() {
+        let a = MatZq::new(2, 3, 7).unwrap();
+        let b = MatZq::new(3, 2, 7).unwrap();
+
+        assert!(matches!(
+            a.hadamard(&b),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+
+    /// Ensure that an error is returned if the moduli mismatch
+    #[test]
+    fn mismatching_modulus() {
+        let a = MatZq::new(2, 2, 7).unwrap();
+        let b = MatZq::new(2, 2, 11).unwrap();
+
+        assert!(matches!(
+            a.hadamard(&b),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+}
@@ -10,6 +10,7 @@
 
 use super::super::MatZq;
 use crate::error::MathError;
+use crate::integer::MatZ;
 use crate::macros::arithmetics::{
     arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
 };
@@ -104,6 +105,54 @@ impl MatZq {
 arithmetic_trait_borrowed_to_owned!(Mul, mul, MatZq, MatZq, MatZq);
 arithmetic_trait_mixed_borrowed_owned!(Mul, mul, MatZq, MatZq, MatZq);
 
+impl Mul<&MatZ> for &MatZq {
+    type Output = MatZq;
+    /// Implements the [`Mul`] trait for a [`MatZq`] and a [`MatZ`].
+    /// [`Mul`] is implemented for any combination of owned and borrowed
+    /// [`MatZq`] and [`MatZ`] in both orders.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the value to multiply with `self`
+    ///
+    /// Returns the product of both matrices as a [`MatZq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::MatZ, integer_mod_q::MatZq};
+    /// use std::str::FromStr;
+    ///
+    /// let a = MatZq::from_str("[[1, 2],[3, 4]] mod 7").unwrap();
+    /// let b = MatZ::from_str("[[1, -2],[13, 4]]").unwrap();
+    ///
+    /// let c: MatZq = &a * &b;
+    /// let d: MatZq = &b * &a;
+    /// let e: MatZq = a * b;
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if the dimensions of both matrices mismatch.
+    fn mul(self, other: &MatZ) -> Self::Output {
+        self.mul_safe(&MatZq::from_mat_z_modulus(other, &self.modulus))
+            .unwrap()
+    }
+}
+
+#[doc(hidden)]
+impl Mul<&MatZq> for &MatZ {
+    type Output = MatZq;
+    /// Documentation at [`MatZq::mul`].
+    fn mul(self, other: &MatZq) -> Self::Output {
+        MatZq::from_mat_z_modulus(self, &other.modulus)
+            .mul_safe(other)
+            .unwrap()
+    }
+}
+
+arithmetic_trait_borrowed_to_owned!(Mul, mul, MatZq, MatZ, MatZq);
+arithmetic_trait_mixed_borrowed_owned!(Mul, mul, MatZq, MatZ, MatZq);
+arithmetic_trait_borrowed_to_owned!(Mul, mul, MatZ, MatZq, MatZq);
+arithmetic_trait_mixed_borrowed_owned!(Mul, mul, MatZ, MatZq, MatZq);
+
 #[cfg(test)]
 mod test_mul {
 
@@ -157,3 +206,36 @@ mod test_mul {
         assert!((mat_1.mul_safe(&mat_3)).is_err());
     }
 }
+
+#[cfg(test)]
+mod test_mul_between_types {
+    use crate::{integer::MatZ, integer_mod_q::MatZq};
+    use std::str::FromStr;
+
+    /// Ensure that multiplication with a [`MatZ`] is performed modulo `q`
+    /// in both orders
+    #[test]
+    fn correct_result() {
+        let a = MatZq::from_str("[[1, 2],[3, 4]] mod 7").unwrap();
+        let b = MatZ::from_str("[[-1, 8],[0, 15]]").unwrap();
+
+        let cmp_right = MatZq::from_str("[[6, 3],[4, 0]] mod 7").unwrap();
+        let cmp_left = MatZq::from_str("[[2, 2],[3, 4]] mod 7").unwrap();
+
+        assert_eq!(cmp_right, &a * &b);
+        assert_eq!(cmp_left, &b * &a);
+        assert_eq!(cmp_right, a.clone() * &b);
+        assert_eq!(cmp_left, b.clone() * &a);
+        assert_eq!(cmp_right, a * b);
+    }
+
+    /// Ensure that mismatching dimensions result in a panic
+    #[test]
+    #[should_panic]
+    fn mismatching_dimensions() {
+        let a = MatZq::new(2, 3, 7).unwrap();
+        let b = MatZ::new(2, 3).unwrap();
+
+        let _ = &a * &b;
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module provides an implementation of the [`Pow`] trait for [`MatZq`].
+
+use crate::{
+    error::MathError,
+    integer::Z,
+    integer_mod_q::MatZq,
+    macros::for_others::{implement_for_others, implement_for_owned},
+    traits::{GetNumColumns, GetNumRows, Pow},
+};
+use flint_sys::fmpz::{fmpz_bits, fmpz_tstbit};
+
+impl Pow<&Z> for MatZq {
+    type Output = MatZq;
+
+    /// Raises the square matrix `self` to the power of a non-negative integer `exp` reduced by the modulus of the matrix
+    /// using binary exponentiation.
+    ///
+    /// Parameters:
+    /// - `exp`: specifies the exponent to which the matrix is raised
+    ///
+    /// Returns the matrix `self` powered by `exp` as a new [`MatZq`] instance.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use qfall_math::traits::*;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZq::from_str("[[1,1],[1,0]] mod 7").unwrap();
+    ///
+    /// let powered_value = matrix.pow(5).unwrap();
+    ///
+    /// assert_eq!(MatZq::from_str("[[1,5],[5,3]] mod 7").unwrap(), powered_value);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidExponent`](MathError::InvalidExponent)
+    /// if the provided exponent is negative.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the matrix is not square.
+    fn pow(&self, exp: &Z) -> Result<Self::Output, MathError> {
+        if exp < &Z::ZERO {
+            return Err(MathError::InvalidExponent(format!(
+                "A negative exponent {} was used for a matrix.",
+                exp
+            )));
+        }
+        if self.get_num_rows() != self.get_num_columns() {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Only square matrices can be powered, but the matrix is '{}x{}'.",
+                self.get_num_rows(),
+                self.get_num_columns()
+            )));
+        }
+
+        // square-and-multiply from the most significant bit of `exp`
+        let mut out = MatZq::identity(
+            self.get_num_rows(),
+            self.get_num_rows(),
+            Z::from(&self.get_mod()),
+        )
+        .unwrap();
+        for i in (0..unsafe { fmpz_bits(&exp.value) }).rev() {
+            out = &out * &out;
+            if 1 == unsafe { fmpz_tstbit(&exp.value, i) } {
+                out = &out * self;
+            }
+        }
+        Ok(out)
+    }
+}
+
+implement_for_owned!(Z, MatZq, Pow);
+implement_for_others!(Z, MatZq, Pow for u8 u16 u32 u64 i8 i16 i32 i64);
+
+#[cfg(test)]
+mod test_pow {
+    use super::*;
+    use std::str::FromStr;
+
+    /// Ensure that `pow` works for small exponents including `0` and `1`
+    #[test]
+    fn small() {
+        let matrix = MatZq::from_str("[[1,1],[1,0]] mod 7").unwrap();
+
+        let res_0 = matrix.pow(0).unwrap();
+        let res_1 = matrix.pow(1).unwrap();
+        let res_2 = matrix.pow(&Z::from(10)).unwrap();
+
+        assert_eq!(MatZq::identity(2, 2, 7).unwrap(), res_0);
+        assert_eq!(matrix, res_1);
+        assert_eq!(MatZq::from_str("[[5,6],[6,6]] mod 7").unwrap(), res_2);
+    }
+
+    /// Ensure that the result is reduced, here by Fermat's little theorem
+    #[test]
+    fn reduced() {
+        let matrix = MatZq::from_str("[[2,0],[0,3]] mod 11").unwrap();
+
+        let res = matrix.pow(10).unwrap();
+
+        assert_eq!(MatZq::identity(2, 2, 11).unwrap(), res);
+    }
+
+    /// Ensure that `pow` works for large moduli and exponents that do not fit into an [`u64`]
+    #[test]
+    fn large() {
+        let matrix = MatZq::from_str("[[2]] mod 7").unwrap();
+        let large_matrix =
+            MatZq::from_str(&format!("[[{}]] mod {}", u64::MAX - 1, u64::MAX)).unwrap();
+        let exp = Z::from(2).pow(70).unwrap();
+
+        assert_eq!(
+            MatZq::from_str("[[2]] mod 7").unwrap(),
+            matrix.pow(&exp).unwrap()
+        );
+        assert_eq!(
+            MatZq::from_str(&format!("[[1]] mod {}", u64::MAX)).unwrap(),
+            large_matrix.pow(&exp).unwrap()
+        );
+    }
+
+    /// Ensure that `pow` returns an error for negative exponents
+    #[test]
+    fn negative_exponent() {
+        let matrix = MatZq::identity(2, 2, 7).unwrap();
+
+        assert!(matches!(matrix.pow(-1), Err(MathError::InvalidExponent(_))));
+    }
+
+    /// Ensure that `pow` returns an error for non-square matrices
+    #[test]
+    fn non_square() {
+        let matrix = MatZq::new(2, 3, 7).unwrap();
+
+        assert!(matches!(
+            matrix.pow(2),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
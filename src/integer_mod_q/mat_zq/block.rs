@@ -0,0 +1,275 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains constructors of block matrices from several [`MatZq`] instances.
+
+use super::MatZq;
+use crate::{
+    error::MathError,
+    integer::Z,
+    traits::{GetNumColumns, GetNumRows},
+};
+
+impl MatZq {
+    /// Creates the block diagonal matrix with the provided `blocks` on its
+    /// diagonal, starting at the top left, and `0` anywhere else.
+    ///
+    /// Parameters:
+    /// - `blocks`: the blocks on the diagonal, which do not have to be square
+    ///
+    /// Returns the block diagonal matrix or an error, if `blocks` is empty
+    /// or the blocks have different moduli.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let block_1 = MatZq::from_str("[[1, 2],[3, 4]] mod 7").unwrap();
+    /// let block_2 = MatZq::from_str("[[5, 6]] mod 7").unwrap();
+    ///
+    /// let matrix = MatZq::block_diagonal(&[&block_1, &block_2]).unwrap();
+    ///
+    /// let cmp = MatZq::from_str("[[1, 2, 0, 0],[3, 4, 0, 0],[0, 0, 5, 6]] mod 7").unwrap();
+    /// assert_eq!(cmp, matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `blocks` is empty.
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the blocks do not share the same modulus.
+    pub fn block_diagonal(blocks: &[&MatZq]) -> Result<Self, MathError> {
+        let modulus = blocks
+            .first()
+            .map_or(Z::ONE, |block| Z::from(&block.get_mod()));
+        check_moduli(blocks.iter())?;
+
+        let num_rows = blocks.iter().map(|block| block.get_num_rows()).sum::<i64>();
+        let num_cols = blocks
+            .iter()
+            .map(|block| block.get_num_columns())
+            .sum::<i64>();
+        let mut out = MatZq::new(num_rows, num_cols, modulus)?;
+
+        let (mut row, mut column) = (0, 0);
+        for block in blocks {
+            out.set_submatrix(row, column, block).unwrap();
+            row += block.get_num_rows();
+            column += block.get_num_columns();
+        }
+        Ok(out)
+    }
+
+    /// Creates a block matrix from a grid of `blocks`, i.e. `blocks[i][j]`
+    /// is placed in the `i`-th row and `j`-th column of blocks.
+    /// All blocks in a row of blocks need to have the same number of rows
+    /// and all blocks in a column of blocks the same number of columns.
+    ///
+    /// Parameters:
+    /// - `blocks`: the rows of blocks, starting with the top row
+    ///
+    /// Returns the block matrix or an error, if `blocks` is empty,
+    /// its rows contain different numbers of blocks, the dimensions
+    /// of the blocks do not fit together or the blocks have different moduli.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let a = MatZq::from_str("[[1, 2],[3, 4]] mod 7").unwrap();
+    /// let b = MatZq::from_str("[[5, 6],[7, 8]] mod 7").unwrap();
+    /// let identity = MatZq::identity(2, 2, 7).unwrap();
+    /// let zero = MatZq::new(2, 2, 7).unwrap();
+    ///
+    /// // [[A, I],[0, B]]
+    /// let matrix = MatZq::from_blocks(&[&[&a, &identity], &[&zero, &b]]).unwrap();
+    ///
+    /// let cmp = MatZq::from_str("[[1, 2, 1, 0],[3, 4, 0, 1],[0, 0, 5, 6],[0, 0, 7, 8]] mod 7").unwrap();
+    /// assert_eq!(cmp, matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `blocks` is empty or its rows contain different numbers of blocks.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the dimensions of the blocks do not fit together.
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the blocks do not share the same modulus.
+    pub fn from_blocks(blocks: &[&[&MatZq]]) -> Result<Self, MathError> {
+        let block_rows: Vec<i64> = blocks
+            .iter()
+            .map(|row| row.first().map_or(0, |block| block.get_num_rows()))
+            .collect();
+        let block_cols: Vec<i64> = blocks.first().map_or(vec![], |row| {
+            row.iter().map(|block| block.get_num_columns()).collect()
+        });
+
+        for (i, row) in blocks.iter().enumerate() {
+            if row.len() != block_cols.len() {
+                return Err(MathError::InvalidMatrix(format!(
+                    "Each row of blocks has to contain {} blocks, but row {} contains {}.",
+                    block_cols.len(),
+                    i,
+                    row.len()
+                )));
+            }
+            for (j, block) in row.iter().enumerate() {
+                if block.get_num_rows() != block_rows[i] || block.get_num_columns() != block_cols[j]
+                {
+                    return Err(MathError::MismatchingMatrixDimension(format!(
+                        "The block at ({}, {}) has to be a '{}x{}' matrix, but is a '{}x{}' matrix.",
+                        i,
+                        j,
+                        block_rows[i],
+                        block_cols[j],
+                        block.get_num_rows(),
+                        block.get_num_columns()
+                    )));
+                }
+            }
+        }
+
+        let modulus = blocks
+            .iter()
+            .find_map(|row| row.first())
+            .map_or(Z::ONE, |block| Z::from(&block.get_mod()));
+        check_moduli(blocks.iter().flat_map(|row| row.iter()))?;
+
+        let mut out = MatZq::new(
+            block_rows.iter().sum::<i64>(),
+            block_cols.iter().sum::<i64>(),
+            modulus,
+        )?;
+        let mut row = 0;
+        for (i, blocks_row) in blocks.iter().enumerate() {
+            let mut column = 0;
+            for (j, block) in blocks_row.iter().enumerate() {
+                out.set_submatrix(row, column, block).unwrap();
+                column += block_cols[j];
+            }
+            row += block_rows[i];
+        }
+        Ok(out)
+    }
+}
+
+/// Checks whether all provided matrices share the same modulus.
+///
+/// Parameters:
+/// - `blocks`: the matrices whose moduli are compared
+///
+/// Returns an empty `Ok` if all moduli are equal and an error otherwise.
+///
+/// # Errors and Failures
+/// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+/// if the blocks do not share the same modulus.
+fn check_moduli<'a>(mut blocks: impl Iterator<Item = &'a &'a MatZq>) -> Result<(), MathError> {
+    if let Some(first) = blocks.next() {
+        let modulus = first.get_mod();
+        if let Some(block) = blocks.find(|block| block.get_mod() != modulus) {
+            return Err(MathError::MismatchingModulus(format!(
+                "All blocks have to share the same modulus, but the moduli {} and {} were provided.",
+                modulus,
+                block.get_mod()
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_block_diagonal {
+    use super::MatZq;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that the blocks are placed correctly and the modulus is kept
+    #[test]
+    fn correct_placement() {
+        let modulus = u64::MAX;
+        let block_1 = MatZq::from_str(&format!("[[{}],[2]] mod {}", i64::MAX, modulus)).unwrap();
+        let block_2 = MatZq::from_str(&format!("[[3, 4, 5]] mod {}", modulus)).unwrap();
+
+        let matrix = MatZq::block_diagonal(&[&block_1, &block_2]).unwrap();
+
+        let cmp = MatZq::from_str(&format!(
+            "[[{}, 0, 0, 0],[2, 0, 0, 0],[0, 3, 4, 5]] mod {}",
+            i64::MAX,
+            modulus
+        ))
+        .unwrap();
+        assert_eq!(cmp, matrix);
+    }
+
+    /// Ensure that empty slices and different moduli result in an error
+    #[test]
+    fn invalid_blocks() {
+        let block_1 = MatZq::new(2, 2, 7).unwrap();
+        let block_2 = MatZq::new(2, 2, 11).unwrap();
+
+        assert!(matches!(
+            MatZq::block_diagonal(&[]),
+            Err(MathError::InvalidMatrix(_))
+        ));
+        assert!(matches!(
+            MatZq::block_diagonal(&[&block_1, &block_2]),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_from_blocks {
+    use super::MatZq;
+    use crate::{error::MathError, traits::Concatenate};
+    use std::str::FromStr;
+
+    /// Ensure that the result equals the concatenation of the blocks
+    #[test]
+    fn same_as_concatenation() {
+        let a = MatZq::from_str("[[1, 2, 3],[4, 5, 6]] mod 17").unwrap();
+        let b = MatZq::from_str("[[7],[8]] mod 17").unwrap();
+        let c = MatZq::from_str("[[-1, 2, 3]] mod 17").unwrap();
+        let d = MatZq::from_str("[[20]] mod 17").unwrap();
+
+        let matrix = MatZq::from_blocks(&[&[&a, &b], &[&c, &d]]).unwrap();
+
+        let top = a.concat_horizontal(&b).unwrap();
+        let bottom = c.concat_horizontal(&d).unwrap();
+        assert_eq!(top.concat_vertical(&bottom).unwrap(), matrix);
+    }
+
+    /// Ensure that invalid grids, mismatching dimensions and
+    /// different moduli result in an error
+    #[test]
+    fn invalid_blocks() {
+        let a = MatZq::new(2, 2, 7).unwrap();
+        let b = MatZq::new(3, 2, 7).unwrap();
+        let c = MatZq::new(2, 2, 11).unwrap();
+
+        assert!(matches!(
+            MatZq::from_blocks(&[&[&a, &a], &[&a]]),
+            Err(MathError::InvalidMatrix(_))
+        ));
+        assert!(matches!(
+            MatZq::from_blocks(&[]),
+            Err(MathError::InvalidMatrix(_))
+        ));
+        assert!(matches!(
+            MatZq::from_blocks(&[&[&a, &b]]),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+        assert!(matches!(
+            MatZq::from_blocks(&[&[&a, &c]]),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+}
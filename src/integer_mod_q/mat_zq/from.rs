@@ -16,14 +16,16 @@
 use super::MatZq;
 use crate::{
     error::MathError,
-    integer::Z,
-    integer_mod_q::Modulus,
-    traits::SetEntry,
+    integer::{MatZ, Z},
+    integer_mod_q::{Modulus, Zq},
+    traits::{GetNumColumns, GetNumRows, SetEntry},
     utils::{
-        dimensions::find_matrix_dimensions, index::evaluate_index, parse::parse_matrix_string,
+        dimensions::{find_matrix_dimensions, find_slice_matrix_dimensions},
+        index::evaluate_index,
+        parse::parse_matrix_string,
     },
 };
-use flint_sys::fmpz_mod_mat::fmpz_mod_mat_init;
+use flint_sys::{fmpz_mat::fmpz_mat_scalar_mod_fmpz, fmpz_mod_mat::fmpz_mod_mat_init};
 use std::{fmt::Display, mem::MaybeUninit, str::FromStr};
 
 impl MatZq {
@@ -89,10 +91,244 @@ impl MatZq {
             Ok(MatZq {
                 matrix: matrix.assume_init(),
                 // we can unwrap here since modulus > 0 was checked before
-                modulus: Modulus::try_from(&modulus).unwrap(),
+                modulus: Modulus::try_from_z(&modulus).unwrap(),
             })
         }
     }
+
+    /// Generate a `num_rows` times `num_columns` matrix with `1` on the
+    /// diagonal and `0` anywhere else, where all entries are reduced by `modulus`.
+    ///
+    /// Parameters:
+    /// - `num_rows`: the number of rows of the identity matrix
+    /// - `num_columns`: the number of columns of the identity matrix
+    /// - `modulus`: the common modulus of the matrix entries
+    ///
+    /// Returns a matrix with `1` across the diagonal and `0` anywhere else.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZq::identity(2, 3, 7).unwrap();
+    ///
+    /// assert_eq!(MatZq::from_str("[[1,0,0],[0,1,0]] mod 7").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix),
+    /// [`OutOfBounds`](MathError::OutOfBounds) or
+    /// [`InvalidIntToModulus`](MathError::InvalidIntToModulus)
+    /// if the provided number of rows and columns or the modulus are not suited
+    /// to create a matrix. For further information see [`MatZq::new`].
+    pub fn identity(
+        num_rows: impl TryInto<i64> + Display + Copy,
+        num_cols: impl TryInto<i64> + Display + Copy,
+        modulus: impl Into<Z>,
+    ) -> Result<Self, MathError> {
+        let mut out = MatZq::new(num_rows, num_cols, modulus)?;
+        let num_rows_i64 = evaluate_index(num_rows).unwrap();
+        let num_cols_i64 = evaluate_index(num_cols).unwrap();
+        for i in 0..num_rows_i64.min(num_cols_i64) {
+            out.set_entry(i, i, Z::ONE).unwrap();
+        }
+        Ok(out)
+    }
+
+    /// Generate a square matrix with the provided values on the diagonal
+    /// and `0` anywhere else.
+    ///
+    /// Parameters:
+    /// - `diagonal`: the entries of the diagonal, starting at the top left
+    ///
+    /// Returns a square matrix with `diagonal` as diagonal or an error,
+    /// if `diagonal` is empty or its entries have different moduli.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{MatZq, Zq};
+    /// use std::str::FromStr;
+    ///
+    /// let diagonal = [Zq::try_from((2, 7)).unwrap(), Zq::try_from((-3, 7)).unwrap()];
+    /// let matrix = MatZq::from_diagonal(&diagonal).unwrap();
+    ///
+    /// assert_eq!(MatZq::from_str("[[2,0],[0,4]] mod 7").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `diagonal` is empty.
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the entries of `diagonal` do not share the same modulus.
+    pub fn from_diagonal(diagonal: &[Zq]) -> Result<Self, MathError> {
+        let modulus = diagonal
+            .first()
+            .map_or(Z::ONE, |entry| Z::from(&entry.modulus));
+        let mut out = MatZq::new(diagonal.len(), diagonal.len(), modulus)?;
+        for (i, entry) in diagonal.iter().enumerate() {
+            out.set_entry(i, i, entry)?;
+        }
+        Ok(out)
+    }
+
+    /// Creates a matrix from a slice of rows, where each row is a slice of
+    /// entries, which can be converted into [`Z`], and a modulus.
+    ///
+    /// Parameters:
+    /// - `rows`: the rows of the matrix, starting with the top row
+    /// - `modulus`: the common modulus of the matrix entries
+    ///
+    /// Returns a [`MatZq`] or an error, if `rows` is empty, contains empty rows
+    /// or rows of different length, or the modulus is not greater than `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZq::from_slices(&[[1, 2, 3].as_slice(), &[4, 5, 6]], 5).unwrap();
+    ///
+    /// assert_eq!(MatZq::from_str("[[1, 2, 3],[4, 0, 1]] mod 5").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `rows` is empty, contains empty rows or the number of entries in rows is unequal.
+    /// - Returns a [`MathError`] of type
+    /// [`InvalidIntToModulus`](MathError::InvalidIntToModulus)
+    /// if the modulus is not greater than `0`.
+    pub fn from_slices<T: Into<Z> + Clone>(
+        rows: &[&[T]],
+        modulus: impl Into<Z>,
+    ) -> Result<Self, MathError> {
+        let (num_rows, num_cols) = find_slice_matrix_dimensions(rows)?;
+        let mut matrix = MatZq::new(num_rows, num_cols, modulus)?;
+
+        for (row_num, row) in rows.iter().enumerate() {
+            for (col_num, entry) in row.iter().enumerate() {
+                let entry: Z = entry.clone().into();
+                matrix.set_entry(row_num, col_num, entry).unwrap();
+            }
+        }
+        Ok(matrix)
+    }
+
+    /// Creates a [`MatZq`] from a [`MatZ`] by reducing each entry by `modulus`.
+    ///
+    /// Parameters:
+    /// - `matrix`: the integer matrix whose entries are reduced
+    /// - `modulus`: the common modulus of the matrix entries
+    ///
+    /// Returns `matrix mod modulus` as a [`MatZq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{MatZ, Z};
+    /// use qfall_math::integer_mod_q::{MatZq, Modulus};
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZ::from_str("[[1, -2],[3, 14]]").unwrap();
+    /// let modulus = Modulus::try_from(&Z::from(5)).unwrap();
+    ///
+    /// let matrix_zq = MatZq::from_mat_z_modulus(&matrix, &modulus);
+    ///
+    /// assert_eq!(MatZq::from_str("[[1, 3],[3, 4]] mod 5").unwrap(), matrix_zq);
+    /// ```
+    pub fn from_mat_z_modulus(matrix: &MatZ, modulus: &Modulus) -> Self {
+        let mut out = MatZq::new(
+            matrix.get_num_rows(),
+            matrix.get_num_columns(),
+            Z::from(modulus),
+        )
+        .unwrap();
+        unsafe {
+            fmpz_mat_scalar_mod_fmpz(
+                &mut out.matrix.mat[0],
+                &matrix.matrix,
+                &modulus.get_fmpz_mod_ctx_struct().n[0],
+            )
+        };
+        out
+    }
+}
+
+impl<Mod: Into<Z>> TryFrom<(&[&[Z]], Mod)> for MatZq {
+    type Error = MathError;
+    /// Create a [`MatZq`] from a slice of rows and a modulus
+    /// using [`from_slices`](MatZq::from_slices)
+    fn try_from((rows, modulus): (&[&[Z]], Mod)) -> Result<Self, Self::Error> {
+        MatZq::from_slices(rows, modulus)
+    }
+}
+
+impl<Mod: Into<Z>> TryFrom<(Vec<Vec<i64>>, Mod)> for MatZq {
+    type Error = MathError;
+    /// Creates a matrix from a vector of rows, where each row is a vector of entries,
+    /// and a modulus.
+    ///
+    /// Parameters:
+    /// - `rows`: the rows of the matrix, starting with the top row
+    /// - `modulus`: the common modulus of the matrix entries
+    ///
+    /// Returns a [`MatZq`] with the given entries or an error, if the dimensions
+    /// or the modulus are not suited to create a matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZq::try_from((vec![vec![1, 2, 3], vec![4, 5, 6]], 5)).unwrap();
+    ///
+    /// assert_eq!(MatZq::from_str("[[1, 2, 3],[4, 0, 1]] mod 5").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `rows` is empty, contains empty rows or the number of entries in rows is unequal.
+    /// - Returns a [`MathError`] of type
+    /// [`InvalidIntToModulus`](MathError::InvalidIntToModulus)
+    /// if the modulus is not greater than `0`.
+    fn try_from((rows, modulus): (Vec<Vec<i64>>, Mod)) -> Result<Self, Self::Error> {
+        let rows: Vec<&[i64]> = rows.iter().map(Vec::as_slice).collect();
+        MatZq::from_slices(&rows, modulus)
+    }
+}
+
+impl<Mod: Into<Z>, const ROWS: usize, const COLS: usize> TryFrom<([[i64; COLS]; ROWS], Mod)>
+    for MatZq
+{
+    type Error = MathError;
+    /// Creates a `ROWS x COLS` matrix from an array of rows and a modulus.
+    ///
+    /// Parameters:
+    /// - `rows`: the rows of the matrix, starting with the top row
+    /// - `modulus`: the common modulus of the matrix entries
+    ///
+    /// Returns a [`MatZq`] with the given entries or an error, if the dimensions
+    /// or the modulus are not suited to create a matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZq::try_from(([[1, 2, 3], [4, 5, 6]], 5)).unwrap();
+    ///
+    /// assert_eq!(MatZq::from_str("[[1, 2, 3],[4, 0, 1]] mod 5").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `ROWS` or `COLS` is `0`.
+    /// - Returns a [`MathError`] of type
+    /// [`InvalidIntToModulus`](MathError::InvalidIntToModulus)
+    /// if the modulus is not greater than `0`.
+    fn try_from((rows, modulus): ([[i64; COLS]; ROWS], Mod)) -> Result<Self, Self::Error> {
+        let rows: Vec<&[i64]> = rows.iter().map(|row| row.as_slice()).collect();
+        MatZq::from_slices(&rows, modulus)
+    }
 }
 
 impl FromStr for MatZq {
@@ -207,6 +443,111 @@ mod test_new {
     }
 }
 
+#[cfg(test)]
+mod test_identity {
+    use crate::integer_mod_q::MatZq;
+    use std::str::FromStr;
+
+    /// Tests if an identity matrix is correctly created
+    #[test]
+    fn square() {
+        let matrix = MatZq::identity(3, 3, 7).unwrap();
+
+        assert_eq!(
+            MatZq::from_str("[[1,0,0],[0,1,0],[0,0,1]] mod 7").unwrap(),
+            matrix
+        );
+    }
+
+    /// Tests if non-square identity matrices are correctly created
+    #[test]
+    fn non_square() {
+        let wide = MatZq::identity(2, 3, u64::MAX).unwrap();
+        let tall = MatZq::identity(3, 2, u64::MAX).unwrap();
+
+        let wide_cmp = format!("[[1,0,0],[0,1,0]] mod {}", u64::MAX);
+        let tall_cmp = format!("[[1,0],[0,1],[0,0]] mod {}", u64::MAX);
+        assert_eq!(MatZq::from_str(&wide_cmp).unwrap(), wide);
+        assert_eq!(MatZq::from_str(&tall_cmp).unwrap(), tall);
+    }
+
+    /// Tests if the diagonal is reduced for modulus `1`
+    #[test]
+    fn modulus_one() {
+        let matrix = MatZq::identity(2, 2, 1).unwrap();
+
+        assert_eq!(MatZq::new(2, 2, 1).unwrap(), matrix);
+    }
+
+    /// Tests if an error is returned for invalid dimensions or moduli
+    #[test]
+    fn invalid_input() {
+        assert!(MatZq::identity(0, 3, 7).is_err());
+        assert!(MatZq::identity(3, -1, 7).is_err());
+        assert!(MatZq::identity(3, 3, 0).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_from_diagonal {
+    use crate::{
+        error::MathError,
+        integer_mod_q::{MatZq, Zq},
+    };
+    use std::str::FromStr;
+
+    /// Tests if the diagonal is correctly set
+    #[test]
+    fn correct_diagonal() {
+        let diagonal = [
+            Zq::try_from((1, 17)).unwrap(),
+            Zq::try_from((-2, 17)).unwrap(),
+            Zq::try_from((0, 17)).unwrap(),
+        ];
+
+        let matrix = MatZq::from_diagonal(&diagonal).unwrap();
+
+        assert_eq!(
+            MatZq::from_str("[[1,0,0],[0,15,0],[0,0,0]] mod 17").unwrap(),
+            matrix
+        );
+    }
+
+    /// Tests if large entries are correctly set
+    #[test]
+    fn large_entries() {
+        let diagonal = [Zq::try_from((i64::MAX, u64::MAX)).unwrap()];
+
+        let matrix = MatZq::from_diagonal(&diagonal).unwrap();
+
+        let cmp = MatZq::from_str(&format!("[[{}]] mod {}", i64::MAX, u64::MAX)).unwrap();
+        assert_eq!(cmp, matrix);
+    }
+
+    /// Tests if an error is returned for different moduli
+    #[test]
+    fn mismatching_modulus() {
+        let diagonal = [
+            Zq::try_from((1, 17)).unwrap(),
+            Zq::try_from((1, 19)).unwrap(),
+        ];
+
+        assert!(matches!(
+            MatZq::from_diagonal(&diagonal),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+
+    /// Tests if an empty diagonal returns an error
+    #[test]
+    fn empty() {
+        assert!(matches!(
+            MatZq::from_diagonal(&[]),
+            Err(MathError::InvalidMatrix(_))
+        ));
+    }
+}
+
 #[cfg(test)]
 mod test_from_str {
     use crate::{integer::Z, integer_mod_q::MatZq, traits::GetEntry};
@@ -324,3 +665,70 @@ mod test_from_str {
         assert!(MatZq::from_str(&matrix_string11).is_err());
     }
 }
+
+#[cfg(test)]
+mod test_from_slices {
+    use crate::{error::MathError, integer::Z, integer_mod_q::MatZq};
+    use std::str::FromStr;
+
+    /// Ensure that entries are reduced by the modulus
+    #[test]
+    fn correct_entries() {
+        let row_0 = [Z::from(u64::MAX), Z::from(-1)];
+        let row_1 = [Z::from(14), Z::ONE];
+
+        let matrix_z = MatZq::try_from(([row_0.as_slice(), &row_1].as_slice(), 7)).unwrap();
+        let matrix_u8 = MatZq::from_slices(&[[17_u8].as_slice(), &[42]], u64::MAX).unwrap();
+
+        assert_eq!(MatZq::from_str("[[1, 6],[0, 1]] mod 7").unwrap(), matrix_z);
+        assert_eq!(
+            MatZq::from_str(&format!("[[17],[42]] mod {}", u64::MAX)).unwrap(),
+            matrix_u8
+        );
+    }
+
+    /// Ensure that vectors and arrays of [`i64`] are converted correctly
+    #[test]
+    fn from_vec_and_array() {
+        let cmp = MatZq::from_str("[[1, 0, 10],[2, 5, 3]] mod 11").unwrap();
+
+        let from_vec = MatZq::try_from((vec![vec![1, 0, -1], vec![2, 5, 14]], 11)).unwrap();
+        let from_array = MatZq::try_from(([[1, 0, -1], [2, 5, 14]], Z::from(11))).unwrap();
+
+        assert_eq!(cmp, from_vec);
+        assert_eq!(cmp, from_array);
+    }
+
+    /// Ensure that invalid dimensions and moduli result in an error
+    #[test]
+    fn invalid_input() {
+        assert!(matches!(
+            MatZq::from_slices(&[[1, 2].as_slice(), &[3]], 7),
+            Err(MathError::InvalidMatrix(_))
+        ));
+        assert!(MatZq::try_from((vec![vec![1_i64; 0]; 2], 7)).is_err());
+        assert!(MatZq::try_from(([[1, 2]], 0)).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_from_mat_z_modulus {
+    use crate::{
+        integer::{MatZ, Z},
+        integer_mod_q::{MatZq, Modulus},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that small, negative and large entries are reduced correctly
+    #[test]
+    fn correct_reduction() {
+        let matrix = MatZ::from_str(&format!("[[1, -1],[{}, {}]]", i64::MIN, u64::MAX)).unwrap();
+        let modulus = Modulus::try_from(&Z::from(u64::MAX)).unwrap();
+
+        let matrix_zq = MatZq::from_mat_z_modulus(&matrix, &modulus);
+
+        let cmp =
+            MatZq::from_str(&format!("[[1, -1],[{}, 0]] mod {}", i64::MIN, u64::MAX)).unwrap();
+        assert_eq!(cmp, matrix_zq);
+    }
+}
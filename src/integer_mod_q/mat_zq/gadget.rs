@@ -0,0 +1,202 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the construction of gadget matrices and
+//! the corresponding base-`b` digit decomposition `G^{-1}(·)`.
+
+use super::MatZq;
+use crate::{
+    error::MathError,
+    integer::{MatZ, Z},
+    traits::{GetEntry, GetNumColumns, GetNumRows, SetEntry},
+};
+use flint_sys::{fmpz::fmpz_set, fmpz_mat::fmpz_mat_entry, fmpz_mod_mat::fmpz_mod_mat_entry};
+use std::fmt::Display;
+
+impl MatZq {
+    /// Generates the gadget matrix `G = I_n ⊗ (1, b, ..., b^{k-1}) mod q`,
+    /// i.e. the `n x (n * k)` matrix whose `i`-th row contains the powers
+    /// `b^0, ..., b^{k-1}` of the `base` in the columns `i * k, ..., (i + 1) * k - 1`.
+    ///
+    /// Parameters:
+    /// - `base`: the base `b` of the gadget vector
+    /// - `k`: the number of powers of `base` in the gadget vector
+    /// - `n`: the number of rows of the gadget matrix
+    /// - `modulus`: the common modulus `q` of the matrix entries
+    ///
+    /// Returns the gadget matrix or an error if the base is not greater than `1`
+    /// or `k`, `n` and `modulus` are not suited to create a matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let gadget = MatZq::gadget(2, 3, 2, 5).unwrap();
+    ///
+    /// let expected = MatZq::from_str("[[1, 2, 4, 0, 0, 0],[0, 0, 0, 1, 2, 4]] mod 5").unwrap();
+    /// assert_eq!(expected, gadget);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidBase`](MathError::InvalidBase)
+    /// if the `base` is not greater than `1`.
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix),
+    /// [`OutOfBounds`](MathError::OutOfBounds) or
+    /// [`InvalidIntToModulus`](MathError::InvalidIntToModulus)
+    /// if `k`, `n` or the modulus are not suited to create a matrix.
+    /// For further information see [`MatZq::new`].
+    pub fn gadget(
+        base: impl Into<Z>,
+        k: impl TryInto<i64> + Display + Copy,
+        n: impl TryInto<i64> + Display + Copy,
+        modulus: impl Into<Z>,
+    ) -> Result<Self, MathError> {
+        let gadget = MatZ::gadget(base, k, n)?;
+        let mut out = MatZq::new(gadget.get_num_rows(), gadget.get_num_columns(), modulus)?;
+        for row in 0..gadget.get_num_rows() {
+            for column in 0..gadget.get_num_columns() {
+                let entry: Z = gadget.get_entry(row, column).unwrap();
+                out.set_entry(row, column, entry).unwrap();
+            }
+        }
+        Ok(out)
+    }
+
+    /// Computes the base-`b` digit decomposition `G^{-1}(self)` with respect to
+    /// the gadget matrix `G = MatZq::gadget(base, k, m, q)`, where `m` is the number
+    /// of rows of `self`. The resulting `(m * k) x n` matrix `X` satisfies
+    /// `G * X = self mod q` and all its entries are in `[0, base)`.
+    ///
+    /// Every entry is represented by its representative in `[0, q)`, which is
+    /// decomposed into its `k` least significant digits w.r.t. `base`.
+    ///
+    /// Parameters:
+    /// - `base`: the base `b` of the gadget vector
+    /// - `k`: the number of digits every entry is decomposed into
+    ///
+    /// Returns the decomposition of `self` as a [`MatZ`] or an error if the base
+    /// is not greater than `1`, `k` is not positive, or an entry can not be represented
+    /// with `k` digits.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::MatZ;
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatZq::from_str("[[5, -3],[2, 7]] mod 8").unwrap();
+    ///
+    /// let decomposition = mat.decompose(2, 3).unwrap();
+    ///
+    /// let expected = MatZ::from_str("[[1, 1],[0, 0],[1, 1],[0, 1],[1, 1],[0, 1]]").unwrap();
+    /// assert_eq!(expected, decomposition);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidBase`](MathError::InvalidBase)
+    /// if the `base` is not greater than `1`.
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix) or
+    /// [`OutOfBounds`](MathError::OutOfBounds) if `k` is not suited to create a matrix.
+    /// For further information see [`MatZ::new`].
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if an entry is not smaller than `base^k`.
+    pub fn decompose(
+        &self,
+        base: impl Into<Z>,
+        k: impl TryInto<i64> + Display + Copy,
+    ) -> Result<MatZ, MathError> {
+        let representatives = MatZ::new(self.get_num_rows(), self.get_num_columns()).unwrap();
+        for row in 0..self.get_num_rows() {
+            for column in 0..self.get_num_columns() {
+                unsafe {
+                    fmpz_set(
+                        fmpz_mat_entry(&representatives.matrix, row, column),
+                        fmpz_mod_mat_entry(&self.matrix, row, column),
+                    )
+                }
+            }
+        }
+        representatives.decompose(base, k)
+    }
+}
+
+#[cfg(test)]
+mod test_gadget {
+    use super::MatZq;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that the powers of the base are reduced by the modulus
+    #[test]
+    fn reduced_entries() {
+        let gadget = MatZq::gadget(3, 3, 1, 7).unwrap();
+
+        assert_eq!(MatZq::from_str("[[1, 3, 2]] mod 7").unwrap(), gadget);
+    }
+
+    /// Ensure that invalid bases and moduli result in an error
+    #[test]
+    fn invalid_input() {
+        assert!(matches!(
+            MatZq::gadget(1, 2, 2, 7),
+            Err(MathError::InvalidBase(_))
+        ));
+        assert!(MatZq::gadget(2, 2, 2, 0).is_err());
+        assert!(MatZq::gadget(2, 0, 2, 7).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_decompose {
+    use super::MatZq;
+    use crate::{error::MathError, integer::Z, traits::GetEntry};
+    use std::str::FromStr;
+
+    /// Ensure that `G * G^{-1}(A) = A mod q` holds for a large modulus
+    #[test]
+    fn inverse_of_gadget() {
+        let modulus = u64::MAX - 58;
+        let mat = MatZq::from_str(&format!(
+            "[[{}, -17, 1],[0, {}, 255]] mod {}",
+            i64::MAX,
+            u64::MAX - 59,
+            modulus
+        ))
+        .unwrap();
+
+        let decomposition = mat.decompose(4, 32).unwrap();
+
+        let gadget = MatZq::gadget(4, 32, 2, modulus).unwrap();
+        for row in 0..2 {
+            for column in 0..3 {
+                let mut sum = Z::ZERO;
+                for i in 0..64 {
+                    let gadget_entry: Z = gadget.get_entry(row, i).unwrap();
+                    let digit: Z = decomposition.get_entry(i, column).unwrap();
+                    assert!(Z::ZERO <= digit && digit < Z::from(4));
+                    sum = sum + gadget_entry * digit;
+                }
+                let entry: Z = mat.get_entry(row, column).unwrap();
+                assert_eq!(entry, sum);
+            }
+        }
+    }
+
+    /// Ensure that too few digits result in an error
+    #[test]
+    fn too_few_digits() {
+        let mat = MatZq::from_str("[[-1]] mod 9").unwrap();
+
+        assert!(matches!(
+            mat.decompose(2, 3),
+            Err(MathError::OutOfBounds(_, _))
+        ));
+        assert!(mat.decompose(2, 4).is_ok());
+    }
+}
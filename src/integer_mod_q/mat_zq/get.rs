@@ -14,10 +14,11 @@ use crate::{
     integer::Z,
     integer_mod_q::{fmpz_mod_helpers::length, Modulus, Zq},
     traits::{GetEntry, GetNumColumns, GetNumRows},
-    utils::index::evaluate_indices,
+    utils::index::{evaluate_index, evaluate_indices},
 };
 use flint_sys::{
     fmpz::{fmpz, fmpz_set},
+    fmpz_mat::fmpz_mat_entry,
     fmpz_mod_mat::fmpz_mod_mat_entry,
 };
 use std::fmt::Display;
@@ -144,6 +145,100 @@ impl GetEntry<Zq> for MatZq {
 }
 
 impl MatZq {
+    /// Outputs the row vector of the specified row.
+    ///
+    /// Parameters:
+    /// - `row`: specifies the row of the matrix
+    ///
+    /// Returns a row vector of the matrix at the position of the given
+    /// row or an error, if the number of rows is
+    /// greater than the matrix or negative.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZq::from_str("[[1, 2, 3],[3, 4, 5]] mod 7").unwrap();
+    ///
+    /// let row0 = matrix.get_row(0).unwrap(); // first row
+    /// let row1 = matrix.get_row(1).unwrap(); // second row
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the number of the row is greater than the matrix or negative.
+    pub fn get_row(&self, row: impl TryInto<i64> + Display + Copy) -> Result<Self, MathError> {
+        let row_i64 = evaluate_index(row)?;
+
+        if self.get_num_rows() <= row_i64 {
+            return Err(MathError::OutOfBounds(
+                format!("be smaller than {}", self.get_num_rows()),
+                format!("{}", row_i64),
+            ));
+        }
+
+        let out = MatZq::new(1, self.get_num_columns(), Z::from(&self.modulus)).unwrap();
+        for column in 0..self.get_num_columns() {
+            unsafe {
+                fmpz_set(
+                    fmpz_mat_entry(&out.matrix.mat[0], 0, column),
+                    fmpz_mod_mat_entry(&self.matrix, row_i64, column),
+                )
+            };
+        }
+        Ok(out)
+    }
+
+    /// Outputs the column vector of the specified column.
+    ///
+    /// Parameters:
+    /// - `column`: specifies the column of the matrix
+    ///
+    /// Returns a column vector of the matrix at the position of the given
+    /// column or an error, if the number of columns is
+    /// greater than the matrix or negative.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZq::from_str("[[1, 2, 3],[3, 4, 5]] mod 7").unwrap();
+    ///
+    /// let col0 = matrix.get_column(0).unwrap(); // first column
+    /// let col1 = matrix.get_column(1).unwrap(); // second column
+    /// let col2 = matrix.get_column(2).unwrap(); // third column
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the number of the column is greater than the matrix or negative.
+    pub fn get_column(
+        &self,
+        column: impl TryInto<i64> + Display + Copy,
+    ) -> Result<Self, MathError> {
+        let column_i64 = evaluate_index(column)?;
+
+        if self.get_num_columns() <= column_i64 {
+            return Err(MathError::OutOfBounds(
+                format!("be smaller than {}", self.get_num_columns()),
+                format!("{}", column_i64),
+            ));
+        }
+
+        let out = MatZq::new(self.get_num_rows(), 1, Z::from(&self.modulus)).unwrap();
+        for row in 0..self.get_num_rows() {
+            unsafe {
+                fmpz_set(
+                    fmpz_mat_entry(&out.matrix.mat[0], row, 0),
+                    fmpz_mod_mat_entry(&self.matrix, row, column_i64),
+                )
+            };
+        }
+        Ok(out)
+    }
+
     #[allow(dead_code)]
     /// Efficiently collects all [`fmpz`]s in a [`MatZq`] without cloning them.
     ///
@@ -407,6 +502,52 @@ mod test_mod {
     }
 }
 
+#[cfg(test)]
+mod test_get_vec {
+    use crate::integer_mod_q::MatZq;
+    use std::str::FromStr;
+
+    /// Ensure that rows are correctly extracted and keep the modulus
+    #[test]
+    fn get_row_works() {
+        let matrix =
+            MatZq::from_str(&format!("[[0,0,0],[4,{},5]] mod {}", i64::MAX, u64::MAX)).unwrap();
+        let row1 = matrix.get_row(0).unwrap();
+        let row2 = matrix.get_row(1).unwrap();
+
+        let cmp1 = MatZq::from_str(&format!("[[0,0,0]] mod {}", u64::MAX)).unwrap();
+        let cmp2 = MatZq::from_str(&format!("[[4,{},5]] mod {}", i64::MAX, u64::MAX)).unwrap();
+
+        assert_eq!(cmp1, row1);
+        assert_eq!(cmp2, row2);
+    }
+
+    /// Ensure that columns are correctly extracted and keep the modulus
+    #[test]
+    fn get_column_works() {
+        let matrix = MatZq::from_str("[[1,0,3],[4,0,5],[-1,0,7]] mod 11").unwrap();
+        let column1 = matrix.get_column(0).unwrap();
+        let column2 = matrix.get_column(2).unwrap();
+
+        let cmp1 = MatZq::from_str("[[1],[4],[10]] mod 11").unwrap();
+        let cmp2 = MatZq::from_str("[[3],[5],[7]] mod 11").unwrap();
+
+        assert_eq!(cmp1, column1);
+        assert_eq!(cmp2, column2);
+    }
+
+    /// Ensure that indices out of bounds are rejected
+    #[test]
+    fn wrong_dim_error() {
+        let matrix = MatZq::from_str("[[1,2,3],[4,5,6]] mod 7").unwrap();
+
+        assert!(matrix.get_row(-1).is_err());
+        assert!(matrix.get_row(2).is_err());
+        assert!(matrix.get_column(-1).is_err());
+        assert!(matrix.get_column(3).is_err());
+    }
+}
+
 #[cfg(test)]
 mod test_collect_entries {
     use super::MatZq;
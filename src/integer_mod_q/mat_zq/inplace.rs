@@ -0,0 +1,148 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains in-place variants of the transposition, negation and
+//! scalar multiplication of [`MatZq`], which reuse the memory of the matrix.
+
+use super::MatZq;
+use crate::integer::Z;
+use crate::traits::{GetNumColumns, GetNumRows};
+use flint_sys::fmpz_mod_mat::{
+    fmpz_mod_mat_neg, fmpz_mod_mat_scalar_mul_fmpz, fmpz_mod_mat_struct, fmpz_mod_mat_transpose,
+};
+
+impl MatZq {
+    /// Transposes the matrix in place, i.e. rows get transformed to columns
+    /// and vice versa. Square matrices are transposed without allocating
+    /// new memory, all others are replaced by their transposed form.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::Z, integer_mod_q::MatZq};
+    /// use std::str::FromStr;
+    ///
+    /// let mut mat = MatZq::from_str("[[1, 2, 3],[4, 5, 6]] mod 7").unwrap();
+    ///
+    /// mat.transpose_inplace();
+    ///
+    /// assert_eq!(MatZq::from_str("[[1, 4],[2, 5],[3, 6]] mod 7").unwrap(), mat);
+    /// ```
+    pub fn transpose_inplace(&mut self) {
+        if self.get_num_rows() == self.get_num_columns() {
+            let matrix: *mut fmpz_mod_mat_struct = &mut self.matrix;
+            unsafe { fmpz_mod_mat_transpose(matrix, matrix) };
+        } else {
+            *self = self.transpose();
+        }
+    }
+
+    /// Negates every entry of the matrix in place and reduces the entries by the modulus.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::Z, integer_mod_q::MatZq};
+    /// use std::str::FromStr;
+    ///
+    /// let mut mat = MatZq::from_str("[[1, 2, 3],[4, 5, 6]] mod 7").unwrap();
+    ///
+    /// mat.negate_inplace();
+    ///
+    /// assert_eq!(MatZq::from_str("[[6, 5, 4],[3, 2, 1]] mod 7").unwrap(), mat);
+    /// ```
+    pub fn negate_inplace(&mut self) {
+        let matrix: *mut fmpz_mod_mat_struct = &mut self.matrix;
+        unsafe { fmpz_mod_mat_neg(matrix, matrix) };
+    }
+
+    /// Multiplies every entry of the matrix in place by the integer `scalar` and reduces the entries by the modulus.
+    ///
+    /// Parameters:
+    /// - `scalar`: specifies the scalar by which the matrix is multiplied
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::Z, integer_mod_q::MatZq};
+    /// use std::str::FromStr;
+    ///
+    /// let mut mat = MatZq::from_str("[[1, 2, 3],[4, 5, 6]] mod 7").unwrap();
+    ///
+    /// mat.scalar_mul_assign(&Z::from(-2));
+    ///
+    /// assert_eq!(MatZq::from_str("[[5, 3, 1],[6, 4, 2]] mod 7").unwrap(), mat);
+    /// ```
+    pub fn scalar_mul_assign(&mut self, scalar: &Z) {
+        let matrix: *mut fmpz_mod_mat_struct = &mut self.matrix;
+        unsafe { fmpz_mod_mat_scalar_mul_fmpz(matrix, matrix, &scalar.value) };
+    }
+}
+
+#[cfg(test)]
+mod test_transpose_inplace {
+    use super::MatZq;
+    use std::str::FromStr;
+
+    /// Ensure that square and non-square matrices are transposed correctly
+    #[test]
+    fn correct_transpose() {
+        let mut square =
+            MatZq::from_str(&format!("[[1, {}],[2, 4]] mod {}", i64::MAX, u64::MAX)).unwrap();
+        let mut row = MatZq::from_str("[[1, 2, 3]] mod 5").unwrap();
+        let cmp_square = square.transpose();
+        let cmp_row = row.transpose();
+
+        square.transpose_inplace();
+        row.transpose_inplace();
+
+        assert_eq!(cmp_square, square);
+        assert_eq!(cmp_row, row);
+        assert_eq!(MatZq::from_str("[[1],[2],[3]] mod 5").unwrap(), row);
+    }
+}
+
+#[cfg(test)]
+mod test_negate_inplace {
+    use super::MatZq;
+    use std::str::FromStr;
+
+    /// Ensure that all entries are negated and reduced
+    #[test]
+    fn correct_negation() {
+        let mut mat =
+            MatZq::from_str(&format!("[[1, 0],[2, {}]] mod {}", u64::MAX - 1, u64::MAX)).unwrap();
+
+        mat.negate_inplace();
+
+        assert_eq!(
+            MatZq::from_str(&format!(
+                "[[{}, 0],[{}, 1]] mod {}",
+                u64::MAX - 1,
+                u64::MAX - 2,
+                u64::MAX
+            ))
+            .unwrap(),
+            mat
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_scalar_mul_assign {
+    use super::{MatZq, Z};
+    use std::str::FromStr;
+
+    /// Ensure that the entries are multiplied and reduced correctly,
+    /// also for scalars larger than the modulus
+    #[test]
+    fn correct_product() {
+        let mut mat = MatZq::from_str("[[1, 0, 6],[2, 3, 4]] mod 7").unwrap();
+
+        mat.scalar_mul_assign(&Z::from(10));
+
+        assert_eq!(MatZq::from_str("[[3, 0, 4],[6, 2, 5]] mod 7").unwrap(), mat);
+    }
+}
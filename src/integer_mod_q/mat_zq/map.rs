@@ -0,0 +1,158 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains functions to apply a closure to every entry of a [`MatZq`].
+
+use super::MatZq;
+use crate::{
+    integer::Z,
+    traits::{GetEntry, GetNumColumns, GetNumRows},
+};
+use flint_sys::{fmpz_mat::fmpz_mat_entry, fmpz_mod::fmpz_mod_set_fmpz};
+use std::convert::Infallible;
+
+impl MatZq {
+    /// Applies `f` to the representative in `[0, q)` of every entry of `self`
+    /// and returns the matrix of results reduced by the modulus `q` of `self`.
+    ///
+    /// Parameters:
+    /// - `f`: the function that is applied to each entry
+    ///
+    /// Returns a [`MatZq`] with the same dimensions and modulus as `self`, where
+    /// each entry is `f` applied to the corresponding entry of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::Z, integer_mod_q::MatZq};
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZq::from_str("[[1, 2],[3, 4]] mod 5").unwrap();
+    ///
+    /// let squared = matrix.map(|entry| entry * entry);
+    ///
+    /// assert_eq!(MatZq::from_str("[[1, 4],[4, 1]] mod 5").unwrap(), squared);
+    /// ```
+    pub fn map(&self, mut f: impl FnMut(&Z) -> Z) -> Self {
+        self.try_map(|entry| Ok::<Z, Infallible>(f(entry))).unwrap()
+    }
+
+    /// Applies the fallible function `f` to the representative in `[0, q)`
+    /// of every entry of `self` in row-major order and returns the matrix
+    /// of results reduced by the modulus `q` of `self`.
+    ///
+    /// Parameters:
+    /// - `f`: the function that is applied to each entry
+    ///
+    /// Returns a [`MatZq`] with the same dimensions and modulus as `self`, where
+    /// each entry is `f` applied to the corresponding entry of `self`,
+    /// or the first error returned by `f`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::Z, integer_mod_q::MatZq};
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZq::from_str("[[1, 2],[3, 4]] mod 5").unwrap();
+    ///
+    /// let result = matrix.try_map(|entry| match entry > &Z::from(3) {
+    ///     true => Err(format!("The entry {} is too large.", entry)),
+    ///     false => Ok(entry.clone()),
+    /// });
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns the first error returned by `f`.
+    pub fn try_map<E>(&self, mut f: impl FnMut(&Z) -> Result<Z, E>) -> Result<Self, E> {
+        let mut out = MatZq::new(
+            self.get_num_rows(),
+            self.get_num_columns(),
+            Z::from(&self.modulus),
+        )
+        .unwrap();
+        for row in 0..self.get_num_rows() {
+            for column in 0..self.get_num_columns() {
+                let entry: Z = self.get_entry(row, column).unwrap();
+                let value = f(&entry)?;
+                unsafe {
+                    fmpz_mod_set_fmpz(
+                        fmpz_mat_entry(&out.matrix.mat[0], row, column),
+                        &value.value,
+                        self.modulus.get_fmpz_mod_ctx_struct(),
+                    )
+                };
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_map {
+    use super::MatZq;
+    use crate::integer::Z;
+    use std::str::FromStr;
+
+    /// Ensure that the results are reduced by a large modulus
+    #[test]
+    fn large_modulus() {
+        let matrix =
+            MatZq::from_str(&format!("[[1, {}],[0, 3]] mod {}", i64::MAX, u64::MAX)).unwrap();
+
+        let doubled = matrix.map(|entry| entry * Z::from(2));
+
+        let cmp =
+            MatZq::from_str(&format!("[[2, {}],[0, 6]] mod {}", u64::MAX - 1, u64::MAX)).unwrap();
+        assert_eq!(cmp, doubled);
+    }
+
+    /// Ensure that negative results are reduced into `[0, q)`
+    /// and that the representatives are passed to `f`
+    #[test]
+    fn negative_results() {
+        let matrix = MatZq::from_str("[[-1, 0],[3, 6]] mod 7").unwrap();
+
+        let negated = matrix.map(|entry| Z::ZERO - entry);
+
+        assert_eq!(MatZq::from_str("[[1, 0],[4, 1]] mod 7").unwrap(), negated);
+    }
+}
+
+#[cfg(test)]
+mod test_try_map {
+    use super::MatZq;
+    use crate::integer::Z;
+    use std::str::FromStr;
+
+    /// Ensure that the mapped matrix is returned if no error occurs
+    #[test]
+    fn success() {
+        let matrix = MatZq::from_str("[[1, 2],[3, 4]] mod 5").unwrap();
+
+        let result = matrix.try_map(|entry| Ok::<Z, ()>(entry + Z::from(3)));
+
+        assert_eq!(
+            MatZq::from_str("[[4, 0],[1, 2]] mod 5").unwrap(),
+            result.unwrap()
+        );
+    }
+
+    /// Ensure that the first error is returned
+    #[test]
+    fn first_error() {
+        let matrix = MatZq::from_str("[[-1, 2],[3, -4]] mod 17").unwrap();
+
+        let result = matrix.try_map(|entry| match entry > &Z::from(8) {
+            true => Err(entry.clone()),
+            false => Ok(entry.clone()),
+        });
+
+        assert_eq!(Err(Z::from(16)), result);
+    }
+}
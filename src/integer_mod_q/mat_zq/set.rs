@@ -13,9 +13,13 @@ use crate::error::MathError;
 use crate::integer::Z;
 use crate::integer_mod_q::Zq;
 use crate::macros::for_others::{implement_for_others, implement_for_owned};
-use crate::traits::SetEntry;
-use crate::utils::index::evaluate_indices;
-use flint_sys::fmpz_mod_mat::fmpz_mod_mat_set_entry;
+use crate::traits::{GetNumColumns, GetNumRows, SetEntry};
+use crate::utils::index::{evaluate_index, evaluate_indices};
+use flint_sys::{
+    fmpz::fmpz_set,
+    fmpz_mat::fmpz_mat_entry,
+    fmpz_mod_mat::{fmpz_mod_mat_entry, fmpz_mod_mat_set_entry},
+};
 use std::fmt::Display;
 
 impl SetEntry<&Z> for MatZq {
@@ -109,6 +113,153 @@ implement_for_owned!(Zq, MatZq, SetEntry);
 
 implement_for_others!(Z, MatZq, SetEntry for i8 i16 i32 i64 u8 u16 u32 u64);
 
+impl MatZq {
+    /// Sets the specified row of the matrix to the given row vector.
+    ///
+    /// Parameters:
+    /// - `row`: specifies the row of the matrix that is set
+    /// - `row_vector`: the row vector with as many entries as `self` has columns
+    ///
+    /// Returns an empty `Ok` if the action could be performed successfully.
+    /// Otherwise, a [`MathError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let mut matrix = MatZq::from_str("[[1,2,3],[3,4,5]] mod 7").unwrap();
+    /// let row_vector = MatZq::from_str("[[6,0,1]] mod 7").unwrap();
+    ///
+    /// matrix.set_row(1, &row_vector).unwrap();
+    ///
+    /// assert_eq!(row_vector, matrix.get_row(1).unwrap());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the number of the row is greater than the matrix or negative.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if `row_vector` is not a row vector with as many entries as `self` has columns.
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the moduli of the matrix and the vector differ.
+    pub fn set_row(
+        &mut self,
+        row: impl TryInto<i64> + Display + Copy,
+        row_vector: &Self,
+    ) -> Result<(), MathError> {
+        let row_i64 = evaluate_index(row)?;
+
+        if self.get_num_rows() <= row_i64 {
+            return Err(MathError::OutOfBounds(
+                format!("be smaller than {}", self.get_num_rows()),
+                format!("{}", row_i64),
+            ));
+        }
+        if row_vector.get_num_rows() != 1 || row_vector.get_num_columns() != self.get_num_columns()
+        {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to set a row of a '{}x{}' matrix to a '{}x{}' matrix.",
+                self.get_num_rows(),
+                self.get_num_columns(),
+                row_vector.get_num_rows(),
+                row_vector.get_num_columns()
+            )));
+        }
+        if self.get_mod() != row_vector.get_mod() {
+            return Err(MathError::MismatchingModulus(format!(
+                "Tried to set a vector with modulus '{}' in a matrix with modulus '{}'.",
+                row_vector.get_mod(),
+                self.get_mod()
+            )));
+        }
+
+        for column in 0..self.get_num_columns() {
+            unsafe {
+                fmpz_set(
+                    fmpz_mat_entry(&self.matrix.mat[0], row_i64, column),
+                    fmpz_mod_mat_entry(&row_vector.matrix, 0, column),
+                )
+            };
+        }
+        Ok(())
+    }
+
+    /// Sets the specified column of the matrix to the given column vector.
+    ///
+    /// Parameters:
+    /// - `column`: specifies the column of the matrix that is set
+    /// - `column_vector`: the column vector with as many entries as `self` has rows
+    ///
+    /// Returns an empty `Ok` if the action could be performed successfully.
+    /// Otherwise, a [`MathError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let mut matrix = MatZq::from_str("[[1,2,3],[3,4,5]] mod 7").unwrap();
+    /// let column_vector = MatZq::from_str("[[2],[3]] mod 7").unwrap();
+    ///
+    /// matrix.set_column(2, &column_vector).unwrap();
+    ///
+    /// assert_eq!(column_vector, matrix.get_column(2).unwrap());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the number of the column is greater than the matrix or negative.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if `column_vector` is not a column vector with as many entries as `self` has rows.
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the moduli of the matrix and the vector differ.
+    pub fn set_column(
+        &mut self,
+        column: impl TryInto<i64> + Display + Copy,
+        column_vector: &Self,
+    ) -> Result<(), MathError> {
+        let column_i64 = evaluate_index(column)?;
+
+        if self.get_num_columns() <= column_i64 {
+            return Err(MathError::OutOfBounds(
+                format!("be smaller than {}", self.get_num_columns()),
+                format!("{}", column_i64),
+            ));
+        }
+        if column_vector.get_num_columns() != 1
+            || column_vector.get_num_rows() != self.get_num_rows()
+        {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to set a column of a '{}x{}' matrix to a '{}x{}' matrix.",
+                self.get_num_rows(),
+                self.get_num_columns(),
+                column_vector.get_num_rows(),
+                column_vector.get_num_columns()
+            )));
+        }
+        if self.get_mod() != column_vector.get_mod() {
+            return Err(MathError::MismatchingModulus(format!(
+                "Tried to set a vector with modulus '{}' in a matrix with modulus '{}'.",
+                column_vector.get_mod(),
+                self.get_mod()
+            )));
+        }
+
+        for row in 0..self.get_num_rows() {
+            unsafe {
+                fmpz_set(
+                    fmpz_mat_entry(&self.matrix.mat[0], row, column_i64),
+                    fmpz_mod_mat_entry(&column_vector.matrix, row, 0),
+                )
+            };
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test_setter {
     use crate::{
@@ -221,3 +372,85 @@ mod test_setter {
             .is_err());
     }
 }
+
+#[cfg(test)]
+mod test_set_vec {
+    use crate::{error::MathError, integer_mod_q::MatZq};
+    use std::str::FromStr;
+
+    /// Ensure that a row is correctly set
+    #[test]
+    fn set_row_works() {
+        let mut matrix = MatZq::new(2, 3, u64::MAX).unwrap();
+        let row = MatZq::from_str(&format!(
+            "[[1,{},{}]] mod {}",
+            i64::MAX,
+            u64::MAX - 1,
+            u64::MAX
+        ))
+        .unwrap();
+
+        matrix.set_row(0, &row).unwrap();
+
+        let cmp = MatZq::from_str(&format!(
+            "[[1,{},{}],[0,0,0]] mod {}",
+            i64::MAX,
+            u64::MAX - 1,
+            u64::MAX
+        ))
+        .unwrap();
+        assert_eq!(cmp, matrix);
+    }
+
+    /// Ensure that a column is correctly set
+    #[test]
+    fn set_column_works() {
+        let mut matrix = MatZq::from_str("[[1,2],[3,4]] mod 7").unwrap();
+        let column = MatZq::from_str("[[-1],[9]] mod 7").unwrap();
+
+        matrix.set_column(0, &column).unwrap();
+
+        assert_eq!(MatZq::from_str("[[6,2],[2,4]] mod 7").unwrap(), matrix);
+    }
+
+    /// Ensure that vectors with a different modulus are rejected
+    #[test]
+    fn mismatching_modulus() {
+        let mut matrix = MatZq::new(2, 2, 7).unwrap();
+
+        assert!(matches!(
+            matrix.set_row(0, &MatZq::new(1, 2, 11).unwrap()),
+            Err(MathError::MismatchingModulus(_))
+        ));
+        assert!(matches!(
+            matrix.set_column(0, &MatZq::new(2, 1, 11).unwrap()),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+
+    /// Ensure that vectors of wrong dimensions are rejected
+    #[test]
+    fn wrong_dimensions() {
+        let mut matrix = MatZq::new(2, 3, 7).unwrap();
+
+        assert!(matches!(
+            matrix.set_row(0, &MatZq::new(1, 2, 7).unwrap()),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+        assert!(matches!(
+            matrix.set_column(0, &MatZq::new(3, 1, 7).unwrap()),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+
+    /// Ensure that indices out of bounds are rejected
+    #[test]
+    fn out_of_bounds() {
+        let mut matrix = MatZq::new(2, 3, 7).unwrap();
+
+        assert!(matrix.set_row(2, &MatZq::new(1, 3, 7).unwrap()).is_err());
+        assert!(matrix
+            .set_column(-1, &MatZq::new(2, 1, 7).unwrap())
+            .is_err());
+    }
+}
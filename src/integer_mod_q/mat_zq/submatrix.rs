@@ -0,0 +1,227 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains functions to extract a submatrix of a [`MatZq`]
+//! and to embed a matrix into a [`MatZq`].
+
+use super::MatZq;
+use crate::{
+    error::MathError,
+    integer::Z,
+    traits::{GetNumColumns, GetNumRows},
+    utils::index::{evaluate_bounded_index, evaluate_index},
+};
+use flint_sys::{fmpz::fmpz_set, fmpz_mat::fmpz_mat_entry, fmpz_mod_mat::fmpz_mod_mat_entry};
+use std::{fmt::Display, ops::Range};
+
+impl MatZq {
+    /// Returns the submatrix consisting of the entries in the rows `rows`
+    /// and the columns `columns` of the matrix.
+    ///
+    /// Parameters:
+    /// - `rows`: the range of rows of the submatrix, excluding its end
+    /// - `columns`: the range of columns of the submatrix, excluding its end
+    ///
+    /// Returns the submatrix or an error if the ranges are empty
+    /// or not within the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZq::from_str("[[1,2,3],[4,5,6],[0,1,2]] mod 7").unwrap();
+    ///
+    /// let submatrix = matrix.get_submatrix(1..3, 0..2).unwrap();
+    ///
+    /// assert_eq!(MatZq::from_str("[[4,5],[0,1]] mod 7").unwrap(), submatrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if one of the ranges is not within the matrix.
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if one of the ranges is empty.
+    pub fn get_submatrix(&self, rows: Range<i64>, columns: Range<i64>) -> Result<Self, MathError> {
+        let row_start = evaluate_index(rows.start)?;
+        let column_start = evaluate_index(columns.start)?;
+        if rows.end > self.get_num_rows() || columns.end > self.get_num_columns() {
+            return Err(MathError::OutOfBounds(
+                format!(
+                    "be within ({},{})",
+                    self.get_num_rows(),
+                    self.get_num_columns()
+                ),
+                format!("({:?},{:?})", rows, columns),
+            ));
+        }
+
+        let out = MatZq::new(
+            (rows.end - row_start).max(0),
+            (columns.end - column_start).max(0),
+            Z::from(&self.modulus),
+        )?;
+        for row in 0..out.get_num_rows() {
+            for column in 0..out.get_num_columns() {
+                unsafe {
+                    fmpz_set(
+                        fmpz_mat_entry(&out.matrix.mat[0], row, column),
+                        fmpz_mod_mat_entry(&self.matrix, row_start + row, column_start + column),
+                    )
+                };
+            }
+        }
+        Ok(out)
+    }
+
+    /// Overwrites the entries of the matrix with the entries of `other`,
+    /// s.t. the top left entry of `other` is placed at position (`row`, `column`).
+    ///
+    /// Parameters:
+    /// - `row`: the row at which the top row of `other` is placed
+    /// - `column`: the column at which the leftmost column of `other` is placed
+    /// - `other`: the matrix that is embedded into `self`
+    ///
+    /// Returns an empty `Ok` if the action could be performed successfully.
+    /// Otherwise, a [`MathError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let mut matrix = MatZq::from_str("[[1,2,3],[4,5,6],[0,1,2]] mod 7").unwrap();
+    /// let other = MatZq::from_str("[[0,6],[0,0]] mod 7").unwrap();
+    ///
+    /// matrix.set_submatrix(0, 1, &other).unwrap();
+    ///
+    /// assert_eq!(MatZq::from_str("[[1,0,6],[4,0,0],[0,1,2]] mod 7").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the position is not within the matrix.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if `other` does not fit into `self` at the provided position.
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the moduli of both matrices differ.
+    pub fn set_submatrix(
+        &mut self,
+        row: impl TryInto<i64> + Display + Copy,
+        column: impl TryInto<i64> + Display + Copy,
+        other: &Self,
+    ) -> Result<(), MathError> {
+        let row = evaluate_bounded_index(row, self.get_num_rows())?;
+        let column = evaluate_bounded_index(column, self.get_num_columns())?;
+        if row + other.get_num_rows() > self.get_num_rows()
+            || column + other.get_num_columns() > self.get_num_columns()
+        {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to embed a '{}x{}' matrix at position ({},{}) of a '{}x{}' matrix.",
+                other.get_num_rows(),
+                other.get_num_columns(),
+                row,
+                column,
+                self.get_num_rows(),
+                self.get_num_columns()
+            )));
+        }
+        if self.get_mod() != other.get_mod() {
+            return Err(MathError::MismatchingModulus(format!(
+                "Tried to embed a matrix with modulus '{}' in a matrix with modulus '{}'.",
+                other.get_mod(),
+                self.get_mod()
+            )));
+        }
+
+        for other_row in 0..other.get_num_rows() {
+            for other_column in 0..other.get_num_columns() {
+                unsafe {
+                    fmpz_set(
+                        fmpz_mat_entry(&self.matrix.mat[0], row + other_row, column + other_column),
+                        fmpz_mod_mat_entry(&other.matrix, other_row, other_column),
+                    )
+                };
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_get_submatrix {
+    use crate::{error::MathError, integer_mod_q::MatZq};
+    use std::str::FromStr;
+
+    /// Ensure that the correct entries are extracted and the modulus is kept
+    #[test]
+    fn correct_entries() {
+        let matrix =
+            MatZq::from_str(&format!("[[1,2,3],[4,{},6]] mod {}", i64::MAX, u64::MAX)).unwrap();
+
+        let submatrix = matrix.get_submatrix(1..2, 0..3).unwrap();
+
+        let cmp = MatZq::from_str(&format!("[[4,{},6]] mod {}", i64::MAX, u64::MAX)).unwrap();
+        assert_eq!(cmp, submatrix);
+    }
+
+    /// Ensure that invalid ranges are rejected
+    #[test]
+    fn invalid_ranges() {
+        let matrix = MatZq::new(2, 3, 7).unwrap();
+
+        assert!(matches!(
+            matrix.get_submatrix(0..2, 0..4),
+            Err(MathError::OutOfBounds(_, _))
+        ));
+        assert!(matches!(
+            matrix.get_submatrix(0..2, 3..3),
+            Err(MathError::InvalidMatrix(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_set_submatrix {
+    use crate::{error::MathError, integer_mod_q::MatZq};
+    use std::str::FromStr;
+
+    /// Ensure that the entries are correctly overwritten
+    #[test]
+    fn correct_entries() {
+        let mut matrix = MatZq::from_str("[[1,1,1],[1,1,1]] mod 11").unwrap();
+        let other = MatZq::from_str("[[5,-6]] mod 11").unwrap();
+
+        matrix.set_submatrix(1, 1, &other).unwrap();
+
+        assert_eq!(MatZq::from_str("[[1,1,1],[1,5,5]] mod 11").unwrap(), matrix);
+    }
+
+    /// Ensure that matrices with a different modulus are rejected
+    #[test]
+    fn mismatching_modulus() {
+        let mut matrix = MatZq::new(2, 2, 7).unwrap();
+
+        assert!(matches!(
+            matrix.set_submatrix(0, 0, &MatZq::new(1, 1, 11).unwrap()),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+
+    /// Ensure that matrices which do not fit are rejected
+    #[test]
+    fn does_not_fit() {
+        let mut matrix = MatZq::new(2, 2, 7).unwrap();
+
+        assert!(matches!(
+            matrix.set_submatrix(0, 1, &MatZq::new(2, 2, 7).unwrap()),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
@@ -11,16 +11,48 @@
 //!
 //! This includes the [`Display`](std::fmt::Display) trait.
 
-use crate::{integer::Z, utils::parse::matrix_to_string};
+use crate::{
+    integer::{MatZ, Z},
+    integer_mod_q::z_q::fmpz_mod_helpers::symmetric_representative,
+    traits::{GetNumColumns, GetNumRows, SetEntry},
+    utils::parse::matrix_to_string,
+};
 
 use super::MatZq;
 use core::fmt;
 
+impl MatZq {
+    /// Converts a matrix of type [`MatZq`] into a [`String`], where each entry
+    /// is represented by its representative in `(-q/2, q/2]` instead of `[0, q)`.
+    ///
+    /// Returns the Matrix in form of a [`String`]. For matrix `[[1, 2, 3],[4, 5, 6]] mod 4`
+    /// the String looks like this `[[1, 2, -1],[0, 1, 2]] mod 4`.
+    ///
+    /// The same representation is used by the alternate flag of the
+    /// [`Display`](std::fmt::Display) trait, i.e. `format!("{:#}", matrix)`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::MatZq;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZq::from_str("[[1,2,3],[4,5,6]] mod 4").unwrap();
+    ///
+    /// assert_eq!("[[1, 2, -1],[0, 1, 2]] mod 4", matrix.to_string_symmetric());
+    /// assert_eq!("[[1, 2, -1],[0, 1, 2]] mod 4", format!("{:#}", matrix));
+    /// ```
+    pub fn to_string_symmetric(&self) -> String {
+        format!("{:#}", self)
+    }
+}
+
 impl fmt::Display for MatZq {
     /// Allows to convert a matrix of type [`MatZq`] into a [`String`].
     ///
     /// Returns the Matrix in form of a [`String`]. For matrix `[[1, 2, 3],[4, 5, 6]] mod 4`
     /// the String looks like this `[[1, 2, 3],[0, 1, 2]] mod 4`.
+    /// If the alternate flag `{:#}` is set, the entries are represented in
+    /// `(-q/2, q/2]`, see [`MatZq::to_string_symmetric`].
     ///
     /// # Examples
     /// ```
@@ -41,7 +73,20 @@ impl fmt::Display for MatZq {
     /// let matrix_string = matrix.to_string();
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let matrix = matrix_to_string::<Z, MatZq>(self);
+        let matrix = if f.alternate() {
+            let modulus = self.matrix.mod_[0];
+            let mut symmetric = MatZ::new(self.get_num_rows(), self.get_num_columns()).unwrap();
+            for (index, entry) in self.collect_entries().iter().enumerate() {
+                let row = index as i64 / self.get_num_columns();
+                let column = index as i64 % self.get_num_columns();
+                symmetric
+                    .set_entry(row, column, symmetric_representative(entry, &modulus))
+                    .unwrap();
+            }
+            matrix_to_string::<Z, MatZ>(&symmetric)
+        } else {
+            matrix_to_string::<Z, MatZq>(self)
+        };
         write!(f, "{} mod {}", matrix, self.get_mod())
     }
 }
@@ -135,3 +180,53 @@ mod test_to_string {
         assert!(MatZq::from_str(&cmp_string2).is_ok())
     }
 }
+
+#[cfg(test)]
+mod test_to_string_symmetric {
+    use crate::integer_mod_q::MatZq;
+    use std::str::FromStr;
+
+    /// tests whether entries larger than `q/2` are represented negatively
+    /// and `q/2` stays positive for even moduli
+    #[test]
+    fn small_entries() {
+        let cmp = MatZq::from_str("[[-2, 1, 3],[5, -6, 7]] mod 4").unwrap();
+
+        assert_eq!("[[2, 1, -1],[1, 2, -1]] mod 4", cmp.to_string_symmetric())
+    }
+
+    /// tests whether large entries are represented correctly
+    #[test]
+    fn large_entries() {
+        let cmp = MatZq::from_str(&format!(
+            "[[-{}, 1],[{}, 0]] mod {}",
+            u64::MAX - 1,
+            i64::MAX,
+            u64::MAX
+        ))
+        .unwrap();
+
+        assert_eq!(
+            format!("[[1, 1],[{}, 0]] mod {}", i64::MAX, u64::MAX),
+            cmp.to_string_symmetric()
+        )
+    }
+
+    /// tests whether the alternate flag of [`Display`](std::fmt::Display)
+    /// yields the symmetric representation and the default one is unchanged
+    #[test]
+    fn alternate_flag() {
+        let cmp = MatZq::from_str("[[-1],[4]] mod 17").unwrap();
+
+        assert_eq!("[[-1],[4]] mod 17", format!("{:#}", cmp));
+        assert_eq!("[[16],[4]] mod 17", format!("{}", cmp));
+    }
+
+    /// tests whether the symmetric string can be used to create the same [`MatZq`]
+    #[test]
+    fn round_trip() {
+        let cmp = MatZq::from_str("[[-2, 1, 3],[5, -6, 7]] mod 9").unwrap();
+
+        assert_eq!(cmp, MatZq::from_str(&cmp.to_string_symmetric()).unwrap())
+    }
+}
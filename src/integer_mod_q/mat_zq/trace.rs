@@ -0,0 +1,98 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the `trace` function.
+
+use super::MatZq;
+use crate::{
+    error::MathError,
+    integer::Z,
+    integer_mod_q::Zq,
+    traits::{GetNumColumns, GetNumRows},
+};
+use flint_sys::fmpz_mod_mat::fmpz_mod_mat_trace;
+
+impl MatZq {
+    /// Returns the trace of a square matrix, i.e. the sum of its diagonal entries
+    /// reduced by the modulus of the matrix.
+    ///
+    /// Returns the trace of the matrix or an error if the matrix is not square.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{MatZq, Zq};
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatZq::from_str("[[1,2],[3,4]] mod 3").unwrap();
+    ///
+    /// let trace = mat.trace().unwrap();
+    ///
+    /// assert_eq!(Zq::try_from((2, 3)).unwrap(), trace);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the matrix is not square.
+    pub fn trace(&self) -> Result<Zq, MathError> {
+        if self.get_num_rows() != self.get_num_columns() {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "The trace is only defined for square matrices, but the matrix is '{}x{}'.",
+                self.get_num_rows(),
+                self.get_num_columns()
+            )));
+        }
+
+        let mut out = Z::default();
+        unsafe { fmpz_mod_mat_trace(&mut out.value, &self.matrix) };
+        Ok(Zq::from_z_modulus(&out, &self.modulus))
+    }
+}
+
+#[cfg(test)]
+mod test_trace {
+    use super::MatZq;
+    use crate::{error::MathError, integer_mod_q::Zq};
+    use std::str::FromStr;
+
+    /// Checks if the trace is correctly computed and reduced
+    #[test]
+    fn correct_trace() {
+        let mat = MatZq::from_str("[[1,2,3],[4,-5,6],[7,8,9]] mod 11").unwrap();
+
+        assert_eq!(Zq::try_from((5, 11)).unwrap(), mat.trace().unwrap());
+    }
+
+    /// Checks if the trace is correctly reduced for large moduli
+    #[test]
+    fn large_entries() {
+        let mat = MatZq::from_str(&format!(
+            "[[{},1],[1,{}]] mod {}",
+            u64::MAX - 1,
+            u64::MAX - 1,
+            u64::MAX
+        ))
+        .unwrap();
+
+        assert_eq!(
+            Zq::try_from((u64::MAX - 2, u64::MAX)).unwrap(),
+            mat.trace().unwrap()
+        );
+    }
+
+    /// Checks if an error is returned for non-square matrices
+    #[test]
+    fn non_square() {
+        let mat = MatZq::new(2, 3, 7).unwrap();
+
+        assert!(matches!(
+            mat.trace(),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
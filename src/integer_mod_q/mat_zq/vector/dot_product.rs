@@ -0,0 +1,153 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module includes functionality to compute the dot product of two vectors.
+
+use super::super::MatZq;
+use crate::error::MathError;
+use crate::integer::Z;
+use crate::integer_mod_q::Zq;
+use crate::traits::{GetNumColumns, GetNumRows};
+use flint_sys::fmpz::fmpz_addmul;
+
+impl MatZq {
+    /// Returns the dot product of two vectors of type [`MatZq`]
+    /// reduced by their common modulus.
+    ///
+    /// Paramters:
+    /// - `other`: specifies the other vector the dot product is calculated over
+    ///
+    /// Returns the resulting `dot_product` as a [`Zq`] or an error,
+    /// if the given [`MatZq`] instances aren't vectors, have different
+    /// numbers of entries or different moduli.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{MatZq, Zq};
+    /// use std::str::FromStr;
+    ///
+    /// let vec_1 = MatZq::from_str("[[1],[2],[3]] mod 5").unwrap();
+    /// let vec_2 = MatZq::from_str("[[1,3,2]] mod 5").unwrap();
+    ///
+    /// let dot_prod = vec_1.dot_product(&vec_2).unwrap();
+    ///
+    /// // 1*1 + 2*3 + 3*2 = 13 = 3 mod 5
+    /// assert_eq!(Zq::try_from((3, 5)).unwrap(), dot_prod);
+    /// ```
+    ///
+    /// Errors and Failures
+    /// - Returns a [`MathError`] of type [`MathError::VectorFunctionCalledOnNonVector`] if
+    /// the given [`MatZq`] instance is not a (row or column) vector.
+    /// - Returns a [`MathError`] of type [`MathError::MismatchingVectorDimensions`] if
+    /// the given vectors have different lengths.
+    /// - Returns a [`MathError`] of type [`MathError::MismatchingModulus`] if
+    /// the given vectors have different moduli.
+    pub fn dot_product(&self, other: &Self) -> Result<Zq, MathError> {
+        if !self.is_vector() {
+            return Err(MathError::VectorFunctionCalledOnNonVector(
+                String::from("dot_product"),
+                self.get_num_rows(),
+                self.get_num_columns(),
+            ));
+        } else if !other.is_vector() {
+            return Err(MathError::VectorFunctionCalledOnNonVector(
+                String::from("dot_product"),
+                other.get_num_rows(),
+                other.get_num_columns(),
+            ));
+        } else if self.get_mod() != other.get_mod() {
+            return Err(MathError::MismatchingModulus(format!(
+                "You called the function 'dot_product' for vectors with moduli {} and {}",
+                self.get_mod(),
+                other.get_mod()
+            )));
+        }
+
+        let self_entries = self.collect_entries();
+        let other_entries = other.collect_entries();
+
+        if self_entries.len() != other_entries.len() {
+            return Err(MathError::MismatchingVectorDimensions(format!(
+                "You called the function 'dot_product' for vectors of different lengths: {} and {}",
+                self_entries.len(),
+                other_entries.len()
+            )));
+        }
+
+        // calculate dot product of vectors over the integers and reduce once afterwards
+        let mut result = Z::ZERO;
+        for i in 0..self_entries.len() {
+            // sets result = result + self.entry[i] * other.entry[i] without cloned Z element
+            unsafe { fmpz_addmul(&mut result.value, &self_entries[i], &other_entries[i]) }
+        }
+
+        Ok(Zq::from_z_modulus(&result, &self.modulus))
+    }
+}
+
+#[cfg(test)]
+mod test_dot_product {
+    use super::{MatZq, Zq};
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Check whether the dot product is calculated correctly for
+    /// row and column vectors in any combination
+    #[test]
+    fn row_and_column() {
+        let row = MatZq::from_str("[[1,2,-3]] mod 7").unwrap();
+        let column = MatZq::from_str("[[1],[3],[2]] mod 7").unwrap();
+        let one = Zq::try_from((1, 7)).unwrap();
+        let zero = Zq::try_from((0, 7)).unwrap();
+
+        // 1*1 + 2*2 + (-3)*(-3) = 14 = 0 mod 7
+        assert_eq!(zero, row.dot_product(&row).unwrap());
+        // 1*1 + 2*3 + (-3)*2 = 1 mod 7
+        assert_eq!(one, row.dot_product(&column).unwrap());
+        assert_eq!(one, column.dot_product(&row).unwrap());
+        // 1*1 + 3*3 + 2*2 = 14 = 0 mod 7
+        assert_eq!(zero, column.dot_product(&column).unwrap());
+    }
+
+    /// Check whether the dot product is reduced correctly with large numbers
+    #[test]
+    fn large_numbers() {
+        let modulus = u64::MAX;
+        let vec_1 = MatZq::from_str(&format!("[[1,{},2]] mod {}", u64::MAX - 1, modulus)).unwrap();
+        let vec_2 = MatZq::from_str(&format!("[[1,{},3]] mod {}", u64::MAX - 1, modulus)).unwrap();
+
+        let dot_prod = vec_1.dot_product(&vec_2).unwrap();
+
+        // 1 + (-1)^2 + 6 = 8
+        assert_eq!(Zq::try_from((8, modulus)).unwrap(), dot_prod);
+    }
+
+    /// Check whether non vector instances, vectors of different lengths
+    /// and different moduli yield an error
+    #[test]
+    fn invalid_input_yield_error() {
+        let vec = MatZq::from_str("[[1,3,2]] mod 7").unwrap();
+        let mat = MatZq::from_str("[[1,2],[2,3],[-3,4]] mod 7").unwrap();
+        let longer = MatZq::from_str("[[1,3,2,4]] mod 7").unwrap();
+        let other_modulus = MatZq::from_str("[[1,3,2]] mod 11").unwrap();
+
+        assert!(matches!(
+            vec.dot_product(&mat),
+            Err(MathError::VectorFunctionCalledOnNonVector(_, _, _))
+        ));
+        assert!(mat.dot_product(&vec).is_err());
+        assert!(matches!(
+            vec.dot_product(&longer),
+            Err(MathError::MismatchingVectorDimensions(_))
+        ));
+        assert!(matches!(
+            vec.dot_product(&other_modulus),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+}
@@ -12,6 +12,11 @@
 //! The explicit functions contain the documentation.
 
 use super::Modulus;
+use crate::integer::fmpz_helpers::to_bytes;
+use std::{
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
 
 impl PartialEq for Modulus {
     /// Compares the two [`fmpz`](flint_sys::fmpz::fmpz) structs hiding behind the
@@ -34,6 +39,10 @@ impl PartialEq for Modulus {
     /// assert_ne!(a, c);
     /// ```
     fn eq(&self, other: &Self) -> bool {
+        // moduli with equal values usually share their context
+        if Rc::ptr_eq(&self.modulus, &other.modulus) {
+            return true;
+        }
         unsafe {
             1 == flint_sys::fmpz::fmpz_equal(
                 &self.get_fmpz_mod_ctx_struct().to_owned().n[0],
@@ -47,6 +56,29 @@ impl PartialEq for Modulus {
 // This is not guaranteed by the [`PartialEq`] trait.
 impl Eq for Modulus {}
 
+impl Hash for Modulus {
+    /// Hashes the value of the [`Modulus`] s.t. equal moduli have equal hashes.
+    /// This allows to use [`Modulus`] as a key of a
+    /// [`HashMap`](std::collections::HashMap).
+    ///
+    /// Parameters:
+    /// - `state`: the [`Hasher`] that is fed with the value of `self`
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::Modulus;
+    /// use std::{collections::HashMap, str::FromStr};
+    ///
+    /// let mut map = HashMap::new();
+    /// map.insert(Modulus::from_str("17").unwrap(), "seventeen");
+    ///
+    /// assert_eq!(Some(&"seventeen"), map.get(&Modulus::from_str("17").unwrap()));
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        to_bytes(&self.get_fmpz_mod_ctx_struct().n[0]).hash(state);
+    }
+}
+
 #[cfg(test)]
 mod test_eq {
 
@@ -90,3 +122,45 @@ mod test_eq {
         assert_ne!(two, big);
     }
 }
+
+#[cfg(test)]
+mod test_hash {
+    use super::Modulus;
+    use std::{
+        collections::{hash_map::DefaultHasher, HashSet},
+        hash::{Hash, Hasher},
+        str::FromStr,
+    };
+
+    /// Computes the hash of `value` with the [`DefaultHasher`]
+    fn hash(value: &Modulus) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Ensure that equal moduli have equal hashes
+    #[test]
+    fn equal_moduli() {
+        let a = Modulus::from_str(&"1".repeat(65)).unwrap();
+        let b = Modulus::from_str(&"1".repeat(65)).unwrap();
+        let c = Modulus::from_str("17").unwrap();
+        let d = Modulus::try_from(17_u64).unwrap();
+
+        assert_eq!(hash(&a), hash(&b));
+        assert_eq!(hash(&c), hash(&d));
+    }
+
+    /// Ensure that moduli can be used in hash based collections
+    #[test]
+    fn hash_set() {
+        let mut set = HashSet::new();
+
+        set.insert(Modulus::from_str("17").unwrap());
+        set.insert(Modulus::from_str("17").unwrap());
+        set.insert(Modulus::from_str(&"1".repeat(65)).unwrap());
+
+        assert_eq!(2, set.len());
+        assert!(set.contains(&Modulus::from_str(&"1".repeat(65)).unwrap()));
+    }
+}
@@ -11,12 +11,30 @@
 //! `from_<type_name>` and the [`From`] trait should be implemented.
 //!
 //! The explicit functions contain the documentation.
+//!
+//! The [`TryFrom`] conversions from integers reject all values smaller than `2`,
+//! as a modulus computed from an integer is usually expected to define
+//! a non-trivial ring.
+//! [`Modulus::try_from_z`] and [`Modulus::from_str`] additionally accept the
+//! trivial modulus `1`, which is required to represent the zero ring, e.g.
+//! the result `0 mod 1` of [`Zq::crt_combine`](crate::integer_mod_q::Zq::crt_combine)
+//! for an empty slice and matrices created by `MatZq::new(.., .., 1)`.
 
 use super::Modulus;
-use crate::{error::MathError, integer::Z};
+use crate::{
+    error::MathError,
+    integer::{fmpz_helpers::to_bytes, Z},
+    utils::context_cache::ContextCache,
+};
 use flint_sys::fmpz_mod::{fmpz_mod_ctx, fmpz_mod_ctx_init};
 use std::{mem::MaybeUninit, rc::Rc, str::FromStr};
 
+thread_local! {
+    /// Stores the contexts of all moduli that are in use in this thread
+    /// s.t. moduli with the same value share their context.
+    static MODULUS_CACHE: ContextCache<fmpz_mod_ctx> = ContextCache::new();
+}
+
 impl Modulus {
     /// Create a [`Modulus`] from [`Z`].
     ///
@@ -35,24 +53,103 @@ impl Modulus {
     /// # Errors and Failures
     ///
     /// - Returns a [`MathError`] of type [`InvalidIntToModulus`](MathError::InvalidIntToModulus)
-    /// if the provided value is not greater than `0`.
+    /// if the provided value is smaller than `1`.
+    /// The trivial modulus `1` is accepted to represent the zero ring,
+    /// use `Modulus::try_from(&Z)` to reject it.
     pub fn try_from_z(value: &Z) -> Result<Self, MathError> {
-        let modulus = ctx_init(value);
         Ok(Self {
-            modulus: Rc::new(modulus?),
+            modulus: cached_ctx(value)?,
         })
     }
 }
 
-// TODO: write macro to generate [`TryFrom`] trait.
 impl TryFrom<&Z> for Modulus {
     type Error = MathError;
-    /// Create [`Modulus`] from [`Z`] reference using [`try_from_z`](Modulus::try_from_z)
+    /// Create a [`Modulus`] from a [`Z`] reference.
+    /// In contrast to [`try_from_z`](Modulus::try_from_z) and
+    /// [`from_str`](Modulus::from_str), the trivial modulus `1` is rejected,
+    /// as it is not meaningful for moduli computed from integers.
+    ///
+    /// Parameters:
+    /// - `value`: the value of the modulus
+    ///
+    /// Returns a [`Modulus`] or a [`MathError`]
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::Z, integer_mod_q::Modulus};
+    ///
+    /// let modulus = Modulus::try_from(&Z::from(42)).unwrap();
+    ///
+    /// assert!(Modulus::try_from(&Z::ONE).is_err());
+    /// ```
+    /// # Errors and Failures
+    ///
+    /// - Returns a [`MathError`] of type [`InvalidIntToModulus`](MathError::InvalidIntToModulus)
+    /// if the provided value is smaller than `2`.
     fn try_from(value: &Z) -> Result<Self, Self::Error> {
+        if value < &Z::from(2) {
+            return Err(MathError::InvalidIntToModulus(format!(
+                "The modulus {} is smaller than 2.",
+                value
+            )));
+        }
         Modulus::try_from_z(value)
     }
 }
 
+impl TryFrom<i64> for Modulus {
+    type Error = MathError;
+    /// Create a [`Modulus`] from an [`i64`] without parsing a string.
+    ///
+    /// Parameters:
+    /// - `value`: the value of the modulus
+    ///
+    /// Returns a [`Modulus`] or a [`MathError`]
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::Modulus;
+    ///
+    /// let modulus = Modulus::try_from(42_i64).unwrap();
+    ///
+    /// assert_eq!("42", modulus.to_string());
+    /// ```
+    /// # Errors and Failures
+    ///
+    /// - Returns a [`MathError`] of type [`InvalidIntToModulus`](MathError::InvalidIntToModulus)
+    /// if the provided value is smaller than `2`.
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Modulus::try_from(&Z::from(value))
+    }
+}
+
+impl TryFrom<u64> for Modulus {
+    type Error = MathError;
+    /// Create a [`Modulus`] from a [`u64`] without parsing a string.
+    ///
+    /// Parameters:
+    /// - `value`: the value of the modulus
+    ///
+    /// Returns a [`Modulus`] or a [`MathError`]
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::Modulus;
+    ///
+    /// let modulus = Modulus::try_from(u64::MAX).unwrap();
+    ///
+    /// assert_eq!(u64::MAX.to_string(), modulus.to_string());
+    /// ```
+    /// # Errors and Failures
+    ///
+    /// - Returns a [`MathError`] of type [`InvalidIntToModulus`](MathError::InvalidIntToModulus)
+    /// if the provided value is smaller than `2`.
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Modulus::try_from(&Z::from(value))
+    }
+}
+
 impl FromStr for Modulus {
     type Err = MathError;
 
@@ -79,28 +176,45 @@ impl FromStr for Modulus {
     /// formatted [`Z`].
     /// - Returns a [`MathError`] of type
     /// [`InvalidIntToModulus`](MathError::InvalidIntToModulus)
-    /// if the provided value is not greater than `0`.
+    /// if the provided value is smaller than `1`.
+    /// The trivial modulus `1` is accepted to represent the zero ring.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let z = Z::from_str(s)?;
 
-        let modulus = ctx_init(&z)?;
         Ok(Self {
-            modulus: Rc::new(modulus),
+            modulus: cached_ctx(&z)?,
         })
     }
 }
 
+/// Returns the FLINT-context object of a [`Modulus`] with value `n`.
+/// If a [`Modulus`] with this value is currently in use in this thread,
+/// its context is shared. Otherwise, a new context is initialized.
+///
+/// Parameters:
+/// - `n`: the value the modulus should have as [`Z`]
+///
+/// Returns a reference to the context object [`fmpz_mod_ctx`] or an error, if the
+/// provided value is smaller than `1`.
+///
+/// # Errors and Failures
+/// - Returns a [`MathError`] of type [`InvalidIntToModulus`](MathError::InvalidIntToModulus)
+/// if the provided value is smaller than `1`.
+fn cached_ctx(n: &Z) -> Result<Rc<fmpz_mod_ctx>, MathError> {
+    MODULUS_CACHE.with(|cache| cache.get_or_try_init(to_bytes(&n.value), || ctx_init(n)))
+}
+
 /// Initializes the FLINT-context object using a [`Z`]-value as input
 ///
 /// Parameters:
 /// - `s`: the value the modulus should have as [`Z`]
 ///
 /// Returns an initialized context object [`fmpz_mod_ctx`] or an error, if the
-/// provided value was not greater than `0`.
+/// provided value is smaller than `1`.
 ///
 /// # Errors and Failures
 /// - Returns a [`MathError`] of type [`InvalidIntToModulus`](MathError::InvalidIntToModulus)
-/// if the provided value is not greater than `0`.
+/// if the provided value is smaller than `1`.
 fn ctx_init(n: &Z) -> Result<fmpz_mod_ctx, MathError> {
     if n <= &Z::ZERO {
         return Err(MathError::InvalidIntToModulus(n.to_string()));
@@ -163,6 +277,71 @@ mod test_try_from_z {
     }
 }
 
+#[cfg(test)]
+mod test_try_from_int {
+    use super::Modulus;
+    use crate::integer::Z;
+
+    /// Ensure that small and large integers are converted correctly
+    #[test]
+    fn valid_values() {
+        assert_eq!(Z::from(2), Z::from(&Modulus::try_from(2_i64).unwrap()));
+        assert_eq!(
+            Z::from(i64::MAX),
+            Z::from(&Modulus::try_from(i64::MAX).unwrap())
+        );
+        assert_eq!(Z::from(17), Z::from(&Modulus::try_from(17_u64).unwrap()));
+        assert_eq!(
+            Z::from(u64::MAX),
+            Z::from(&Modulus::try_from(u64::MAX).unwrap())
+        );
+    }
+
+    /// Ensure that an error is returned for values smaller than `2`
+    #[test]
+    fn invalid_values() {
+        assert!(Modulus::try_from(1_i64).is_err());
+        assert!(Modulus::try_from(1_u64).is_err());
+        assert!(Modulus::try_from(&Z::ONE).is_err());
+        assert!(Modulus::try_from(0_i64).is_err());
+        assert!(Modulus::try_from(-1_i64).is_err());
+        assert!(Modulus::try_from(i64::MIN).is_err());
+        assert!(Modulus::try_from(0_u64).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_cached_ctx {
+    use super::Modulus;
+    use crate::integer::Z;
+    use std::{rc::Rc, str::FromStr};
+
+    /// Ensure that moduli with the same value share their context
+    #[test]
+    fn shared_context() {
+        let a = Modulus::from_str(&"1".repeat(65)).unwrap();
+        let b = Modulus::try_from(&Z::from_str(&"1".repeat(65)).unwrap()).unwrap();
+        let c = Modulus::from_str("17").unwrap();
+
+        assert!(Rc::ptr_eq(&a.modulus, &b.modulus));
+        assert!(!Rc::ptr_eq(&a.modulus, &c.modulus));
+        assert_eq!(2, Rc::strong_count(&a.modulus));
+    }
+
+    /// Ensure that a new context is initialized once all moduli
+    /// with this value were dropped
+    #[test]
+    fn context_reinitialized() {
+        let a = Modulus::from_str("17").unwrap();
+        drop(a);
+
+        let b = Modulus::from_str("17").unwrap();
+
+        assert_eq!(1, Rc::strong_count(&b.modulus));
+        assert_eq!(Z::from(17), Z::from(&b));
+    }
+}
+
 #[cfg(test)]
 mod test_ctx_init {
     use super::ctx_init;
@@ -10,11 +10,84 @@
 //! [`Modulus`].
 
 use super::Modulus;
-use flint_sys::fmpz_mod::fmpz_mod_ctx_struct;
+use crate::integer::Z;
+use flint_sys::{fmpz::fmpz_bits, fmpz_mod::fmpz_mod_ctx_struct};
 
 impl Modulus {
+    /// Returns the value of the [`Modulus`] as a [`Z`] without consuming it.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::Z;
+    /// use qfall_math::integer_mod_q::Modulus;
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = Modulus::from_str("42").unwrap();
+    ///
+    /// assert_eq!(Z::from(42), modulus.to_z());
+    /// ```
+    pub fn to_z(&self) -> Z {
+        Z::from(self)
+    }
+
+    /// Returns the number of bits required to represent the value of the [`Modulus`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::Modulus;
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = Modulus::from_str("17").unwrap();
+    ///
+    /// assert_eq!(5, modulus.bit_size());
+    /// ```
+    pub fn bit_size(&self) -> u64 {
+        unsafe { fmpz_bits(&self.get_fmpz_mod_ctx_struct().n[0]) }
+    }
+
     /// Returns the [`fmpz_mod_ctx_struct`] of a modulus and is only used internally.
     pub(crate) fn get_fmpz_mod_ctx_struct(&self) -> &fmpz_mod_ctx_struct {
         self.modulus.as_ref()
     }
 }
+
+#[cfg(test)]
+mod test_to_z {
+    use super::Modulus;
+    use crate::integer::Z;
+    use std::str::FromStr;
+
+    /// Ensure that the value is returned for small and large moduli
+    #[test]
+    fn correct_value() {
+        let small = Modulus::from_str("1").unwrap();
+        let large = Modulus::from_str(&"1".repeat(65)).unwrap();
+
+        assert_eq!(Z::ONE, small.to_z());
+        assert_eq!(Z::from_str(&"1".repeat(65)).unwrap(), large.to_z());
+    }
+}
+
+#[cfg(test)]
+mod test_bit_size {
+    use super::Modulus;
+    use std::str::FromStr;
+
+    /// Ensure that the bit size is correct for small and large moduli
+    #[test]
+    fn correct_size() {
+        assert_eq!(1, Modulus::from_str("1").unwrap().bit_size());
+        assert_eq!(8, Modulus::from_str("255").unwrap().bit_size());
+        assert_eq!(9, Modulus::from_str("256").unwrap().bit_size());
+        assert_eq!(
+            64,
+            Modulus::from_str(&u64::MAX.to_string()).unwrap().bit_size()
+        );
+        assert_eq!(
+            128,
+            Modulus::from_str(&u128::MAX.to_string())
+                .unwrap()
+                .bit_size()
+        );
+    }
+}
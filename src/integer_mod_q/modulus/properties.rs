@@ -10,7 +10,8 @@
 //! This includes checks such as primeness.
 
 use super::Modulus;
-use flint_sys::fmpz::fmpz_is_prime;
+use crate::integer::Z;
+use flint_sys::fmpz::{fmpz_is_perfect_power, fmpz_is_prime};
 
 impl Modulus {
     /// Checks if a [`Modulus`] is prime.
@@ -27,6 +28,47 @@ impl Modulus {
     pub fn is_prime(&self) -> bool {
         1 == unsafe { fmpz_is_prime(&self.get_fmpz_mod_ctx_struct().n[0]) }
     }
+
+    /// Checks if a [`Modulus`] is a prime power, i.e. of the form `p^k`
+    /// for a prime `p` and a positive integer `k`.
+    ///
+    /// Returns true if the modulus is a prime power.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use qfall_math::integer_mod_q::Modulus;
+    ///
+    /// let modulus = Modulus::from_str("343").unwrap();
+    /// assert!(modulus.is_prime_power())
+    /// ```
+    pub fn is_prime_power(&self) -> bool {
+        self.get_prime_power_decomposition().is_some()
+    }
+
+    /// Decomposes the [`Modulus`] into a prime `p` and an exponent `k`
+    /// s.t. the modulus is equal to `p^k`.
+    ///
+    /// Returns `Some((p, k))` if the modulus is a prime power and `None` otherwise.
+    pub(crate) fn get_prime_power_decomposition(&self) -> Option<(Z, u64)> {
+        let mut base = Z::from_fmpz(&self.get_fmpz_mod_ctx_struct().n[0]);
+        let mut exponent = 1;
+        if base < Z::from(2) {
+            return None;
+        }
+
+        // `fmpz_is_perfect_power` does not necessarily return the largest exponent,
+        // hence the decomposition is repeated until the base is prime
+        while 1 != unsafe { fmpz_is_prime(&base.value) } {
+            let mut root = Z::default();
+            let k = unsafe { fmpz_is_perfect_power(&mut root.value, &base.value) };
+            if k <= 1 {
+                return None;
+            }
+            base = root;
+            exponent *= k as u64;
+        }
+        Some((base, exponent))
+    }
 }
 
 #[cfg(test)]
@@ -49,3 +91,72 @@ mod test_is_prime {
         assert!(!modulus.is_prime())
     }
 }
+
+#[cfg(test)]
+mod test_is_prime_power {
+    use crate::integer_mod_q::Modulus;
+    use std::str::FromStr;
+
+    /// ensure that `true` is returned for primes and prime powers
+    #[test]
+    fn modulus_is_prime_power() {
+        for value in ["2", "17", "1024", "343", &format!("{}", 3_u64.pow(40))] {
+            let modulus = Modulus::from_str(value).unwrap();
+            assert!(modulus.is_prime_power())
+        }
+    }
+
+    /// ensure that `false` is returned for moduli that are no prime powers
+    #[test]
+    fn modulus_is_not_prime_power() {
+        for value in ["1", "6", "1000", "18446744073709551615"] {
+            let modulus = Modulus::from_str(value).unwrap();
+            assert!(!modulus.is_prime_power())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_get_prime_power_decomposition {
+    use crate::{integer::Z, integer_mod_q::Modulus};
+    use std::str::FromStr;
+
+    /// ensure that primes and prime powers are decomposed correctly
+    #[test]
+    fn prime_powers() {
+        let prime = Modulus::from_str("17").unwrap();
+        let power = Modulus::from_str(&format!("{}", 2_i32.pow(16))).unwrap();
+        let large = Modulus::from_str("340282366920938463463374607431768211507").unwrap();
+        let large_power = Modulus::from_str(&format!("{}", 3_u64.pow(40))).unwrap();
+
+        assert_eq!(
+            Some((Z::from(17), 1)),
+            prime.get_prime_power_decomposition()
+        );
+        assert_eq!(
+            Some((Z::from(2), 16)),
+            power.get_prime_power_decomposition()
+        );
+        assert_eq!(
+            Some((
+                Z::from_str("340282366920938463463374607431768211507").unwrap(),
+                1
+            )),
+            large.get_prime_power_decomposition()
+        );
+        assert_eq!(
+            Some((Z::from(3), 40)),
+            large_power.get_prime_power_decomposition()
+        );
+    }
+
+    /// ensure that `None` is returned for moduli that are no prime powers
+    #[test]
+    fn no_prime_powers() {
+        for value in ["1", "6", "36", "1000", "18446744073709551615"] {
+            let modulus = Modulus::from_str(value).unwrap();
+
+            assert!(modulus.get_prime_power_decomposition().is_none());
+        }
+    }
+}
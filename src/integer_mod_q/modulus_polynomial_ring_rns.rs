@@ -0,0 +1,46 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! [`ModulusPolynomialRingRns`] is the context object for
+//! [`PolynomialRingRns`](super::PolynomialRingRns), i.e. the ring
+//! `Z_q[X]/f(X)` for a modulus `q` that is a product of distinct primes.
+//! The ring is represented in the residue number system (RNS), i.e. as the
+//! product of the rings `Z_{q_i}[X]/f(X)` for each prime `q_i`.
+
+use super::ModulusPolynomialRingZq;
+use crate::integer::PolyOverZ;
+
+mod from;
+mod get;
+
+/// [`ModulusPolynomialRingRns`] represents the modulus object for
+/// [`PolynomialRingRns`](crate::integer_mod_q::PolynomialRingRns),
+/// where `q = q_1 * ... * q_k` is the product of distinct primes.
+/// If every `q_i` is NTT-friendly for f(X), the NTT is used for multiplications
+/// in each of the rings `Z_{q_i}[X]/f(X)`.
+///
+/// Attributes
+/// - `poly`: holds the modulus polynomial f(X) over the integers
+/// - `moduli`: holds the modulus objects f(X) mod `q_i` for each prime `q_i`
+///
+/// # Example
+/// ```
+/// use qfall_math::integer::PolyOverZ;
+/// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns};
+/// use std::str::FromStr;
+///
+/// let poly = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+/// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+///
+/// let modulus = ModulusPolynomialRingRns::new(&poly, &primes).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModulusPolynomialRingRns {
+    poly: PolyOverZ,
+    moduli: Vec<ModulusPolynomialRingZq>,
+}
@@ -0,0 +1,144 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementations to create a [`ModulusPolynomialRingRns`] value from other types.
+//!
+//! The explicit functions contain the documentation.
+
+use super::ModulusPolynomialRingRns;
+use crate::{
+    error::MathError,
+    integer::PolyOverZ,
+    integer_mod_q::{Modulus, ModulusPolynomialRingZq, PolyOverZq},
+};
+
+impl ModulusPolynomialRingRns {
+    /// Creates the modulus object for the ring `Z_q[X]/f(X)`,
+    /// where `q` is the product of the given primes.
+    ///
+    /// Parameters:
+    /// - `poly`: the modulus polynomial f(X)
+    /// - `primes`: the distinct primes, whose product is `q`
+    ///
+    /// Returns the new modulus object or an error if `primes` is empty
+    /// or contains a non-prime or duplicate modulus.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    ///
+    /// let modulus = ModulusPolynomialRingRns::new(&poly, &primes).unwrap();
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if no prime is provided.
+    /// - Returns a [`MathError`] of type [`NotPrime`](MathError::NotPrime)
+    /// if one of the moduli is not prime.
+    /// - Returns a [`MathError`] of type [`NotCoprime`](MathError::NotCoprime)
+    /// if a prime is provided more than once.
+    pub fn new(poly: &PolyOverZ, primes: &[Modulus]) -> Result<Self, MathError> {
+        if primes.is_empty() {
+            return Err(MathError::OutOfBounds(
+                "contain at least one prime".to_owned(),
+                "an empty slice".to_owned(),
+            ));
+        }
+        for (i, prime) in primes.iter().enumerate() {
+            if primes[..i].contains(prime) {
+                return Err(MathError::NotCoprime(format!(
+                    "The prime {} is provided more than once.",
+                    prime
+                )));
+            }
+        }
+
+        let moduli = primes
+            .iter()
+            .map(|prime| {
+                ModulusPolynomialRingZq::try_from(&PolyOverZq::from_poly_over_z(poly, prime))
+            })
+            .collect::<Result<Vec<_>, MathError>>()?;
+        Ok(Self {
+            poly: poly.clone(),
+            moduli,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_new {
+    use crate::{
+        error::MathError,
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingRns},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that the modulus is created for distinct primes
+    #[test]
+    fn distinct_primes() {
+        let poly = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+        let primes = [
+            Modulus::from_str("17").unwrap(),
+            Modulus::from_str("12289").unwrap(),
+            Modulus::from_str(&(u64::MAX - 58).to_string()).unwrap(),
+        ];
+
+        let modulus = ModulusPolynomialRingRns::new(&poly, &primes).unwrap();
+
+        assert_eq!(3, modulus.get_moduli().len());
+    }
+
+    /// Ensure that an error is returned for an empty list of primes
+    #[test]
+    fn empty() {
+        let poly = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+
+        assert!(matches!(
+            ModulusPolynomialRingRns::new(&poly, &[]),
+            Err(MathError::OutOfBounds(_, _))
+        ));
+    }
+
+    /// Ensure that an error is returned for non-prime moduli
+    #[test]
+    fn not_prime() {
+        let poly = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+        let primes = [
+            Modulus::from_str("17").unwrap(),
+            Modulus::from_str("15").unwrap(),
+        ];
+
+        assert!(matches!(
+            ModulusPolynomialRingRns::new(&poly, &primes),
+            Err(MathError::NotPrime(_))
+        ));
+    }
+
+    /// Ensure that an error is returned for duplicate primes
+    #[test]
+    fn duplicate() {
+        let poly = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+        let primes = [
+            Modulus::from_str("17").unwrap(),
+            Modulus::from_str("41").unwrap(),
+            Modulus::from_str("17").unwrap(),
+        ];
+
+        assert!(matches!(
+            ModulusPolynomialRingRns::new(&poly, &primes),
+            Err(MathError::NotCoprime(_))
+        ));
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementations to get information about a [`ModulusPolynomialRingRns`].
+
+use super::ModulusPolynomialRingRns;
+use crate::{
+    integer::{PolyOverZ, Z},
+    integer_mod_q::ModulusPolynomialRingZq,
+};
+
+impl ModulusPolynomialRingRns {
+    /// Returns the modulus `q` of the coefficients, i.e. the product of all primes.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly, &primes).unwrap();
+    ///
+    /// assert_eq!(Z::from(697), modulus.get_q());
+    /// ```
+    pub fn get_q(&self) -> Z {
+        self.moduli
+            .iter()
+            .fold(Z::ONE, |q, modulus| q * modulus.get_q())
+    }
+
+    /// Returns the degree of the modulus polynomial f(X).
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly, &primes).unwrap();
+    ///
+    /// assert_eq!(4, modulus.get_degree());
+    /// ```
+    pub fn get_degree(&self) -> i64 {
+        self.moduli[0].get_degree()
+    }
+
+    /// Returns the modulus polynomial f(X) over the integers.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly, &primes).unwrap();
+    ///
+    /// assert_eq!(&poly, modulus.get_poly());
+    /// ```
+    pub fn get_poly(&self) -> &PolyOverZ {
+        &self.poly
+    }
+
+    /// Returns the modulus objects f(X) mod `q_i` for each prime `q_i`
+    /// in the order in which the primes were provided.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, ModulusPolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly, &primes).unwrap();
+    ///
+    /// let moduli = modulus.get_moduli();
+    ///
+    /// assert_eq!(ModulusPolynomialRingZq::from_str("5  1 0 0 0 1 mod 41").unwrap(), moduli[1]);
+    /// ```
+    pub fn get_moduli(&self) -> &[ModulusPolynomialRingZq] {
+        &self.moduli
+    }
+}
+
+#[cfg(test)]
+mod test_get_q {
+    use crate::{
+        integer::{PolyOverZ, Z},
+        integer_mod_q::{Modulus, ModulusPolynomialRingRns},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that the product of large primes is computed correctly
+    #[test]
+    fn large_primes() {
+        let poly = PolyOverZ::from_str("3  1 0 1").unwrap();
+        let primes = [
+            Modulus::from_str(&(u64::MAX - 58).to_string()).unwrap(),
+            Modulus::from_str("4611686018427322369").unwrap(),
+        ];
+        let modulus = ModulusPolynomialRingRns::new(&poly, &primes).unwrap();
+
+        assert_eq!(
+            Z::from(u64::MAX - 58) * Z::from(4611686018427322369_u64),
+            modulus.get_q()
+        );
+    }
+}
@@ -11,11 +11,13 @@
 //! This implementation uses the [FLINT](https://flintlib.org/) library.
 
 use flint_sys::fq::fq_ctx_struct;
+use ntt::NttTables;
 use std::rc::Rc;
 
 mod cmp;
 mod from;
 mod get;
+mod ntt;
 mod ownership;
 mod serialize;
 mod to_string;
@@ -26,6 +28,8 @@ mod to_string;
 /// Attributes
 /// - `modulus`: holds the specific content, i.e. the prime `q` and f(X); it
 /// holds [FLINT](https://flintlib.org/)'s [struct](fq_ctx_struct)
+/// - `ntt`: holds the precomputed twiddle factors of the NTT if the
+/// modulus is of the form `X^n + 1` and supports an NTT of length `n`
 ///
 /// # Example
 /// ```
@@ -40,4 +44,5 @@ mod to_string;
 #[derive(Debug)]
 pub struct ModulusPolynomialRingZq {
     modulus: Rc<fq_ctx_struct>,
+    ntt: Rc<Option<NttTables>>,
 }
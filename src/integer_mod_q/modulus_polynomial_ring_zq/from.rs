@@ -12,10 +12,121 @@
 //!
 //! The explicit functions contain the documentation.
 
-use super::ModulusPolynomialRingZq;
-use crate::{error::MathError, integer_mod_q::PolyOverZq};
-use flint_sys::fq::fq_ctx_init_modulus;
-use std::{ffi::CString, mem::MaybeUninit, rc::Rc, str::FromStr};
+use super::{ModulusPolynomialRingZq, NttTables};
+use crate::{
+    error::MathError,
+    integer::PolyOverZ,
+    integer_mod_q::{Modulus, PolyOverZq},
+    traits::SetCoefficient,
+    utils::{context_cache::ContextCache, index::evaluate_index},
+};
+use flint_sys::{
+    fmpz_poly::fmpz_poly_cyclotomic,
+    fq::{fq_ctx_init_modulus, fq_ctx_struct},
+};
+use std::{ffi::CString, fmt::Display, mem::MaybeUninit, str::FromStr};
+
+thread_local! {
+    /// Stores the contexts of all polynomial moduli that are in use in this thread
+    /// s.t. moduli with the same polynomial share their context.
+    static MODULUS_POLYNOMIAL_CACHE: ContextCache<fq_ctx_struct> = ContextCache::new();
+
+    /// Stores the NTT tables of all polynomial moduli that are in use in this thread
+    /// and support the NTT s.t. the twiddle factors are only computed once.
+    static NTT_TABLES_CACHE: ContextCache<Option<NttTables>> = ContextCache::new();
+}
+
+impl ModulusPolynomialRingZq {
+    /// Creates the modulus `X^n + 1 mod q` for a power of two `n`,
+    /// i.e. the `2n`-th cyclotomic polynomial, which is the most common
+    /// modulus for ring-based lattice cryptography.
+    ///
+    /// Parameters:
+    /// - `n`: the degree of the modulus polynomial, which has to be a power of two
+    /// - `modulus`: the prime modulus `q` of the coefficients
+    ///
+    /// Returns the modulus object for `X^n + 1 mod q` or an error if `n`
+    /// is not a power of two or `q` is not prime.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = Modulus::from_str("12289").unwrap();
+    ///
+    /// let poly_mod = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(1024, &modulus).unwrap();
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// `n` is not a power of two or does not fit into an [`i64`].
+    /// - Returns a [`MathError`] of type [`NotPrime`](MathError::NotPrime)
+    /// if `modulus` is not prime.
+    pub fn new_power_of_two_cyclotomic(
+        n: impl TryInto<i64> + Display + Copy,
+        modulus: &Modulus,
+    ) -> Result<Self, MathError> {
+        let n = evaluate_index(n)?;
+        if n < 1 || n & (n - 1) != 0 {
+            return Err(MathError::OutOfBounds(
+                "be a power of two".to_owned(),
+                n.to_string(),
+            ));
+        }
+
+        let mut poly = PolyOverZq::from(modulus);
+        poly.set_coeff(0, 1)?;
+        poly.set_coeff(n, 1)?;
+        Self::try_from(&poly)
+    }
+
+    /// Creates the modulus `Phi_m(X) mod q`, where `Phi_m` is
+    /// the `m`-th cyclotomic polynomial, i.e. the minimal polynomial
+    /// of a primitive `m`-th root of unity over the rationals.
+    ///
+    /// Parameters:
+    /// - `m`: the index of the cyclotomic polynomial
+    /// - `modulus`: the prime modulus `q` of the coefficients
+    ///
+    /// Returns the modulus object for `Phi_m(X) mod q` or an error if `m`
+    /// is smaller than `1` or `q` is not prime.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = Modulus::from_str("17").unwrap();
+    ///
+    /// // Phi_3(X) = X^2 + X + 1
+    /// let poly_mod = ModulusPolynomialRingZq::new_cyclotomic(3, &modulus).unwrap();
+    ///
+    /// assert_eq!(ModulusPolynomialRingZq::from_str("3  1 1 1 mod 17").unwrap(), poly_mod);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// `m` is smaller than `1` or does not fit into an [`i64`].
+    /// - Returns a [`MathError`] of type [`NotPrime`](MathError::NotPrime)
+    /// if `modulus` is not prime.
+    pub fn new_cyclotomic(
+        m: impl TryInto<i64> + Display + Copy,
+        modulus: &Modulus,
+    ) -> Result<Self, MathError> {
+        let m = evaluate_index(m)?;
+        if m < 1 {
+            return Err(MathError::OutOfBounds(
+                "be at least 1".to_owned(),
+                m.to_string(),
+            ));
+        }
+
+        let mut poly = PolyOverZ::default();
+        unsafe { fmpz_poly_cyclotomic(&mut poly.poly, m as u64) };
+        Self::try_from(&PolyOverZq::from_poly_over_z(&poly, modulus))
+    }
+}
 
 impl TryFrom<&PolyOverZq> for ModulusPolynomialRingZq {
     type Error = MathError;
@@ -47,19 +158,26 @@ impl TryFrom<&PolyOverZq> for ModulusPolynomialRingZq {
         if !modulus_poly.modulus.is_prime() {
             return Err(MathError::NotPrime(modulus_poly.modulus.to_string()));
         }
-        let mut modulus = MaybeUninit::uninit();
-        let c_string = CString::new("X").unwrap();
-        unsafe {
-            fq_ctx_init_modulus(
-                modulus.as_mut_ptr(),
-                &modulus_poly.poly,
-                modulus_poly.modulus.get_fmpz_mod_ctx_struct(),
-                c_string.as_ptr(),
-            );
-            Ok(Self {
-                modulus: Rc::new(modulus.assume_init()),
+        let key = modulus_poly.to_string().into_bytes();
+        let ntt = NTT_TABLES_CACHE.with(|cache| {
+            cache.get_or_try_init::<MathError>(key.clone(), || Ok(NttTables::new(modulus_poly)))
+        })?;
+        let modulus = MODULUS_POLYNOMIAL_CACHE.with(|cache| {
+            cache.get_or_try_init::<MathError>(key, || {
+                let mut modulus = MaybeUninit::uninit();
+                let c_string = CString::new("X").unwrap();
+                unsafe {
+                    fq_ctx_init_modulus(
+                        modulus.as_mut_ptr(),
+                        &modulus_poly.poly,
+                        modulus_poly.modulus.get_fmpz_mod_ctx_struct(),
+                        c_string.as_ptr(),
+                    );
+                    Ok(modulus.assume_init())
+                }
             })
-        }
+        })?;
+        Ok(Self { modulus, ntt })
     }
 }
 
@@ -95,6 +213,136 @@ impl FromStr for ModulusPolynomialRingZq {
     }
 }
 
+#[cfg(test)]
+mod test_cached_ctx {
+    use crate::integer_mod_q::ModulusPolynomialRingZq;
+    use std::{rc::Rc, str::FromStr};
+
+    /// Ensure that moduli with the same polynomial share their context
+    #[test]
+    fn shared_context() {
+        let a = ModulusPolynomialRingZq::from_str("3  1 0 1 mod 17").unwrap();
+        let b = ModulusPolynomialRingZq::from_str("3  18 0 1 mod 17").unwrap();
+        let c = ModulusPolynomialRingZq::from_str("3  1 0 1 mod 19").unwrap();
+
+        assert!(Rc::ptr_eq(&a.modulus, &b.modulus));
+        assert!(!Rc::ptr_eq(&a.modulus, &c.modulus));
+    }
+}
+
+#[cfg(test)]
+mod test_new_power_of_two_cyclotomic {
+    use crate::{
+        error::MathError,
+        integer_mod_q::{Modulus, ModulusPolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that `X^n + 1` is constructed for small and large degrees
+    #[test]
+    fn correct_polynomial() {
+        let modulus = Modulus::from_str("12289").unwrap();
+
+        let poly_mod_1 = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(1, &modulus).unwrap();
+        let poly_mod_4 = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(4, &modulus).unwrap();
+        let poly_mod_1024 =
+            ModulusPolynomialRingZq::new_power_of_two_cyclotomic(1024, &modulus).unwrap();
+
+        assert_eq!(
+            ModulusPolynomialRingZq::from_str("2  1 1 mod 12289").unwrap(),
+            poly_mod_1
+        );
+        assert_eq!(
+            ModulusPolynomialRingZq::from_str("5  1 0 0 0 1 mod 12289").unwrap(),
+            poly_mod_4
+        );
+        assert_eq!(
+            ModulusPolynomialRingZq::from_str(&format!("1025  1{} 1 mod 12289", " 0".repeat(1023)))
+                .unwrap(),
+            poly_mod_1024
+        );
+    }
+
+    /// Ensure that an error is returned if `n` is not a power of two
+    #[test]
+    fn not_power_of_two() {
+        let modulus = Modulus::from_str("17").unwrap();
+
+        for n in [-4, 0, 3, 12] {
+            assert!(matches!(
+                ModulusPolynomialRingZq::new_power_of_two_cyclotomic(n, &modulus),
+                Err(MathError::OutOfBounds(_, _))
+            ));
+        }
+    }
+
+    /// Ensure that an error is returned for composite moduli
+    #[test]
+    fn not_prime() {
+        let modulus = Modulus::from_str("16").unwrap();
+
+        assert!(matches!(
+            ModulusPolynomialRingZq::new_power_of_two_cyclotomic(4, &modulus),
+            Err(MathError::NotPrime(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_new_cyclotomic {
+    use crate::{
+        error::MathError,
+        integer_mod_q::{Modulus, ModulusPolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that the correct cyclotomic polynomials are constructed
+    #[test]
+    fn correct_polynomial() {
+        let modulus = Modulus::from_str("17").unwrap();
+
+        assert_eq!(
+            ModulusPolynomialRingZq::from_str("2  -1 1 mod 17").unwrap(),
+            ModulusPolynomialRingZq::new_cyclotomic(1, &modulus).unwrap()
+        );
+        assert_eq!(
+            ModulusPolynomialRingZq::from_str("5  1 1 1 1 1 mod 17").unwrap(),
+            ModulusPolynomialRingZq::new_cyclotomic(5, &modulus).unwrap()
+        );
+        assert_eq!(
+            ModulusPolynomialRingZq::from_str("3  1 -1 1 mod 17").unwrap(),
+            ModulusPolynomialRingZq::new_cyclotomic(6, &modulus).unwrap()
+        );
+    }
+
+    /// Ensure that powers of two match [`ModulusPolynomialRingZq::new_power_of_two_cyclotomic`]
+    #[test]
+    fn power_of_two() {
+        let modulus = Modulus::from_str("12289").unwrap();
+
+        assert_eq!(
+            ModulusPolynomialRingZq::new_power_of_two_cyclotomic(512, &modulus).unwrap(),
+            ModulusPolynomialRingZq::new_cyclotomic(1024, &modulus).unwrap()
+        );
+    }
+
+    /// Ensure that errors are returned for invalid indices and composite moduli
+    #[test]
+    fn invalid_parameters() {
+        let prime = Modulus::from_str("17").unwrap();
+        let composite = Modulus::from_str("16").unwrap();
+
+        assert!(matches!(
+            ModulusPolynomialRingZq::new_cyclotomic(0, &prime),
+            Err(MathError::OutOfBounds(_, _))
+        ));
+        assert!(matches!(
+            ModulusPolynomialRingZq::new_cyclotomic(3, &composite),
+            Err(MathError::NotPrime(_))
+        ));
+    }
+}
+
 /// most tests with specific values are covered in [`PolyOverZq`](crate::integer_mod_q::PolyOverZq)
 /// since the format is reused, we omit some tests
 #[cfg(test)]
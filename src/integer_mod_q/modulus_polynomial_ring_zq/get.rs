@@ -9,12 +9,85 @@
 //! Implementations to get content of a
 //! [`ModulusPolynomialRingZq].
 
-use super::ModulusPolynomialRingZq;
+use super::{ModulusPolynomialRingZq, NttTables};
+use crate::integer::Z;
 use flint_sys::fq::fq_ctx_struct;
 
 impl ModulusPolynomialRingZq {
+    /// Returns the degree `n` of the modulus polynomial `f(X)`,
+    /// i.e. the dimension of the ring `Z_q[X]/f(X)` as a module over `Z_q`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::ModulusPolynomialRingZq;
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    ///
+    /// assert_eq!(3, modulus.get_degree());
+    /// ```
+    pub fn get_degree(&self) -> i64 {
+        self.get_fq_ctx_struct().modulus[0].length - 1
+    }
+
+    /// Returns the prime modulus `q` of the coefficients as a [`Z`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::Z;
+    /// use qfall_math::integer_mod_q::ModulusPolynomialRingZq;
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    ///
+    /// assert_eq!(Z::from(17), modulus.get_q());
+    /// ```
+    pub fn get_q(&self) -> Z {
+        Z::from_fmpz(&self.get_fq_ctx_struct().ctxp[0].n[0])
+    }
+
     /// Returns the [`fq_ctx_struct`] of a modulus and is only used internally.
     pub(crate) fn get_fq_ctx_struct(&self) -> &fq_ctx_struct {
         self.modulus.as_ref()
     }
+
+    /// Returns the precomputed [`NttTables`] of a modulus if it supports the NTT
+    /// and is only used internally.
+    pub(crate) fn get_ntt_tables(&self) -> Option<&NttTables> {
+        self.ntt.as_ref().as_ref()
+    }
+}
+
+#[cfg(test)]
+mod test_get_degree {
+    use super::ModulusPolynomialRingZq;
+    use std::str::FromStr;
+
+    /// Ensure that leading coefficients reduced to `0` do not count towards the degree
+    #[test]
+    fn degree() {
+        let modulus_1 = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let modulus_2 = ModulusPolynomialRingZq::from_str("5  1 0 1 1 17 mod 17").unwrap();
+
+        assert_eq!(3, modulus_1.get_degree());
+        assert_eq!(3, modulus_2.get_degree());
+    }
+}
+
+#[cfg(test)]
+mod test_get_q {
+    use super::ModulusPolynomialRingZq;
+    use crate::integer::Z;
+    use std::str::FromStr;
+
+    /// Ensure that small and large prime moduli are returned
+    #[test]
+    fn q() {
+        let modulus_1 = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let modulus_2 =
+            ModulusPolynomialRingZq::from_str(&format!("3  1 0 1 mod {}", u64::MAX - 58)).unwrap();
+
+        assert_eq!(Z::from(17), modulus_1.get_q());
+        assert_eq!(Z::from(u64::MAX - 58), modulus_2.get_q());
+    }
 }
@@ -0,0 +1,329 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the precomputed tables of the negacyclic
+//! number theoretic transform (NTT), which is used to multiply
+//! elements of `Z_q[X]/(X^n + 1)` in `O(n log n)`.
+//!
+//! The NTT is only supported if `n` is a power of two, `q` is a prime
+//! with `q = 1 mod 2n` and `q < 2^62`, s.t. all intermediate values fit into an [`u64`].
+
+use crate::{
+    integer::{PolyOverZ, Z},
+    integer_mod_q::{PolyOverZq, Zq},
+    traits::SetCoefficient,
+};
+use flint_sys::{
+    fmpz::{fmpz_bits, fmpz_get_ui},
+    fmpz_poly::{fmpz_poly_get_coeff_ui, fmpz_poly_set_coeff_ui},
+};
+
+/// The largest supported modulus is smaller than `2^62`,
+/// s.t. the sum of two reduced values does not overflow.
+const MAX_MODULUS_BITS: u64 = 62;
+
+/// [`NttTables`] stores the twiddle factors of the negacyclic NTT
+/// of length `n` modulo `q`.
+///
+/// Attributes:
+/// - `modulus`: the prime `q`
+/// - `psi_powers`: the powers of a primitive `2n`-th root of unity `psi`
+/// in bit-reversed order
+/// - `psi_inv_powers`: the powers of `psi^(-1)` in bit-reversed order
+/// - `n_inv`: the inverse of `n` modulo `q`
+#[derive(Debug)]
+pub(crate) struct NttTables {
+    modulus: u64,
+    psi_powers: Vec<u64>,
+    psi_inv_powers: Vec<u64>,
+    n_inv: u64,
+}
+
+impl NttTables {
+    /// Precomputes the twiddle factors for the modulus polynomial `modulus_poly`.
+    ///
+    /// Parameters:
+    /// - `modulus_poly`: the modulus polynomial of the ring
+    ///
+    /// Returns the [`NttTables`] if `modulus_poly` is of the form `X^n + 1`
+    /// and the NTT is supported for `n` and `q`, otherwise [`None`].
+    pub(crate) fn new(modulus_poly: &PolyOverZq) -> Option<Self> {
+        let n = modulus_poly.get_degree();
+        if n < 1 || n & (n - 1) != 0 {
+            return None;
+        }
+        let mut power_of_two_cyclotomic = PolyOverZq::from(&modulus_poly.modulus);
+        power_of_two_cyclotomic.set_coeff(0, 1).unwrap();
+        power_of_two_cyclotomic.set_coeff(n, 1).unwrap();
+        if modulus_poly != &power_of_two_cyclotomic {
+            return None;
+        }
+
+        let q = Z::from(&modulus_poly.modulus);
+        if unsafe { fmpz_bits(&q.value) } > MAX_MODULUS_BITS || !modulus_poly.modulus.is_prime() {
+            return None;
+        }
+        // a primitive `2n`-th root of unity exists iff `2n` divides `q - 1`
+        let psi = Zq::primitive_root_of_unity(2 * n, &modulus_poly.modulus).ok()?;
+        unsafe {
+            Some(Self::from_root(
+                n as usize,
+                fmpz_get_ui(&q.value),
+                fmpz_get_ui(&psi.value.value),
+            ))
+        }
+    }
+
+    /// Computes the tables from a primitive `2n`-th root of unity `psi` modulo `q`.
+    fn from_root(n: usize, q: u64, psi: u64) -> Self {
+        let psi_inv = pow_mod(psi, 2 * n as u64 - 1, q);
+        let log_n = n.trailing_zeros();
+
+        let mut psi_powers = vec![0; n];
+        let mut psi_inv_powers = vec![0; n];
+        let (mut power, mut power_inv) = (1, 1);
+        for i in 0..n {
+            let index = bit_reverse(i, log_n);
+            psi_powers[index] = power;
+            psi_inv_powers[index] = power_inv;
+            power = mul_mod(power, psi, q);
+            power_inv = mul_mod(power_inv, psi_inv, q);
+        }
+
+        Self {
+            modulus: q,
+            psi_powers,
+            psi_inv_powers,
+            n_inv: pow_mod(n as u64, q - 2, q),
+        }
+    }
+
+    /// Returns the length `n` of the transform.
+    fn len(&self) -> usize {
+        self.psi_powers.len()
+    }
+
+    /// Multiplies two polynomials in `Z_q[X]/(X^n + 1)`, whose coefficients
+    /// are reduced, i.e. lie in `[0, q)`.
+    ///
+    /// Parameters:
+    /// - `a`: the first factor
+    /// - `b`: the second factor
+    ///
+    /// Returns the reduced product of `a` and `b`.
+    pub(crate) fn multiply(&self, a: &PolyOverZ, b: &PolyOverZ) -> PolyOverZ {
+        let mut a = self.get_coefficients(a);
+        let mut b = self.get_coefficients(b);
+        self.forward(&mut a);
+        self.forward(&mut b);
+        self.pointwise(&mut a, &b);
+        self.inverse(&mut a);
+        to_poly(a)
+    }
+
+    /// Multiplies two polynomials in the evaluation domain of the NTT
+    /// entry by entry.
+    ///
+    /// Parameters:
+    /// - `a`: the first factor in the evaluation domain
+    /// - `b`: the second factor in the evaluation domain
+    ///
+    /// Returns the product of `a` and `b` in the evaluation domain.
+    pub(crate) fn multiply_pointwise(&self, a: &PolyOverZ, b: &PolyOverZ) -> PolyOverZ {
+        let mut a = self.get_coefficients(a);
+        self.pointwise(&mut a, &self.get_coefficients(b));
+        to_poly(a)
+    }
+
+    /// Transforms a polynomial with reduced coefficients into the evaluation domain.
+    ///
+    /// Parameters:
+    /// - `poly`: the polynomial in the coefficient domain
+    ///
+    /// Returns the evaluations of `poly` in bit-reversed order,
+    /// stored as the coefficients of a [`PolyOverZ`].
+    pub(crate) fn to_ntt_domain(&self, poly: &PolyOverZ) -> PolyOverZ {
+        let mut values = self.get_coefficients(poly);
+        self.forward(&mut values);
+        to_poly(values)
+    }
+
+    /// Transforms a polynomial from the evaluation domain back into the coefficient domain.
+    /// This is the inverse operation of [`NttTables::to_ntt_domain`].
+    ///
+    /// Parameters:
+    /// - `poly`: the evaluations in bit-reversed order
+    ///
+    /// Returns the polynomial with reduced coefficients.
+    pub(crate) fn to_coeff_domain(&self, poly: &PolyOverZ) -> PolyOverZ {
+        let mut values = self.get_coefficients(poly);
+        self.inverse(&mut values);
+        to_poly(values)
+    }
+
+    /// Multiplies `a` by `b` entry by entry in place.
+    fn pointwise(&self, a: &mut [u64], b: &[u64]) {
+        a.iter_mut()
+            .zip(b.iter())
+            .for_each(|(a_i, b_i)| *a_i = mul_mod(*a_i, *b_i, self.modulus));
+    }
+
+    /// Returns the first `n` coefficients of `poly` as [`u64`] values.
+    fn get_coefficients(&self, poly: &PolyOverZ) -> Vec<u64> {
+        (0..self.len())
+            .map(|i| unsafe { fmpz_poly_get_coeff_ui(&poly.poly, i as i64) })
+            .collect()
+    }
+
+    /// Computes the forward transform in place using Cooley-Tukey butterflies.
+    /// The output is in bit-reversed order.
+    fn forward(&self, values: &mut [u64]) {
+        let q = self.modulus;
+        let n = self.len();
+        let mut t = n;
+        let mut m = 1;
+        while m < n {
+            t /= 2;
+            for i in 0..m {
+                let start = 2 * i * t;
+                let psi = self.psi_powers[m + i];
+                for j in start..start + t {
+                    let u = values[j];
+                    let v = mul_mod(values[j + t], psi, q);
+                    values[j] = add_mod(u, v, q);
+                    values[j + t] = add_mod(u, q - v, q);
+                }
+            }
+            m *= 2;
+        }
+    }
+
+    /// Computes the inverse transform in place using Gentleman-Sande butterflies.
+    /// The input is expected in bit-reversed order.
+    fn inverse(&self, values: &mut [u64]) {
+        let q = self.modulus;
+        let mut t = 1;
+        let mut m = self.len();
+        while m > 1 {
+            let h = m / 2;
+            for i in 0..h {
+                let start = 2 * i * t;
+                let psi_inv = self.psi_inv_powers[h + i];
+                for j in start..start + t {
+                    let u = values[j];
+                    let v = values[j + t];
+                    values[j] = add_mod(u, v, q);
+                    values[j + t] = mul_mod(add_mod(u, q - v, q), psi_inv, q);
+                }
+            }
+            t *= 2;
+            m = h;
+        }
+        values
+            .iter_mut()
+            .for_each(|value| *value = mul_mod(*value, self.n_inv, q));
+    }
+}
+
+/// Returns the polynomial with coefficients `values` in ascending order.
+fn to_poly(values: Vec<u64>) -> PolyOverZ {
+    let mut out = PolyOverZ::default();
+    for (i, coeff) in values.into_iter().enumerate() {
+        unsafe { fmpz_poly_set_coeff_ui(&mut out.poly, i as i64, coeff) };
+    }
+    out
+}
+
+/// Reverses the lowest `bits` bits of `value`.
+fn bit_reverse(value: usize, bits: u32) -> usize {
+    if bits == 0 {
+        return 0;
+    }
+    value.reverse_bits() >> (usize::BITS - bits)
+}
+
+/// Computes `a + b mod q` for reduced `a` and `b`.
+fn add_mod(a: u64, b: u64, q: u64) -> u64 {
+    let sum = a + b;
+    if sum >= q {
+        sum - q
+    } else {
+        sum
+    }
+}
+
+/// Computes `a * b mod q`.
+fn mul_mod(a: u64, b: u64, q: u64) -> u64 {
+    ((a as u128 * b as u128) % q as u128) as u64
+}
+
+/// Computes `base^exponent mod q` by square-and-multiply.
+fn pow_mod(mut base: u64, mut exponent: u64, q: u64) -> u64 {
+    let mut out = 1 % q;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            out = mul_mod(out, base, q);
+        }
+        base = mul_mod(base, base, q);
+        exponent >>= 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod test_ntt_tables {
+    use super::NttTables;
+    use crate::{integer::PolyOverZ, integer_mod_q::PolyOverZq};
+    use std::str::FromStr;
+
+    /// Ensure that the tables are only computed for supported moduli
+    #[test]
+    fn supported() {
+        let supported = PolyOverZq::from_str("5  1 0 0 0 1 mod 17").unwrap();
+        let not_cyclotomic = PolyOverZq::from_str("5  2 0 0 0 1 mod 17").unwrap();
+        let not_power_of_two = PolyOverZq::from_str("4  1 0 0 1 mod 19").unwrap();
+        let no_root = PolyOverZq::from_str("5  1 0 0 0 1 mod 19").unwrap();
+        let too_large = PolyOverZq::from_str(&format!("3  1 0 1 mod {}", u64::MAX - 58)).unwrap();
+
+        assert!(NttTables::new(&supported).is_some());
+        assert!(NttTables::new(&not_cyclotomic).is_none());
+        assert!(NttTables::new(&not_power_of_two).is_none());
+        assert!(NttTables::new(&no_root).is_none());
+        assert!(NttTables::new(&too_large).is_none());
+    }
+
+    /// Ensure that the inverse transform undoes the forward transform
+    #[test]
+    fn round_trip() {
+        let modulus_poly =
+            PolyOverZq::from_str(&format!("17  1{} 1 mod 12289", " 0".repeat(15))).unwrap();
+        let tables = NttTables::new(&modulus_poly).unwrap();
+        let values: Vec<u64> = (0..16).map(|i| (i * 1009 + 7) % 12289).collect();
+
+        let mut transformed = values.clone();
+        tables.forward(&mut transformed);
+        assert_ne!(values, transformed);
+        tables.inverse(&mut transformed);
+
+        assert_eq!(values, transformed);
+    }
+
+    /// Ensure that the multiplication is negacyclic, i.e. `X^(n-1) * X = -1`
+    #[test]
+    fn negacyclic() {
+        let modulus_poly = PolyOverZq::from_str("9  1 0 0 0 0 0 0 0 1 mod 17").unwrap();
+        let tables = NttTables::new(&modulus_poly).unwrap();
+        let a = PolyOverZ::from_str("8  0 0 0 0 0 0 0 1").unwrap();
+        let b = PolyOverZ::from_str("2  0 1").unwrap();
+
+        assert_eq!(
+            PolyOverZ::from_str("1  16").unwrap(),
+            tables.multiply(&a, &b)
+        );
+    }
+}
@@ -15,11 +15,19 @@ use flint_sys::fmpz_mod_poly::fmpz_mod_poly_struct;
 
 mod arithmetic;
 mod cmp;
+mod compose;
+mod derivative;
 mod evaluate;
+mod factor;
 mod from;
+mod gcd;
 mod get;
+mod invert;
+mod lift;
 mod ownership;
 mod properties;
+mod roots;
+mod sample;
 mod serialize;
 mod set;
 mod to_string;
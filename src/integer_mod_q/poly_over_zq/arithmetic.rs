@@ -7,8 +7,9 @@
 // Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
 
 //! The arithmetic module contains implementations of simple arithmetics
-//! such as addition or subtraction.
+//! such as addition, subtraction, multiplication or division.
 
 mod add;
+mod div;
 mod mul;
 mod sub;
@@ -0,0 +1,273 @@
+// Copyright © 2023 Phil Milewski
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementation of the [`Div`] trait for [`PolyOverZq`] values
+//! and of the Euclidean division with remainder.
+
+use super::super::PolyOverZq;
+use crate::{
+    error::MathError,
+    integer::Z,
+    macros::arithmetics::{
+        arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
+    },
+};
+use flint_sys::{
+    fmpz::fmpz_is_one,
+    fmpz_mod_poly::{fmpz_mod_poly_divrem_f, fmpz_mod_poly_is_zero},
+};
+use std::ops::Div;
+
+impl Div for &PolyOverZq {
+    type Output = PolyOverZq;
+    /// Implements the [`Div`] trait for two [`PolyOverZq`] values.
+    /// [`Div`] is implemented for any combination of [`PolyOverZq`] and borrowed [`PolyOverZq`].
+    /// The quotient of [`PolyOverZq::div_rem`] is returned.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the polynomial `self` is divided by
+    ///
+    /// Returns the quotient of both polynomials as a [`PolyOverZq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::PolyOverZq;
+    /// use std::str::FromStr;
+    ///
+    /// let a: PolyOverZq = PolyOverZq::from_str("3  2 4 1 mod 7").unwrap();
+    /// let b: PolyOverZq = PolyOverZq::from_str("2  5 3 mod 7").unwrap();
+    ///
+    /// let c: PolyOverZq = &a / &b;
+    /// let d: PolyOverZq = a / b;
+    /// let e: PolyOverZq = &c / d;
+    /// let f: PolyOverZq = c / &e;
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if the moduli of both [`PolyOverZq`] mismatch, `other` is the
+    /// zero polynomial or the leading coefficient of `other` is not invertible.
+    fn div(self, other: Self) -> Self::Output {
+        self.div_rem(other).unwrap().0
+    }
+}
+
+arithmetic_trait_borrowed_to_owned!(Div, div, PolyOverZq, PolyOverZq, PolyOverZq);
+arithmetic_trait_mixed_borrowed_owned!(Div, div, PolyOverZq, PolyOverZq, PolyOverZq);
+
+impl PolyOverZq {
+    /// Divides `self` by `divisor` with remainder, i.e. computes `q` and `r`
+    /// s.t. `self = q * divisor + r` and the degree of `r` is smaller
+    /// than the degree of `divisor`.
+    /// This requires the leading coefficient of `divisor` to be invertible,
+    /// which is always the case for non-zero divisors if the modulus is prime.
+    ///
+    /// Parameters:
+    /// - `divisor`: specifies the polynomial `self` is divided by
+    ///
+    /// Returns the quotient and the remainder as a tuple of [`PolyOverZq`]
+    /// or an error if the division is not possible.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::PolyOverZq;
+    /// use std::str::FromStr;
+    ///
+    /// let a = PolyOverZq::from_str("3  1 0 1 mod 7").unwrap();
+    /// let b = PolyOverZq::from_str("2  1 2 mod 7").unwrap();
+    ///
+    /// let (quotient, remainder) = a.div_rem(&b).unwrap();
+    ///
+    /// assert_eq!(PolyOverZq::from_str("2  5 4 mod 7").unwrap(), quotient);
+    /// assert_eq!(PolyOverZq::from_str("1  3 mod 7").unwrap(), remainder);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the moduli of both [`PolyOverZq`] mismatch.
+    /// - Returns a [`MathError`] of type [`DivisionByZeroError`](MathError::DivisionByZeroError)
+    /// if `divisor` is the zero polynomial.
+    /// - Returns a [`MathError`] of type [`NotInvertible`](MathError::NotInvertible)
+    /// if the leading coefficient of `divisor` is not invertible.
+    pub fn div_rem(&self, divisor: &Self) -> Result<(Self, Self), MathError> {
+        if self.modulus != divisor.modulus {
+            return Err(MathError::MismatchingModulus(format!(
+                " Tried to divide polynomial with modulus '{}' by polynomial with modulus '{}'.
+            If the modulus should be ignored please convert into a PolyOverZ beforehand.",
+                self.modulus, divisor.modulus
+            )));
+        }
+        let ctx = self.modulus.get_fmpz_mod_ctx_struct();
+        if 1 == unsafe { fmpz_mod_poly_is_zero(&divisor.poly, ctx) } {
+            return Err(MathError::DivisionByZeroError(format!(
+                "tried to divide {} by zero",
+                self
+            )));
+        }
+
+        let mut factor = Z::default();
+        let mut quotient = PolyOverZq::from(&self.modulus);
+        let mut remainder = PolyOverZq::from(&self.modulus);
+        unsafe {
+            fmpz_mod_poly_divrem_f(
+                &mut factor.value,
+                &mut quotient.poly,
+                &mut remainder.poly,
+                &self.poly,
+                &divisor.poly,
+                ctx,
+            )
+        };
+
+        // `factor` is the gcd of the leading coefficient of `divisor` and the modulus
+        if 0 == unsafe { fmpz_is_one(&factor.value) } {
+            return Err(MathError::NotInvertible(format!(
+                "The leading coefficient of {} is not invertible as it shares the factor {} \
+                with the modulus.",
+                divisor, factor
+            )));
+        }
+        Ok((quotient, remainder))
+    }
+}
+
+#[cfg(test)]
+mod test_div {
+    use super::PolyOverZq;
+    use std::str::FromStr;
+
+    /// testing division for two [`PolyOverZq`]
+    #[test]
+    fn div() {
+        let a: PolyOverZq = PolyOverZq::from_str("3  6 0 1 mod 7").unwrap();
+        let b: PolyOverZq = PolyOverZq::from_str("2  1 1 mod 7").unwrap();
+        let c: PolyOverZq = a / b;
+        assert_eq!(c, PolyOverZq::from_str("2  6 1 mod 7").unwrap());
+    }
+
+    /// testing division for two borrowed [`PolyOverZq`]
+    #[test]
+    fn div_borrow() {
+        let a: PolyOverZq = PolyOverZq::from_str("3  6 0 1 mod 7").unwrap();
+        let b: PolyOverZq = PolyOverZq::from_str("2  1 1 mod 7").unwrap();
+        let c: PolyOverZq = &a / &b;
+        assert_eq!(c, PolyOverZq::from_str("2  6 1 mod 7").unwrap());
+    }
+
+    /// testing division for borrowed and owned [`PolyOverZq`] in both orders
+    #[test]
+    fn div_mixed_borrowed() {
+        let a: PolyOverZq = PolyOverZq::from_str("3  6 0 1 mod 7").unwrap();
+        let b: PolyOverZq = PolyOverZq::from_str("2  1 1 mod 7").unwrap();
+        let c: PolyOverZq = &a / b.clone();
+        let d: PolyOverZq = a / &b;
+        assert_eq!(c, PolyOverZq::from_str("2  6 1 mod 7").unwrap());
+        assert_eq!(c, d);
+    }
+
+    /// testing division with large coefficients and modulus
+    #[test]
+    fn div_large_numbers() {
+        let a: PolyOverZq =
+            PolyOverZq::from_str(&format!("3  {} 0 {} mod {}", i64::MAX, i64::MIN, u64::MAX))
+                .unwrap();
+        let b: PolyOverZq =
+            PolyOverZq::from_str(&format!("2  {} 2 mod {}", u64::MAX - 1, u64::MAX)).unwrap();
+        let c: PolyOverZq = &a * &b;
+        assert_eq!(a, c / b);
+    }
+
+    /// testing whether division by the zero polynomial panics
+    #[test]
+    #[should_panic]
+    fn div_by_zero() {
+        let a: PolyOverZq = PolyOverZq::from_str("3  1 0 1 mod 7").unwrap();
+        let _ = a / PolyOverZq::from_str("0 mod 7").unwrap();
+    }
+
+    /// testing whether division with mismatching moduli panics
+    #[test]
+    #[should_panic]
+    fn div_mismatching_modulus() {
+        let a: PolyOverZq = PolyOverZq::from_str("3  1 0 1 mod 7").unwrap();
+        let b: PolyOverZq = PolyOverZq::from_str("2  1 1 mod 11").unwrap();
+        let _ = a / b;
+    }
+}
+
+#[cfg(test)]
+mod test_div_rem {
+    use super::PolyOverZq;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that `self = q * divisor + r` holds for large coefficients
+    /// and the remainder has a smaller degree
+    #[test]
+    fn large_numbers() {
+        let a = PolyOverZq::from_str(&format!(
+            "5  {} 3 -7 0 {} mod {}",
+            u64::MAX - 2,
+            i64::MIN,
+            u64::MAX
+        ))
+        .unwrap();
+        let b = PolyOverZq::from_str(&format!("3  5 -2 {} mod {}", i64::MAX, u64::MAX)).unwrap();
+
+        let (quotient, remainder) = a.div_rem(&b).unwrap();
+
+        assert_eq!(a, &quotient * &b + &remainder);
+        assert!(remainder.lift().get_degree() < 2);
+    }
+
+    /// Ensure that division works for composite moduli if the leading
+    /// coefficient of the divisor is invertible
+    #[test]
+    fn composite_modulus() {
+        let a = PolyOverZq::from_str("4  1 2 3 4 mod 12").unwrap();
+        let b = PolyOverZq::from_str("2  4 5 mod 12").unwrap();
+
+        let (quotient, remainder) = a.div_rem(&b).unwrap();
+
+        assert_eq!(a, &quotient * &b + &remainder);
+        assert!(remainder.lift().get_degree() < 1);
+    }
+
+    /// Ensure that an error is returned if the leading coefficient
+    /// of the divisor is not invertible
+    #[test]
+    fn not_invertible() {
+        let a = PolyOverZq::from_str("4  1 2 3 4 mod 12").unwrap();
+        let b = PolyOverZq::from_str("2  1 4 mod 12").unwrap();
+
+        assert!(matches!(a.div_rem(&b), Err(MathError::NotInvertible(_))));
+    }
+
+    /// Ensure that an error is returned for the zero polynomial as divisor
+    #[test]
+    fn division_by_zero() {
+        let a = PolyOverZq::from_str("2  1 3 mod 7").unwrap();
+        let b = PolyOverZq::from_str("0 mod 7").unwrap();
+
+        assert!(matches!(
+            a.div_rem(&b),
+            Err(MathError::DivisionByZeroError(_))
+        ));
+    }
+
+    /// Ensure that an error is returned for mismatching moduli
+    #[test]
+    fn mismatching_modulus() {
+        let a = PolyOverZq::from_str("2  1 3 mod 7").unwrap();
+        let b = PolyOverZq::from_str("2  1 3 mod 11").unwrap();
+
+        assert!(matches!(
+            a.div_rem(&b),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+}
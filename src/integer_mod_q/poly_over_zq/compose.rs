@@ -0,0 +1,230 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the composition of [`PolyOverZq`] instances,
+//! also reduced modulo a third polynomial.
+
+use super::PolyOverZq;
+use crate::{error::MathError, integer::Z};
+use flint_sys::{
+    fmpz::{fmpz_gcd, fmpz_is_one},
+    fmpz_mod_poly::{
+        fmpz_mod_poly_compose, fmpz_mod_poly_compose_mod, fmpz_mod_poly_degree, fmpz_mod_poly_lead,
+    },
+};
+
+impl PolyOverZq {
+    /// Composes `self` with `inner`, i.e. computes `self(inner(X))`.
+    ///
+    /// Parameters:
+    /// - `inner`: specifies the polynomial that is inserted into `self`
+    ///
+    /// Returns the composition `self ∘ inner` as a [`PolyOverZq`] or an error
+    /// if the moduli mismatch.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::PolyOverZq;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZq::from_str("3  1 2 3 mod 5").unwrap();
+    /// let inner = PolyOverZq::from_str("2  1 1 mod 5").unwrap();
+    ///
+    /// let composition = poly.compose(&inner).unwrap();
+    ///
+    /// assert_eq!(PolyOverZq::from_str("3  1 3 3 mod 5").unwrap(), composition);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the moduli of both [`PolyOverZq`] mismatch.
+    pub fn compose(&self, inner: &Self) -> Result<Self, MathError> {
+        self.check_same_modulus(inner)?;
+
+        let mut out = PolyOverZq::from(&self.modulus);
+        unsafe {
+            fmpz_mod_poly_compose(
+                &mut out.poly,
+                &self.poly,
+                &inner.poly,
+                self.modulus.get_fmpz_mod_ctx_struct(),
+            )
+        };
+        Ok(out)
+    }
+
+    /// Composes `self` with `inner` modulo `modulus_poly`,
+    /// i.e. computes `self(inner(X)) mod modulus_poly`.
+    /// This is more efficient than reducing the result of [`PolyOverZq::compose`].
+    ///
+    /// Parameters:
+    /// - `inner`: specifies the polynomial that is inserted into `self`
+    /// - `modulus_poly`: specifies the polynomial by which the composition is reduced
+    ///
+    /// Returns the reduced composition as a [`PolyOverZq`] or an error
+    /// if the moduli mismatch or `modulus_poly` has no invertible leading coefficient.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::PolyOverZq;
+    /// use std::str::FromStr;
+    ///
+    /// // the automorphism X -> X^3 on Z_17[X]/(X^4 + 1)
+    /// let poly = PolyOverZq::from_str("4  1 2 3 4 mod 17").unwrap();
+    /// let inner = PolyOverZq::from_str("4  0 0 0 1 mod 17").unwrap();
+    /// let modulus_poly = PolyOverZq::from_str("5  1 0 0 0 1 mod 17").unwrap();
+    ///
+    /// let composition = poly.compose_mod(&inner, &modulus_poly).unwrap();
+    ///
+    /// assert_eq!(PolyOverZq::from_str("4  1 4 14 2 mod 17").unwrap(), composition);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the moduli of the [`PolyOverZq`] instances mismatch.
+    /// - Returns a [`MathError`] of type [`NotInvertible`](MathError::NotInvertible)
+    /// if the leading coefficient of `modulus_poly` is not invertible,
+    /// e.g. if `modulus_poly` is the zero polynomial.
+    pub fn compose_mod(&self, inner: &Self, modulus_poly: &Self) -> Result<Self, MathError> {
+        self.check_same_modulus(inner)?;
+        self.check_same_modulus(modulus_poly)?;
+        let ctx = self.modulus.get_fmpz_mod_ctx_struct();
+
+        let mut gcd = Z::default();
+        if unsafe { fmpz_mod_poly_degree(&modulus_poly.poly, ctx) } < 0 || {
+            unsafe {
+                fmpz_gcd(
+                    &mut gcd.value,
+                    fmpz_mod_poly_lead(&modulus_poly.poly, ctx),
+                    &ctx.n[0],
+                )
+            };
+            0 == unsafe { fmpz_is_one(&gcd.value) }
+        } {
+            return Err(MathError::NotInvertible(format!(
+                "The leading coefficient of {} is not invertible.",
+                modulus_poly
+            )));
+        }
+
+        let mut out = PolyOverZq::from(&self.modulus);
+        unsafe {
+            fmpz_mod_poly_compose_mod(
+                &mut out.poly,
+                &self.poly,
+                &inner.poly,
+                &modulus_poly.poly,
+                ctx,
+            )
+        };
+        Ok(out)
+    }
+
+    /// Checks whether `self` and `other` have the same modulus.
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the moduli of both [`PolyOverZq`] mismatch.
+    fn check_same_modulus(&self, other: &Self) -> Result<(), MathError> {
+        if self.modulus != other.modulus {
+            return Err(MathError::MismatchingModulus(format!(
+                " Tried to compose polynomial with modulus '{}' and polynomial with modulus '{}'.",
+                self.modulus, other.modulus
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_compose {
+    use super::PolyOverZq;
+    use crate::{error::MathError, integer::Z, traits::Evaluate};
+    use std::str::FromStr;
+
+    /// Ensure that the evaluation of the composition is the composition
+    /// of the evaluations for large moduli
+    #[test]
+    fn evaluation() {
+        let poly = PolyOverZq::from_str(&format!("3  {} -1 2 mod {}", i64::MAX, u64::MAX)).unwrap();
+        let inner = PolyOverZq::from_str(&format!("2  {} 3 mod {}", i64::MIN, u64::MAX)).unwrap();
+        let value = Z::from(-17);
+
+        let composition = poly.compose(&inner).unwrap();
+
+        assert_eq!(
+            poly.evaluate(&inner.evaluate(&value)),
+            composition.evaluate(&value)
+        );
+    }
+
+    /// Ensure that an error is returned for mismatching moduli
+    #[test]
+    fn mismatching_modulus() {
+        let poly = PolyOverZq::from_str("3  1 2 3 mod 5").unwrap();
+        let inner = PolyOverZq::from_str("2  1 1 mod 7").unwrap();
+
+        assert!(matches!(
+            poly.compose(&inner),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_compose_mod {
+    use super::PolyOverZq;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that the result equals the reduced composition
+    #[test]
+    fn reduced_composition() {
+        let poly =
+            PolyOverZq::from_str(&format!("5  {} 1 0 -3 7 mod {}", i64::MAX, u64::MAX)).unwrap();
+        let inner = PolyOverZq::from_str("6  0 0 0 0 0 1 mod 18446744073709551615").unwrap();
+        let modulus_poly = PolyOverZq::from_str("5  1 0 0 0 1 mod 18446744073709551615").unwrap();
+
+        let composition = poly.compose_mod(&inner, &modulus_poly).unwrap();
+
+        // X^5 = -X, X^10 = X^2, X^15 = -X^3 and X^20 = -1 mod X^4 + 1
+        let cmp =
+            PolyOverZq::from_str(&format!("4  {} -1 0 3 mod {}", i64::MAX - 7, u64::MAX)).unwrap();
+        assert_eq!(cmp, composition);
+    }
+
+    /// Ensure that an error is returned for moduli polynomials
+    /// without invertible leading coefficient
+    #[test]
+    fn not_invertible() {
+        let poly = PolyOverZq::from_str("3  1 2 3 mod 6").unwrap();
+        let inner = PolyOverZq::from_str("2  1 1 mod 6").unwrap();
+
+        for modulus_poly in ["3  1 0 2 mod 6", "0 mod 6"] {
+            let modulus_poly = PolyOverZq::from_str(modulus_poly).unwrap();
+
+            assert!(matches!(
+                poly.compose_mod(&inner, &modulus_poly),
+                Err(MathError::NotInvertible(_))
+            ));
+        }
+    }
+
+    /// Ensure that an error is returned for mismatching moduli
+    #[test]
+    fn mismatching_modulus() {
+        let poly = PolyOverZq::from_str("3  1 2 3 mod 5").unwrap();
+        let inner = PolyOverZq::from_str("2  1 1 mod 5").unwrap();
+        let modulus_poly = PolyOverZq::from_str("3  1 0 1 mod 7").unwrap();
+
+        assert!(matches!(
+            poly.compose_mod(&inner, &modulus_poly),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+}
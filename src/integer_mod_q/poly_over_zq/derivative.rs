@@ -0,0 +1,79 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the formal derivative of a [`PolyOverZq`].
+
+use super::PolyOverZq;
+use flint_sys::fmpz_mod_poly::fmpz_mod_poly_derivative;
+
+impl PolyOverZq {
+    /// Computes the formal derivative of `self`, i.e. for
+    /// `self = a_0 + a_1 X + ... + a_n X^n` it computes
+    /// `a_1 + 2 a_2 X + ... + n a_n X^(n-1)` modulo `q`.
+    ///
+    /// Returns the derivative of `self` as a fresh [`PolyOverZq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::PolyOverZq;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZq::from_str("4  1 2 3 4 mod 7").unwrap();
+    ///
+    /// let derivative = poly.derivative();
+    ///
+    /// assert_eq!(PolyOverZq::from_str("3  2 6 5 mod 7").unwrap(), derivative);
+    /// ```
+    pub fn derivative(&self) -> Self {
+        let mut out = PolyOverZq::from(&self.modulus);
+        unsafe {
+            fmpz_mod_poly_derivative(
+                &mut out.poly,
+                &self.poly,
+                self.modulus.get_fmpz_mod_ctx_struct(),
+            )
+        };
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_derivative {
+    use super::PolyOverZq;
+    use std::str::FromStr;
+
+    /// Ensure that the derivative is computed correctly for large coefficients
+    #[test]
+    fn large_coefficients() {
+        let modulus = u64::MAX;
+        let poly = PolyOverZq::from_str(&format!("3  1 {} {} mod {}", i64::MAX, i64::MIN, modulus))
+            .unwrap();
+        let cmp = PolyOverZq::from_str(&format!(
+            "2  {} {} mod {}",
+            i64::MAX,
+            2 * (i64::MIN as i128),
+            modulus
+        ))
+        .unwrap();
+
+        assert_eq!(cmp, poly.derivative());
+    }
+
+    /// Ensure that the derivative vanishes for constants and
+    /// for `X^p` over `Z_p`
+    #[test]
+    fn vanishing() {
+        let constant = PolyOverZq::from_str("1  5 mod 7").unwrap();
+        let power = PolyOverZq::from_str("8  1 0 0 0 0 0 0 1 mod 7").unwrap();
+        let zero = PolyOverZq::from_str("0 mod 7").unwrap();
+
+        assert_eq!(zero, constant.derivative());
+        assert_eq!(zero, power.derivative());
+        assert_eq!(zero, zero.derivative());
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the computation of the squarefree part
+//! of a [`PolyOverZq`] over a prime modulus.
+
+use super::PolyOverZq;
+use crate::{error::MathError, traits::SetCoefficient};
+use flint_sys::{
+    fmpz_mod_poly::fmpz_mod_poly_make_monic,
+    fmpz_mod_poly_factor::{
+        fmpz_mod_poly_factor_clear, fmpz_mod_poly_factor_get_fmpz_mod_poly,
+        fmpz_mod_poly_factor_init, fmpz_mod_poly_factor_squarefree, fmpz_mod_poly_factor_struct,
+    },
+};
+use std::mem::MaybeUninit;
+
+impl PolyOverZq {
+    /// Computes the squarefree part of `self` over a prime modulus, i.e. the
+    /// monic product of all distinct irreducible factors of `self`.
+    /// Hence, the squarefree part has the same roots as `self`,
+    /// but each of them with multiplicity `1`.
+    ///
+    /// Returns the squarefree part of `self` or an error if the modulus is not prime.
+    /// The squarefree part of a non-zero constant polynomial is `1`
+    /// and the squarefree part of the zero polynomial is `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::PolyOverZq;
+    /// use std::str::FromStr;
+    ///
+    /// // 2 * (X + 1)^2 = 2X^2 + 4X + 2
+    /// let poly = PolyOverZq::from_str("3  2 4 2 mod 7").unwrap();
+    ///
+    /// let squarefree = poly.squarefree_part().unwrap();
+    ///
+    /// assert_eq!(PolyOverZq::from_str("2  1 1 mod 7").unwrap(), squarefree);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NotPrime`](MathError::NotPrime)
+    /// if the modulus is not prime.
+    pub fn squarefree_part(&self) -> Result<Self, MathError> {
+        self.check_prime_modulus(self)?;
+        if self.get_degree() < 0 {
+            return Ok(self.clone());
+        }
+
+        let ctx = self.modulus.get_fmpz_mod_ctx_struct();
+        let mut out = PolyOverZq::from(&self.modulus);
+        out.set_coeff(0, 1)?;
+
+        let mut factors = MaybeUninit::<fmpz_mod_poly_factor_struct>::uninit();
+        unsafe {
+            fmpz_mod_poly_factor_init(factors.as_mut_ptr(), ctx);
+            let mut factors = factors.assume_init();
+            fmpz_mod_poly_factor_squarefree(&mut factors, &self.poly, ctx);
+
+            // the squarefree factors are pairwise coprime,
+            // hence their product contains every irreducible factor exactly once
+            let mut factor = PolyOverZq::from(&self.modulus);
+            for i in 0..factors.num {
+                fmpz_mod_poly_factor_get_fmpz_mod_poly(&mut factor.poly, &factors, i, ctx);
+                out = out * &factor;
+            }
+
+            fmpz_mod_poly_factor_clear(&mut factors, ctx);
+
+            // FLINT does not guarantee monic factors
+            fmpz_mod_poly_make_monic(&mut out.poly, &out.poly, ctx);
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_squarefree_part {
+    use super::PolyOverZq;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that repeated factors are removed and the result is monic
+    #[test]
+    fn repeated_factors() {
+        let factor_1 = PolyOverZq::from_str("2  -2 1 mod 7").unwrap();
+        let factor_2 = PolyOverZq::from_str("3  1 0 1 mod 7").unwrap();
+        let constant = PolyOverZq::from_str("1  3 mod 7").unwrap();
+        let poly = &constant * &factor_1 * &factor_1 * &factor_1 * &factor_2 * &factor_2;
+
+        let squarefree = poly.squarefree_part().unwrap();
+
+        assert_eq!(factor_1 * factor_2, squarefree);
+    }
+
+    /// Ensure that factors with vanishing derivative are detected,
+    /// e.g. `X^7 + 1 = (X + 1)^7` over `Z_7`
+    #[test]
+    fn frobenius() {
+        let poly = PolyOverZq::from_str("8  1 0 0 0 0 0 0 1 mod 7").unwrap();
+
+        let squarefree = poly.squarefree_part().unwrap();
+
+        assert_eq!(PolyOverZq::from_str("2  1 1 mod 7").unwrap(), squarefree);
+    }
+
+    /// Ensure that the squarefree part of non-monic inputs is monic,
+    /// e.g. `3X + 1 = 3 (X + 5)` and `3X^7 + 3 = 3 (X + 1)^7` over `Z_7`
+    #[test]
+    fn non_monic() {
+        let linear = PolyOverZq::from_str("2  1 3 mod 7").unwrap();
+        let power = PolyOverZq::from_str("8  3 0 0 0 0 0 0 3 mod 7").unwrap();
+
+        assert_eq!(
+            PolyOverZq::from_str("2  5 1 mod 7").unwrap(),
+            linear.squarefree_part().unwrap()
+        );
+        assert_eq!(
+            PolyOverZq::from_str("2  1 1 mod 7").unwrap(),
+            power.squarefree_part().unwrap()
+        );
+    }
+
+    /// Ensure that squarefree polynomials are only made monic for a large modulus
+    #[test]
+    fn squarefree() {
+        let modulus = u64::MAX - 58;
+        let poly = PolyOverZq::from_str(&format!("3  -1 0 1 mod {}", modulus)).unwrap();
+
+        assert_eq!(poly, poly.squarefree_part().unwrap());
+    }
+
+    /// Ensure that constants have squarefree part `1` and zero stays zero
+    #[test]
+    fn constant() {
+        let constant = PolyOverZq::from_str("1  5 mod 7").unwrap();
+        let zero = PolyOverZq::from_str("0 mod 7").unwrap();
+
+        assert_eq!(
+            PolyOverZq::from_str("1  1 mod 7").unwrap(),
+            constant.squarefree_part().unwrap()
+        );
+        assert_eq!(zero, zero.squarefree_part().unwrap());
+    }
+
+    /// Ensure that an error is returned for composite moduli
+    #[test]
+    fn not_prime() {
+        let poly = PolyOverZq::from_str("3  1 2 1 mod 16").unwrap();
+
+        assert!(matches!(
+            poly.squarefree_part(),
+            Err(MathError::NotPrime(_))
+        ));
+    }
+}
@@ -17,6 +17,82 @@ use crate::{error::MathError, integer::PolyOverZ, integer_mod_q::modulus::Modulu
 use flint_sys::fmpz_mod_poly::{fmpz_mod_poly_init, fmpz_mod_poly_set_fmpz_poly};
 use std::{mem::MaybeUninit, str::FromStr};
 
+impl From<&Modulus> for PolyOverZq {
+    /// Create a new polynomial of type [`PolyOverZq`] with the given
+    /// [`Modulus`], which is initialized as the zero polynomial.
+    ///
+    /// Parameters:
+    /// - `modulus`: the modulus of the coefficients of the polynomial
+    ///
+    /// Returns the zero polynomial modulo `modulus`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{Modulus, PolyOverZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = Modulus::from_str("42").unwrap();
+    ///
+    /// let poly = PolyOverZq::from(&modulus);
+    ///
+    /// assert_eq!(PolyOverZq::from_str("0 mod 42").unwrap(), poly);
+    /// ```
+    fn from(modulus: &Modulus) -> Self {
+        let mut poly = MaybeUninit::uninit();
+        unsafe {
+            fmpz_mod_poly_init(poly.as_mut_ptr(), modulus.get_fmpz_mod_ctx_struct());
+            Self {
+                poly: poly.assume_init(),
+                modulus: modulus.clone(),
+            }
+        }
+    }
+}
+
+impl PolyOverZq {
+    /// Create a [`PolyOverZq`] from a [`PolyOverZ`] by reducing each coefficient
+    /// modulo `modulus`.
+    ///
+    /// Parameters:
+    /// - `poly`: the polynomial whose coefficients are reduced
+    /// - `modulus`: the modulus of the coefficients of the new polynomial
+    ///
+    /// Returns a [`PolyOverZq`] with the reduced coefficients of `poly`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, PolyOverZq};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZ::from_str("4  0 -1 17 20").unwrap();
+    /// let modulus = Modulus::from_str("17").unwrap();
+    ///
+    /// let poly_zq = PolyOverZq::from_poly_over_z(&poly, &modulus);
+    ///
+    /// assert_eq!(PolyOverZq::from_str("4  0 16 0 3 mod 17").unwrap(), poly_zq);
+    /// ```
+    pub fn from_poly_over_z(poly: &PolyOverZ, modulus: &Modulus) -> Self {
+        let mut out = PolyOverZq::from(modulus);
+        unsafe {
+            fmpz_mod_poly_set_fmpz_poly(
+                &mut out.poly,
+                &poly.poly,
+                modulus.get_fmpz_mod_ctx_struct(),
+            )
+        };
+        out
+    }
+}
+
+impl From<(&PolyOverZ, &Modulus)> for PolyOverZq {
+    /// Create a [`PolyOverZq`] from a [`PolyOverZ`] and a [`Modulus`] using
+    /// [`from_poly_over_z`](PolyOverZq::from_poly_over_z)
+    fn from(value: (&PolyOverZ, &Modulus)) -> Self {
+        PolyOverZq::from_poly_over_z(value.0, value.1)
+    }
+}
+
 impl FromStr for PolyOverZq {
     type Err = MathError;
 
@@ -66,17 +142,61 @@ impl FromStr for PolyOverZq {
         let poly_over_z = PolyOverZ::from_str(poly_s)?;
         let modulus = Modulus::from_str(modulus)?;
 
-        let mut poly = MaybeUninit::uninit();
-        unsafe {
-            fmpz_mod_poly_init(poly.as_mut_ptr(), modulus.get_fmpz_mod_ctx_struct());
-            let mut poly = poly.assume_init();
-            fmpz_mod_poly_set_fmpz_poly(
-                &mut poly,
-                &poly_over_z.poly,
-                modulus.get_fmpz_mod_ctx_struct(),
-            );
-            Ok(Self { poly, modulus })
-        }
+        Ok(Self::from_poly_over_z(&poly_over_z, &modulus))
+    }
+}
+
+#[cfg(test)]
+mod test_from_modulus {
+    use super::PolyOverZq;
+    use crate::integer_mod_q::Modulus;
+    use std::str::FromStr;
+
+    /// Ensure that the zero polynomial is created with the given modulus
+    #[test]
+    fn zero_polynomial() {
+        let modulus = Modulus::from_str(&u64::MAX.to_string()).unwrap();
+
+        let poly = PolyOverZq::from(&modulus);
+
+        assert_eq!(
+            PolyOverZq::from_str(&format!("0 mod {}", u64::MAX)).unwrap(),
+            poly
+        );
+        assert_eq!(modulus, poly.modulus);
+    }
+}
+
+#[cfg(test)]
+mod test_from_poly_over_z {
+    use super::PolyOverZq;
+    use crate::{integer::PolyOverZ, integer_mod_q::Modulus};
+    use std::str::FromStr;
+
+    /// Ensure that large and negative coefficients are reduced correctly
+    #[test]
+    fn large_coefficients() {
+        let poly = PolyOverZ::from_str(&format!("3  {} -1 {}", u64::MAX, i64::MIN)).unwrap();
+        let modulus = Modulus::from_str(&u64::MAX.to_string()).unwrap();
+
+        let poly_zq = PolyOverZq::from_poly_over_z(&poly, &modulus);
+
+        assert_eq!(
+            PolyOverZq::from_str(&format!("3  0 -1 {} mod {}", i64::MIN, u64::MAX)).unwrap(),
+            poly_zq
+        );
+        assert_eq!(poly_zq, PolyOverZq::from((&poly, &modulus)));
+    }
+
+    /// Ensure that the polynomial is normalised if the leading coefficients vanish
+    #[test]
+    fn leading_zeros() {
+        let poly = PolyOverZ::from_str("3  1 2 17").unwrap();
+        let modulus = Modulus::from_str("17").unwrap();
+
+        let poly_zq = PolyOverZq::from((&poly, &modulus));
+
+        assert_eq!(PolyOverZq::from_str("2  1 2 mod 17").unwrap(), poly_zq);
     }
 }
 
@@ -0,0 +1,290 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the [`Gcd`]
+//! and [`Xgcd`] trait for [`PolyOverZq`].
+
+use super::PolyOverZq;
+use crate::{
+    error::MathError,
+    traits::{Gcd, Xgcd},
+};
+use flint_sys::fmpz_mod_poly::{fmpz_mod_poly_gcd, fmpz_mod_poly_xgcd};
+
+impl Gcd<&PolyOverZq> for PolyOverZq {
+    type Output = Result<PolyOverZq, MathError>;
+
+    /// Outputs the greatest common divisor (gcd) of the two given polynomials
+    /// over a prime modulus. The gcd is monic, except for `gcd(0,0) = 0`.
+    ///
+    /// Paramters:
+    /// - `other`: specifies one of the polynomials of which the gcd is computed
+    ///
+    /// Returns the greatest common divisor of `self` and `other` as
+    /// a [`PolyOverZq`] instance or an error if the moduli mismatch
+    /// or the modulus is not prime.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::PolyOverZq;
+    /// use qfall_math::traits::*;
+    /// use std::str::FromStr;
+    ///
+    /// let poly_1 = PolyOverZq::from_str("3  6 0 1 mod 7").unwrap();
+    /// let poly_2 = PolyOverZq::from_str("2  2 2 mod 7").unwrap();
+    ///
+    /// let gcd = poly_1.gcd(&poly_2).unwrap();
+    ///
+    /// assert_eq!(PolyOverZq::from_str("2  1 1 mod 7").unwrap(), gcd);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the moduli of both [`PolyOverZq`] mismatch.
+    /// - Returns a [`MathError`] of type [`NotPrime`](MathError::NotPrime)
+    /// if the modulus is not prime.
+    fn gcd(&self, other: &Self) -> Self::Output {
+        self.check_prime_modulus(other)?;
+
+        let mut out = PolyOverZq::from(&self.modulus);
+        unsafe {
+            fmpz_mod_poly_gcd(
+                &mut out.poly,
+                &self.poly,
+                &other.poly,
+                self.modulus.get_fmpz_mod_ctx_struct(),
+            )
+        };
+        Ok(out)
+    }
+}
+
+impl Gcd<PolyOverZq> for PolyOverZq {
+    type Output = Result<PolyOverZq, MathError>;
+
+    /// Documentation can be found at [`PolyOverZq::gcd`].
+    fn gcd(&self, other: PolyOverZq) -> Self::Output {
+        self.gcd(&other)
+    }
+}
+
+impl Xgcd<&PolyOverZq> for PolyOverZq {
+    type Output = Result<(PolyOverZq, PolyOverZq, PolyOverZq), MathError>;
+
+    /// Outputs the extended greatest common divisor (xgcd) of the two given polynomials
+    /// over a prime modulus, i.e. a triple `(gcd(a,b), x, y)`, where `a*x + b*y = gcd(a,b)`.
+    /// The gcd is monic, except for `gcd(0,0) = 0`.
+    ///
+    /// Paramters:
+    /// - `other`: specifies one of the polynomials of which the xgcd is computed
+    ///
+    /// Returns a triple `(gcd(a,b), x, y)` containing the greatest common divisor,
+    /// `x`, and `y` s.t. `gcd(a,b) = a*x + b*y` or an error if the moduli mismatch
+    /// or the modulus is not prime.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::PolyOverZq;
+    /// use qfall_math::traits::*;
+    /// use std::str::FromStr;
+    ///
+    /// let poly_1 = PolyOverZq::from_str("3  1 0 1 mod 7").unwrap();
+    /// let poly_2 = PolyOverZq::from_str("2  2 1 mod 7").unwrap();
+    ///
+    /// let (gcd, x, y) = poly_1.xgcd(&poly_2).unwrap();
+    ///
+    /// assert_eq!(PolyOverZq::from_str("1  1 mod 7").unwrap(), gcd);
+    /// assert_eq!(gcd, &poly_1 * &x + &poly_2 * &y);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the moduli of both [`PolyOverZq`] mismatch.
+    /// - Returns a [`MathError`] of type [`NotPrime`](MathError::NotPrime)
+    /// if the modulus is not prime.
+    fn xgcd(&self, other: &Self) -> Self::Output {
+        self.check_prime_modulus(other)?;
+
+        let mut gcd = PolyOverZq::from(&self.modulus);
+        let mut x = PolyOverZq::from(&self.modulus);
+        let mut y = PolyOverZq::from(&self.modulus);
+        unsafe {
+            fmpz_mod_poly_xgcd(
+                &mut gcd.poly,
+                &mut x.poly,
+                &mut y.poly,
+                &self.poly,
+                &other.poly,
+                self.modulus.get_fmpz_mod_ctx_struct(),
+            )
+        };
+        Ok((gcd, x, y))
+    }
+}
+
+impl Xgcd<PolyOverZq> for PolyOverZq {
+    type Output = Result<(PolyOverZq, PolyOverZq, PolyOverZq), MathError>;
+
+    /// Documentation can be found at [`PolyOverZq::xgcd`].
+    fn xgcd(&self, other: PolyOverZq) -> Self::Output {
+        self.xgcd(&other)
+    }
+}
+
+impl PolyOverZq {
+    /// Checks whether `self` and `other` have the same modulus and
+    /// whether this modulus is prime, s.t. `Z_q[X]` is a Euclidean domain.
+    ///
+    /// Parameters:
+    /// - `other`: the second polynomial involved in the computation
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the moduli of both [`PolyOverZq`] mismatch.
+    /// - Returns a [`MathError`] of type [`NotPrime`](MathError::NotPrime)
+    /// if the modulus is not prime.
+    pub(crate) fn check_prime_modulus(&self, other: &Self) -> Result<(), MathError> {
+        if self.modulus != other.modulus {
+            return Err(MathError::MismatchingModulus(format!(
+                " Tried to combine a polynomial with modulus '{}' and \
+                a polynomial with modulus '{}'.",
+                self.modulus, other.modulus
+            )));
+        }
+        if !self.modulus.is_prime() {
+            return Err(MathError::NotPrime(format!(
+                "This operation is only defined over a prime modulus, \
+                but the modulus is {}.",
+                self.modulus
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_gcd {
+    use super::{Gcd, PolyOverZq};
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensures that the gcd is monic and computed correctly for a large modulus
+    /// and ensures the property `gcd(a,b) == gcd(b,a)`
+    #[test]
+    fn large_modulus() {
+        let modulus = u64::MAX - 58;
+        let factor = PolyOverZq::from_str(&format!("2  {} 1 mod {}", i64::MAX, modulus)).unwrap();
+        let poly_1 =
+            &factor * PolyOverZq::from_str(&format!("2  {} 3 mod {}", i64::MIN, modulus)).unwrap();
+        let poly_2 = &factor * PolyOverZq::from_str(&format!("3  1 0 1 mod {}", modulus)).unwrap();
+
+        assert_eq!(factor, poly_1.gcd(&poly_2).unwrap());
+        assert_eq!(factor, poly_2.gcd(poly_1).unwrap());
+    }
+
+    /// Ensures that `gcd(a,0)` is the monic version of `a` and `gcd(0,0) == 0`
+    #[test]
+    fn zero() {
+        let poly = PolyOverZq::from_str("3  1 0 2 mod 7").unwrap();
+        let zero = PolyOverZq::from_str("0 mod 7").unwrap();
+
+        assert_eq!(
+            PolyOverZq::from_str("3  4 0 1 mod 7").unwrap(),
+            poly.gcd(&zero).unwrap()
+        );
+        assert_eq!(zero, zero.gcd(&zero).unwrap());
+    }
+
+    /// Ensures that an error is returned for composite moduli
+    #[test]
+    fn not_prime() {
+        let poly_1 = PolyOverZq::from_str("3  1 0 1 mod 8").unwrap();
+        let poly_2 = PolyOverZq::from_str("2  1 1 mod 8").unwrap();
+
+        assert!(matches!(poly_1.gcd(&poly_2), Err(MathError::NotPrime(_))));
+    }
+
+    /// Ensures that an error is returned for mismatching moduli
+    #[test]
+    fn mismatching_modulus() {
+        let poly_1 = PolyOverZq::from_str("3  1 0 1 mod 7").unwrap();
+        let poly_2 = PolyOverZq::from_str("2  1 1 mod 11").unwrap();
+
+        assert!(matches!(
+            poly_1.gcd(&poly_2),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_xgcd {
+    use super::{PolyOverZq, Xgcd};
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensures that `a*x + b*y = gcd(a,b)` holds for a large modulus
+    #[test]
+    fn large_modulus() {
+        let modulus = u64::MAX - 58;
+        let factor = PolyOverZq::from_str(&format!("2  {} 1 mod {}", i64::MAX, modulus)).unwrap();
+        let poly_1 =
+            &factor * PolyOverZq::from_str(&format!("2  {} 3 mod {}", i64::MIN, modulus)).unwrap();
+        let poly_2 = &factor * PolyOverZq::from_str(&format!("3  1 0 1 mod {}", modulus)).unwrap();
+
+        let (gcd, x, y) = poly_1.xgcd(poly_2.clone()).unwrap();
+
+        assert_eq!(factor, gcd);
+        assert_eq!(gcd, &poly_1 * &x + &poly_2 * &y);
+    }
+
+    /// Ensures that the inverse modulo another polynomial is computed for coprime polynomials
+    #[test]
+    fn coprime() {
+        let poly_1 = PolyOverZq::from_str("3  2 0 5 mod 17").unwrap();
+        let poly_2 = PolyOverZq::from_str("5  1 0 0 0 1 mod 17").unwrap();
+
+        let (gcd, x, y) = poly_1.xgcd(&poly_2).unwrap();
+
+        assert_eq!(PolyOverZq::from_str("1  1 mod 17").unwrap(), gcd);
+        assert_eq!(gcd, &poly_1 * &x + &poly_2 * &y);
+    }
+
+    /// Ensures that `a*x + b*y = gcd(a,b)` holds if one of the polynomials is zero
+    #[test]
+    fn zero() {
+        let poly = PolyOverZq::from_str("3  1 0 2 mod 7").unwrap();
+        let zero = PolyOverZq::from_str("0 mod 7").unwrap();
+
+        let (gcd, x, y) = poly.xgcd(&zero).unwrap();
+
+        assert_eq!(PolyOverZq::from_str("3  4 0 1 mod 7").unwrap(), gcd);
+        assert_eq!(gcd, &poly * &x + &zero * &y);
+    }
+
+    /// Ensures that an error is returned for composite moduli
+    #[test]
+    fn not_prime() {
+        let poly_1 = PolyOverZq::from_str("3  1 0 1 mod 8").unwrap();
+        let poly_2 = PolyOverZq::from_str("2  1 1 mod 8").unwrap();
+
+        assert!(matches!(poly_1.xgcd(&poly_2), Err(MathError::NotPrime(_))));
+    }
+
+    /// Ensures that an error is returned for mismatching moduli
+    #[test]
+    fn mismatching_modulus() {
+        let poly_1 = PolyOverZq::from_str("3  1 0 1 mod 7").unwrap();
+        let poly_2 = PolyOverZq::from_str("2  1 1 mod 11").unwrap();
+
+        assert!(matches!(
+            poly_1.xgcd(&poly_2),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+}
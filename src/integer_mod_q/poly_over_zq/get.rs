@@ -14,9 +14,45 @@ use crate::{
     error::MathError, integer::Z, integer_mod_q::Zq, traits::GetCoefficient,
     utils::index::evaluate_index,
 };
-use flint_sys::fmpz_mod_poly::fmpz_mod_poly_get_coeff_fmpz;
+use flint_sys::fmpz_mod_poly::{fmpz_mod_poly_degree, fmpz_mod_poly_get_coeff_fmpz};
 use std::fmt::Display;
 
+impl PolyOverZq {
+    /// Returns the degree of a polynomial [`PolyOverZq`] as a [`i64`].
+    /// The zero polynomial has degree `-1`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::PolyOverZq;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZq::from_str("4  0 1 2 3 mod 17").unwrap();
+    ///
+    /// assert_eq!(3, poly.get_degree());
+    /// assert_eq!(-1, PolyOverZq::from_str("0 mod 17").unwrap().get_degree());
+    /// ```
+    pub fn get_degree(&self) -> i64 {
+        unsafe { fmpz_mod_poly_degree(&self.poly, self.modulus.get_fmpz_mod_ctx_struct()) }
+    }
+
+    /// Returns the leading coefficient of a polynomial [`PolyOverZq`] as a [`Zq`],
+    /// i.e. the coefficient of the highest power of `X` with a non-zero coefficient.
+    /// The leading coefficient of the zero polynomial is `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{PolyOverZq, Zq};
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZq::from_str("4  0 1 2 -3 mod 17").unwrap();
+    ///
+    /// assert_eq!(Zq::try_from((14, 17)).unwrap(), poly.get_leading_coeff());
+    /// ```
+    pub fn get_leading_coeff(&self) -> Zq {
+        self.get_coeff(self.get_degree().max(0)).unwrap()
+    }
+}
+
 impl GetCoefficient<Zq> for PolyOverZq {
     /// Returns the coefficient of a polynomial [`PolyOverZq`] as a [`Zq`].
     ///
@@ -96,6 +132,70 @@ impl GetCoefficient<Z> for PolyOverZq {
 
 // we omit the tests for the value of the [`Zq`], and focus on the [`Modulus`] being set correctly
 // since get_coefficient for [`Z`] is called, where we will check the value itself
+#[cfg(test)]
+mod test_get_degree {
+    use crate::integer_mod_q::PolyOverZq;
+    use std::str::FromStr;
+
+    /// Ensure that the degree is correct for polynomials with leading zeros
+    /// in their string representation and large coefficients
+    #[test]
+    fn degree() {
+        let modulus_str = format!("17{}", u64::MAX);
+        let poly_1 = PolyOverZq::from_str("5  1 2 3 0 0 mod 17").unwrap();
+        let poly_2 =
+            PolyOverZq::from_str(&format!("2  {} {} mod {}", u64::MAX, i64::MIN, modulus_str))
+                .unwrap();
+        let poly_3 = PolyOverZq::from_str("1  -7 mod 17").unwrap();
+
+        assert_eq!(2, poly_1.get_degree());
+        assert_eq!(1, poly_2.get_degree());
+        assert_eq!(0, poly_3.get_degree());
+    }
+
+    /// Ensure that coefficients reduced to `0` do not count towards the degree
+    #[test]
+    fn reduced_coefficients() {
+        let poly = PolyOverZq::from_str("3  1 2 17 mod 17").unwrap();
+        let zero = PolyOverZq::from_str("2  -17 34 mod 17").unwrap();
+
+        assert_eq!(1, poly.get_degree());
+        assert_eq!(-1, zero.get_degree());
+    }
+}
+
+#[cfg(test)]
+mod test_get_leading_coeff {
+    use crate::integer_mod_q::{PolyOverZq, Zq};
+    use std::str::FromStr;
+
+    /// Ensure that the leading coefficient is returned for small
+    /// and large coefficients
+    #[test]
+    fn leading_coeff() {
+        let modulus_str = format!("17{}", u64::MAX);
+        let poly_1 = PolyOverZq::from_str("5  1 2 -3 0 0 mod 17").unwrap();
+        let poly_2 =
+            PolyOverZq::from_str(&format!("2  {} {} mod {}", u64::MAX, i64::MAX, modulus_str))
+                .unwrap();
+
+        assert_eq!(Zq::try_from((14, 17)).unwrap(), poly_1.get_leading_coeff());
+        assert_eq!(
+            Zq::from_str(&format!("{} mod {}", i64::MAX, modulus_str)).unwrap(),
+            poly_2.get_leading_coeff()
+        );
+    }
+
+    /// Ensure that the leading coefficient of the zero polynomial is `0`
+    /// and carries the modulus of the polynomial
+    #[test]
+    fn zero() {
+        let poly = PolyOverZq::from_str("0 mod 17").unwrap();
+
+        assert_eq!(Zq::try_from((0, 17)).unwrap(), poly.get_leading_coeff());
+    }
+}
+
 #[cfg(test)]
 mod test_get_coeff_zq_modulus {
 
@@ -0,0 +1,153 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the inversion of a [`PolyOverZq`]
+//! modulo another polynomial.
+
+use super::PolyOverZq;
+use crate::error::MathError;
+use flint_sys::fmpz_mod_poly::{fmpz_mod_poly_degree, fmpz_mod_poly_invmod};
+
+impl PolyOverZq {
+    /// Computes the inverse of `self` modulo the polynomial `modulus_poly`
+    /// over a prime modulus, i.e. a polynomial `a` of degree smaller than
+    /// the degree of `modulus_poly` s.t. `self * a = 1 mod modulus_poly`.
+    ///
+    /// Only prime moduli `q` are supported, as the inverse is computed by
+    /// the extended Euclidean algorithm over the field `Z_q`.
+    /// Composite moduli, including prime powers like `q = 2^11`, result in an error.
+    /// For a prime power `q = p^k`, the inverse modulo `p` can be computed
+    /// with this function and lifted to `Z_q` via Newton iteration `a = a * (2 - self * a)`.
+    ///
+    /// Parameters:
+    /// - `modulus_poly`: specifies the polynomial modulo which `self` is inverted
+    ///
+    /// Returns the inverse of `self` as a [`PolyOverZq`] or an error
+    /// if it does not exist or the modulus is not prime.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::PolyOverZq;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZq::from_str("2  1 1 mod 17").unwrap();
+    /// let modulus_poly = PolyOverZq::from_str("5  1 0 0 0 1 mod 17").unwrap();
+    ///
+    /// let inverse = poly.invert_mod(&modulus_poly).unwrap();
+    ///
+    /// assert_eq!(PolyOverZq::from_str("4  9 8 9 8 mod 17").unwrap(), inverse);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the moduli of both [`PolyOverZq`] mismatch.
+    /// - Returns a [`MathError`] of type [`NotPrime`](MathError::NotPrime)
+    /// if the modulus is not prime, which includes prime powers.
+    /// - Returns a [`MathError`] of type [`NotInvertible`](MathError::NotInvertible)
+    /// if `modulus_poly` has degree smaller than `1` or
+    /// `self` and `modulus_poly` are not coprime.
+    pub fn invert_mod(&self, modulus_poly: &Self) -> Result<Self, MathError> {
+        self.check_prime_modulus(modulus_poly)?;
+        let ctx = self.modulus.get_fmpz_mod_ctx_struct();
+
+        if unsafe { fmpz_mod_poly_degree(&modulus_poly.poly, ctx) } < 1 {
+            return Err(MathError::NotInvertible(format!(
+                "Inverses modulo {} are not defined, as its degree is smaller than 1.",
+                modulus_poly
+            )));
+        }
+
+        let mut out = PolyOverZq::from(&self.modulus);
+        if 0 == unsafe { fmpz_mod_poly_invmod(&mut out.poly, &self.poly, &modulus_poly.poly, ctx) }
+        {
+            return Err(MathError::NotInvertible(format!(
+                "{} is not invertible modulo {}.",
+                self, modulus_poly
+            )));
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_invert_mod {
+    use super::PolyOverZq;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that the inverse is computed correctly for a large modulus
+    /// and is reduced modulo the polynomial
+    #[test]
+    fn large_modulus() {
+        let modulus = u64::MAX - 58;
+        let poly = PolyOverZq::from_str(&format!(
+            "6  {} 0 -3 1 0 {} mod {}",
+            i64::MAX,
+            i64::MIN,
+            modulus
+        ))
+        .unwrap();
+        let modulus_poly = PolyOverZq::from_str(&format!("5  1 0 0 0 1 mod {}", modulus)).unwrap();
+        let one = PolyOverZq::from_str(&format!("1  1 mod {}", modulus)).unwrap();
+
+        let inverse = poly.invert_mod(&modulus_poly).unwrap();
+
+        let (_, remainder) = (&poly * &inverse).div_rem(&modulus_poly).unwrap();
+        assert_eq!(one, remainder);
+        assert_eq!(inverse, inverse.div_rem(&modulus_poly).unwrap().1);
+    }
+
+    /// Ensure that an error is returned for polynomials sharing a common factor
+    #[test]
+    fn not_coprime() {
+        let poly = PolyOverZq::from_str("2  1 1 mod 17").unwrap();
+        let modulus_poly = PolyOverZq::from_str("3  -1 0 1 mod 17").unwrap();
+        let zero = PolyOverZq::from_str("0 mod 17").unwrap();
+
+        assert!(matches!(
+            poly.invert_mod(&modulus_poly),
+            Err(MathError::NotInvertible(_))
+        ));
+        assert!(matches!(
+            zero.invert_mod(&modulus_poly),
+            Err(MathError::NotInvertible(_))
+        ));
+    }
+
+    /// Ensure that an error is returned for constant modulus polynomials
+    #[test]
+    fn constant_modulus_poly() {
+        let poly = PolyOverZq::from_str("2  1 1 mod 17").unwrap();
+
+        for modulus_poly in ["1  3 mod 17", "0 mod 17"] {
+            let modulus_poly = PolyOverZq::from_str(modulus_poly).unwrap();
+
+            assert!(matches!(
+                poly.invert_mod(&modulus_poly),
+                Err(MathError::NotInvertible(_))
+            ));
+        }
+    }
+
+    /// Ensure that an error is returned for prime power or mismatching moduli
+    #[test]
+    fn invalid_modulus() {
+        let poly = PolyOverZq::from_str("2  1 1 mod 16").unwrap();
+        let modulus_poly = PolyOverZq::from_str("3  1 0 1 mod 16").unwrap();
+        let other_modulus_poly = PolyOverZq::from_str("3  1 0 1 mod 17").unwrap();
+
+        assert!(matches!(
+            poly.invert_mod(&modulus_poly),
+            Err(MathError::NotPrime(_))
+        ));
+        assert!(matches!(
+            poly.invert_mod(&other_modulus_poly),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the lifting of [`PolyOverZq`] instances
+//! to [`PolyOverZ`] instances.
+
+use super::PolyOverZq;
+use crate::{
+    integer::{PolyOverZ, Z},
+    integer_mod_q::fmpz_mod_helpers::symmetric_representative,
+};
+use flint_sys::{
+    fmpz_mod_poly::{fmpz_mod_poly_get_coeff_fmpz, fmpz_mod_poly_get_fmpz_poly},
+    fmpz_poly::fmpz_poly_set_coeff_fmpz,
+};
+
+impl PolyOverZq {
+    /// Lifts `self` to a [`PolyOverZ`], where each coefficient is given by its
+    /// canonical representative in `[0, q)`.
+    ///
+    /// Returns the lifted polynomial as a [`PolyOverZ`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::PolyOverZq;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZq::from_str("3  -1 5 9 mod 7").unwrap();
+    ///
+    /// let lifted = poly.lift();
+    ///
+    /// assert_eq!(PolyOverZ::from_str("3  6 5 2").unwrap(), lifted);
+    /// ```
+    pub fn lift(&self) -> PolyOverZ {
+        let mut out = PolyOverZ::default();
+        unsafe {
+            fmpz_mod_poly_get_fmpz_poly(
+                &mut out.poly,
+                &self.poly,
+                self.modulus.get_fmpz_mod_ctx_struct(),
+            )
+        };
+        out
+    }
+
+    /// Lifts `self` to a [`PolyOverZ`], where each coefficient is given by its
+    /// centered representative in `(-q/2, q/2]`.
+    ///
+    /// Returns the lifted polynomial as a [`PolyOverZ`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::PolyOverZq;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZq::from_str("3  -1 5 9 mod 7").unwrap();
+    ///
+    /// let lifted = poly.lift_symmetric();
+    ///
+    /// assert_eq!(PolyOverZ::from_str("3  -1 -2 2").unwrap(), lifted);
+    /// ```
+    pub fn lift_symmetric(&self) -> PolyOverZ {
+        let ctx = self.modulus.get_fmpz_mod_ctx_struct();
+        let mut out = PolyOverZ::default();
+        let mut coefficient = Z::default();
+        for index in 0..self.poly.length {
+            unsafe { fmpz_mod_poly_get_coeff_fmpz(&mut coefficient.value, &self.poly, index, ctx) };
+            let representative = symmetric_representative(&coefficient.value, &ctx.n[0]);
+            unsafe { fmpz_poly_set_coeff_fmpz(&mut out.poly, index, &representative.value) };
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_lift {
+    use super::PolyOverZq;
+    use crate::integer::PolyOverZ;
+    use std::str::FromStr;
+
+    /// Ensure that coefficients are lifted to `[0, q)` for large moduli
+    #[test]
+    fn large_modulus() {
+        let poly = PolyOverZq::from_str(&format!("3  -1 0 {} mod {}", i64::MAX, u64::MAX)).unwrap();
+
+        let lifted = poly.lift();
+
+        assert_eq!(
+            PolyOverZ::from_str(&format!("3  {} 0 {}", u64::MAX - 1, i64::MAX)).unwrap(),
+            lifted
+        );
+    }
+
+    /// Ensure that the zero polynomial is lifted to the zero polynomial
+    #[test]
+    fn zero() {
+        let poly = PolyOverZq::from_str("0 mod 17").unwrap();
+
+        assert_eq!(PolyOverZ::default(), poly.lift());
+    }
+}
+
+#[cfg(test)]
+mod test_lift_symmetric {
+    use super::PolyOverZq;
+    use crate::integer::PolyOverZ;
+    use std::str::FromStr;
+
+    /// Ensure that coefficients are lifted to `(-q/2, q/2]` for large moduli
+    #[test]
+    fn large_modulus() {
+        let poly = PolyOverZq::from_str(&format!(
+            "4  -1 {} {} 1 mod {}",
+            i64::MAX,
+            i64::MAX as u64 + 1,
+            u64::MAX
+        ))
+        .unwrap();
+
+        let lifted = poly.lift_symmetric();
+
+        assert_eq!(
+            PolyOverZ::from_str(&format!("4  -1 {} {} 1", i64::MAX, i64::MIN + 1)).unwrap(),
+            lifted
+        );
+    }
+
+    /// Ensure that `q/2` is lifted to the positive representative for even moduli
+    #[test]
+    fn even_modulus() {
+        let poly = PolyOverZq::from_str("3  8 9 7 mod 16").unwrap();
+
+        assert_eq!(
+            PolyOverZ::from_str("3  8 -7 7").unwrap(),
+            poly.lift_symmetric()
+        );
+    }
+
+    /// Ensure that the zero polynomial is lifted to the zero polynomial
+    #[test]
+    fn zero() {
+        let poly = PolyOverZq::from_str("0 mod 17").unwrap();
+
+        assert_eq!(PolyOverZ::default(), poly.lift_symmetric());
+    }
+}
@@ -10,7 +10,11 @@
 //! This includes checks such as reducibility.
 
 use super::PolyOverZq;
-use flint_sys::fmpz_mod_poly_factor::fmpz_mod_poly_is_irreducible;
+use crate::error::MathError;
+use flint_sys::{
+    fmpz_mod_poly::{fmpz_mod_poly_is_monic, fmpz_mod_poly_make_monic},
+    fmpz_mod_poly_factor::fmpz_mod_poly_is_irreducible,
+};
 
 impl PolyOverZq {
     /// Checks if a [`PolyOverZq`] is irreducible.
@@ -31,6 +35,68 @@ impl PolyOverZq {
             fmpz_mod_poly_is_irreducible(&self.poly, self.modulus.get_fmpz_mod_ctx_struct())
         }
     }
+
+    /// Checks if a [`PolyOverZq`] is monic, i.e. if its leading coefficient is `1`.
+    ///
+    /// Returns true if the polynomial is monic and false otherwise.
+    /// The zero polynomial is not monic.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::PolyOverZq;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZq::from_str("3  4 0 18 mod 17").unwrap();
+    ///
+    /// assert!(poly.is_monic())
+    /// ```
+    pub fn is_monic(&self) -> bool {
+        self.get_degree() >= 0
+            && 1 == unsafe {
+                fmpz_mod_poly_is_monic(&self.poly, self.modulus.get_fmpz_mod_ctx_struct())
+            }
+    }
+
+    /// Returns the monic polynomial which is obtained by dividing `self`
+    /// by its leading coefficient.
+    ///
+    /// Returns the monic version of `self` or an error if the leading
+    /// coefficient is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::PolyOverZq;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverZq::from_str("3  1 0 2 mod 7").unwrap();
+    ///
+    /// let monic = poly.make_monic().unwrap();
+    ///
+    /// assert_eq!(PolyOverZq::from_str("3  4 0 1 mod 7").unwrap(), monic);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NotInvertible`](MathError::NotInvertible)
+    /// if the leading coefficient of `self` is not invertible,
+    /// e.g. if `self` is the zero polynomial.
+    pub fn make_monic(&self) -> Result<Self, MathError> {
+        if self.get_leading_coeff().inverse().is_err() {
+            return Err(MathError::NotInvertible(format!(
+                "The leading coefficient of {} is not invertible.",
+                self
+            )));
+        }
+
+        let mut out = PolyOverZq::from(&self.modulus);
+        unsafe {
+            fmpz_mod_poly_make_monic(
+                &mut out.poly,
+                &self.poly,
+                self.modulus.get_fmpz_mod_ctx_struct(),
+            )
+        };
+        Ok(out)
+    }
 }
 
 #[cfg(test)]
@@ -53,3 +119,69 @@ mod test_is_irreducible {
         assert!(!poly_irr.is_irreducible())
     }
 }
+
+#[cfg(test)]
+mod test_is_monic {
+    use crate::integer_mod_q::PolyOverZq;
+    use std::str::FromStr;
+
+    /// Ensure that monic polynomials are detected after reduction
+    /// and for large moduli
+    #[test]
+    fn monic() {
+        let poly_1 = PolyOverZq::from_str("3  4 0 18 mod 17").unwrap();
+        let poly_2 = PolyOverZq::from_str(&format!("2  {} 1 mod {}", i64::MAX, u64::MAX)).unwrap();
+
+        assert!(poly_1.is_monic());
+        assert!(poly_2.is_monic());
+    }
+
+    /// Ensure that non-monic polynomials and the zero polynomial are not monic
+    #[test]
+    fn not_monic() {
+        let poly = PolyOverZq::from_str("3  1 0 2 mod 17").unwrap();
+        let zero = PolyOverZq::from_str("0 mod 17").unwrap();
+
+        assert!(!poly.is_monic());
+        assert!(!zero.is_monic());
+    }
+}
+
+#[cfg(test)]
+mod test_make_monic {
+    use crate::{error::MathError, integer_mod_q::PolyOverZq};
+    use std::str::FromStr;
+
+    /// Ensure that the leading coefficient is normalized to `1`
+    /// and monic polynomials are unchanged
+    #[test]
+    fn monic() {
+        let poly = PolyOverZq::from_str(&format!("3  1 2 {} mod {}", i64::MAX, u64::MAX)).unwrap();
+        let leading_coeff =
+            PolyOverZq::from_str(&format!("1  {} mod {}", i64::MAX, u64::MAX)).unwrap();
+        let monic = PolyOverZq::from_str("3  1 2 1 mod 17").unwrap();
+
+        let result = poly.make_monic().unwrap();
+
+        assert!(result.is_monic());
+        assert_eq!(poly, &result * &leading_coeff);
+        assert_eq!(monic, monic.make_monic().unwrap());
+    }
+
+    /// Ensure that an error is returned if the leading coefficient
+    /// is not invertible
+    #[test]
+    fn not_invertible() {
+        let poly = PolyOverZq::from_str("3  1 0 2 mod 16").unwrap();
+        let zero = PolyOverZq::from_str("0 mod 17").unwrap();
+
+        assert!(matches!(
+            poly.make_monic(),
+            Err(MathError::NotInvertible(_))
+        ));
+        assert!(matches!(
+            zero.make_monic(),
+            Err(MathError::NotInvertible(_))
+        ));
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the computation of the roots of a [`PolyOverZq`]
+//! over a prime modulus.
+
+use super::PolyOverZq;
+use crate::{error::MathError, integer::Z, integer_mod_q::Zq, traits::GetCoefficient};
+use flint_sys::fmpz_mod_poly_factor::{
+    fmpz_mod_poly_factor_clear, fmpz_mod_poly_factor_get_fmpz_mod_poly, fmpz_mod_poly_factor_init,
+    fmpz_mod_poly_factor_struct, fmpz_mod_poly_roots,
+};
+use std::mem::MaybeUninit;
+
+impl PolyOverZq {
+    /// Computes all roots of `self` in `Z_q` together with their multiplicities,
+    /// i.e. all `r` with `self(r) = 0 mod q` and the largest `e` s.t.
+    /// `(X - r)^e` divides `self`. The modulus `q` has to be prime.
+    ///
+    /// Returns a vector of all distinct roots together with their multiplicity
+    /// or an error if the modulus is not prime. For constant polynomials and
+    /// the zero polynomial, the vector is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{PolyOverZq, Zq};
+    /// use std::str::FromStr;
+    ///
+    /// // (X - 1)^2 * (X + 1) = X^3 - X^2 - X + 1
+    /// let poly = PolyOverZq::from_str("4  1 -1 -1 1 mod 17").unwrap();
+    ///
+    /// let roots = poly.roots().unwrap();
+    ///
+    /// assert_eq!(2, roots.len());
+    /// assert!(roots.contains(&(Zq::try_from((1, 17)).unwrap(), 2)));
+    /// assert!(roots.contains(&(Zq::try_from((16, 17)).unwrap(), 1)));
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NotPrime`](MathError::NotPrime)
+    /// if the modulus is not prime.
+    pub fn roots(&self) -> Result<Vec<(Zq, u64)>, MathError> {
+        self.check_prime_modulus(self)?;
+        if self.poly.length == 0 {
+            return Ok(vec![]);
+        }
+
+        let ctx = self.modulus.get_fmpz_mod_ctx_struct();
+        let mut factors = MaybeUninit::<fmpz_mod_poly_factor_struct>::uninit();
+        unsafe {
+            fmpz_mod_poly_factor_init(factors.as_mut_ptr(), ctx);
+            let mut factors = factors.assume_init();
+            fmpz_mod_poly_roots(&mut factors, &self.poly, 1, ctx);
+
+            // every factor is of the form `X - r`, i.e. its constant coefficient is `-r`
+            let out = (0..factors.num)
+                .map(|i| {
+                    let mut factor = PolyOverZq::from(&self.modulus);
+                    fmpz_mod_poly_factor_get_fmpz_mod_poly(&mut factor.poly, &factors, i, ctx);
+                    let constant: Z = factor.get_coeff(0).unwrap();
+                    (
+                        Zq::from_z_modulus(&(Z::ZERO - constant), &self.modulus),
+                        *factors.exp.add(i as usize) as u64,
+                    )
+                })
+                .collect();
+
+            fmpz_mod_poly_factor_clear(&mut factors, ctx);
+            Ok(out)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_roots {
+    use super::PolyOverZq;
+    use crate::{error::MathError, integer_mod_q::Zq, traits::Evaluate};
+    use std::str::FromStr;
+
+    /// Ensure that all roots are found with their multiplicities
+    #[test]
+    fn multiplicities() {
+        // (X - 2)^3 * (X - 5) * (X^2 + 1) over Z_7, where X^2 + 1 is irreducible
+        let linear_1 = PolyOverZq::from_str("2  -2 1 mod 7").unwrap();
+        let linear_2 = PolyOverZq::from_str("2  -5 1 mod 7").unwrap();
+        let irreducible = PolyOverZq::from_str("3  1 0 1 mod 7").unwrap();
+        let poly = &linear_1 * &linear_1 * &linear_1 * &linear_2 * &irreducible;
+
+        let roots = poly.roots().unwrap();
+
+        assert_eq!(2, roots.len());
+        assert!(roots.contains(&(Zq::try_from((2, 7)).unwrap(), 3)));
+        assert!(roots.contains(&(Zq::try_from((5, 7)).unwrap(), 1)));
+    }
+
+    /// Ensure that the roots are correct for a large modulus
+    #[test]
+    fn large_modulus() {
+        let modulus = u64::MAX - 58;
+        let poly = PolyOverZq::from_str(&format!("3  -1 0 1 mod {}", modulus)).unwrap();
+
+        let roots = poly.roots().unwrap();
+
+        assert_eq!(2, roots.len());
+        for (root, multiplicity) in roots {
+            let value: Zq = poly.evaluate(&root);
+            assert_eq!(Zq::try_from((0, modulus)).unwrap(), value);
+            assert_eq!(1, multiplicity);
+        }
+    }
+
+    /// Ensure that `X^(q-1) - 1` has every non-zero element as root,
+    /// as required for NTT-friendly moduli
+    #[test]
+    fn all_units() {
+        let poly = PolyOverZq::from_str("17  -1 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 1 mod 17").unwrap();
+
+        let roots = poly.roots().unwrap();
+
+        assert_eq!(16, roots.len());
+        assert!(!roots.contains(&(Zq::try_from((0, 17)).unwrap(), 1)));
+    }
+
+    /// Ensure that irreducible, constant and zero polynomials have no roots
+    #[test]
+    fn no_roots() {
+        let irreducible = PolyOverZq::from_str("3  1 0 1 mod 7").unwrap();
+        let constant = PolyOverZq::from_str("1  3 mod 7").unwrap();
+        let zero = PolyOverZq::from_str("0 mod 7").unwrap();
+
+        assert!(irreducible.roots().unwrap().is_empty());
+        assert!(constant.roots().unwrap().is_empty());
+        assert!(zero.roots().unwrap().is_empty());
+    }
+
+    /// Ensure that an error is returned for composite moduli
+    #[test]
+    fn not_prime() {
+        let poly = PolyOverZq::from_str("2  1 1 mod 16").unwrap();
+
+        assert!(matches!(poly.roots(), Err(MathError::NotPrime(_))));
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains algorithms for sampling [`PolyOverZq`] instances
+//! at random.
+
+use super::PolyOverZq;
+use crate::{
+    error::MathError,
+    integer::Z,
+    integer_mod_q::Modulus,
+    traits::SetCoefficient,
+    utils::{index::evaluate_index, sample::sample_uniform_rejection},
+};
+use std::fmt::Display;
+
+impl PolyOverZq {
+    /// Samples a polynomial of degree at most `max_degree`, whose coefficients
+    /// are chosen uniformly at random from `[0, q)` using
+    /// a cryptographically secure pseudorandom number generator.
+    ///
+    /// Parameters:
+    /// - `max_degree`: specifies the maximal degree of the sampled polynomial
+    /// - `modulus`: specifies the modulus `q` of the coefficients
+    ///
+    /// Returns a fresh [`PolyOverZq`] with `max_degree + 1` uniformly sampled
+    /// coefficients or an error if `max_degree` is negative.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{Modulus, PolyOverZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = Modulus::from_str("17").unwrap();
+    ///
+    /// let poly = PolyOverZq::sample_uniform(255, &modulus).unwrap();
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// `max_degree` is negative or it does not fit into an [`i64`].
+    pub fn sample_uniform(
+        max_degree: impl TryInto<i64> + Display + Copy,
+        modulus: &Modulus,
+    ) -> Result<Self, MathError> {
+        let max_degree = evaluate_index(max_degree)?;
+        let modulus_z = Z::from(modulus);
+
+        let mut poly = PolyOverZq::from(modulus);
+        for index in 0..=max_degree {
+            poly.set_coeff(index, &sample_uniform_rejection(&modulus_z)?)?;
+        }
+        Ok(poly)
+    }
+
+    /// Samples a monic irreducible polynomial of degree `degree` over a prime modulus
+    /// uniformly at random using a cryptographically secure pseudorandom number generator.
+    /// Such a polynomial can be used to construct the finite field `GF(q^degree)`.
+    ///
+    /// Parameters:
+    /// - `degree`: specifies the degree of the sampled polynomial
+    /// - `modulus`: specifies the prime modulus of the coefficients
+    ///
+    /// Returns a monic irreducible [`PolyOverZq`] of degree `degree` or an error
+    /// if `degree` is smaller than `1` or `modulus` is not prime.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{Modulus, PolyOverZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = Modulus::from_str("17").unwrap();
+    ///
+    /// let poly = PolyOverZq::sample_irreducible(4, &modulus).unwrap();
+    ///
+    /// assert!(poly.is_irreducible());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// `degree` is smaller than `1` or does not fit into an [`i64`].
+    /// - Returns a [`MathError`] of type [`NotPrime`](MathError::NotPrime)
+    /// if `modulus` is not prime.
+    pub fn sample_irreducible(
+        degree: impl TryInto<i64> + Display + Copy,
+        modulus: &Modulus,
+    ) -> Result<Self, MathError> {
+        let degree = evaluate_index(degree)?;
+        if degree < 1 {
+            return Err(MathError::OutOfBounds(
+                "be at least 1".to_owned(),
+                degree.to_string(),
+            ));
+        }
+        if !modulus.is_prime() {
+            return Err(MathError::NotPrime(format!(
+                "Irreducible polynomials are only sampled over a prime modulus, \
+                but the modulus is {}.",
+                modulus
+            )));
+        }
+
+        // roughly one out of `degree` monic polynomials is irreducible
+        let modulus_z = Z::from(modulus);
+        let mut poly = PolyOverZq::from(modulus);
+        poly.set_coeff(degree, 1)?;
+        loop {
+            for index in 0..degree {
+                poly.set_coeff(index, &sample_uniform_rejection(&modulus_z)?)?;
+            }
+            if poly.is_irreducible() {
+                return Ok(poly);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_sample_uniform {
+    use super::PolyOverZq;
+    use crate::{
+        error::MathError,
+        integer::{PolyOverZ, Z},
+        integer_mod_q::Modulus,
+        traits::GetCoefficient,
+    };
+    use std::str::FromStr;
+
+    /// Ensure that all coefficients are reduced and the degree is bounded
+    #[test]
+    fn coefficients_reduced() {
+        let modulus = Modulus::from_str(&"1".repeat(65)).unwrap();
+        let modulus_z = Z::from(&modulus);
+
+        let poly = PolyOverZq::sample_uniform(64, &modulus).unwrap();
+
+        let lifted: PolyOverZ = poly.lift();
+        assert!(lifted.get_degree() <= 64);
+        for index in 0..=64 {
+            let coeff: Z = poly.get_coeff(index).unwrap();
+            assert!(Z::ZERO <= coeff);
+            assert!(coeff < modulus_z);
+        }
+    }
+
+    /// Ensure that every residue of a small modulus is sampled
+    #[test]
+    fn all_residues_sampled() {
+        let modulus = Modulus::from_str("7").unwrap();
+
+        let poly = PolyOverZq::sample_uniform(300, &modulus).unwrap();
+        let coeffs: Vec<Z> = (0..=300).map(|i| poly.get_coeff(i).unwrap()).collect();
+
+        for value in 0..7 {
+            assert!(coeffs.contains(&Z::from(value)));
+        }
+    }
+
+    /// Ensure that an error is returned for negative degrees
+    #[test]
+    fn negative_degree() {
+        let modulus = Modulus::from_str("17").unwrap();
+
+        assert!(matches!(
+            PolyOverZq::sample_uniform(-1, &modulus),
+            Err(MathError::OutOfBounds(_, _))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_sample_irreducible {
+    use super::PolyOverZq;
+    use crate::{
+        error::MathError,
+        integer::{PolyOverZ, Z},
+        integer_mod_q::Modulus,
+        traits::GetCoefficient,
+    };
+    use std::str::FromStr;
+
+    /// Ensure that the sampled polynomial is monic, irreducible
+    /// and of the correct degree for small and large moduli
+    #[test]
+    fn monic_irreducible() {
+        for modulus in ["2", "17", &(u64::MAX - 58).to_string()] {
+            let modulus = Modulus::from_str(modulus).unwrap();
+
+            for degree in [1, 2, 7] {
+                let poly = PolyOverZq::sample_irreducible(degree, &modulus).unwrap();
+
+                let lifted: PolyOverZ = poly.lift();
+                let leading_coeff: Z = poly.get_coeff(degree).unwrap();
+                assert!(poly.is_irreducible());
+                assert_eq!(degree, lifted.get_degree());
+                assert_eq!(Z::ONE, leading_coeff);
+            }
+        }
+    }
+
+    /// Ensure that an error is returned for degrees smaller than `1`
+    #[test]
+    fn invalid_degree() {
+        let modulus = Modulus::from_str("17").unwrap();
+
+        assert!(matches!(
+            PolyOverZq::sample_irreducible(0, &modulus),
+            Err(MathError::OutOfBounds(_, _))
+        ));
+        assert!(matches!(
+            PolyOverZq::sample_irreducible(-1, &modulus),
+            Err(MathError::OutOfBounds(_, _))
+        ));
+    }
+
+    /// Ensure that an error is returned for composite moduli
+    #[test]
+    fn not_prime() {
+        let modulus = Modulus::from_str("16").unwrap();
+
+        assert!(matches!(
+            PolyOverZq::sample_irreducible(3, &modulus),
+            Err(MathError::NotPrime(_))
+        ));
+    }
+}
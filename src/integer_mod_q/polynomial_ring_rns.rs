@@ -0,0 +1,50 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! [`PolynomialRingRns`] is a type of ring over `Z_q[X]/f(X)`, where `q` is
+//! a product of distinct primes. Each element is stored in the
+//! residue number system (RNS), i.e. as its residues in `Z_{q_i}[X]/f(X)`
+//! for each prime `q_i`, s.t. all arithmetic is performed on word-sized primes.
+
+use super::{ModulusPolynomialRingRns, PolynomialRingZq};
+
+mod arithmetic;
+mod basis;
+mod from;
+mod get;
+mod ntt;
+
+/// [`PolynomialRingRns`] represents polynomials over `Z_q[X]/f(X)`, where
+/// `q = q_1 * ... * q_k` is a product of distinct primes, by their
+/// residues in [`PolynomialRingZq`] for each prime `q_i`.
+///
+/// Attributes
+/// - `residues`: holds the residue of the value for each prime `q_i`
+/// - `modulus`: holds the primes `q_i` and f(X)
+///
+/// # Example
+/// ```
+/// use qfall_math::integer::PolyOverZ;
+/// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns};
+/// use std::str::FromStr;
+///
+/// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+/// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+/// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+///
+/// let poly = PolyOverZ::from_str("4  -1 0 500 1").unwrap();
+/// let a = PolynomialRingRns::from((&poly, &modulus));
+/// let b = &a * &a;
+///
+/// let c: PolyOverZ = b.to_poly_over_z();
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct PolynomialRingRns {
+    residues: Vec<PolynomialRingZq>,
+    modulus: ModulusPolynomialRingRns,
+}
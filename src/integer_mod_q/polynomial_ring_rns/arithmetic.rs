@@ -0,0 +1,14 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! The arithmetic module contains implementations of simple arithmetics
+//! such as addition, subtraction and multiplication.
+
+mod add;
+mod mul;
+mod sub;
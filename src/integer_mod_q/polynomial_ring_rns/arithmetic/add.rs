@@ -0,0 +1,174 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementation of the [`Add`] trait for [`PolynomialRingRns`] values.
+
+use super::super::PolynomialRingRns;
+use crate::{
+    error::MathError,
+    macros::arithmetics::{
+        arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
+    },
+};
+use std::ops::Add;
+
+impl Add for &PolynomialRingRns {
+    type Output = PolynomialRingRns;
+    /// Implements the [`Add`] trait for two [`PolynomialRingRns`] values.
+    /// [`Add`] is implemented for any combination of [`PolynomialRingRns`]
+    /// and borrowed [`PolynomialRingRns`].
+    ///
+    /// Parameters:
+    /// - `other`: specifies the polynomial to add to `self`
+    ///
+    /// Returns the sum of both polynomials as a [`PolynomialRingRns`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+    /// let a = PolynomialRingRns::from((&PolyOverZ::from_str("4  -1 0 1 1").unwrap(), &modulus));
+    /// let b = PolynomialRingRns::from((&PolyOverZ::from_str("4  2 0 3 1").unwrap(), &modulus));
+    ///
+    /// let c: PolynomialRingRns = &a + &b;
+    /// let d: PolynomialRingRns = a + b;
+    /// let e: PolynomialRingRns = &c + d;
+    /// let f: PolynomialRingRns = c + &e;
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if the moduli of both [`PolynomialRingRns`] mismatch.
+    fn add(self, other: Self) -> Self::Output {
+        self.add_safe(other).unwrap()
+    }
+}
+
+impl PolynomialRingRns {
+    /// Implements addition for two [`PolynomialRingRns`] values
+    /// by adding their residues for each prime.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the polynomial to add to `self`
+    ///
+    /// Returns the sum of both polynomials as a [`PolynomialRingRns`] or an error if the moduli
+    /// mismatch.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+    /// let a = PolynomialRingRns::from((&PolyOverZ::from_str("4  -1 0 1 1").unwrap(), &modulus));
+    /// let b = PolynomialRingRns::from((&PolyOverZ::from_str("4  2 0 3 1").unwrap(), &modulus));
+    ///
+    /// let c: PolynomialRingRns = a.add_safe(&b).unwrap();
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MathError::MismatchingModulus`] if the moduli of
+    /// both [`PolynomialRingRns`] mismatch.
+    pub fn add_safe(&self, other: &Self) -> Result<PolynomialRingRns, MathError> {
+        if self.modulus != other.modulus {
+            return Err(MathError::MismatchingModulus(format!(
+                " Tried to add polynomials with moduli {:?} and {:?}.",
+                self.modulus, other.modulus
+            )));
+        }
+        let residues = self
+            .residues
+            .iter()
+            .zip(other.residues.iter())
+            .map(|(a, b)| a.add_safe(b))
+            .collect::<Result<_, MathError>>()?;
+        Ok(PolynomialRingRns {
+            residues,
+            modulus: self.modulus.clone(),
+        })
+    }
+}
+
+arithmetic_trait_borrowed_to_owned!(
+    Add,
+    add,
+    PolynomialRingRns,
+    PolynomialRingRns,
+    PolynomialRingRns
+);
+arithmetic_trait_mixed_borrowed_owned!(
+    Add,
+    add,
+    PolynomialRingRns,
+    PolynomialRingRns,
+    PolynomialRingRns
+);
+
+#[cfg(test)]
+mod test_add {
+    use super::PolynomialRingRns;
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingRns},
+    };
+    use std::str::FromStr;
+
+    /// Returns the primes `17` and `41`
+    fn primes() -> [Modulus; 2] {
+        [
+            Modulus::from_str("17").unwrap(),
+            Modulus::from_str("41").unwrap(),
+        ]
+    }
+
+    /// Ensure that the sum matches the sum over the combined modulus
+    #[test]
+    fn add() {
+        let poly_mod = PolyOverZ::from_str("4  1 0 0 1").unwrap();
+        let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes()).unwrap();
+        let a = PolynomialRingRns::from((&PolyOverZ::from_str("3  2 4 10").unwrap(), &modulus));
+        let b = PolynomialRingRns::from((&PolyOverZ::from_str("3  -5 4 690").unwrap(), &modulus));
+
+        let c = a + &b;
+
+        let cmp = PolyOverZ::from_str("3  694 8 3").unwrap();
+        assert_eq!(cmp, c.to_poly_over_z());
+    }
+
+    /// Ensure that mismatching moduli yield an error
+    #[test]
+    fn add_safe_is_err() {
+        let poly_mod = PolyOverZ::from_str("4  1 0 0 1").unwrap();
+        let modulus_1 = ModulusPolynomialRingRns::new(&poly_mod, &primes()).unwrap();
+        let modulus_2 = ModulusPolynomialRingRns::new(&poly_mod, &primes()[..1]).unwrap();
+        let a = PolynomialRingRns::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_1));
+        let b = PolynomialRingRns::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_2));
+
+        assert!(a.add_safe(&b).is_err());
+    }
+
+    /// Ensure that mismatching moduli panic
+    #[test]
+    #[should_panic]
+    fn add_mismatching_modulus() {
+        let poly_mod = PolyOverZ::from_str("4  1 0 0 1").unwrap();
+        let modulus_1 = ModulusPolynomialRingRns::new(&poly_mod, &primes()).unwrap();
+        let modulus_2 = ModulusPolynomialRingRns::new(&poly_mod, &primes()[1..]).unwrap();
+        let a = PolynomialRingRns::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_1));
+        let b = PolynomialRingRns::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_2));
+
+        let _ = a + b;
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementation of the [`Mul`] trait for [`PolynomialRingRns`] values.
+
+use super::super::PolynomialRingRns;
+use crate::{
+    error::MathError,
+    macros::arithmetics::{
+        arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
+    },
+};
+use std::ops::Mul;
+
+impl Mul for &PolynomialRingRns {
+    type Output = PolynomialRingRns;
+    /// Implements the [`Mul`] trait for two [`PolynomialRingRns`] values.
+    /// [`Mul`] is implemented for any combination of [`PolynomialRingRns`]
+    /// and borrowed [`PolynomialRingRns`].
+    ///
+    /// Parameters:
+    /// - `other`: specifies the polynomial to multiply with `self`
+    ///
+    /// Returns the product of both polynomials as a [`PolynomialRingRns`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+    /// let a = PolynomialRingRns::from((&PolyOverZ::from_str("4  -1 0 1 1").unwrap(), &modulus));
+    /// let b = PolynomialRingRns::from((&PolyOverZ::from_str("4  2 0 3 1").unwrap(), &modulus));
+    ///
+    /// let c: PolynomialRingRns = &a * &b;
+    /// let d: PolynomialRingRns = a * b;
+    /// let e: PolynomialRingRns = &c * d;
+    /// let f: PolynomialRingRns = c * &e;
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if the moduli of both [`PolynomialRingRns`] mismatch.
+    fn mul(self, other: Self) -> Self::Output {
+        self.mul_safe(other).unwrap()
+    }
+}
+
+impl PolynomialRingRns {
+    /// Implements multiplication for two [`PolynomialRingRns`] values
+    /// by multiplying their residues for each prime.
+    /// If the moduli of the residues support the NTT, the residues are multiplied
+    /// via the NTT in `O(n log n)`.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the polynomial to multiply with `self`
+    ///
+    /// Returns the product of both polynomials as a [`PolynomialRingRns`] or an error if the moduli
+    /// mismatch.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+    /// let a = PolynomialRingRns::from((&PolyOverZ::from_str("4  -1 0 1 1").unwrap(), &modulus));
+    /// let b = PolynomialRingRns::from((&PolyOverZ::from_str("4  2 0 3 1").unwrap(), &modulus));
+    ///
+    /// let c: PolynomialRingRns = a.mul_safe(&b).unwrap();
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MathError::MismatchingModulus`] if the moduli of
+    /// both [`PolynomialRingRns`] mismatch.
+    pub fn mul_safe(&self, other: &Self) -> Result<PolynomialRingRns, MathError> {
+        if self.modulus != other.modulus {
+            return Err(MathError::MismatchingModulus(format!(
+                " Tried to multiply polynomials with moduli {:?} and {:?}.",
+                self.modulus, other.modulus
+            )));
+        }
+        let residues = self
+            .residues
+            .iter()
+            .zip(other.residues.iter())
+            .map(|(a, b)| a.mul_safe(b))
+            .collect::<Result<_, MathError>>()?;
+        Ok(PolynomialRingRns {
+            residues,
+            modulus: self.modulus.clone(),
+        })
+    }
+}
+
+arithmetic_trait_borrowed_to_owned!(
+    Mul,
+    mul,
+    PolynomialRingRns,
+    PolynomialRingRns,
+    PolynomialRingRns
+);
+arithmetic_trait_mixed_borrowed_owned!(
+    Mul,
+    mul,
+    PolynomialRingRns,
+    PolynomialRingRns,
+    PolynomialRingRns
+);
+
+#[cfg(test)]
+mod test_mul {
+    use super::PolynomialRingRns;
+    use crate::{
+        integer::{PolyOverZ, Z},
+        integer_mod_q::{Modulus, ModulusPolynomialRingRns, Zq},
+        traits::{GetCoefficient, SetCoefficient},
+    };
+    use std::str::FromStr;
+
+    /// Returns the primes `17` and `41`
+    fn primes() -> [Modulus; 2] {
+        [
+            Modulus::from_str("17").unwrap(),
+            Modulus::from_str("41").unwrap(),
+        ]
+    }
+
+    /// Ensure that the product matches the product over the combined modulus
+    #[test]
+    fn mul() {
+        let poly_mod = PolyOverZ::from_str("4  1 0 0 1").unwrap();
+        let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes()).unwrap();
+        let a = PolynomialRingRns::from((&PolyOverZ::from_str("3  2 4 10").unwrap(), &modulus));
+        let b = PolynomialRingRns::from((&PolyOverZ::from_str("3  -5 4 690").unwrap(), &modulus));
+
+        let c = a * &b;
+
+        // (10X^2 + 4X + 2) * (-7X^2 + 4X - 5) = -70X^4 + 12X^3 - 48X^2 - 12X - 10
+        // = -48X^2 + 58X - 22 mod X^3 + 1
+        let cmp = PolyOverZ::from_str("3  675 58 649").unwrap();
+        assert_eq!(cmp, c.to_poly_over_z());
+    }
+
+    /// Ensure that the product is computed via the NTT for NTT-friendly primes
+    /// and matches the product over the combined modulus
+    #[test]
+    fn mul_large_numbers() {
+        let poly_mod = PolyOverZ::from_str("9  1 0 0 0 0 0 0 0 1").unwrap();
+        let primes = [
+            Modulus::from_str("12289").unwrap(),
+            Modulus::from_str("4611686018427322369").unwrap(),
+        ];
+        let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+        let q = modulus.get_q();
+        let poly_1 = PolyOverZ::sample_uniform(7, 0, &q).unwrap();
+        let poly_2 = PolyOverZ::sample_uniform(7, 0, &q).unwrap();
+        let a = PolynomialRingRns::from((&poly_1, &modulus));
+        let b = PolynomialRingRns::from((&poly_2, &modulus));
+
+        let c = &a * b;
+
+        // X^8 = -1 mod X^8 + 1
+        let product = &poly_1 * &poly_2;
+        let mut cmp = PolyOverZ::default();
+        for i in 0..8 {
+            let low: Z = product.get_coeff(i).unwrap();
+            let high: Z = product.get_coeff(i + 8).unwrap();
+            let coeff = Zq::try_from((low - high, q.clone())).unwrap();
+            cmp.set_coeff(i, &coeff.value).unwrap();
+        }
+        assert_eq!(cmp, c.to_poly_over_z());
+    }
+
+    /// Ensure that mismatching moduli yield an error
+    #[test]
+    fn mul_safe_is_err() {
+        let poly_mod = PolyOverZ::from_str("4  1 0 0 1").unwrap();
+        let modulus_1 = ModulusPolynomialRingRns::new(&poly_mod, &primes()).unwrap();
+        let modulus_2 = ModulusPolynomialRingRns::new(&poly_mod, &primes()[..1]).unwrap();
+        let a = PolynomialRingRns::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_1));
+        let b = PolynomialRingRns::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_2));
+
+        assert!(a.mul_safe(&b).is_err());
+    }
+
+    /// Ensure that mismatching moduli panic
+    #[test]
+    #[should_panic]
+    fn mul_mismatching_modulus() {
+        let poly_mod = PolyOverZ::from_str("4  1 0 0 1").unwrap();
+        let modulus_1 = ModulusPolynomialRingRns::new(&poly_mod, &primes()).unwrap();
+        let modulus_2 = ModulusPolynomialRingRns::new(&poly_mod, &primes()[1..]).unwrap();
+        let a = PolynomialRingRns::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_1));
+        let b = PolynomialRingRns::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_2));
+
+        let _ = a * b;
+    }
+}
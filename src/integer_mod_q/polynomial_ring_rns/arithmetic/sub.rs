@@ -0,0 +1,174 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementation of the [`Sub`] trait for [`PolynomialRingRns`] values.
+
+use super::super::PolynomialRingRns;
+use crate::{
+    error::MathError,
+    macros::arithmetics::{
+        arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
+    },
+};
+use std::ops::Sub;
+
+impl Sub for &PolynomialRingRns {
+    type Output = PolynomialRingRns;
+    /// Implements the [`Sub`] trait for two [`PolynomialRingRns`] values.
+    /// [`Sub`] is implemented for any combination of [`PolynomialRingRns`]
+    /// and borrowed [`PolynomialRingRns`].
+    ///
+    /// Parameters:
+    /// - `other`: specifies the polynomial to subtract from `self`
+    ///
+    /// Returns the difference of both polynomials as a [`PolynomialRingRns`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+    /// let a = PolynomialRingRns::from((&PolyOverZ::from_str("4  -1 0 1 1").unwrap(), &modulus));
+    /// let b = PolynomialRingRns::from((&PolyOverZ::from_str("4  2 0 3 1").unwrap(), &modulus));
+    ///
+    /// let c: PolynomialRingRns = &a - &b;
+    /// let d: PolynomialRingRns = a - b;
+    /// let e: PolynomialRingRns = &c - d;
+    /// let f: PolynomialRingRns = c - &e;
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if the moduli of both [`PolynomialRingRns`] mismatch.
+    fn sub(self, other: Self) -> Self::Output {
+        self.sub_safe(other).unwrap()
+    }
+}
+
+impl PolynomialRingRns {
+    /// Implements subtraction for two [`PolynomialRingRns`] values
+    /// by subtracting their residues for each prime.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the polynomial to subtract from `self`
+    ///
+    /// Returns the difference of both polynomials as a [`PolynomialRingRns`] or an error if the moduli
+    /// mismatch.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+    /// let a = PolynomialRingRns::from((&PolyOverZ::from_str("4  -1 0 1 1").unwrap(), &modulus));
+    /// let b = PolynomialRingRns::from((&PolyOverZ::from_str("4  2 0 3 1").unwrap(), &modulus));
+    ///
+    /// let c: PolynomialRingRns = a.sub_safe(&b).unwrap();
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MathError::MismatchingModulus`] if the moduli of
+    /// both [`PolynomialRingRns`] mismatch.
+    pub fn sub_safe(&self, other: &Self) -> Result<PolynomialRingRns, MathError> {
+        if self.modulus != other.modulus {
+            return Err(MathError::MismatchingModulus(format!(
+                " Tried to subtract polynomials with moduli {:?} and {:?}.",
+                self.modulus, other.modulus
+            )));
+        }
+        let residues = self
+            .residues
+            .iter()
+            .zip(other.residues.iter())
+            .map(|(a, b)| a.sub_safe(b))
+            .collect::<Result<_, MathError>>()?;
+        Ok(PolynomialRingRns {
+            residues,
+            modulus: self.modulus.clone(),
+        })
+    }
+}
+
+arithmetic_trait_borrowed_to_owned!(
+    Sub,
+    sub,
+    PolynomialRingRns,
+    PolynomialRingRns,
+    PolynomialRingRns
+);
+arithmetic_trait_mixed_borrowed_owned!(
+    Sub,
+    sub,
+    PolynomialRingRns,
+    PolynomialRingRns,
+    PolynomialRingRns
+);
+
+#[cfg(test)]
+mod test_sub {
+    use super::PolynomialRingRns;
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingRns},
+    };
+    use std::str::FromStr;
+
+    /// Returns the primes `17` and `41`
+    fn primes() -> [Modulus; 2] {
+        [
+            Modulus::from_str("17").unwrap(),
+            Modulus::from_str("41").unwrap(),
+        ]
+    }
+
+    /// Ensure that the difference matches the difference over the combined modulus
+    #[test]
+    fn sub() {
+        let poly_mod = PolyOverZ::from_str("4  1 0 0 1").unwrap();
+        let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes()).unwrap();
+        let a = PolynomialRingRns::from((&PolyOverZ::from_str("3  2 4 10").unwrap(), &modulus));
+        let b = PolynomialRingRns::from((&PolyOverZ::from_str("3  -5 4 690").unwrap(), &modulus));
+
+        let c = a - &b;
+
+        let cmp = PolyOverZ::from_str("3  7 0 17").unwrap();
+        assert_eq!(cmp, c.to_poly_over_z());
+    }
+
+    /// Ensure that mismatching moduli yield an error
+    #[test]
+    fn sub_safe_is_err() {
+        let poly_mod = PolyOverZ::from_str("4  1 0 0 1").unwrap();
+        let modulus_1 = ModulusPolynomialRingRns::new(&poly_mod, &primes()).unwrap();
+        let modulus_2 = ModulusPolynomialRingRns::new(&poly_mod, &primes()[..1]).unwrap();
+        let a = PolynomialRingRns::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_1));
+        let b = PolynomialRingRns::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_2));
+
+        assert!(a.sub_safe(&b).is_err());
+    }
+
+    /// Ensure that mismatching moduli panic
+    #[test]
+    #[should_panic]
+    fn sub_mismatching_modulus() {
+        let poly_mod = PolyOverZ::from_str("4  1 0 0 1").unwrap();
+        let modulus_1 = ModulusPolynomialRingRns::new(&poly_mod, &primes()).unwrap();
+        let modulus_2 = ModulusPolynomialRingRns::new(&poly_mod, &primes()[1..]).unwrap();
+        let a = PolynomialRingRns::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_1));
+        let b = PolynomialRingRns::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_2));
+
+        let _ = a - b;
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the conversion of a [`PolynomialRingRns`]
+//! between different bases of the residue number system.
+
+use super::PolynomialRingRns;
+use crate::{error::MathError, integer_mod_q::ModulusPolynomialRingRns};
+
+impl PolynomialRingRns {
+    /// Converts `self` into the basis of primes of `modulus`, i.e. reconstructs
+    /// the representative of `self` with coefficients in `[0, q)` exactly and
+    /// reduces it modulo each prime of `modulus`.
+    /// If the product of the new primes is at least `q`, the representative is preserved.
+    ///
+    /// Parameters:
+    /// - `modulus`: the modulus with the new basis of primes
+    ///
+    /// Returns `self` in the new basis or an error if the modulus polynomials mismatch.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes_1 = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let primes_2 = [Modulus::from_str("12289").unwrap()];
+    /// let modulus_1 = ModulusPolynomialRingRns::new(&poly_mod, &primes_1).unwrap();
+    /// let modulus_2 = ModulusPolynomialRingRns::new(&poly_mod, &primes_2).unwrap();
+    /// let poly = PolyOverZ::from_str("4  -1 0 500 1").unwrap();
+    /// let poly_ring = PolynomialRingRns::from((&poly, &modulus_1));
+    ///
+    /// let switched = poly_ring.switch_basis(&modulus_2).unwrap();
+    ///
+    /// assert_eq!(poly_ring.to_poly_over_z(), switched.to_poly_over_z());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the modulus polynomials f(X) of `self` and `modulus` mismatch.
+    pub fn switch_basis(&self, modulus: &ModulusPolynomialRingRns) -> Result<Self, MathError> {
+        if self.modulus.get_poly() != modulus.get_poly() {
+            return Err(MathError::MismatchingModulus(format!(
+                " Tried to switch from modulus polynomial '{}' to '{}'.",
+                self.modulus.get_poly(),
+                modulus.get_poly()
+            )));
+        }
+        Ok(Self::from((&self.to_poly_over_z(), modulus)))
+    }
+}
+
+#[cfg(test)]
+mod test_switch_basis {
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that the representative is preserved for a larger basis
+    #[test]
+    fn larger_basis() {
+        let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+        let primes_1 = [
+            Modulus::from_str("17").unwrap(),
+            Modulus::from_str("41").unwrap(),
+        ];
+        let primes_2 = [
+            Modulus::from_str("12289").unwrap(),
+            Modulus::from_str(&(u64::MAX - 58).to_string()).unwrap(),
+        ];
+        let modulus_1 = ModulusPolynomialRingRns::new(&poly_mod, &primes_1).unwrap();
+        let modulus_2 = ModulusPolynomialRingRns::new(&poly_mod, &primes_2).unwrap();
+        let poly_ring =
+            PolynomialRingRns::from((&PolyOverZ::from_str("4  -1 2 -3 4").unwrap(), &modulus_1));
+
+        let switched = poly_ring.switch_basis(&modulus_2).unwrap();
+
+        assert_eq!(modulus_2, switched.get_mod());
+        assert_eq!(
+            PolyOverZ::from_str("4  696 2 694 4").unwrap(),
+            switched.to_poly_over_z()
+        );
+    }
+
+    /// Ensure that the representative is reduced for a smaller basis
+    #[test]
+    fn smaller_basis() {
+        let poly_mod = PolyOverZ::from_str("3  1 0 1").unwrap();
+        let primes_1 = [
+            Modulus::from_str("17").unwrap(),
+            Modulus::from_str("41").unwrap(),
+        ];
+        let primes_2 = [Modulus::from_str("41").unwrap()];
+        let modulus_1 = ModulusPolynomialRingRns::new(&poly_mod, &primes_1).unwrap();
+        let modulus_2 = ModulusPolynomialRingRns::new(&poly_mod, &primes_2).unwrap();
+        let poly_ring =
+            PolynomialRingRns::from((&PolyOverZ::from_str("2  500 1").unwrap(), &modulus_1));
+
+        let switched = poly_ring.switch_basis(&modulus_2).unwrap();
+
+        assert_eq!(
+            PolyOverZ::from_str("2  8 1").unwrap(),
+            switched.to_poly_over_z()
+        );
+    }
+
+    /// Ensure that mismatching modulus polynomials yield an error
+    #[test]
+    fn mismatching_poly() {
+        let primes = [Modulus::from_str("17").unwrap()];
+        let modulus_1 =
+            ModulusPolynomialRingRns::new(&PolyOverZ::from_str("3  1 0 1").unwrap(), &primes)
+                .unwrap();
+        let modulus_2 =
+            ModulusPolynomialRingRns::new(&PolyOverZ::from_str("3  2 0 1").unwrap(), &primes)
+                .unwrap();
+        let poly_ring =
+            PolynomialRingRns::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_1));
+
+        assert!(poly_ring.switch_basis(&modulus_2).is_err());
+    }
+}
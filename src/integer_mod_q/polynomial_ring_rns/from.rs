@@ -0,0 +1,208 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementations to create a [`PolynomialRingRns`] value from other types.
+//! For each reasonable type, an explicit function with the format
+//! `from_<type_name>` and the [`From`] trait should be implemented.
+//!
+//! The explicit functions contain the documentation.
+
+use super::PolynomialRingRns;
+use crate::{
+    error::MathError,
+    integer::PolyOverZ,
+    integer_mod_q::{ModulusPolynomialRingRns, PolynomialRingZq},
+};
+
+impl From<(&PolyOverZ, &ModulusPolynomialRingRns)> for PolynomialRingRns {
+    /// Create a new polynomial ring element of type [`PolynomialRingRns`].
+    ///
+    /// Parameters:
+    /// - `value`: is a tuple of `(poly, modulus)`
+    ///     - `poly`: defines the polynomial
+    ///     - `modulus`: the modulus which defines the ring
+    ///
+    /// Returns a new element inside the polynomial ring.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+    ///
+    /// let poly = PolyOverZ::from_str("4  -1 0 500 1").unwrap();
+    /// let poly_ring = PolynomialRingRns::from((&poly, &modulus));
+    /// ```
+    fn from(value: (&PolyOverZ, &ModulusPolynomialRingRns)) -> Self {
+        Self::from_poly_over_z_modulus_polynomial_ring_rns(value.0, value.1)
+    }
+}
+
+impl PolynomialRingRns {
+    /// Create a new polynomial ring object of type [`PolynomialRingRns`]
+    /// by reducing `poly` modulo each prime `q_i` and f(X).
+    ///
+    /// Parameters:
+    /// - `poly`: the polynomial
+    /// - `modulus`: the modulus which defines the ring
+    ///
+    /// Returns a new element inside the polynomial ring.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+    ///
+    /// let poly = PolyOverZ::from_str("4  -1 0 500 1").unwrap();
+    /// let poly_ring = PolynomialRingRns::from_poly_over_z_modulus_polynomial_ring_rns(&poly, &modulus);
+    /// ```
+    pub fn from_poly_over_z_modulus_polynomial_ring_rns(
+        poly: &PolyOverZ,
+        modulus: &ModulusPolynomialRingRns,
+    ) -> Self {
+        let residues = modulus
+            .get_moduli()
+            .iter()
+            .map(|modulus| PolynomialRingZq::from((poly, modulus)))
+            .collect();
+        Self {
+            residues,
+            modulus: modulus.clone(),
+        }
+    }
+
+    /// Create a new polynomial ring object of type [`PolynomialRingRns`]
+    /// from its residues for each prime `q_i`.
+    ///
+    /// Parameters:
+    /// - `residues`: the residues of the element, which have to be defined over
+    /// the moduli of `modulus` in the same order
+    /// - `modulus`: the modulus which defines the ring
+    ///
+    /// Returns a new element inside the polynomial ring or an error
+    /// if the moduli of the residues do not match `modulus`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns, PolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+    /// let residues = vec![
+    ///     PolynomialRingZq::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus.get_moduli()[0])),
+    ///     PolynomialRingZq::from((&PolyOverZ::from_str("2  2 1").unwrap(), &modulus.get_moduli()[1])),
+    /// ];
+    ///
+    /// let poly_ring = PolynomialRingRns::from_residues(residues, &modulus).unwrap();
+    ///
+    /// assert_eq!(PolyOverZ::from_str("2  494 1").unwrap(), poly_ring.to_poly_over_z());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MismatchingModulus`](MathError::MismatchingModulus)
+    /// if the number of residues or their moduli do not match `modulus`.
+    pub fn from_residues(
+        residues: Vec<PolynomialRingZq>,
+        modulus: &ModulusPolynomialRingRns,
+    ) -> Result<Self, MathError> {
+        if residues.len() != modulus.get_moduli().len()
+            || residues
+                .iter()
+                .zip(modulus.get_moduli())
+                .any(|(residue, modulus)| &residue.get_mod() != modulus)
+        {
+            return Err(MathError::MismatchingModulus(format!(
+                " The {} residues are not defined over the moduli of {:?}.",
+                residues.len(),
+                modulus
+            )));
+        }
+        Ok(Self {
+            residues,
+            modulus: modulus.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_from_poly_over_z_modulus_polynomial_ring_rns {
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns, PolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that each residue is reduced by its prime and f(X)
+    #[test]
+    fn residues() {
+        let poly_mod = PolyOverZ::from_str("4  1 0 0 1").unwrap();
+        let primes = [
+            Modulus::from_str("17").unwrap(),
+            Modulus::from_str(&(u64::MAX - 58).to_string()).unwrap(),
+        ];
+        let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+        let poly = PolyOverZ::from_str(&format!("5  -1 0 1 {} 1", u64::MAX)).unwrap();
+
+        let poly_ring = PolynomialRingRns::from((&poly, &modulus));
+
+        for (residue, modulus) in poly_ring.get_residues().iter().zip(modulus.get_moduli()) {
+            assert_eq!(&PolynomialRingZq::from((&poly, modulus)), residue);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_from_residues {
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{
+            Modulus, ModulusPolynomialRingRns, ModulusPolynomialRingZq, PolynomialRingRns,
+            PolynomialRingZq,
+        },
+    };
+    use std::str::FromStr;
+
+    /// Ensure that residues with mismatching moduli yield an error
+    #[test]
+    fn mismatching_modulus() {
+        let poly_mod = PolyOverZ::from_str("3  1 0 1").unwrap();
+        let primes = [
+            Modulus::from_str("17").unwrap(),
+            Modulus::from_str("41").unwrap(),
+        ];
+        let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+        let other = ModulusPolynomialRingZq::from_str("3  1 0 1 mod 19").unwrap();
+        let poly = PolyOverZ::from_str("2  1 1").unwrap();
+
+        let too_few = vec![PolynomialRingZq::from((&poly, &modulus.get_moduli()[0]))];
+        let wrong_order = vec![
+            PolynomialRingZq::from((&poly, &modulus.get_moduli()[1])),
+            PolynomialRingZq::from((&poly, &modulus.get_moduli()[0])),
+        ];
+        let wrong_prime = vec![
+            PolynomialRingZq::from((&poly, &modulus.get_moduli()[0])),
+            PolynomialRingZq::from((&poly, &other)),
+        ];
+
+        assert!(PolynomialRingRns::from_residues(too_few, &modulus).is_err());
+        assert!(PolynomialRingRns::from_residues(wrong_order, &modulus).is_err());
+        assert!(PolynomialRingRns::from_residues(wrong_prime, &modulus).is_err());
+    }
+}
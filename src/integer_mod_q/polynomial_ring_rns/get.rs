@@ -0,0 +1,158 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementations to get content of a [`PolynomialRingRns`].
+
+use super::PolynomialRingRns;
+use crate::{
+    integer::PolyOverZ,
+    integer_mod_q::{ModulusPolynomialRingRns, PolynomialRingZq, Zq},
+    traits::{GetCoefficient, SetCoefficient},
+};
+
+impl PolynomialRingRns {
+    /// Returns the residues of the element for each prime `q_i`
+    /// in the order of the primes of the modulus.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns, PolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+    /// let poly = PolyOverZ::from_str("2  100 1").unwrap();
+    /// let poly_ring = PolynomialRingRns::from((&poly, &modulus));
+    ///
+    /// let residues = poly_ring.get_residues();
+    ///
+    /// let cmp = PolyOverZ::from_str("2  15 1").unwrap();
+    /// assert_eq!(PolynomialRingZq::from((&cmp, &modulus.get_moduli()[0])), residues[0]);
+    /// ```
+    pub fn get_residues(&self) -> &[PolynomialRingZq] {
+        &self.residues
+    }
+
+    /// Returns the modulus object of the [`PolynomialRingRns`] element.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+    /// let poly_ring = PolynomialRingRns::from((&PolyOverZ::default(), &modulus));
+    ///
+    /// assert_eq!(modulus, poly_ring.get_mod());
+    /// ```
+    pub fn get_mod(&self) -> ModulusPolynomialRingRns {
+        self.modulus.clone()
+    }
+
+    /// Reconstructs the unique representative of the element with coefficients
+    /// in `[0, q)` from its residues using the Chinese Remainder Theorem.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+    /// let poly = PolyOverZ::from_str("4  -1 0 500 1").unwrap();
+    /// let poly_ring = PolynomialRingRns::from((&poly, &modulus));
+    ///
+    /// assert_eq!(PolyOverZ::from_str("4  696 0 500 1").unwrap(), poly_ring.to_poly_over_z());
+    /// ```
+    pub fn to_poly_over_z(&self) -> PolyOverZ {
+        let mut out = PolyOverZ::default();
+        for index in 0..self.modulus.get_degree() {
+            let coeffs: Vec<Zq> = self
+                .residues
+                .iter()
+                .map(|residue| residue.get_coeff(index).unwrap())
+                .collect();
+            // the primes are distinct, hence pairwise coprime
+            let coeff = Zq::crt_combine(&coeffs).unwrap();
+            out.set_coeff(index, &coeff.value).unwrap();
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_to_poly_over_z {
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that the reconstruction is exact for a modulus with more than 128 bits
+    #[test]
+    fn large_modulus() {
+        let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+        let primes = [
+            Modulus::from_str("12289").unwrap(),
+            Modulus::from_str(&(u64::MAX - 58).to_string()).unwrap(),
+            Modulus::from_str("4611686018427322369").unwrap(),
+        ];
+        let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+        let poly =
+            PolyOverZ::from_str(&format!("4  {} 0 {}{} 1", u128::MAX, u64::MAX, u64::MAX)).unwrap();
+
+        let poly_ring = PolynomialRingRns::from((&poly, &modulus));
+
+        assert_eq!(poly, poly_ring.to_poly_over_z());
+    }
+
+    /// Ensure that the reconstruction reduces the coefficients into `[0, q)`
+    #[test]
+    fn reduced() {
+        let poly_mod = PolyOverZ::from_str("4  1 0 0 1").unwrap();
+        let primes = [
+            Modulus::from_str("17").unwrap(),
+            Modulus::from_str("41").unwrap(),
+        ];
+        let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+        // X^3 = -1 mod X^3 + 1
+        let poly = PolyOverZ::from_str("4  -2 697 698 1").unwrap();
+
+        let poly_ring = PolynomialRingRns::from((&poly, &modulus));
+
+        assert_eq!(
+            PolyOverZ::from_str("3  694 0 1").unwrap(),
+            poly_ring.to_poly_over_z()
+        );
+    }
+
+    /// Ensure that the residues in the NTT domain are reconstructed correctly
+    #[test]
+    fn ntt_friendly() {
+        let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+        let primes = [
+            Modulus::from_str("17").unwrap(),
+            Modulus::from_str("41").unwrap(),
+            Modulus::from_str("12289").unwrap(),
+        ];
+        let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+        let poly = PolyOverZ::from_str("4  1 2 3 4").unwrap();
+        let a = PolynomialRingRns::from((&poly, &modulus));
+
+        let b = a.to_ntt_domain().unwrap();
+
+        assert_eq!(poly, b.to_poly_over_z());
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the conversions of the residues of a [`PolynomialRingRns`]
+//! between the coefficient domain and the evaluation domain of the
+//! number theoretic transform (NTT).
+
+use super::PolynomialRingRns;
+use crate::error::MathError;
+
+impl PolynomialRingRns {
+    /// Returns `self` with all residues in the evaluation domain of the
+    /// number theoretic transform (NTT), see [`PolynomialRingZq::to_ntt_domain`](crate::integer_mod_q::PolynomialRingZq::to_ntt_domain).
+    ///
+    /// Returns `self` in the NTT domain or an error if the NTT is not supported
+    /// for one of the primes.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+    /// let poly_ring = PolynomialRingRns::from((&PolyOverZ::from_str("2  1 2").unwrap(), &modulus));
+    ///
+    /// let poly_ntt = poly_ring.to_ntt_domain().unwrap();
+    ///
+    /// assert_eq!(poly_ring, poly_ntt);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NttNotSupported`](MathError::NttNotSupported)
+    /// if the NTT is not supported for one of the primes.
+    pub fn to_ntt_domain(&self) -> Result<Self, MathError> {
+        let residues = self
+            .residues
+            .iter()
+            .map(|residue| residue.to_ntt_domain())
+            .collect::<Result<_, MathError>>()?;
+        Ok(Self {
+            residues,
+            modulus: self.modulus.clone(),
+        })
+    }
+
+    /// Returns `self` with all residues in the coefficient domain.
+    /// This is the inverse operation of [`PolynomialRingRns::to_ntt_domain`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns};
+    /// use std::str::FromStr;
+    ///
+    /// let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+    /// let primes = [Modulus::from_str("17").unwrap(), Modulus::from_str("41").unwrap()];
+    /// let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+    /// let poly_ring = PolynomialRingRns::from((&PolyOverZ::from_str("2  1 2").unwrap(), &modulus));
+    ///
+    /// let poly_coeff = poly_ring.to_ntt_domain().unwrap().to_coeff_domain();
+    ///
+    /// assert_eq!(poly_ring, poly_coeff);
+    /// ```
+    pub fn to_coeff_domain(&self) -> Self {
+        Self {
+            residues: self
+                .residues
+                .iter()
+                .map(|residue| residue.to_coeff_domain())
+                .collect(),
+            modulus: self.modulus.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_ntt_domain {
+    use crate::{
+        error::MathError,
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingRns, PolynomialRingRns},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that all residues are converted into the NTT domain and back
+    #[test]
+    fn round_trip() {
+        let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+        let primes = [
+            Modulus::from_str("17").unwrap(),
+            Modulus::from_str("12289").unwrap(),
+        ];
+        let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+        let poly_ring =
+            PolynomialRingRns::from((&PolyOverZ::from_str("4  1 2 3 4").unwrap(), &modulus));
+
+        let poly_ntt = poly_ring.to_ntt_domain().unwrap();
+        let poly_coeff = poly_ntt.to_coeff_domain();
+
+        assert!(poly_ntt.get_residues().iter().all(|r| r.is_ntt_domain()));
+        assert!(poly_coeff.get_residues().iter().all(|r| !r.is_ntt_domain()));
+        assert_eq!(poly_ring, poly_coeff);
+    }
+
+    /// Ensure that an error is returned if one of the primes does not support the NTT
+    #[test]
+    fn not_supported() {
+        let poly_mod = PolyOverZ::from_str("5  1 0 0 0 1").unwrap();
+        let primes = [
+            Modulus::from_str("17").unwrap(),
+            Modulus::from_str("19").unwrap(),
+        ];
+        let modulus = ModulusPolynomialRingRns::new(&poly_mod, &primes).unwrap();
+        let poly_ring =
+            PolynomialRingRns::from((&PolyOverZ::from_str("4  1 2 3 4").unwrap(), &modulus));
+
+        assert!(matches!(
+            poly_ring.to_ntt_domain(),
+            Err(MathError::NttNotSupported(_))
+        ));
+    }
+}
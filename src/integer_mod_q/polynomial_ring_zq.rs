@@ -19,10 +19,18 @@
 
 use super::ModulusPolynomialRingZq;
 use crate::integer::PolyOverZ;
-use serde::{Deserialize, Serialize};
 
+mod arithmetic;
+mod cmp;
 mod from;
+mod get;
+mod norm;
+mod ntt;
 mod reduce;
+mod rotation;
+mod sample;
+mod serialize;
+mod set;
 
 /// [`PolynomialRingZq`] represents polynomials over the finite field
 /// [`PolyOverZq`](crate::integer_mod_q::PolyOverZq)/f(X) where `q` is prime and f(X) is a polynomial over [`Zq`](super::Zq).
@@ -30,10 +38,13 @@ mod reduce;
 /// Attributes
 /// - `poly`: holds the value
 /// - `modulus`: holds the prime `q` and f(X)
+/// - `ntt_domain`: defines whether `poly` holds the coefficients or
+/// the evaluations of the number theoretic transform (NTT)
 ///
 /// # Example
-#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Debug)]
 pub struct PolynomialRingZq {
     poly: PolyOverZ,
     modulus: ModulusPolynomialRingZq,
+    ntt_domain: bool,
 }
@@ -0,0 +1,279 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementation of the [`Mul`] trait for [`PolynomialRingZq`] values.
+
+use super::super::PolynomialRingZq;
+use crate::{
+    error::MathError,
+    integer::PolyOverZ,
+    macros::arithmetics::{
+        arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
+    },
+};
+use flint_sys::fq::fq_mul;
+use std::ops::Mul;
+
+impl Mul for &PolynomialRingZq {
+    type Output = PolynomialRingZq;
+    /// Implements the [`Mul`] trait for two [`PolynomialRingZq`] values.
+    /// [`Mul`] is implemented for any combination of [`PolynomialRingZq`]
+    /// and borrowed [`PolynomialRingZq`].
+    ///
+    /// Parameters:
+    /// - `other`: specifies the polynomial to multiply with `self`
+    ///
+    /// Returns the product of both polynomials as a [`PolynomialRingZq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly_1 = PolyOverZ::from_str("4  -1 0 1 1").unwrap();
+    /// let a = PolynomialRingZq::from((&poly_1, &modulus));
+    /// let poly_2 = PolyOverZ::from_str("4  2 0 3 1").unwrap();
+    /// let b = PolynomialRingZq::from((&poly_2, &modulus));
+    ///
+    /// let c: PolynomialRingZq = &a * &b;
+    /// let d: PolynomialRingZq = a * b;
+    /// let e: PolynomialRingZq = &c * d;
+    /// let f: PolynomialRingZq = c * &e;
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if the moduli of both [`PolynomialRingZq`] mismatch.
+    fn mul(self, other: Self) -> Self::Output {
+        self.mul_safe(other).unwrap()
+    }
+}
+
+impl PolynomialRingZq {
+    /// Implements multiplication for two [`PolynomialRingZq`] values.
+    /// If the modulus is of the form `X^n + 1` for a power of two `n` and
+    /// `q = 1 mod 2n`, the product is computed via the number theoretic transform (NTT)
+    /// in `O(n log n)` using the twiddle factors cached in the modulus.
+    /// If any of both values is in the NTT domain, the product is computed
+    /// entrywise and returned in the NTT domain.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the polynomial to multiply to `self`
+    ///
+    /// Returns the product of both polynomials as a [`PolynomialRingZq`] or an error if the moduli
+    /// mismatch.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let q = Modulus::from_str("12289").unwrap();
+    /// let modulus = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(1024, &q).unwrap();
+    /// let poly_1 = PolyOverZ::sample_uniform(1023, 0, 12289).unwrap();
+    /// let a = PolynomialRingZq::from((&poly_1, &modulus));
+    /// let poly_2 = PolyOverZ::sample_uniform(1023, 0, 12289).unwrap();
+    /// let b = PolynomialRingZq::from((&poly_2, &modulus));
+    ///
+    /// let c: PolynomialRingZq = a.mul_safe(&b).unwrap();
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MathError::MismatchingModulus`] if the moduli of
+    /// both [`PolynomialRingZq`] mismatch.
+    pub fn mul_safe(&self, other: &Self) -> Result<PolynomialRingZq, MathError> {
+        if self.modulus != other.modulus {
+            return Err(MathError::MismatchingModulus(format!(
+                " Tried to multiply polynomials with moduli '{}' and '{}'.",
+                self.modulus, other.modulus
+            )));
+        }
+        match self.modulus.get_ntt_tables() {
+            Some(ntt) if self.ntt_domain || other.ntt_domain => Ok(PolynomialRingZq {
+                poly: ntt.multiply_pointwise(
+                    &self.to_ntt_domain().unwrap().poly,
+                    &other.to_ntt_domain().unwrap().poly,
+                ),
+                modulus: self.modulus.clone(),
+                ntt_domain: true,
+            }),
+            Some(ntt) => Ok(PolynomialRingZq {
+                poly: ntt.multiply(&self.poly, &other.poly),
+                modulus: self.modulus.clone(),
+                ntt_domain: false,
+            }),
+            None => Ok(self.mul_fq(other)),
+        }
+    }
+
+    /// Multiplies two [`PolynomialRingZq`] values with the same modulus
+    /// using [FLINT](https://flintlib.org/)'s multiplication and reduction.
+    fn mul_fq(&self, other: &Self) -> PolynomialRingZq {
+        let mut out = PolynomialRingZq {
+            poly: PolyOverZ::default(),
+            modulus: self.modulus.clone(),
+            ntt_domain: false,
+        };
+        unsafe {
+            fq_mul(
+                &mut out.poly.poly,
+                &self.poly.poly,
+                &other.poly.poly,
+                self.modulus.get_fq_ctx_struct(),
+            )
+        };
+        out
+    }
+}
+
+arithmetic_trait_borrowed_to_owned!(
+    Mul,
+    mul,
+    PolynomialRingZq,
+    PolynomialRingZq,
+    PolynomialRingZq
+);
+arithmetic_trait_mixed_borrowed_owned!(
+    Mul,
+    mul,
+    PolynomialRingZq,
+    PolynomialRingZq,
+    PolynomialRingZq
+);
+
+#[cfg(test)]
+mod test_mul {
+    use super::PolynomialRingZq;
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// testing multiplication for two [`PolynomialRingZq`]
+    #[test]
+    fn mul() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let a = PolynomialRingZq::from((&PolyOverZ::from_str("3  2 4 1").unwrap(), &modulus));
+        let b = PolynomialRingZq::from((&PolyOverZ::from_str("2  2 4").unwrap(), &modulus));
+
+        let c = a * &b;
+
+        // (X^2 + 4X + 2) * (4X + 2) = 4X^3 + 18X^2 + 16X + 4 = 18X^2 + 16X
+        let cmp = PolynomialRingZq::from((&PolyOverZ::from_str("3  0 16 1").unwrap(), &modulus));
+        assert_eq!(cmp, c);
+    }
+
+    /// testing multiplication for large [`PolynomialRingZq`]
+    #[test]
+    fn mul_large_numbers() {
+        let modulus =
+            ModulusPolynomialRingZq::from_str(&format!("3  1 0 1 mod {}", u64::MAX - 58)).unwrap();
+        let a = PolynomialRingZq::from((&PolyOverZ::from_str("2  0 1").unwrap(), &modulus));
+        let b = PolynomialRingZq::from((
+            &PolyOverZ::from_str(&format!("2  {} {}", i64::MAX, u64::MAX)).unwrap(),
+            &modulus,
+        ));
+
+        let c = &a * &b;
+
+        // X * (58X + i64::MAX) = 58 X^2 + i64::MAX X = i64::MAX X - 58
+        let cmp = PolynomialRingZq::from((
+            &PolyOverZ::from_str(&format!("2  -58 {}", i64::MAX)).unwrap(),
+            &modulus,
+        ));
+        assert_eq!(cmp, c);
+    }
+
+    /// Ensure that the NTT is used for suitable moduli and
+    /// matches the product computed by FLINT
+    #[test]
+    fn ntt_matches_fq() {
+        for (n, q) in [
+            (1, 17),
+            (8, 17),
+            (1024, 12289),
+            (64, 4611686018427322369_u64),
+        ] {
+            let modulus = Modulus::from_str(&q.to_string()).unwrap();
+            let modulus =
+                ModulusPolynomialRingZq::new_power_of_two_cyclotomic(n, &modulus).unwrap();
+            let a = PolynomialRingZq::from((
+                &PolyOverZ::sample_uniform(n - 1, 0, q).unwrap(),
+                &modulus,
+            ));
+            let b = PolynomialRingZq::from((
+                &PolyOverZ::sample_uniform(n - 1, 0, q).unwrap(),
+                &modulus,
+            ));
+
+            assert!(modulus.get_ntt_tables().is_some());
+            assert_eq!(a.mul_fq(&b), &a * &b);
+        }
+    }
+
+    /// Ensure that moduli without NTT support fall back to FLINT's multiplication
+    #[test]
+    fn no_ntt() {
+        let modulus = ModulusPolynomialRingZq::from_str("5  1 0 0 0 1 mod 19").unwrap();
+        let a = PolynomialRingZq::from((&PolyOverZ::from_str("4  1 2 3 4").unwrap(), &modulus));
+        let b = PolynomialRingZq::from((&PolyOverZ::from_str("4  5 6 7 8").unwrap(), &modulus));
+
+        assert!(modulus.get_ntt_tables().is_none());
+        assert_eq!(a.mul_fq(&b), a * b);
+    }
+
+    /// testing multiplication for [`PolynomialRingZq`] with different moduli does not work
+    #[test]
+    #[should_panic]
+    fn mul_mismatching_modulus() {
+        let modulus_1 = ModulusPolynomialRingZq::from_str("3  1 0 1 mod 17").unwrap();
+        let modulus_2 = ModulusPolynomialRingZq::from_str("3  1 0 1 mod 19").unwrap();
+        let a = PolynomialRingZq::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_1));
+        let b = PolynomialRingZq::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_2));
+
+        let _ = a * b;
+    }
+
+    /// testing whether mul_safe throws an error for mismatching moduli
+    #[test]
+    fn mul_safe_is_err() {
+        let modulus_1 = ModulusPolynomialRingZq::from_str("3  1 0 1 mod 17").unwrap();
+        let modulus_2 = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let a = PolynomialRingZq::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_1));
+        let b = PolynomialRingZq::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_2));
+
+        assert!(a.mul_safe(&b).is_err());
+    }
+
+    /// Ensure that products in the NTT domain stay in the NTT domain
+    /// and match the product in the coefficient domain
+    #[test]
+    fn ntt_domain() {
+        let modulus = Modulus::from_str("12289").unwrap();
+        let modulus = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(512, &modulus).unwrap();
+        let a =
+            PolynomialRingZq::from((&PolyOverZ::sample_uniform(511, 0, 12289).unwrap(), &modulus));
+        let b =
+            PolynomialRingZq::from((&PolyOverZ::sample_uniform(511, 0, 12289).unwrap(), &modulus));
+        let cmp = &(&a * &b) * &b;
+
+        let a_ntt = a.to_ntt_domain().unwrap();
+        let b_ntt = b.to_ntt_domain().unwrap();
+        let c = &(&a_ntt * &b_ntt) * &b;
+        let d = &(&a * &b_ntt) * &b;
+
+        assert!(c.is_ntt_domain());
+        assert!(d.is_ntt_domain());
+        assert_eq!(cmp, c);
+        assert_eq!(cmp.poly, c.to_coeff_domain().poly);
+        assert_eq!(cmp, d);
+    }
+}
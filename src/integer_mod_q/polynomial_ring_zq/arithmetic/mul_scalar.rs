@@ -0,0 +1,278 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementation of scalar multiplication for [`PolynomialRingZq`] values.
+
+use super::super::PolynomialRingZq;
+use crate::{
+    error::MathError,
+    integer::{PolyOverZ, Z},
+    integer_mod_q::Zq,
+    macros::{
+        arithmetics::{arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned},
+        for_others::implement_for_others,
+    },
+};
+use flint_sys::fq::fq_mul_fmpz;
+use std::ops::Mul;
+
+impl Mul<&Z> for &PolynomialRingZq {
+    type Output = PolynomialRingZq;
+    /// Implements multiplication for a [`PolynomialRingZq`] with a [`Z`] integer.
+    /// The result is in the same domain as `self`.
+    ///
+    /// Parameters:
+    /// - `scalar`: specifies the scalar by which the polynomial is multiplied
+    ///
+    /// Returns the product of `self` and `scalar` as a [`PolynomialRingZq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("4  -1 0 1 1").unwrap();
+    /// let a = PolynomialRingZq::from((&poly, &modulus));
+    /// let integer = Z::from(3);
+    ///
+    /// let b: PolynomialRingZq = &a * &integer;
+    /// let c: PolynomialRingZq = &integer * &a;
+    /// let d: PolynomialRingZq = a * 3;
+    /// ```
+    fn mul(self, scalar: &Z) -> Self::Output {
+        // the multiplication is performed entrywise, hence it is valid in both domains
+        let mut out = PolynomialRingZq {
+            poly: PolyOverZ::default(),
+            modulus: self.modulus.clone(),
+            ntt_domain: self.ntt_domain,
+        };
+        unsafe {
+            fq_mul_fmpz(
+                &mut out.poly.poly,
+                &self.poly.poly,
+                &scalar.value,
+                self.modulus.get_fq_ctx_struct(),
+            )
+        };
+        out
+    }
+}
+
+impl Mul<&PolynomialRingZq> for &Z {
+    type Output = PolynomialRingZq;
+    /// Implements multiplication for a [`Z`] integer with a [`PolynomialRingZq`].
+    ///
+    /// Parameters:
+    /// - `poly`: specifies the polynomial which is multiplied by the given scalar
+    ///
+    /// Returns the product of `self` and `poly` as a [`PolynomialRingZq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("4  -1 0 1 1").unwrap();
+    /// let a = PolynomialRingZq::from((&poly, &modulus));
+    /// let integer = Z::from(3);
+    ///
+    /// let b: PolynomialRingZq = &integer * &a;
+    /// ```
+    fn mul(self, poly: &PolynomialRingZq) -> Self::Output {
+        poly.mul(self)
+    }
+}
+
+impl Mul<&Zq> for &PolynomialRingZq {
+    type Output = PolynomialRingZq;
+    /// Implements multiplication for a [`PolynomialRingZq`] with a [`Zq`].
+    ///
+    /// Parameters:
+    /// - `scalar`: specifies the scalar by which the polynomial is multiplied
+    ///
+    /// Returns the product of `self` and `scalar` as a [`PolynomialRingZq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq, Zq};
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("4  -1 0 1 1").unwrap();
+    /// let a = PolynomialRingZq::from((&poly, &modulus));
+    /// let scalar = Zq::try_from((3, 17)).unwrap();
+    ///
+    /// let b: PolynomialRingZq = &a * &scalar;
+    /// let c: PolynomialRingZq = scalar * a;
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if the moduli of `self` and `scalar` mismatch.
+    fn mul(self, scalar: &Zq) -> Self::Output {
+        self.mul_scalar_zq_safe(scalar).unwrap()
+    }
+}
+
+impl Mul<&PolynomialRingZq> for &Zq {
+    type Output = PolynomialRingZq;
+    /// Implements multiplication for a [`Zq`] with a [`PolynomialRingZq`].
+    ///
+    /// Parameters:
+    /// - `poly`: specifies the polynomial which is multiplied by the given scalar
+    ///
+    /// Returns the product of `self` and `poly` as a [`PolynomialRingZq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq, Zq};
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("4  -1 0 1 1").unwrap();
+    /// let a = PolynomialRingZq::from((&poly, &modulus));
+    /// let scalar = Zq::try_from((3, 17)).unwrap();
+    ///
+    /// let b: PolynomialRingZq = &scalar * &a;
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if the moduli of `self` and `poly` mismatch.
+    fn mul(self, poly: &PolynomialRingZq) -> Self::Output {
+        poly.mul(self)
+    }
+}
+
+impl PolynomialRingZq {
+    /// Implements multiplication for a [`PolynomialRingZq`] with a [`Zq`].
+    ///
+    /// Parameters:
+    /// - `scalar`: specifies the scalar by which the polynomial is multiplied
+    ///
+    /// Returns the product of `self` and `scalar` as a [`PolynomialRingZq`]
+    /// or an error if the moduli mismatch.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq, Zq};
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("4  -1 0 1 1").unwrap();
+    /// let a = PolynomialRingZq::from((&poly, &modulus));
+    /// let scalar = Zq::try_from((3, 17)).unwrap();
+    ///
+    /// let b: PolynomialRingZq = a.mul_scalar_zq_safe(&scalar).unwrap();
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MathError::MismatchingModulus`] if the moduli of
+    /// `self` and `scalar` mismatch.
+    pub fn mul_scalar_zq_safe(&self, scalar: &Zq) -> Result<PolynomialRingZq, MathError> {
+        let q = self.modulus.get_q();
+        if Z::from(&scalar.modulus) != q {
+            return Err(MathError::MismatchingModulus(format!(
+                " Tried to multiply polynomial with modulus '{}' and scalar with modulus '{}'.",
+                self.modulus, scalar.modulus
+            )));
+        }
+        Ok(self * &scalar.value)
+    }
+}
+
+arithmetic_trait_borrowed_to_owned!(Mul, mul, PolynomialRingZq, Z, PolynomialRingZq);
+arithmetic_trait_borrowed_to_owned!(Mul, mul, Z, PolynomialRingZq, PolynomialRingZq);
+arithmetic_trait_mixed_borrowed_owned!(Mul, mul, PolynomialRingZq, Z, PolynomialRingZq);
+arithmetic_trait_mixed_borrowed_owned!(Mul, mul, Z, PolynomialRingZq, PolynomialRingZq);
+arithmetic_trait_borrowed_to_owned!(Mul, mul, PolynomialRingZq, Zq, PolynomialRingZq);
+arithmetic_trait_borrowed_to_owned!(Mul, mul, Zq, PolynomialRingZq, PolynomialRingZq);
+arithmetic_trait_mixed_borrowed_owned!(Mul, mul, PolynomialRingZq, Zq, PolynomialRingZq);
+arithmetic_trait_mixed_borrowed_owned!(Mul, mul, Zq, PolynomialRingZq, PolynomialRingZq);
+
+implement_for_others!(Z, PolynomialRingZq, Mul Scalar for i8 i16 i32 i64 u8 u16 u32 u64);
+
+#[cfg(test)]
+mod test_mul_scalar {
+    use super::PolynomialRingZq;
+    use crate::{
+        integer::{PolyOverZ, Z},
+        integer_mod_q::{Modulus, ModulusPolynomialRingZq, Zq},
+    };
+    use std::str::FromStr;
+
+    /// Checks if scalar multiplication works fine for all combinations
+    /// of borrowed and owned values
+    #[test]
+    fn borrowed_and_owned() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let a = PolynomialRingZq::from((&PolyOverZ::from_str("3  2 0 -1").unwrap(), &modulus));
+        let integer = Z::from(3);
+        let scalar = Zq::try_from((3, 17)).unwrap();
+
+        let cmp = PolynomialRingZq::from((&PolyOverZ::from_str("3  6 0 14").unwrap(), &modulus));
+        assert_eq!(cmp, &a * &integer);
+        assert_eq!(cmp, &integer * &a);
+        assert_eq!(cmp, &a * &scalar);
+        assert_eq!(cmp, &scalar * &a);
+        assert_eq!(cmp, &a * scalar);
+        assert_eq!(cmp, (&a * &Z::from(-3)) * -1_i64);
+        assert_eq!(cmp, 3_u8 * a);
+    }
+
+    /// Checks if scalar multiplication works fine for large values
+    #[test]
+    fn large_numbers() {
+        let modulus =
+            ModulusPolynomialRingZq::from_str(&format!("3  1 0 1 mod {}", u64::MAX - 58)).unwrap();
+        let a = PolynomialRingZq::from((
+            &PolyOverZ::from_str(&format!("2  1 {}", i64::MAX)).unwrap(),
+            &modulus,
+        ));
+
+        let cmp = PolynomialRingZq::from((
+            &PolyOverZ::from_str(&format!(
+                "2  {} {}",
+                u64::MAX,
+                u64::MAX as u128 * i64::MAX as u128
+            ))
+            .unwrap(),
+            &modulus,
+        ));
+        assert_eq!(cmp, a * Z::from(u64::MAX));
+    }
+
+    /// Ensure that the scalar multiplication stays in the NTT domain
+    #[test]
+    fn ntt_domain() {
+        let q = Modulus::from_str("17").unwrap();
+        let modulus = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(8, &q).unwrap();
+        let a = PolynomialRingZq::from((&PolyOverZ::from_str("4  1 2 3 4").unwrap(), &modulus));
+
+        let b = a.to_ntt_domain().unwrap() * 5;
+
+        assert!(b.is_ntt_domain());
+        assert_eq!(a * 5, b);
+    }
+
+    /// Ensure that the multiplication with a [`Zq`] of a different modulus
+    /// returns an error
+    #[test]
+    fn mismatching_modulus() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let a = PolynomialRingZq::from((&PolyOverZ::from_str("3  2 0 -1").unwrap(), &modulus));
+        let scalar = Zq::try_from((3, 19)).unwrap();
+
+        assert!(a.mul_scalar_zq_safe(&scalar).is_err());
+    }
+}
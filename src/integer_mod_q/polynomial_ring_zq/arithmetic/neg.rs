@@ -0,0 +1,115 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementation of the [`Neg`] trait for [`PolynomialRingZq`] values.
+
+use super::super::PolynomialRingZq;
+use crate::integer::PolyOverZ;
+use flint_sys::fq::fq_neg;
+use std::ops::Neg;
+
+impl Neg for &PolynomialRingZq {
+    type Output = PolynomialRingZq;
+    /// Implements the [`Neg`] trait for [`PolynomialRingZq`] values.
+    /// [`Neg`] is implemented for [`PolynomialRingZq`] and borrowed [`PolynomialRingZq`].
+    /// The result is in the same domain as `self`.
+    ///
+    /// Returns the additive inverse of `self` as a [`PolynomialRingZq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("4  -1 0 1 1").unwrap();
+    /// let a = PolynomialRingZq::from((&poly, &modulus));
+    ///
+    /// let b: PolynomialRingZq = -&a;
+    /// let c: PolynomialRingZq = -a;
+    /// ```
+    fn neg(self) -> Self::Output {
+        let mut out = PolynomialRingZq {
+            poly: PolyOverZ::default(),
+            modulus: self.modulus.clone(),
+            ntt_domain: self.ntt_domain,
+        };
+        unsafe {
+            fq_neg(
+                &mut out.poly.poly,
+                &self.poly.poly,
+                self.modulus.get_fq_ctx_struct(),
+            )
+        };
+        out
+    }
+}
+
+#[doc(hidden)]
+impl Neg for PolynomialRingZq {
+    type Output = PolynomialRingZq;
+
+    /// Documentation at [`PolynomialRingZq::neg`].
+    fn neg(self) -> Self::Output {
+        (&self).neg()
+    }
+}
+
+#[cfg(test)]
+mod test_neg {
+    use super::PolynomialRingZq;
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// testing negation of [`PolynomialRingZq`]
+    #[test]
+    fn neg() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let a = PolynomialRingZq::from((&PolyOverZ::from_str("3  2 0 -1").unwrap(), &modulus));
+        let zero = PolynomialRingZq::from((&PolyOverZ::default(), &modulus));
+
+        let cmp = PolynomialRingZq::from((&PolyOverZ::from_str("3  15 0 1").unwrap(), &modulus));
+        assert_eq!(cmp, -&a);
+        assert_eq!(zero, &a + -a);
+        assert_eq!(zero, -&zero);
+    }
+
+    /// testing negation of large [`PolynomialRingZq`]
+    #[test]
+    fn neg_large_numbers() {
+        let modulus =
+            ModulusPolynomialRingZq::from_str(&format!("3  1 0 1 mod {}", u64::MAX - 58)).unwrap();
+        let a = PolynomialRingZq::from((
+            &PolyOverZ::from_str(&format!("2  {} {}", u64::MAX, i64::MIN)).unwrap(),
+            &modulus,
+        ));
+
+        let cmp = PolynomialRingZq::from((
+            &PolyOverZ::from_str(&format!("2  -58 {}", i64::MAX as u64 + 1)).unwrap(),
+            &modulus,
+        ));
+        assert_eq!(cmp, -a);
+    }
+
+    /// Ensure that the negation stays in the NTT domain
+    #[test]
+    fn ntt_domain() {
+        let q = Modulus::from_str("17").unwrap();
+        let modulus = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(8, &q).unwrap();
+        let a = PolynomialRingZq::from((&PolyOverZ::from_str("4  1 2 3 4").unwrap(), &modulus));
+
+        let b = -a.to_ntt_domain().unwrap();
+
+        assert!(b.is_ntt_domain());
+        assert_eq!(-a, b);
+    }
+}
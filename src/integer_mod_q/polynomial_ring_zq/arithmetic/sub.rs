@@ -0,0 +1,219 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementation of the [`Sub`] trait for [`PolynomialRingZq`] values.
+
+use super::super::PolynomialRingZq;
+use crate::{
+    error::MathError,
+    integer::PolyOverZ,
+    macros::arithmetics::{
+        arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
+    },
+};
+use flint_sys::fq::fq_sub;
+use std::ops::Sub;
+
+impl Sub for &PolynomialRingZq {
+    type Output = PolynomialRingZq;
+    /// Implements the [`Sub`] trait for two [`PolynomialRingZq`] values.
+    /// [`Sub`] is implemented for any combination of [`PolynomialRingZq`]
+    /// and borrowed [`PolynomialRingZq`].
+    ///
+    /// Parameters:
+    /// - `other`: specifies the polynomial to subtract from `self`
+    ///
+    /// Returns the result of the subtraction of both polynomials as a [`PolynomialRingZq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly_1 = PolyOverZ::from_str("4  -1 0 1 1").unwrap();
+    /// let a = PolynomialRingZq::from((&poly_1, &modulus));
+    /// let poly_2 = PolyOverZ::from_str("4  2 0 3 1").unwrap();
+    /// let b = PolynomialRingZq::from((&poly_2, &modulus));
+    ///
+    /// let c: PolynomialRingZq = &a - &b;
+    /// let d: PolynomialRingZq = a - b;
+    /// let e: PolynomialRingZq = &c - d;
+    /// let f: PolynomialRingZq = c - &e;
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if the moduli of both [`PolynomialRingZq`] mismatch.
+    fn sub(self, other: Self) -> Self::Output {
+        self.sub_safe(other).unwrap()
+    }
+}
+
+impl PolynomialRingZq {
+    /// Implements subtraction for two [`PolynomialRingZq`] values.
+    /// If only one of both values is in the NTT domain,
+    /// the difference is computed and returned in the NTT domain.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the polynomial to subtract from `self`
+    ///
+    /// Returns the result of the subtraction of both polynomials as a [`PolynomialRingZq`] or an error if the moduli
+    /// mismatch.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use qfall_math::integer::PolyOverZ;
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly_1 = PolyOverZ::from_str("4  -1 0 1 1").unwrap();
+    /// let a = PolynomialRingZq::from((&poly_1, &modulus));
+    /// let poly_2 = PolyOverZ::from_str("4  2 0 3 1").unwrap();
+    /// let b = PolynomialRingZq::from((&poly_2, &modulus));
+    ///
+    /// let c: PolynomialRingZq = a.sub_safe(&b).unwrap();
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MathError::MismatchingModulus`] if the moduli of
+    /// both [`PolynomialRingZq`] mismatch.
+    pub fn sub_safe(&self, other: &Self) -> Result<PolynomialRingZq, MathError> {
+        if self.modulus != other.modulus {
+            return Err(MathError::MismatchingModulus(format!(
+                " Tried to subtract polynomials with moduli '{}' and '{}'.",
+                self.modulus, other.modulus
+            )));
+        }
+        if self.ntt_domain != other.ntt_domain {
+            return self.to_ntt_domain()?.sub_safe(&other.to_ntt_domain()?);
+        }
+
+        // the subtraction is performed entrywise, hence it is valid in both domains
+        let mut out = PolynomialRingZq {
+            poly: PolyOverZ::default(),
+            modulus: self.modulus.clone(),
+            ntt_domain: self.ntt_domain,
+        };
+        unsafe {
+            fq_sub(
+                &mut out.poly.poly,
+                &self.poly.poly,
+                &other.poly.poly,
+                self.modulus.get_fq_ctx_struct(),
+            )
+        };
+        Ok(out)
+    }
+}
+
+arithmetic_trait_borrowed_to_owned!(
+    Sub,
+    sub,
+    PolynomialRingZq,
+    PolynomialRingZq,
+    PolynomialRingZq
+);
+arithmetic_trait_mixed_borrowed_owned!(
+    Sub,
+    sub,
+    PolynomialRingZq,
+    PolynomialRingZq,
+    PolynomialRingZq
+);
+
+#[cfg(test)]
+mod test_sub {
+    use super::PolynomialRingZq;
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// testing subtraction for two [`PolynomialRingZq`]
+    #[test]
+    fn sub() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let a = PolynomialRingZq::from((&PolyOverZ::from_str("3  2 4 10").unwrap(), &modulus));
+        let b = PolynomialRingZq::from((&PolyOverZ::from_str("3  -5 4 9").unwrap(), &modulus));
+
+        let c = a - &b;
+
+        let cmp = PolynomialRingZq::from((&PolyOverZ::from_str("3  7 0 1").unwrap(), &modulus));
+        assert_eq!(cmp, c);
+    }
+
+    /// testing subtraction for large [`PolynomialRingZq`]
+    #[test]
+    fn sub_large_numbers() {
+        let modulus =
+            ModulusPolynomialRingZq::from_str(&format!("3  1 0 1 mod {}", u64::MAX - 58)).unwrap();
+        let a = PolynomialRingZq::from((
+            &PolyOverZ::from_str(&format!("2  {} {}", u64::MAX, i64::MIN)).unwrap(),
+            &modulus,
+        ));
+        let b = PolynomialRingZq::from((
+            &PolyOverZ::from_str(&format!("2  {} {}", i64::MAX, i64::MAX)).unwrap(),
+            &modulus,
+        ));
+
+        let c = &a - b;
+
+        let cmp = PolynomialRingZq::from((
+            &PolyOverZ::from_str(&format!("2  {} -{}", 58 - i64::MAX, u64::MAX)).unwrap(),
+            &modulus,
+        ));
+        assert_eq!(cmp, c);
+    }
+
+    /// Ensure that the difference is computed correctly if both or only one of
+    /// the polynomials are in the NTT domain
+    #[test]
+    fn ntt_domain() {
+        let q = Modulus::from_str("12289").unwrap();
+        let modulus = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(64, &q).unwrap();
+        let a =
+            PolynomialRingZq::from((&PolyOverZ::sample_uniform(63, 0, 12289).unwrap(), &modulus));
+        let b =
+            PolynomialRingZq::from((&PolyOverZ::sample_uniform(63, 0, 12289).unwrap(), &modulus));
+        let cmp = &a - &b;
+
+        let c = a.to_ntt_domain().unwrap() - b.to_ntt_domain().unwrap();
+        let d = a.to_ntt_domain().unwrap() - &b;
+
+        assert!(c.is_ntt_domain());
+        assert!(d.is_ntt_domain());
+        assert_eq!(cmp, c);
+        assert_eq!(cmp, d);
+    }
+
+    /// testing subtraction for [`PolynomialRingZq`] with different moduli does not work
+    #[test]
+    #[should_panic]
+    fn sub_mismatching_modulus() {
+        let modulus_1 = ModulusPolynomialRingZq::from_str("3  1 0 1 mod 17").unwrap();
+        let modulus_2 = ModulusPolynomialRingZq::from_str("3  1 0 1 mod 19").unwrap();
+        let a = PolynomialRingZq::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_1));
+        let b = PolynomialRingZq::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_2));
+
+        let _ = a - b;
+    }
+
+    /// testing whether sub_safe throws an error for mismatching moduli
+    #[test]
+    fn sub_safe_is_err() {
+        let modulus_1 = ModulusPolynomialRingZq::from_str("3  1 0 1 mod 17").unwrap();
+        let modulus_2 = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let a = PolynomialRingZq::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_1));
+        let b = PolynomialRingZq::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus_2));
+
+        assert!(a.sub_safe(&b).is_err());
+    }
+}
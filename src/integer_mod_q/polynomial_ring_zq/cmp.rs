@@ -0,0 +1,102 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementations to compare [`PolynomialRingZq`] with other values.
+//! This uses the traits from [`std::cmp`].
+
+use super::PolynomialRingZq;
+
+impl PartialEq for PolynomialRingZq {
+    /// Checks if two polynomials of type [`PolynomialRingZq`] are equal.
+    /// They are considered equal if their moduli match and their coefficients
+    /// match, independent of whether they are stored in the coefficient domain
+    /// or the NTT domain.
+    /// Used by the `==` and `!=` operators.
+    ///
+    /// Parameters:
+    /// - `other`: the other value that is used to compare the elements
+    ///
+    /// Returns `true` if the elements are equal, otherwise `false`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("3  1 0 1 mod 17").unwrap();
+    /// let a = PolynomialRingZq::from((&PolyOverZ::from_str("2  1 1").unwrap(), &modulus));
+    /// let b = PolynomialRingZq::from((&PolyOverZ::from_str("2  18 1").unwrap(), &modulus));
+    ///
+    /// // These are all equivalent and return true.
+    /// let compared: bool = (a == b);
+    /// # assert!(compared);
+    /// let compared: bool = (&a == &b);
+    /// # assert!(compared);
+    /// let compared: bool = (a.eq(&b));
+    /// # assert!(compared);
+    /// let compared: bool = (PolynomialRingZq::eq(&a, &b));
+    /// # assert!(compared);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        if self.modulus != other.modulus {
+            return false;
+        }
+        match (self.ntt_domain, other.ntt_domain) {
+            (true, false) => self.to_coeff_domain().poly == other.poly,
+            (false, true) => self.poly == other.to_coeff_domain().poly,
+            _ => self.poly == other.poly,
+        }
+    }
+}
+
+// With the [`Eq`] trait, `a == a` is always true.
+// This is not guaranteed by the [`PartialEq`] trait.
+impl Eq for PolynomialRingZq {}
+
+#[cfg(test)]
+mod test_eq {
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingZq, PolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that equal elements are equal independent of their domain
+    #[test]
+    fn equal_across_domains() {
+        let modulus = Modulus::from_str("12289").unwrap();
+        let modulus = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(16, &modulus).unwrap();
+        let poly = PolynomialRingZq::from((
+            &PolyOverZ::from_str(&format!("3  -1 {} 5", u64::MAX)).unwrap(),
+            &modulus,
+        ));
+        let poly_ntt = poly.to_ntt_domain().unwrap();
+
+        assert_eq!(poly, poly_ntt);
+        assert_eq!(poly_ntt, poly);
+        assert_eq!(poly_ntt, poly_ntt);
+    }
+
+    /// Ensure that different elements or moduli are not equal
+    #[test]
+    fn not_equal() {
+        let modulus_1 = ModulusPolynomialRingZq::from_str("3  1 0 1 mod 17").unwrap();
+        let modulus_2 = ModulusPolynomialRingZq::from_str("3  1 0 1 mod 19").unwrap();
+        let poly_1 = PolyOverZ::from_str("2  1 1").unwrap();
+        let poly_2 = PolyOverZ::from_str("2  2 1").unwrap();
+        let a = PolynomialRingZq::from((&poly_1, &modulus_1));
+
+        assert_ne!(a, PolynomialRingZq::from((&poly_2, &modulus_1)));
+        assert_ne!(a, PolynomialRingZq::from((&poly_1, &modulus_2)));
+        assert_ne!(
+            a.to_ntt_domain().unwrap(),
+            PolynomialRingZq::from((&poly_2, &modulus_1))
+        );
+    }
+}
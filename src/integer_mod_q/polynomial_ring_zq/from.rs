@@ -13,7 +13,13 @@
 //! The explicit functions contain the documentation.
 
 use super::PolynomialRingZq;
-use crate::{integer::PolyOverZ, integer_mod_q::ModulusPolynomialRingZq};
+use crate::{
+    error::MathError,
+    integer::{PolyOverZ, Z},
+    integer_mod_q::{MatZq, ModulusPolynomialRingZq},
+    traits::{GetNumColumns, GetNumRows},
+};
+use flint_sys::fmpz_poly::fmpz_poly_set_coeff_fmpz;
 
 impl From<(&PolyOverZ, &ModulusPolynomialRingZq)> for PolynomialRingZq {
     /// Create a new polynomial ring element of type [`PolynomialRingZq`].
@@ -68,10 +74,81 @@ impl PolynomialRingZq {
         let mut out = Self {
             poly: poly.clone(),
             modulus: modulus.clone(),
+            ntt_domain: false,
         };
         out.reduce();
         out
     }
+
+    /// Creates a polynomial ring element from a (row or column) coefficient vector
+    /// with `n` entries modulo `q`, where `n` is the degree of the modulus polynomial,
+    /// i.e. the `i`-th entry of `vector` is the coefficient of `X^i`.
+    /// This is the inverse operation of [`PolynomialRingZq::to_mat_zq`].
+    ///
+    /// Parameters:
+    /// - `vector`: the coefficient vector of the ring element
+    /// - `modulus`: the modulus which defines the ring
+    ///
+    /// Returns a new element inside the polynomial ring or an error if `vector`
+    /// is not a vector with `n` entries modulo `q`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{MatZq, ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("5  1 0 0 0 1 mod 17").unwrap();
+    /// let vector = MatZq::from_str("[[16],[0],[2],[0]] mod 17").unwrap();
+    ///
+    /// let poly_ring = PolynomialRingZq::from_mat_zq(&vector, &modulus).unwrap();
+    ///
+    /// let poly = PolyOverZ::from_str("3  -1 0 2").unwrap();
+    /// assert_eq!(PolynomialRingZq::from((&poly, &modulus)), poly_ring);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MathError::VectorFunctionCalledOnNonVector`] if
+    /// the given [`MatZq`] instance is not a (row or column) vector.
+    /// - Returns a [`MathError`] of type [`MathError::MismatchingVectorDimensions`] if
+    /// the number of entries of `vector` does not match the degree of the modulus polynomial.
+    /// - Returns a [`MathError`] of type [`MathError::MismatchingModulus`] if
+    /// the modulus of `vector` is not `q`.
+    pub fn from_mat_zq(
+        vector: &MatZq,
+        modulus: &ModulusPolynomialRingZq,
+    ) -> Result<Self, MathError> {
+        if !vector.is_vector() {
+            return Err(MathError::VectorFunctionCalledOnNonVector(
+                String::from("from_mat_zq"),
+                vector.get_num_rows(),
+                vector.get_num_columns(),
+            ));
+        }
+        let length = vector.get_num_rows() * vector.get_num_columns();
+        if length != modulus.get_degree() {
+            return Err(MathError::MismatchingVectorDimensions(format!(
+                "The vector has {} entries, but the modulus polynomial has degree {}.",
+                length,
+                modulus.get_degree()
+            )));
+        }
+        if Z::from(&vector.get_mod()) != modulus.get_q() {
+            return Err(MathError::MismatchingModulus(format!(
+                " Modulus of vector: '{}'. Modulus of ring: '{}'.",
+                vector.get_mod(),
+                modulus
+            )));
+        }
+
+        let mut poly = PolyOverZ::default();
+        for (index, entry) in vector.collect_entries().iter().enumerate() {
+            unsafe { fmpz_poly_set_coeff_fmpz(&mut poly.poly, index as i64, entry) };
+        }
+        Ok(Self::from_poly_over_z_modulus_polynomial_ring_zq(
+            &poly, modulus,
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -116,3 +193,67 @@ mod test_from_poly_over_z_modulus_polynomial_ring_zq {
         assert_eq!(poly_ring_1, poly_ring_2);
     }
 }
+
+#[cfg(test)]
+mod test_from_mat_zq {
+    use crate::{
+        error::MathError,
+        integer::PolyOverZ,
+        integer_mod_q::{MatZq, ModulusPolynomialRingZq, PolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that row and column vectors are converted correctly
+    #[test]
+    fn row_and_column() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let row = MatZq::from_str("[[3, 0, 16]] mod 17").unwrap();
+        let column = MatZq::from_str("[[3],[0],[16]] mod 17").unwrap();
+
+        let cmp = PolynomialRingZq::from((&PolyOverZ::from_str("3  3 0 -1").unwrap(), &modulus));
+        assert_eq!(cmp, PolynomialRingZq::from_mat_zq(&row, &modulus).unwrap());
+        assert_eq!(
+            cmp,
+            PolynomialRingZq::from_mat_zq(&column, &modulus).unwrap()
+        );
+    }
+
+    /// Ensure that converting to a vector and back yields the same element
+    /// for large entries
+    #[test]
+    fn round_trip() {
+        let q = u64::MAX - 58;
+        let modulus = ModulusPolynomialRingZq::from_str(&format!("4  1 0 0 1 mod {}", q)).unwrap();
+        let poly = PolyOverZ::from_str(&format!("3  {} {} 1", u64::MAX, i64::MIN)).unwrap();
+        let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+
+        let vector = poly_ring.to_mat_zq();
+
+        assert_eq!(
+            poly_ring,
+            PolynomialRingZq::from_mat_zq(&vector, &modulus).unwrap()
+        );
+    }
+
+    /// Ensure that errors are returned for matrices, wrong lengths and moduli
+    #[test]
+    fn invalid_vectors() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let matrix = MatZq::from_str("[[1, 2, 3],[4, 5, 6]] mod 17").unwrap();
+        let too_short = MatZq::from_str("[[1],[2]] mod 17").unwrap();
+        let wrong_modulus = MatZq::from_str("[[1],[2],[3]] mod 19").unwrap();
+
+        assert!(matches!(
+            PolynomialRingZq::from_mat_zq(&matrix, &modulus),
+            Err(MathError::VectorFunctionCalledOnNonVector(_, _, _))
+        ));
+        assert!(matches!(
+            PolynomialRingZq::from_mat_zq(&too_short, &modulus),
+            Err(MathError::MismatchingVectorDimensions(_))
+        ));
+        assert!(matches!(
+            PolynomialRingZq::from_mat_zq(&wrong_modulus, &modulus),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementations to get content of a [`PolynomialRingZq`].
+
+use super::PolynomialRingZq;
+use crate::{
+    error::MathError,
+    integer::Z,
+    integer_mod_q::{MatZq, ModulusPolynomialRingZq, Zq},
+    traits::{GetCoefficient, SetEntry},
+};
+use std::fmt::Display;
+
+impl GetCoefficient<Zq> for PolynomialRingZq {
+    /// Returns the coefficient of a polynomial [`PolynomialRingZq`] as a [`Zq`].
+    /// If `self` is in the NTT domain, the coefficient of its representation
+    /// in the coefficient domain is returned.
+    ///
+    /// If a index is provided which exceeds the highest set coefficient, `0` is returned.
+    ///
+    /// Parameters:
+    /// - `index`: the index of the coefficient to get (has to be positive)
+    ///
+    /// Returns the coefficient as a [`Zq`] or a [`MathError`] if the provided index
+    /// is negative and therefore invalid or it does not fit into an [`i64`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::traits::*;
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq, Zq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("3  0 1 -2").unwrap();
+    /// let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+    ///
+    /// let coeff_0: Zq = poly_ring.get_coeff(0).unwrap();
+    /// let coeff_2: Zq = poly_ring.get_coeff(2).unwrap();
+    /// let coeff_4: Zq = poly_ring.get_coeff(4).unwrap(); // This would only return 0
+    ///
+    /// assert_eq!(Zq::try_from((15, 17)).unwrap(), coeff_2);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// either the index is negative or it does not fit into an [`i64`].
+    fn get_coeff(&self, index: impl TryInto<i64> + Display + Copy) -> Result<Zq, MathError> {
+        let out_z: Z = self.get_coeff(index)?;
+        Ok(Zq::try_from((out_z, self.modulus.get_q())).unwrap())
+    }
+}
+
+impl GetCoefficient<Z> for PolynomialRingZq {
+    /// Returns the coefficient of a polynomial [`PolynomialRingZq`] as a [`Z`]
+    /// in the range `[0, q)`.
+    /// If `self` is in the NTT domain, the coefficient of its representation
+    /// in the coefficient domain is returned.
+    ///
+    /// If a index is provided which exceeds the highest set coefficient, `0` is returned.
+    ///
+    /// Parameters:
+    /// - `index`: the index of the coefficient to get (has to be positive)
+    ///
+    /// Returns the coefficient as a [`Z`] or a [`MathError`] if the provided index
+    /// is negative and therefore invalid or it does not fit into an [`i64`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::traits::*;
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("3  0 1 -2").unwrap();
+    /// let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+    ///
+    /// let coeff_0: Z = poly_ring.get_coeff(0).unwrap();
+    /// let coeff_2: Z = poly_ring.get_coeff(2).unwrap();
+    /// let coeff_4: Z = poly_ring.get_coeff(4).unwrap(); // This would only return 0
+    ///
+    /// assert_eq!(Z::from(15), coeff_2);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// either the index is negative or it does not fit into an [`i64`].
+    fn get_coeff(&self, index: impl TryInto<i64> + Display + Copy) -> Result<Z, MathError> {
+        match self.ntt_domain {
+            true => self.to_coeff_domain().poly.get_coeff(index),
+            false => self.poly.get_coeff(index),
+        }
+    }
+}
+
+impl PolynomialRingZq {
+    /// Returns the modulus object of the [`PolynomialRingZq`] element.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("3  -1 0 2").unwrap();
+    /// let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+    ///
+    /// assert_eq!(modulus, poly_ring.get_mod());
+    /// ```
+    pub fn get_mod(&self) -> ModulusPolynomialRingZq {
+        self.modulus.clone()
+    }
+
+    /// Returns the coefficient vector of `self` as a column vector of type [`MatZq`]
+    /// with `n` entries modulo `q`, where `n` is the degree of the modulus polynomial.
+    /// The `i`-th entry is the coefficient of `X^i`.
+    /// This is the inverse operation of [`PolynomialRingZq::from_mat_zq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{MatZq, ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("5  1 0 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("3  -1 0 2").unwrap();
+    /// let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+    ///
+    /// let vector = poly_ring.to_mat_zq();
+    ///
+    /// assert_eq!(MatZq::from_str("[[16],[0],[2],[0]] mod 17").unwrap(), vector);
+    /// ```
+    pub fn to_mat_zq(&self) -> MatZq {
+        let degree = self.modulus.get_degree();
+        let poly = self.to_coeff_domain().poly;
+        let mut out = MatZq::new(degree, 1, self.modulus.get_q()).unwrap();
+        for index in 0..=poly.get_degree() {
+            let coeff: Z = poly.get_coeff(index).unwrap();
+            out.set_entry(index, 0, &coeff).unwrap();
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_get_coeff {
+    use crate::{
+        integer::{PolyOverZ, Z},
+        integer_mod_q::{Modulus, ModulusPolynomialRingZq, PolynomialRingZq, Zq},
+        traits::GetCoefficient,
+    };
+    use std::str::FromStr;
+
+    /// Ensure that the reduced coefficients are returned
+    #[test]
+    fn reduced() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let poly = PolyOverZ::from_str("5  -1 0 1 0 2").unwrap();
+        let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+
+        let coeff_0: Z = poly_ring.get_coeff(0).unwrap();
+        let coeff_1: Zq = poly_ring.get_coeff(1).unwrap();
+        let coeff_4: Z = poly_ring.get_coeff(4).unwrap();
+
+        assert_eq!(Z::from(16), coeff_0);
+        assert_eq!(Zq::try_from((15, 17)).unwrap(), coeff_1);
+        assert_eq!(Z::ZERO, coeff_4);
+    }
+
+    /// Ensure that large coefficients are returned correctly
+    #[test]
+    fn large_coefficients() {
+        let q = u64::MAX - 58;
+        let modulus = ModulusPolynomialRingZq::from_str(&format!("3  1 0 1 mod {}", q)).unwrap();
+        let poly = PolyOverZ::from_str(&format!("2  {} -1", u64::MAX)).unwrap();
+        let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+
+        let coeff_0: Z = poly_ring.get_coeff(0).unwrap();
+        let coeff_1: Zq = poly_ring.get_coeff(1).unwrap();
+
+        assert_eq!(Z::from(58), coeff_0);
+        assert_eq!(Zq::try_from((q - 1, q)).unwrap(), coeff_1);
+    }
+
+    /// Ensure that coefficients of elements in the NTT domain are returned
+    /// in the coefficient domain
+    #[test]
+    fn ntt_domain() {
+        let q = Modulus::from_str("17").unwrap();
+        let modulus = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(4, &q).unwrap();
+        let poly = PolyOverZ::from_str("3  1 2 3").unwrap();
+        let poly_ring = PolynomialRingZq::from((&poly, &modulus))
+            .to_ntt_domain()
+            .unwrap();
+
+        let coeff: Z = poly_ring.get_coeff(2).unwrap();
+
+        assert_eq!(Z::from(3), coeff);
+    }
+
+    /// Ensure that negative indices return an error
+    #[test]
+    fn negative_index() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let poly_ring = PolynomialRingZq::from((&PolyOverZ::default(), &modulus));
+
+        assert!(GetCoefficient::<Z>::get_coeff(&poly_ring, -1).is_err());
+        assert!(GetCoefficient::<Zq>::get_coeff(&poly_ring, i64::MIN).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_to_mat_zq {
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{MatZq, ModulusPolynomialRingZq, PolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that the vector is padded with zeros up to the degree of the modulus
+    #[test]
+    fn padded() {
+        let modulus = ModulusPolynomialRingZq::from_str("5  1 0 0 0 1 mod 17").unwrap();
+        let poly_ring = PolynomialRingZq::from((&PolyOverZ::from_str("2  3 1").unwrap(), &modulus));
+        let zero = PolynomialRingZq::from((&PolyOverZ::default(), &modulus));
+
+        assert_eq!(
+            MatZq::from_str("[[3],[1],[0],[0]] mod 17").unwrap(),
+            poly_ring.to_mat_zq()
+        );
+        assert_eq!(MatZq::new(4, 1, 17).unwrap(), zero.to_mat_zq());
+    }
+
+    /// Ensure that large coefficients and moduli are supported
+    #[test]
+    fn large_entries() {
+        let q = u64::MAX - 58;
+        let modulus = ModulusPolynomialRingZq::from_str(&format!("3  1 0 1 mod {}", q)).unwrap();
+        let poly = PolyOverZ::from_str(&format!("2  {} {}", u64::MAX, i64::MIN)).unwrap();
+        let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+
+        assert_eq!(
+            MatZq::from_str(&format!("[[58],[{}]] mod {}", i64::MIN, q)).unwrap(),
+            poly_ring.to_mat_zq()
+        );
+    }
+}
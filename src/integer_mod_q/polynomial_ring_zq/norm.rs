@@ -0,0 +1,176 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module includes functionality to compute several norms
+//! defined on the coefficient vector of polynomials in the ring.
+
+use super::PolynomialRingZq;
+use crate::integer::Z;
+
+impl PolynomialRingZq {
+    /// Returns the squared Euclidean norm or 2-norm of the coefficient vector
+    /// of the given polynomial.
+    ///
+    /// Each length of a coefficient is defined as the shortest distance
+    /// to the next zero instance in `Z_q`, i.e. the norm is computed
+    /// on the centered lift of the coefficients.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("3  1 2 -3").unwrap();
+    /// let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+    ///
+    /// let sqrd_2_norm = poly_ring.norm_eucl_sqrd();
+    ///
+    /// // 1*1 + 2*2 + (-3)*(-3) = 14
+    /// assert_eq!(Z::from(14), sqrd_2_norm);
+    /// ```
+    pub fn norm_eucl_sqrd(&self) -> Z {
+        self.to_mat_zq().norm_eucl_sqrd().unwrap()
+    }
+
+    /// Returns the infinity norm or ∞-norm of the coefficient vector
+    /// of the given polynomial.
+    ///
+    /// Each length of a coefficient is defined as the shortest distance
+    /// to the next zero instance in `Z_q`, i.e. the norm is computed
+    /// on the centered lift of the coefficients.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("3  1 2 15").unwrap();
+    /// let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+    ///
+    /// let infty_norm = poly_ring.norm_infty();
+    ///
+    /// // max{1, 2, |-2|} = 2
+    /// assert_eq!(Z::from(2), infty_norm);
+    /// ```
+    pub fn norm_infty(&self) -> Z {
+        self.to_mat_zq().norm_infty().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test_norm_eucl_sqrd {
+    use super::{PolynomialRingZq, Z};
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// Check whether the squared euclidean norm is computed
+    /// on the centered lift of the reduced coefficients
+    #[test]
+    fn small_coefficients() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let poly_1 = PolynomialRingZq::from((&PolyOverZ::default(), &modulus));
+        let poly_2 = PolynomialRingZq::from((&PolyOverZ::from_str("3  16 9 8").unwrap(), &modulus));
+        // X^4 = -X mod X^3 + 1
+        let poly_3 =
+            PolynomialRingZq::from((&PolyOverZ::from_str("5  0 3 0 0 1").unwrap(), &modulus));
+
+        assert_eq!(poly_1.norm_eucl_sqrd(), Z::ZERO);
+        assert_eq!(poly_2.norm_eucl_sqrd(), Z::from(1 + 64 + 64));
+        assert_eq!(poly_3.norm_eucl_sqrd(), Z::from(4));
+    }
+
+    /// Check whether the squared euclidean norm is calculated correctly
+    /// for large coefficients
+    #[test]
+    fn large_coefficients() {
+        let modulus =
+            ModulusPolynomialRingZq::from_str(&format!("4  1 0 0 1 mod {}", u64::MAX - 58))
+                .unwrap();
+        let poly =
+            PolyOverZ::from_str(&format!("3  {} -{} 2", i64::MAX / 2, i64::MAX / 2)).unwrap();
+        let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+        let max = Z::from(i64::MAX / 2);
+
+        assert_eq!(
+            poly_ring.norm_eucl_sqrd(),
+            Z::from(2) * &max * &max + Z::from(4)
+        );
+    }
+
+    /// Check whether the norm is independent of the domain of the polynomial
+    #[test]
+    fn ntt_domain() {
+        let q = Modulus::from_str("17").unwrap();
+        let modulus = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(4, &q).unwrap();
+        let poly_ring =
+            PolynomialRingZq::from((&PolyOverZ::from_str("3  1 -2 3").unwrap(), &modulus));
+
+        assert_eq!(
+            poly_ring.norm_eucl_sqrd(),
+            poly_ring.to_ntt_domain().unwrap().norm_eucl_sqrd()
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_norm_infty {
+    use super::{PolynomialRingZq, Z};
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// Check whether the infinity norm is computed
+    /// on the centered lift of the reduced coefficients
+    #[test]
+    fn small_coefficients() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let poly_1 = PolynomialRingZq::from((&PolyOverZ::default(), &modulus));
+        let poly_2 = PolynomialRingZq::from((&PolyOverZ::from_str("3  16 9 1").unwrap(), &modulus));
+        // X^3 = -1 mod X^3 + 1
+        let poly_3 =
+            PolynomialRingZq::from((&PolyOverZ::from_str("4  -5 0 0 1").unwrap(), &modulus));
+
+        assert_eq!(poly_1.norm_infty(), Z::ZERO);
+        assert_eq!(poly_2.norm_infty(), Z::from(8));
+        assert_eq!(poly_3.norm_infty(), Z::from(6));
+    }
+
+    /// Check whether the infinity norm is calculated correctly
+    /// for large coefficients
+    #[test]
+    fn large_coefficients() {
+        let modulus =
+            ModulusPolynomialRingZq::from_str(&format!("4  1 0 0 1 mod {}", u64::MAX - 58))
+                .unwrap();
+        let poly =
+            PolyOverZ::from_str(&format!("3  {} -{} 2", i64::MAX / 2, i64::MAX / 2)).unwrap();
+        let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+
+        assert_eq!(poly_ring.norm_infty(), Z::from(i64::MAX / 2));
+    }
+
+    /// Check whether the norm is independent of the domain of the polynomial
+    #[test]
+    fn ntt_domain() {
+        let q = Modulus::from_str("17").unwrap();
+        let modulus = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(4, &q).unwrap();
+        let poly_ring =
+            PolynomialRingZq::from((&PolyOverZ::from_str("3  1 -7 3").unwrap(), &modulus));
+
+        assert_eq!(Z::from(7), poly_ring.to_ntt_domain().unwrap().norm_infty());
+    }
+}
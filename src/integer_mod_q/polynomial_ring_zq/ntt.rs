@@ -0,0 +1,179 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the conversions of a [`PolynomialRingZq`] between
+//! the coefficient domain and the evaluation domain of the
+//! number theoretic transform (NTT).
+
+use super::PolynomialRingZq;
+use crate::error::MathError;
+
+impl PolynomialRingZq {
+    /// Returns `self` in the evaluation domain of the number theoretic transform (NTT).
+    /// Products of elements in the NTT domain are computed entrywise in `O(n)`,
+    /// s.t. consecutive multiplications should be performed in the NTT domain.
+    /// The NTT is supported if the modulus is of the form `X^n + 1`
+    /// for a power of two `n` and `q` is a prime with `q = 1 mod 2n` and `q < 2^62`.
+    ///
+    /// Returns `self` in the NTT domain or an error if the NTT is not supported
+    /// for the modulus of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let q = Modulus::from_str("17").unwrap();
+    /// let modulus = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(8, &q).unwrap();
+    /// let poly = PolyOverZ::from_str("4  -1 0 1 1").unwrap();
+    /// let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+    ///
+    /// let poly_ntt = poly_ring.to_ntt_domain().unwrap();
+    ///
+    /// assert!(poly_ntt.is_ntt_domain());
+    /// assert_eq!(poly_ring, poly_ntt);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NttNotSupported`](MathError::NttNotSupported)
+    /// if the NTT is not supported for the modulus of `self`.
+    pub fn to_ntt_domain(&self) -> Result<Self, MathError> {
+        let ntt = self.modulus.get_ntt_tables().ok_or_else(|| {
+            MathError::NttNotSupported(format!(
+                "The modulus {} has to be of the form X^n + 1 for a power of two n \
+                and a prime q = 1 mod 2n with q < 2^62.",
+                self.modulus
+            ))
+        })?;
+
+        let poly = match self.ntt_domain {
+            true => self.poly.clone(),
+            false => ntt.to_ntt_domain(&self.poly),
+        };
+        Ok(Self {
+            poly,
+            modulus: self.modulus.clone(),
+            ntt_domain: true,
+        })
+    }
+
+    /// Returns `self` in the coefficient domain.
+    /// This is the inverse operation of [`PolynomialRingZq::to_ntt_domain`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{Modulus, ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let q = Modulus::from_str("17").unwrap();
+    /// let modulus = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(8, &q).unwrap();
+    /// let poly = PolyOverZ::from_str("4  -1 0 1 1").unwrap();
+    /// let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+    ///
+    /// let poly_coeff = poly_ring.to_ntt_domain().unwrap().to_coeff_domain();
+    ///
+    /// assert!(!poly_coeff.is_ntt_domain());
+    /// assert_eq!(poly_ring, poly_coeff);
+    /// ```
+    pub fn to_coeff_domain(&self) -> Self {
+        let poly = match self.modulus.get_ntt_tables() {
+            Some(ntt) if self.ntt_domain => ntt.to_coeff_domain(&self.poly),
+            _ => self.poly.clone(),
+        };
+        Self {
+            poly,
+            modulus: self.modulus.clone(),
+            ntt_domain: false,
+        }
+    }
+
+    /// Checks if `self` is stored in the evaluation domain of the
+    /// number theoretic transform (NTT).
+    ///
+    /// Returns `true` if `self` is in the NTT domain and `false` if it
+    /// is in the coefficient domain.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("3  1 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("2  -1 1").unwrap();
+    /// let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+    ///
+    /// assert!(!poly_ring.is_ntt_domain());
+    /// assert!(poly_ring.to_ntt_domain().unwrap().is_ntt_domain());
+    /// ```
+    pub fn is_ntt_domain(&self) -> bool {
+        self.ntt_domain
+    }
+}
+
+#[cfg(test)]
+mod test_ntt_domain {
+    use crate::{
+        error::MathError,
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingZq, PolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that converting into the NTT domain and back yields the same element
+    #[test]
+    fn round_trip() {
+        for (n, q) in [(1, 17), (256, 12289), (64, 4611686018427322369_u64)] {
+            let modulus = Modulus::from_str(&q.to_string()).unwrap();
+            let modulus =
+                ModulusPolynomialRingZq::new_power_of_two_cyclotomic(n, &modulus).unwrap();
+            let poly = PolynomialRingZq::from((
+                &PolyOverZ::sample_uniform(n - 1, 0, q).unwrap(),
+                &modulus,
+            ));
+
+            let poly_ntt = poly.to_ntt_domain().unwrap();
+            let poly_coeff = poly_ntt.to_coeff_domain();
+
+            assert!(poly_ntt.is_ntt_domain());
+            assert!(!poly_coeff.is_ntt_domain());
+            assert_eq!(poly.poly, poly_coeff.poly);
+        }
+    }
+
+    /// Ensure that converting twice into the same domain does not change the value
+    #[test]
+    fn idempotent() {
+        let modulus = ModulusPolynomialRingZq::from_str("5  1 0 0 0 1 mod 17").unwrap();
+        let poly = PolynomialRingZq::from((&PolyOverZ::from_str("3  1 2 3").unwrap(), &modulus));
+
+        let poly_ntt = poly.to_ntt_domain().unwrap();
+
+        assert_eq!(poly_ntt.poly, poly_ntt.to_ntt_domain().unwrap().poly);
+        assert_eq!(poly.poly, poly.to_coeff_domain().poly);
+    }
+
+    /// Ensure that an error is returned if the NTT is not supported
+    #[test]
+    fn not_supported() {
+        let not_cyclotomic = ModulusPolynomialRingZq::from_str("5  1 0 0 1 1 mod 17").unwrap();
+        let no_root = ModulusPolynomialRingZq::from_str("5  1 0 0 0 1 mod 19").unwrap();
+        let poly = PolyOverZ::from_str("3  1 2 3").unwrap();
+
+        assert!(matches!(
+            PolynomialRingZq::from((&poly, &not_cyclotomic)).to_ntt_domain(),
+            Err(MathError::NttNotSupported(_))
+        ));
+        assert!(matches!(
+            PolynomialRingZq::from((&poly, &no_root)).to_ntt_domain(),
+            Err(MathError::NttNotSupported(_))
+        ));
+    }
+}
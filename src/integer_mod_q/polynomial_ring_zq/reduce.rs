@@ -57,7 +57,11 @@ mod test_reduced {
         let modulus =
             ModulusPolynomialRingZq::from_str(&format!("4  1 0 0 1 mod {}", BITPRIME64)).unwrap();
         let poly = PolyOverZ::from_str(&format!("4  {} {} 1 1", BITPRIME64 + 2, u64::MAX)).unwrap();
-        let mut poly_ring = PolynomialRingZq { poly, modulus };
+        let mut poly_ring = PolynomialRingZq {
+            poly,
+            modulus,
+            ntt_domain: false,
+        };
 
         let cmp_modulus =
             ModulusPolynomialRingZq::from_str(&format!("4  1 0 0 1 mod {}", BITPRIME64)).unwrap();
@@ -65,6 +69,7 @@ mod test_reduced {
         let cmp_poly_ring = PolynomialRingZq {
             poly: cmp_poly.clone(),
             modulus: cmp_modulus,
+            ntt_domain: false,
         };
 
         // we only compare the parts individually, not under the modulus, hence they should not be the same
@@ -0,0 +1,121 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the computation of the rotation matrix of a
+//! [`PolynomialRingZq`], which expresses the multiplication with an element
+//! of the ring as a matrix-vector product.
+
+use super::PolynomialRingZq;
+use crate::{
+    integer::{PolyOverZ, Z},
+    integer_mod_q::MatZq,
+    traits::{GetCoefficient, SetCoefficient, SetEntry},
+};
+
+impl PolynomialRingZq {
+    /// Returns the `n x n` rotation matrix of `self`, where `n` is the degree
+    /// of the modulus polynomial `f`. The `j`-th column holds the coefficients of
+    /// `self * X^j mod f`, s.t. multiplying the matrix with the coefficient vector
+    /// of an element `b` yields the coefficient vector of `self * b`.
+    /// For `f = X^n + 1`, this is the negacyclic matrix of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{MatZq, ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("3  1 2 3").unwrap();
+    /// let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+    ///
+    /// let matrix = poly_ring.rot_matrix();
+    ///
+    /// let cmp = MatZq::from_str("[[1, 14, 15],[2, 1, 14],[3, 2, 1]] mod 17").unwrap();
+    /// assert_eq!(cmp, matrix);
+    /// ```
+    pub fn rot_matrix(&self) -> MatZq {
+        let degree = self.modulus.get_degree();
+        let mut out = MatZq::new(degree, degree, self.modulus.get_q()).unwrap();
+
+        let mut x = PolyOverZ::default();
+        x.set_coeff(1, 1).unwrap();
+        let x = PolynomialRingZq::from((&x, &self.modulus));
+
+        let mut column = self.to_coeff_domain();
+        for j in 0..degree {
+            for i in 0..=column.poly.get_degree() {
+                let coeff: Z = column.poly.get_coeff(i).unwrap();
+                out.set_entry(i, j, &coeff).unwrap();
+            }
+            column = &column * &x;
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_rot_matrix {
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{MatZq, Modulus, ModulusPolynomialRingZq, PolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that the rotation matrix is negacyclic for `X^n + 1`
+    #[test]
+    fn negacyclic() {
+        let modulus = ModulusPolynomialRingZq::from_str("5  1 0 0 0 1 mod 17").unwrap();
+        let poly_ring =
+            PolynomialRingZq::from((&PolyOverZ::from_str("4  1 2 3 4").unwrap(), &modulus));
+
+        let cmp =
+            MatZq::from_str("[[1, 13, 14, 15],[2, 1, 13, 14],[3, 2, 1, 13],[4, 3, 2, 1]] mod 17")
+                .unwrap();
+        assert_eq!(cmp, poly_ring.rot_matrix());
+    }
+
+    /// Ensure that the matrix-vector product equals the product in the ring
+    /// for a general modulus polynomial
+    #[test]
+    fn matches_multiplication() {
+        let modulus =
+            ModulusPolynomialRingZq::from_str(&format!("4  3 1 5 1 mod {}", u64::MAX - 58))
+                .unwrap();
+        let a = PolynomialRingZq::from((
+            &PolyOverZ::from_str(&format!("3  {} -2 {}", u64::MAX, i64::MIN)).unwrap(),
+            &modulus,
+        ));
+        let b = PolynomialRingZq::from((&PolyOverZ::from_str("3  7 -1 9").unwrap(), &modulus));
+
+        assert_eq!((&a * &b).to_mat_zq(), a.rot_matrix() * b.to_mat_zq());
+    }
+
+    /// Ensure that the rotation matrix can be computed in the NTT domain
+    #[test]
+    fn ntt_domain() {
+        let q = Modulus::from_str("17").unwrap();
+        let modulus = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(4, &q).unwrap();
+        let poly_ring =
+            PolynomialRingZq::from((&PolyOverZ::from_str("4  1 2 3 4").unwrap(), &modulus));
+
+        assert_eq!(
+            poly_ring.rot_matrix(),
+            poly_ring.to_ntt_domain().unwrap().rot_matrix()
+        );
+    }
+
+    /// Ensure that the rotation matrix of the zero polynomial is the zero matrix
+    #[test]
+    fn zero() {
+        let modulus = ModulusPolynomialRingZq::from_str("3  1 0 1 mod 17").unwrap();
+        let zero = PolynomialRingZq::from((&PolyOverZ::default(), &modulus));
+
+        assert_eq!(MatZq::new(2, 2, 17).unwrap(), zero.rot_matrix());
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains algorithms for sampling [`PolynomialRingZq`] instances
+//! uniformly at random.
+
+use super::PolynomialRingZq;
+use crate::{integer::PolyOverZ, integer_mod_q::ModulusPolynomialRingZq};
+
+impl PolynomialRingZq {
+    /// Samples an element of the ring `Z_q[X]/f(X)` uniformly at random, i.e.
+    /// each of the `n` coefficients is chosen uniformly at random from `[0, q)` using
+    /// a cryptographically secure pseudorandom number generator,
+    /// where `n` is the degree of `f(X)`.
+    ///
+    /// Parameters:
+    /// - `modulus`: the modulus which defines the ring
+    ///
+    /// Returns a uniformly random element of the ring defined by `modulus`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("5  1 0 0 0 1 mod 17").unwrap();
+    ///
+    /// let sample = PolynomialRingZq::sample_uniform(&modulus);
+    /// ```
+    pub fn sample_uniform(modulus: &ModulusPolynomialRingZq) -> Self {
+        let poly = PolyOverZ::sample_uniform(modulus.get_degree() - 1, 0, modulus.get_q()).unwrap();
+        Self::from_poly_over_z_modulus_polynomial_ring_zq(&poly, modulus)
+    }
+}
+
+#[cfg(test)]
+mod test_sample_uniform {
+    use super::PolynomialRingZq;
+    use crate::{
+        integer::Z,
+        integer_mod_q::ModulusPolynomialRingZq,
+        traits::{GetCoefficient, GetEntry},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that all coefficients are reduced and the degree is bounded
+    /// for a large modulus
+    #[test]
+    fn coefficients_reduced() {
+        let q = Z::from(u64::MAX - 58);
+        let modulus =
+            ModulusPolynomialRingZq::from_str(&format!("65  1{} 1 mod {}", " 0".repeat(63), q))
+                .unwrap();
+
+        let sample = PolynomialRingZq::sample_uniform(&modulus);
+
+        assert_eq!(modulus, sample.modulus);
+        assert!(sample.poly.get_degree() < 64);
+        for index in 0..64 {
+            let coeff: Z = sample.poly.get_coeff(index).unwrap();
+            assert!(Z::ZERO <= coeff);
+            assert!(coeff < q);
+        }
+    }
+
+    /// Ensure that every residue of a small modulus is sampled
+    #[test]
+    fn all_residues_sampled() {
+        let modulus =
+            ModulusPolynomialRingZq::from_str(&format!("257  1{} 1 mod 7", " 0".repeat(255)))
+                .unwrap();
+
+        let vector = PolynomialRingZq::sample_uniform(&modulus).to_mat_zq();
+        let entries: Vec<Z> = (0..256).map(|i| vector.get_entry(i, 0).unwrap()).collect();
+
+        for value in 0..7 {
+            assert!(entries.contains(&Z::from(value)));
+        }
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains implementations of functions
+//! important for serialization such as the [`Serialize`] and [`Deserialize`] trait.
+//!
+//! The explicit functions contain the documentation.
+
+use super::PolynomialRingZq;
+use crate::{
+    integer::{PolyOverZ, Z},
+    integer_mod_q::ModulusPolynomialRingZq,
+    traits::GetCoefficient,
+};
+use core::fmt;
+use serde::{
+    de::{Error, MapAccess, Unexpected, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Serialize,
+};
+use std::str::FromStr;
+
+impl Serialize for PolynomialRingZq {
+    /// Implements the serialize option. This allows to create a Json-object
+    /// from a given [`PolynomialRingZq`] containing its coefficients and its modulus,
+    /// e.g. `{"poly":"2  1 16","modulus":"4  1 0 0 1 mod 17"}`.
+    /// Elements in the NTT domain are serialized in the coefficient domain.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("PolynomialRingZq", 2)?;
+        state.serialize_field("poly", &self.to_coeff_domain().poly.to_string())?;
+        state.serialize_field("modulus", &self.modulus.to_string())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for PolynomialRingZq {
+    /// Implements the deserialize option. This allows to create a [`PolynomialRingZq`]
+    /// from a given Json-object containing its coefficients and its modulus.
+    /// The polynomial has to be reduced, i.e. its degree has to be smaller than
+    /// the degree of the modulus polynomial and its coefficients have to lie in `[0, q)`.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        /// This enum defines the content of the struct to be generated using [`Deserialize`]
+        const FIELDS: &[&str] = &["poly", "modulus"];
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field {
+            Poly,
+            Modulus,
+        }
+
+        /// This visitor iterates over the strings content and collects all possible fields.
+        /// It sets the corresponding values of the struct based on the values found.
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PolynomialRingZq;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter
+                    .write_str("struct PolynomialRingZq with a reduced polynomial and a modulus")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> Result<PolynomialRingZq, V::Error>
+            where
+                V: MapAccess<'de>,
+            {
+                let mut poly: Option<String> = None;
+                let mut modulus: Option<String> = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::Poly => {
+                            if poly.is_some() {
+                                return Err(Error::duplicate_field("poly"));
+                            }
+                            poly = Some(map.next_value()?);
+                        }
+                        Field::Modulus => {
+                            if modulus.is_some() {
+                                return Err(Error::duplicate_field("modulus"));
+                            }
+                            modulus = Some(map.next_value()?);
+                        }
+                    }
+                }
+                let poly = poly.ok_or_else(|| Error::missing_field("poly"))?;
+                let modulus = modulus.ok_or_else(|| Error::missing_field("modulus"))?;
+
+                let modulus = ModulusPolynomialRingZq::from_str(&modulus)
+                    .map_err(|_| Error::invalid_value(Unexpected::Str(&modulus), &self))?;
+                let poly = PolyOverZ::from_str(&poly)
+                    .map_err(|_| Error::invalid_value(Unexpected::Str(&poly), &self))?;
+
+                // only reduced polynomials are accepted s.t. each [`PolynomialRingZq`]
+                // has a unique encoding
+                let q = modulus.get_q();
+                let is_reduced = poly.get_degree() < modulus.get_degree()
+                    && (0..=poly.get_degree()).all(|i| {
+                        let coeff: Z = poly.get_coeff(i).unwrap();
+                        coeff >= Z::ZERO && coeff < q
+                    });
+                if !is_reduced {
+                    return Err(Error::invalid_value(
+                        Unexpected::Str(&poly.to_string()),
+                        &"a polynomial reduced by the modulus",
+                    ));
+                }
+                Ok(PolynomialRingZq {
+                    poly,
+                    modulus,
+                    ntt_domain: false,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct("PolynomialRingZq", FIELDS, StructVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test_serialize {
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{Modulus, ModulusPolynomialRingZq, PolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// tests whether the serialization of a [`PolynomialRingZq`] contains
+    /// the reduced polynomial and the modulus.
+    #[test]
+    fn serialize_output() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let poly = PolyOverZ::from_str("5  1 -1 0 0 1").unwrap();
+        let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+        let cmp_string = "{\"poly\":\"2  1 15\",\"modulus\":\"4  1 0 0 1 mod 17\"}";
+
+        assert_eq!(cmp_string, serde_json::to_string(&poly_ring).unwrap())
+    }
+
+    /// tests whether the serialization of a large [`PolynomialRingZq`] works.
+    #[test]
+    fn serialize_output_large() {
+        let modulus =
+            ModulusPolynomialRingZq::from_str(&format!("3  1 0 1 mod {}", u64::MAX - 58)).unwrap();
+        let poly = PolyOverZ::from_str(&format!("2  -1 {}", u64::MAX)).unwrap();
+        let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+        let cmp_string = format!(
+            "{{\"poly\":\"2  {} 58\",\"modulus\":\"3  1 0 1 mod {}\"}}",
+            u64::MAX - 59,
+            u64::MAX - 58
+        );
+
+        assert_eq!(cmp_string, serde_json::to_string(&poly_ring).unwrap())
+    }
+
+    /// tests whether elements in the NTT domain are serialized in the coefficient domain.
+    #[test]
+    fn serialize_ntt_domain() {
+        let q = Modulus::from_str("17").unwrap();
+        let modulus = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(4, &q).unwrap();
+        let poly = PolyOverZ::from_str("3  1 2 3").unwrap();
+        let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+
+        assert_eq!(
+            serde_json::to_string(&poly_ring).unwrap(),
+            serde_json::to_string(&poly_ring.to_ntt_domain().unwrap()).unwrap()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test_deserialize {
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq},
+    };
+    use std::str::FromStr;
+
+    /// tests whether the deserialization of a [`PolynomialRingZq`] works.
+    #[test]
+    fn deserialize_small() {
+        let poly_string = "{\"poly\":\"2  1 15\",\"modulus\":\"4  1 0 0 1 mod 17\"}";
+
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let poly = PolyOverZ::from_str("2  1 15").unwrap();
+        assert_eq!(
+            PolynomialRingZq::from((&poly, &modulus)),
+            serde_json::from_str::<PolynomialRingZq>(poly_string).unwrap()
+        )
+    }
+
+    /// tests whether the order of the fields is irrelevant.
+    #[test]
+    fn deserialize_field_order() {
+        let poly_string = "{\"modulus\":\"4  1 0 0 1 mod 17\",\"poly\":\"0\"}";
+
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        assert_eq!(
+            PolynomialRingZq::from((&PolyOverZ::default(), &modulus)),
+            serde_json::from_str::<PolynomialRingZq>(poly_string).unwrap()
+        )
+    }
+
+    /// tests whether serialization and deserialization of a large [`PolynomialRingZq`] round-trips.
+    #[test]
+    fn round_trip_large() {
+        let modulus =
+            ModulusPolynomialRingZq::from_str(&format!("4  1 0 0 1 mod {}", u64::MAX - 58))
+                .unwrap();
+        let poly = PolyOverZ::from_str(&format!("3  {} -1 {}", i64::MIN, u64::MAX)).unwrap();
+        let poly_ring = PolynomialRingZq::from((&poly, &modulus));
+
+        let poly_string = serde_json::to_string(&poly_ring).unwrap();
+
+        assert_eq!(
+            poly_ring,
+            serde_json::from_str::<PolynomialRingZq>(&poly_string).unwrap()
+        )
+    }
+
+    /// tests whether polynomials that are not reduced yield an error.
+    #[test]
+    fn not_reduced() {
+        for poly in ["2  1 17", "2  -1 1", "4  1 0 0 1", "5  0 0 0 0 1"] {
+            let poly_string = format!(
+                "{{\"poly\":\"{}\",\"modulus\":\"4  1 0 0 1 mod 17\"}}",
+                poly
+            );
+
+            assert!(serde_json::from_str::<PolynomialRingZq>(&poly_string).is_err());
+        }
+    }
+
+    /// tests whether invalid moduli yield an error.
+    #[test]
+    fn invalid_modulus() {
+        for modulus in ["4  1 0 0 1 mod 16", "4  1 0 0 1 mod -17", "4  1 0 0 1"] {
+            let poly_string = format!("{{\"poly\":\"2  1 1\",\"modulus\":\"{}\"}}", modulus);
+
+            assert!(serde_json::from_str::<PolynomialRingZq>(&poly_string).is_err());
+        }
+    }
+
+    /// tests whether missing, duplicate and unknown fields yield an error.
+    #[test]
+    fn invalid_fields() {
+        for poly_string in [
+            "{\"poly\":\"2  1 1\"}",
+            "{\"modulus\":\"4  1 0 0 1 mod 17\"}",
+            "{\"poly\":\"2  1 1\",\"poly\":\"2  1 1\",\"modulus\":\"4  1 0 0 1 mod 17\"}",
+            "{\"poly\":\"2  1 1\",\"modulus\":\"4  1 0 0 1 mod 17\",\"tree\":\"1\"}",
+            "{\"poly\":\"2  1 1 mod 17\"}",
+        ] {
+            assert!(serde_json::from_str::<PolynomialRingZq>(poly_string).is_err());
+        }
+    }
+}
@@ -0,0 +1,235 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementations to set coefficients for a [`PolynomialRingZq`] value from other types.
+//! Each reasonable type should be used to set a coefficient.
+
+use super::PolynomialRingZq;
+use crate::{
+    error::MathError,
+    integer::Z,
+    integer_mod_q::Zq,
+    macros::for_others::{implement_for_others, implement_for_owned},
+    traits::SetCoefficient,
+    utils::index::evaluate_index,
+};
+use flint_sys::{fmpz::fmpz_mod, fmpz_poly::fmpz_poly_set_coeff_fmpz};
+use std::fmt::Display;
+
+impl SetCoefficient<&Z> for PolynomialRingZq {
+    /// Sets the coefficient of a polynomial [`PolynomialRingZq`].
+    /// The value is reduced modulo `q`. If `self` is in the NTT domain,
+    /// it is converted into the coefficient domain beforehand.
+    ///
+    /// Parameters:
+    /// - `index`: the index of the coefficient to set (has to be positive and
+    /// smaller than the degree of the modulus polynomial)
+    /// - `value`: the new value the index should have from a borrowed [`Z`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq};
+    /// use qfall_math::traits::*;
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("3  0 1 2").unwrap();
+    /// let mut poly_ring = PolynomialRingZq::from((&poly, &modulus));
+    /// let value = Z::from(1000);
+    ///
+    /// assert!(poly_ring.set_coeff(2, &value).is_ok());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// either the index is negative, it does not fit into an [`i64`] or it is not
+    /// smaller than the degree of the modulus polynomial.
+    fn set_coeff(
+        &mut self,
+        index: impl TryInto<i64> + Display + Copy,
+        value: &Z,
+    ) -> Result<(), MathError> {
+        let index = evaluate_index(index)?;
+        let degree = self.modulus.get_degree();
+        if index >= degree {
+            return Err(MathError::OutOfBounds(
+                format!(
+                    "be smaller than the degree of the modulus polynomial {}",
+                    degree
+                ),
+                index.to_string(),
+            ));
+        }
+
+        if self.ntt_domain {
+            *self = self.to_coeff_domain();
+        }
+        let mut reduced = Z::default();
+        unsafe {
+            fmpz_mod(
+                &mut reduced.value,
+                &value.value,
+                &self.modulus.get_q().value,
+            );
+            fmpz_poly_set_coeff_fmpz(&mut self.poly.poly, index, &reduced.value);
+        };
+        Ok(())
+    }
+}
+
+impl SetCoefficient<&Zq> for PolynomialRingZq {
+    /// Sets the coefficient of a polynomial [`PolynomialRingZq`].
+    /// If `self` is in the NTT domain, it is converted into the
+    /// coefficient domain beforehand.
+    ///
+    /// Parameters:
+    /// - `index`: the index of the coefficient to set (has to be positive and
+    /// smaller than the degree of the modulus polynomial)
+    /// - `value`: the new value the index should have from a borrowed [`Zq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::PolyOverZ;
+    /// use qfall_math::integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq, Zq};
+    /// use qfall_math::traits::*;
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+    /// let poly = PolyOverZ::from_str("3  0 1 2").unwrap();
+    /// let mut poly_ring = PolynomialRingZq::from((&poly, &modulus));
+    /// let value = Zq::try_from((1000, 17)).unwrap();
+    ///
+    /// assert!(poly_ring.set_coeff(2, &value).is_ok());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// either the index is negative, it does not fit into an [`i64`] or it is not
+    /// smaller than the degree of the modulus polynomial.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingModulus`](MathError::MismatchingModulus) if the moduli of
+    /// the polynomial and the input mismatch
+    fn set_coeff(
+        &mut self,
+        index: impl TryInto<i64> + Display + Copy,
+        value: &Zq,
+    ) -> Result<(), MathError> {
+        if self.modulus.get_q() != Z::from(&value.modulus) {
+            return Err(MathError::MismatchingModulus(value.to_string()));
+        }
+        self.set_coeff(index, &value.value)
+    }
+}
+
+implement_for_others!(Z, PolynomialRingZq, SetCoefficient for i8 i16 i32 i64 u8 u16 u32 u64);
+implement_for_owned!(Z, PolynomialRingZq, SetCoefficient);
+implement_for_owned!(Zq, PolynomialRingZq, SetCoefficient);
+
+#[cfg(test)]
+mod test_set_coeff_z {
+    use crate::{
+        integer::{PolyOverZ, Z},
+        integer_mod_q::{Modulus, ModulusPolynomialRingZq, PolynomialRingZq},
+        traits::{GetCoefficient, SetCoefficient},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that the coefficient is set and reduced
+    #[test]
+    fn set_reduced() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let mut poly_ring =
+            PolynomialRingZq::from((&PolyOverZ::from_str("3  0 1 2").unwrap(), &modulus));
+
+        poly_ring.set_coeff(0, -1).unwrap();
+        poly_ring.set_coeff(2, Z::from(1000)).unwrap();
+
+        let cmp = PolynomialRingZq::from((&PolyOverZ::from_str("3  16 1 14").unwrap(), &modulus));
+        assert_eq!(cmp, poly_ring);
+    }
+
+    /// Ensure that large values are reduced
+    #[test]
+    fn set_large() {
+        let q = u64::MAX - 58;
+        let modulus = ModulusPolynomialRingZq::from_str(&format!("3  1 0 1 mod {}", q)).unwrap();
+        let mut poly_ring = PolynomialRingZq::from((&PolyOverZ::default(), &modulus));
+
+        poly_ring.set_coeff(1, u64::MAX).unwrap();
+        poly_ring.set_coeff(0, i64::MIN).unwrap();
+
+        let coeff_0: Z = poly_ring.get_coeff(0).unwrap();
+        let coeff_1: Z = poly_ring.get_coeff(1).unwrap();
+        assert_eq!(Z::from(q) + Z::from(i64::MIN), coeff_0);
+        assert_eq!(Z::from(58), coeff_1);
+    }
+
+    /// Ensure that elements in the NTT domain are converted into the coefficient domain
+    #[test]
+    fn ntt_domain() {
+        let q = Modulus::from_str("17").unwrap();
+        let modulus = ModulusPolynomialRingZq::new_power_of_two_cyclotomic(4, &q).unwrap();
+        let poly_ring =
+            PolynomialRingZq::from((&PolyOverZ::from_str("3  1 2 3").unwrap(), &modulus));
+        let mut poly_ntt = poly_ring.to_ntt_domain().unwrap();
+
+        poly_ntt.set_coeff(3, 4).unwrap();
+
+        let cmp = PolynomialRingZq::from((&PolyOverZ::from_str("4  1 2 3 4").unwrap(), &modulus));
+        assert!(!poly_ntt.is_ntt_domain());
+        assert_eq!(cmp, poly_ntt);
+    }
+
+    /// Ensure that indices out of bounds return an error
+    #[test]
+    fn out_of_bounds() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let mut poly_ring = PolynomialRingZq::from((&PolyOverZ::default(), &modulus));
+
+        assert!(poly_ring.set_coeff(-1, 1).is_err());
+        assert!(poly_ring.set_coeff(i64::MIN, 1).is_err());
+        assert!(poly_ring.set_coeff(3, 1).is_err());
+        assert!(poly_ring.set_coeff(u64::MAX, 1).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_set_coeff_zq {
+    use crate::{
+        integer::PolyOverZ,
+        integer_mod_q::{ModulusPolynomialRingZq, PolynomialRingZq, Zq},
+        traits::SetCoefficient,
+    };
+    use std::str::FromStr;
+
+    /// Ensure that the coefficient is set
+    #[test]
+    fn set() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let mut poly_ring = PolynomialRingZq::from((&PolyOverZ::default(), &modulus));
+
+        poly_ring
+            .set_coeff(1, Zq::try_from((20, 17)).unwrap())
+            .unwrap();
+
+        let cmp = PolynomialRingZq::from((&PolyOverZ::from_str("2  0 3").unwrap(), &modulus));
+        assert_eq!(cmp, poly_ring);
+    }
+
+    /// Ensure that mismatching moduli return an error
+    #[test]
+    fn mismatching_modulus() {
+        let modulus = ModulusPolynomialRingZq::from_str("4  1 0 0 1 mod 17").unwrap();
+        let mut poly_ring = PolynomialRingZq::from((&PolyOverZ::default(), &modulus));
+
+        assert!(poly_ring
+            .set_coeff(1, &Zq::try_from((1, 19)).unwrap())
+            .is_err());
+    }
+}
@@ -19,13 +19,17 @@
 
 use super::Modulus;
 use crate::integer::Z;
-use serde::{Deserialize, Serialize};
 
 mod arithmetic;
+mod crt;
 pub(crate) mod fmpz_mod_helpers;
 mod from;
+mod group;
 mod properties;
 mod reduce;
+mod sample;
+mod serialize;
+mod sqrt;
 mod to_string;
 
 /// [`Zq`] represents an integer value in a modulus ring.
@@ -51,11 +55,14 @@ mod to_string;
 ///
 /// // to_string incl. (de-)serialization
 /// assert_eq!("5 mod 10", &a.to_string());
-/// let _ = serde_json::to_string(&a).unwrap();
+/// assert_eq!(
+///     "{\"value\":\"5\",\"modulus\":\"10\"}",
+///     serde_json::to_string(&a).unwrap()
+/// );
 ///
 /// # Ok::<(), MathError>(())
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Zq {
     pub(crate) value: Z,
     pub(crate) modulus: Modulus,
@@ -74,9 +74,9 @@ impl Zq {
     pub fn add_safe(&self, other: &Self) -> Result<Zq, MathError> {
         if self.modulus != other.modulus {
             return Err(MathError::MismatchingModulus(format!(
-                " Tried to add '{}' and '{}'.
+                " Tried to add '{}' and '{}' with the moduli '{}' and '{}'.
             If the modulus should be ignored please convert into a Z beforehand.",
-                self, other
+                self, other, self.modulus, other.modulus
             )));
         }
         let mut out = Zq::from_z_modulus(&Z::from(1), &self.modulus);
@@ -161,4 +161,15 @@ mod test_add {
         let b: Zq = Zq::try_from((1, 3)).unwrap();
         assert!(&a.add_safe(&b).is_err());
     }
+
+    /// testing whether the error message of add_safe contains both moduli
+    #[test]
+    fn add_safe_error_contains_moduli() {
+        let a: Zq = Zq::try_from((4, 11)).unwrap();
+        let b: Zq = Zq::try_from((1, 3)).unwrap();
+
+        let message = a.add_safe(&b).unwrap_err().to_string();
+
+        assert!(message.contains("'11' and '3'"));
+    }
 }
@@ -0,0 +1,183 @@
+// Copyright © 2023 Phil Milewski
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementation of the [`Div`] trait for [`Zq`] values.
+
+use super::super::Zq;
+use crate::{
+    error::MathError,
+    macros::arithmetics::{
+        arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
+    },
+};
+use std::ops::Div;
+
+impl Div for &Zq {
+    type Output = Zq;
+    /// Implements the [`Div`] trait for two [`Zq`] values, i.e. multiplies
+    /// `self` with the inverse of `other`.
+    /// [`Div`] is implemented for any combination of [`Zq`] and borrowed [`Zq`].
+    ///
+    /// Parameters:
+    /// - `other`: specifies the value `self` is divided by
+    ///
+    /// Returns the result of the division as a [`Zq`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::Zq;
+    ///
+    /// let a: Zq = Zq::try_from((23, 42)).unwrap();
+    /// let b: Zq = Zq::try_from((5, 42)).unwrap();
+    ///
+    /// let c: Zq = &a / &b;
+    /// let d: Zq = a / b;
+    /// let e: Zq = &c / d;
+    /// let f: Zq = c / &e;
+    /// ```
+    ///
+    /// # Panics
+    /// - Panics if the moduli of both [`Zq`] mismatch.
+    /// - Panics if `other` is not invertible.
+    fn div(self, other: Self) -> Self::Output {
+        self.div_safe(other).unwrap()
+    }
+}
+
+impl Zq {
+    /// Implements division for two [`Zq`] values, i.e. multiplies
+    /// `self` with the inverse of `divisor`.
+    ///
+    /// Parameters:
+    /// - `divisor`: specifies the value `self` is divided by
+    ///
+    /// Returns the result of the division as a [`Zq`] or an error if the moduli
+    /// mismatch or `divisor` is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::Zq;
+    ///
+    /// let a: Zq = Zq::try_from((3, 7)).unwrap();
+    /// let b: Zq = Zq::try_from((2, 7)).unwrap();
+    ///
+    /// let c: Zq = a.div_safe(&b).unwrap();
+    ///
+    /// assert_eq!(Zq::try_from((5, 7)).unwrap(), c);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`MathError::MismatchingModulus`] if the moduli of
+    /// both [`Zq`] mismatch.
+    /// - Returns a [`MathError`] of type [`MathError::NotInvertible`] if
+    /// `divisor` has no inverse modulo its modulus.
+    pub fn div_safe(&self, divisor: &Self) -> Result<Zq, MathError> {
+        if self.modulus != divisor.modulus {
+            return Err(MathError::MismatchingModulus(format!(
+                " Tried to divide '{}' by '{}' with the moduli '{}' and '{}'.
+            If the modulus should be ignored please convert into a Z beforehand.",
+                self, divisor, self.modulus, divisor.modulus
+            )));
+        }
+        self.mul_safe(&divisor.inverse()?)
+    }
+}
+
+arithmetic_trait_borrowed_to_owned!(Div, div, Zq, Zq, Zq);
+arithmetic_trait_mixed_borrowed_owned!(Div, div, Zq, Zq, Zq);
+
+#[cfg(test)]
+mod test_div {
+    use super::Zq;
+
+    /// testing division for two [`Zq`]
+    #[test]
+    fn div() {
+        let a: Zq = Zq::try_from((11, 17)).unwrap();
+        let b: Zq = Zq::try_from((12, 17)).unwrap();
+        let c: Zq = a / b;
+        assert_eq!(Zq::try_from((8, 17)).unwrap(), c);
+    }
+
+    /// testing division for two borrowed [`Zq`]
+    #[test]
+    fn div_borrow() {
+        let a: Zq = Zq::try_from((10, 11)).unwrap();
+        let b: Zq = Zq::try_from((2, 11)).unwrap();
+        let c: Zq = &a / &b;
+        assert_eq!(Zq::try_from((5, 11)).unwrap(), c);
+    }
+
+    /// testing division for borrowed [`Zq`] and [`Zq`]
+    #[test]
+    fn div_first_borrowed() {
+        let a: Zq = Zq::try_from((1, 11)).unwrap();
+        let b: Zq = Zq::try_from((5, 11)).unwrap();
+        let c: Zq = &a / b;
+        assert_eq!(Zq::try_from((9, 11)).unwrap(), c);
+    }
+
+    /// testing division for [`Zq`] and borrowed [`Zq`]
+    #[test]
+    fn div_second_borrowed() {
+        let a: Zq = Zq::try_from((12, 11)).unwrap();
+        let b: Zq = Zq::try_from((10, 11)).unwrap();
+        let c: Zq = a / &b;
+        assert_eq!(Zq::try_from((-1, 11)).unwrap(), c);
+    }
+
+    /// testing division for large [`Zq`]
+    #[test]
+    fn div_large() {
+        let a: Zq = Zq::try_from((u64::MAX - 1, u64::MAX)).unwrap();
+        let b: Zq = Zq::try_from((i64::MAX, u64::MAX)).unwrap();
+        let c: Zq = &a / &b;
+        assert_eq!(a, c * b);
+    }
+
+    /// testing that division by a non-invertible value panics
+    #[test]
+    #[should_panic]
+    fn div_not_invertible() {
+        let a: Zq = Zq::try_from((3, 8)).unwrap();
+        let b: Zq = Zq::try_from((2, 8)).unwrap();
+        let _ = a / b;
+    }
+
+    /// testing that division with mismatching moduli panics
+    #[test]
+    #[should_panic]
+    fn div_mismatching_modulus() {
+        let a: Zq = Zq::try_from((3, 7)).unwrap();
+        let b: Zq = Zq::try_from((2, 11)).unwrap();
+        let _ = a / b;
+    }
+
+    /// testing that `div_safe` returns errors instead of panicking
+    #[test]
+    fn div_safe_errors() {
+        let a: Zq = Zq::try_from((3, 8)).unwrap();
+        let b: Zq = Zq::try_from((2, 8)).unwrap();
+        let c: Zq = Zq::try_from((2, 7)).unwrap();
+
+        assert!(a.div_safe(&b).is_err());
+        assert!(a.div_safe(&c).is_err());
+        assert_eq!(Zq::try_from((1, 8)).unwrap(), a.div_safe(&a).unwrap());
+    }
+
+    /// testing whether the error message of div_safe contains both moduli
+    #[test]
+    fn div_safe_error_contains_moduli() {
+        let a: Zq = Zq::try_from((3, 8)).unwrap();
+        let b: Zq = Zq::try_from((2, 7)).unwrap();
+
+        let message = a.div_safe(&b).unwrap_err().to_string();
+
+        assert!(message.contains("'8' and '7'"));
+    }
+}
@@ -21,6 +21,8 @@ impl Pow<&Z> for Zq {
     type Output = Zq;
 
     /// Raises the value of `self` to the power of an integer `exp`.
+    /// Negative exponents are supported for invertible values of `self`,
+    /// i.e. `self^(-e)` is computed as `(self^(-1))^e`.
     ///
     /// Parameters:
     /// - `exp`: specifies the exponent to which the value is raised
@@ -40,6 +42,9 @@ impl Pow<&Z> for Zq {
     ///
     /// let cmp = Zq::try_from((7, 9)).unwrap();
     /// assert_eq!(cmp, powered_value);
+    ///
+    /// let inverse_powered = base.pow(-4).unwrap();
+    /// assert_eq!(Zq::try_from((4, 9)).unwrap(), inverse_powered);
     /// ```
     ///
     /// # Errors and Failures
@@ -108,6 +113,37 @@ mod test_pow {
         assert_eq!(cmp_2, res_2);
     }
 
+    /// Ensure that `pow` works for negative exponents that do not fit into an [`i64`]
+    #[test]
+    fn large_negative_exponent() {
+        let base = Zq::try_from((3, 7)).unwrap();
+        let inverse = base.inverse().unwrap();
+        // 3 has order 6 modulo 7, hence only the exponent modulo 6 matters
+        let exp = Z::from(i64::MIN) * Z::from(u64::MAX);
+        let exp_abs = Z::ZERO - &exp;
+
+        let res = base.pow(&exp).unwrap();
+
+        assert_eq!(inverse.pow(&exp_abs).unwrap(), res);
+        assert_eq!(Zq::try_from((1, 7)).unwrap(), res);
+    }
+
+    /// Ensure that negative exponents yield the inverse of positive ones
+    #[test]
+    fn negative_exponent_inverse() {
+        let base = Zq::try_from((i64::MAX, u64::MAX)).unwrap();
+
+        for exp in [1_i64, 2, 17, i64::MAX] {
+            let res_positive = base.pow(exp).unwrap();
+            let res_negative = base.pow(-exp).unwrap();
+
+            assert_eq!(
+                Zq::try_from((1, u64::MAX)).unwrap(),
+                res_positive * res_negative
+            );
+        }
+    }
+
     /// Ensures that the `pow` trait is available for other types
     #[test]
     fn availability() {
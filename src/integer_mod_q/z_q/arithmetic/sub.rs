@@ -74,9 +74,9 @@ impl Zq {
     pub fn sub_safe(&self, other: &Self) -> Result<Zq, MathError> {
         if self.modulus != other.modulus {
             return Err(MathError::MismatchingModulus(format!(
-                " Tried to subtract '{}' from '{}'.
+                " Tried to subtract '{}' from '{}' with the moduli '{}' and '{}'.
             If the modulus should be ignored please convert into a Z beforehand.",
-                self, other
+                other, self, other.modulus, self.modulus
             )));
         }
         let mut out = Zq::from_z_modulus(&Z::from(1), &self.modulus);
@@ -161,4 +161,15 @@ mod test_sub {
         let b: Zq = Zq::try_from((1, 3)).unwrap();
         assert!(&a.sub_safe(&b).is_err());
     }
+
+    /// testing whether the error message of sub_safe contains both moduli
+    #[test]
+    fn sub_safe_error_contains_moduli() {
+        let a: Zq = Zq::try_from((4, 11)).unwrap();
+        let b: Zq = Zq::try_from((1, 3)).unwrap();
+
+        let message = a.sub_safe(&b).unwrap_err().to_string();
+
+        assert!(message.contains("'3' and '11'"));
+    }
 }
@@ -0,0 +1,254 @@
+// Copyright © 2023 Marcel Luca Schmidt
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the Chinese Remainder Theorem for [`Zq`], i.e. the
+//! combination of values with coprime moduli and the inverse splitting.
+
+use super::Zq;
+use crate::{error::MathError, integer::Z, integer_mod_q::Modulus};
+use flint_sys::fmpz::{fmpz_CRT, fmpz_divisible, fmpz_gcd, fmpz_is_one, fmpz_mul};
+
+impl Zq {
+    /// Combines values with pairwise coprime moduli `q_1, ..., q_k` into the
+    /// unique value modulo `q_1 * ... * q_k` that is congruent to each
+    /// value modulo its modulus, i.e. applies the Chinese Remainder Theorem.
+    ///
+    /// Parameters:
+    /// - `values`: the values that are combined
+    ///
+    /// Returns the combined [`Zq`] or an error if the moduli are not pairwise coprime.
+    /// Values modulo `1` are ignored. For an empty slice, `0 mod 1` is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::Zq;
+    ///
+    /// let values = [
+    ///     Zq::try_from((2, 3)).unwrap(),
+    ///     Zq::try_from((3, 5)).unwrap(),
+    ///     Zq::try_from((2, 7)).unwrap(),
+    /// ];
+    ///
+    /// let combined = Zq::crt_combine(&values).unwrap();
+    ///
+    /// assert_eq!(Zq::try_from((23, 105)).unwrap(), combined);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NotCoprime`](MathError::NotCoprime)
+    /// if the moduli of the values are not pairwise coprime.
+    pub fn crt_combine(values: &[Zq]) -> Result<Zq, MathError> {
+        let mut value = Z::ZERO;
+        let mut modulus = Z::ONE;
+
+        for other in values {
+            let other_modulus = &other.modulus.get_fmpz_mod_ctx_struct().n[0];
+            // values modulo `1` carry no information and can not be combined by FLINT
+            if 1 == unsafe { fmpz_is_one(other_modulus) } {
+                continue;
+            }
+            let mut gcd = Z::default();
+            unsafe { fmpz_gcd(&mut gcd.value, &modulus.value, other_modulus) };
+            if 1 != unsafe { fmpz_is_one(&gcd.value) } {
+                return Err(MathError::NotCoprime(format!(
+                    "The modulus of {} shares the factor {} with the previous moduli.",
+                    other, gcd
+                )));
+            }
+
+            let mut combined = Z::default();
+            unsafe {
+                fmpz_CRT(
+                    &mut combined.value,
+                    &value.value,
+                    &modulus.value,
+                    &other.value.value,
+                    other_modulus,
+                    0,
+                );
+                fmpz_mul(&mut modulus.value, &modulus.value, other_modulus);
+            }
+            value = combined;
+        }
+
+        let modulus = Modulus::try_from_z(&modulus)?;
+        Ok(Zq::from_z_modulus(&value, &modulus))
+    }
+
+    /// Splits `self` into its residues modulo each of the given moduli,
+    /// i.e. the inverse operation of [`Zq::crt_combine`].
+    ///
+    /// Parameters:
+    /// - `moduli`: the moduli to which `self` is reduced, each has to divide
+    /// the modulus of `self`
+    ///
+    /// Returns the residues of `self` modulo the given moduli or an error
+    /// if one of the moduli does not divide the modulus of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{Modulus, Zq};
+    /// use std::str::FromStr;
+    ///
+    /// let value = Zq::try_from((23, 105)).unwrap();
+    /// let moduli = [
+    ///     Modulus::from_str("3").unwrap(),
+    ///     Modulus::from_str("35").unwrap(),
+    /// ];
+    ///
+    /// let residues = value.crt_split(&moduli).unwrap();
+    ///
+    /// assert_eq!(Zq::try_from((2, 3)).unwrap(), residues[0]);
+    /// assert_eq!(Zq::try_from((23, 35)).unwrap(), residues[1]);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingModulus`](MathError::MismatchingModulus) if one of the
+    /// moduli does not divide the modulus of `self`.
+    pub fn crt_split(&self, moduli: &[Modulus]) -> Result<Vec<Zq>, MathError> {
+        let self_modulus = &self.modulus.get_fmpz_mod_ctx_struct().n[0];
+
+        moduli
+            .iter()
+            .map(|modulus| {
+                if 0 == unsafe {
+                    fmpz_divisible(self_modulus, &modulus.get_fmpz_mod_ctx_struct().n[0])
+                } {
+                    return Err(MathError::MismatchingModulus(format!(
+                        " The modulus {} does not divide the modulus of {}.",
+                        modulus, self
+                    )));
+                }
+                Ok(Zq::from_z_modulus(&self.value, modulus))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test_crt_combine {
+    use super::Zq;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that the combined value is congruent to all values
+    #[test]
+    fn small_moduli() {
+        for (a, b) in [(0, 0), (1, 4), (6, 10), (2, 3)] {
+            let values = [
+                Zq::try_from((a, 7)).unwrap(),
+                Zq::try_from((b, 11)).unwrap(),
+            ];
+
+            let combined = Zq::crt_combine(&values).unwrap();
+
+            assert_eq!(Zq::try_from((77, 77)).unwrap().modulus, combined.modulus);
+            assert_eq!(
+                values[0],
+                Zq::from_z_modulus(&combined.value, &values[0].modulus)
+            );
+            assert_eq!(
+                values[1],
+                Zq::from_z_modulus(&combined.value, &values[1].modulus)
+            );
+        }
+    }
+
+    /// Ensure that large moduli are combined correctly
+    #[test]
+    fn large_moduli() {
+        let values = [
+            Zq::try_from((i64::MAX, u64::MAX)).unwrap(),
+            Zq::try_from((42, u64::MAX - 1)).unwrap(),
+            Zq::try_from((-1, 101)).unwrap(),
+        ];
+
+        let combined = Zq::crt_combine(&values).unwrap();
+
+        for value in values.iter() {
+            assert_eq!(*value, Zq::from_z_modulus(&combined.value, &value.modulus));
+        }
+    }
+
+    /// Ensure that `0 mod 1` is returned for an empty slice
+    #[test]
+    fn empty_slice() {
+        let combined = Zq::crt_combine(&[]).unwrap();
+
+        assert_eq!(Zq::from_str("0 mod 1").unwrap(), combined);
+    }
+
+    /// Ensure that values modulo `1` are ignored
+    #[test]
+    fn modulus_one() {
+        let values = [
+            Zq::from_str("0 mod 1").unwrap(),
+            Zq::try_from((2, 3)).unwrap(),
+            Zq::from_str("0 mod 1").unwrap(),
+            Zq::try_from((3, 5)).unwrap(),
+        ];
+
+        let combined = Zq::crt_combine(&values).unwrap();
+        let only_one = Zq::crt_combine(&values[..1]).unwrap();
+
+        assert_eq!(Zq::try_from((8, 15)).unwrap(), combined);
+        assert_eq!(Zq::from_str("0 mod 1").unwrap(), only_one);
+    }
+
+    /// Ensure that an error is returned for moduli that are not coprime
+    #[test]
+    fn not_coprime() {
+        let values = [
+            Zq::try_from((1, 6)).unwrap(),
+            Zq::try_from((1, 35)).unwrap(),
+            Zq::try_from((1, 10)).unwrap(),
+        ];
+
+        assert!(matches!(
+            Zq::crt_combine(&values),
+            Err(MathError::NotCoprime(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_crt_split {
+    use super::Zq;
+    use crate::{error::MathError, integer_mod_q::Modulus};
+    use std::str::FromStr;
+
+    /// Ensure that splitting and combining are inverse operations
+    #[test]
+    fn inverse_of_combine() {
+        let moduli = [
+            Modulus::from_str(&u64::MAX.to_string()).unwrap(),
+            Modulus::from_str("101").unwrap(),
+            Modulus::from_str("64").unwrap(),
+        ];
+        let value =
+            Zq::from_str(&format!("{} mod {}", i64::MAX, 101 * 64 * u64::MAX as u128)).unwrap();
+
+        let residues = value.crt_split(&moduli).unwrap();
+        let combined = Zq::crt_combine(&residues).unwrap();
+
+        assert_eq!(value, combined);
+    }
+
+    /// Ensure that an error is returned if a modulus does not divide the modulus of `self`
+    #[test]
+    fn not_dividing() {
+        let value = Zq::try_from((23, 105)).unwrap();
+        let moduli = [Modulus::from_str("4").unwrap()];
+
+        assert!(matches!(
+            value.crt_split(&moduli),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+}
@@ -9,7 +9,7 @@
 //! This module contains helpful functions on [`fmpz`] values in a ring/`modulus` context.
 
 use crate::integer::{fmpz_helpers::distance, Z};
-use flint_sys::fmpz::fmpz;
+use flint_sys::fmpz::{fmpz, fmpz_cmp, fmpz_mul_ui, fmpz_sub};
 
 const ZERO_FMPZ: fmpz = fmpz(0);
 
@@ -43,6 +43,37 @@ pub(crate) fn length(value: &fmpz, modulus: &fmpz) -> Z {
     }
 }
 
+/// Computes the representative of `value` in the symmetric interval
+/// `(-modulus/2, modulus/2]`.
+///
+/// WARNING: This function assumes `value` to be reduced,
+/// i.e. `0 <= value < modulus`.
+///
+/// # Example
+/// ```compile_fail
+/// use flint_sys::fmpz::fmpz;
+/// use qfall_math::integer_mod_q::fmpz_mod_helpers::symmetric_representative;
+///
+/// let modulus = fmpz(15);
+/// let value = fmpz(10);
+///
+/// let representative = symmetric_representative(&value, &modulus);
+///
+/// assert_eq!(-5, representative.0);
+/// ```
+pub(crate) fn symmetric_representative(value: &fmpz, modulus: &fmpz) -> Z {
+    let mut out = Z::ZERO;
+    unsafe { fmpz_mul_ui(&mut out.value, value, 2) };
+
+    // if 2 * value > modulus => return value - modulus
+    if unsafe { fmpz_cmp(&out.value, modulus) } > 0 {
+        unsafe { fmpz_sub(&mut out.value, value, modulus) };
+        out
+    } else {
+        Z::from_fmpz(value)
+    }
+}
+
 #[cfg(test)]
 mod test_length {
     use super::*;
@@ -75,3 +106,41 @@ mod test_length {
         assert_eq!(Z::from(58), length(&pos_2.value, &modulus.value));
     }
 }
+
+#[cfg(test)]
+mod test_symmetric_representative {
+    use super::*;
+    use crate::integer::Z;
+
+    /// Checks whether values are mapped into `(-modulus/2, modulus/2]`
+    /// for odd and even moduli
+    #[test]
+    fn small_values() {
+        let odd = fmpz(15);
+        let even = fmpz(16);
+
+        assert_eq!(Z::from(-5), symmetric_representative(&fmpz(10), &odd));
+        assert_eq!(Z::from(7), symmetric_representative(&fmpz(7), &odd));
+        assert_eq!(Z::from(-7), symmetric_representative(&fmpz(8), &odd));
+        assert_eq!(Z::ZERO, symmetric_representative(&fmpz(0), &odd));
+        assert_eq!(Z::from(8), symmetric_representative(&fmpz(8), &even));
+        assert_eq!(Z::from(-7), symmetric_representative(&fmpz(9), &even));
+    }
+
+    /// Checks whether large values are mapped into `(-modulus/2, modulus/2]`
+    #[test]
+    fn large_values() {
+        let modulus = Z::from(u64::MAX);
+        let pos_1 = Z::from(i64::MAX);
+        let pos_2 = Z::from(u64::MAX - 58);
+
+        assert_eq!(
+            Z::from(i64::MAX),
+            symmetric_representative(&pos_1.value, &modulus.value)
+        );
+        assert_eq!(
+            Z::from(-58),
+            symmetric_representative(&pos_2.value, &modulus.value)
+        );
+    }
+}
@@ -175,6 +175,23 @@ impl<T1: Into<Z>, T2: Into<Z>> TryFrom<(T1, T2)> for Zq {
     /// # Ok::<(), MathError>(())
     /// ```
     ///
+    /// Borrowed [`Z`] and [`Modulus`] values can be used as well.
+    /// The context of the [`Modulus`] is shared in this case.
+    /// ```
+    /// # use qfall_math::error::MathError;
+    /// use qfall_math::integer::Z;
+    /// use qfall_math::integer_mod_q::{Modulus, Zq};
+    /// use std::str::FromStr;
+    ///
+    /// let value = Z::from(42);
+    /// let modulus = Modulus::from_str("17")?;
+    ///
+    /// let answer = Zq::try_from((&value, &modulus))?;
+    ///
+    /// assert_eq!(Zq::from_z_modulus(&value, &modulus), answer);
+    /// # Ok::<(), MathError>(())
+    /// ```
+    ///
     /// # Errors and Failures
     /// - Returns a [`MathError`] of type
     ///   [`InvalidIntToModulus`](MathError::InvalidIntToModulus) if the
@@ -323,7 +340,11 @@ mod test_try_from_z_z {
 #[cfg(test)]
 mod test_try_from_trait {
 
-    use crate::{integer::Z, integer_mod_q::Zq};
+    use crate::{
+        integer::Z,
+        integer_mod_q::{Modulus, Zq},
+    };
+    use std::{rc::Rc, str::FromStr};
 
     /// Showcase some of the different types supported by the trait.
     #[test]
@@ -338,6 +359,19 @@ mod test_try_from_trait {
         assert!(Zq::try_from((Z::from(1), Z::from(2))).is_ok());
     }
 
+    /// Ensure that borrowed values and moduli are supported and
+    /// that the context of the modulus is shared.
+    #[test]
+    fn borrowed_value_and_modulus() {
+        let value = Z::from(u64::MAX);
+        let modulus = Modulus::from_str(&"1".repeat(65)).unwrap();
+
+        let zq = Zq::try_from((&value, &modulus)).unwrap();
+
+        assert_eq!(Zq::from_z_modulus(&value, &modulus), zq);
+        assert!(Rc::ptr_eq(&modulus.modulus, &zq.modulus.modulus));
+    }
+
     /// Ensure that the modulus calculation is performed at initialization.
     #[test]
     fn modulus_at_initialization() {
@@ -0,0 +1,525 @@
+// Copyright © 2023 Marcel Luca Schmidt
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains functions concerning the multiplicative group
+//! of the units modulo `q`, such as the order of an element and generators.
+
+use super::Zq;
+use crate::{
+    error::MathError,
+    integer::{fmpz_helpers::factor, Z},
+    integer_mod_q::Modulus,
+    traits::Pow,
+};
+use flint_sys::fmpz::{
+    fmpz_divexact, fmpz_divisible, fmpz_gcd, fmpz_is_one, fmpz_mul, fmpz_pow_ui,
+};
+
+impl Zq {
+    /// Computes the multiplicative order of `self`, i.e. the smallest positive
+    /// integer `k` with `self^k = 1 mod q`.
+    ///
+    /// Returns the order of `self` as a [`Z`] or an error if `self` is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::Z;
+    /// use qfall_math::integer_mod_q::Zq;
+    ///
+    /// let value = Zq::try_from((2, 7)).unwrap();
+    ///
+    /// let order = value.order().unwrap();
+    ///
+    /// assert_eq!(Z::from(3), order);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NotInvertible`](MathError::NotInvertible)
+    /// if `self` is not coprime to the modulus.
+    pub fn order(&self) -> Result<Z, MathError> {
+        if !self.is_unit() {
+            return Err(MathError::NotInvertible(format!(
+                "{}. Only units have a multiplicative order",
+                self
+            )));
+        }
+        let (mut order, factors) = euler_phi_factorization(&self.modulus);
+
+        // the order divides `phi(q)`, hence all prime factors are removed
+        // from `phi(q)` as long as the power still yields `1`
+        for (prime, exponent) in factors.iter() {
+            for _ in 0..*exponent {
+                let mut candidate = Z::default();
+                unsafe { fmpz_divexact(&mut candidate.value, &order.value, &prime.value) };
+                if !self.pow(&candidate).unwrap().is_one() {
+                    break;
+                }
+                order = candidate;
+            }
+        }
+        Ok(order)
+    }
+
+    /// Computes the multiplicative order of `self`, i.e. the smallest positive
+    /// integer `k` with `self^k = 1 mod q`. This is an alias of [`Zq::order`].
+    ///
+    /// Returns the order of `self` as a [`Z`] or an error if `self` is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::Z;
+    /// use qfall_math::integer_mod_q::Zq;
+    ///
+    /// let value = Zq::try_from((2, 7)).unwrap();
+    ///
+    /// let order = value.element_order().unwrap();
+    ///
+    /// assert_eq!(Z::from(3), order);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NotInvertible`](MathError::NotInvertible)
+    /// if `self` is not coprime to the modulus.
+    pub fn element_order(&self) -> Result<Z, MathError> {
+        self.order()
+    }
+
+    /// Computes a generator of the multiplicative group modulo `modulus`,
+    /// i.e. a primitive root. The smallest non-negative primitive root is returned.
+    /// Such a generator only exists if `modulus` is `1`, `2`, `4`, `p^k`
+    /// or `2p^k` for an odd prime `p`.
+    ///
+    /// Parameters:
+    /// - `modulus`: the modulus of the multiplicative group
+    ///
+    /// Returns a generator of the multiplicative group as a [`Zq`] or an error
+    /// if the group is not cyclic.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{Modulus, Zq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = Modulus::from_str("7").unwrap();
+    ///
+    /// let generator = Zq::generator(&modulus).unwrap();
+    ///
+    /// assert_eq!(Zq::try_from((3, 7)).unwrap(), generator);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NoPrimitiveRoot`](MathError::NoPrimitiveRoot)
+    /// if the multiplicative group modulo `modulus` is not cyclic.
+    pub fn generator(modulus: &Modulus) -> Result<Zq, MathError> {
+        let modulus_factors = factor(&modulus.get_fmpz_mod_ctx_struct().n[0]);
+        let odd_factors: Vec<&(Z, u64)> = modulus_factors
+            .iter()
+            .filter(|(prime, _)| prime != &Z::from(2))
+            .collect();
+        let exponent_two = modulus_factors.len() - odd_factors.len();
+        let is_cyclic = match odd_factors.len() {
+            0 => modulus_factors.is_empty() || modulus_factors[0].1 <= 2,
+            1 => exponent_two == 0 || modulus_factors[0].1 == 1,
+            _ => false,
+        };
+        if !is_cyclic {
+            return Err(MathError::NoPrimitiveRoot(format!(
+                "The multiplicative group modulo {} is not cyclic.",
+                modulus
+            )));
+        }
+
+        let (phi, factors) = euler_phi_factorization(modulus);
+        let mut candidate = Zq::from_z_modulus(&Z::ONE, modulus);
+        while !candidate.is_generator_with_factorization(&phi, &factors) {
+            candidate.value = &candidate.value + Z::ONE;
+        }
+        Ok(candidate)
+    }
+
+    /// Checks whether `self` generates the multiplicative group modulo `q`,
+    /// i.e. whether `self` is a primitive root and its order is `phi(q)`.
+    /// If the multiplicative group is not cyclic, no value is a generator.
+    ///
+    /// Returns `true` if `self` is a generator and `false` otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::Zq;
+    ///
+    /// assert!(Zq::try_from((3, 7)).unwrap().is_generator());
+    /// assert!(!Zq::try_from((2, 7)).unwrap().is_generator());
+    /// ```
+    pub fn is_generator(&self) -> bool {
+        let (phi, factors) = euler_phi_factorization(&self.modulus);
+        self.is_generator_with_factorization(&phi, &factors)
+    }
+
+    /// Checks whether `self` is a unit of order `phi`, i.e. whether
+    /// `self^(phi/p) != 1 mod q` holds for all prime factors `p` of `phi`.
+    ///
+    /// Parameters:
+    /// - `phi`: the order of the multiplicative group modulo `q`
+    /// - `factors`: the prime factorization of `phi`
+    fn is_generator_with_factorization(&self, phi: &Z, factors: &[(Z, u64)]) -> bool {
+        self.is_unit()
+            && factors.iter().all(|(prime, _)| {
+                let mut exponent = Z::default();
+                unsafe { fmpz_divexact(&mut exponent.value, &phi.value, &prime.value) };
+                !self.pow(&exponent).unwrap().is_one()
+            })
+    }
+
+    /// Computes a primitive `n`-th root of unity modulo `modulus`, i.e. a value
+    /// `w` with `w^n = 1 mod q` and `w^k != 1 mod q` for all `0 < k < n`.
+    /// The root is computed as `g^(phi(q)/n)` for the generator `g` returned
+    /// by [`Zq::generator`]. Hence, the multiplicative group modulo `q`
+    /// has to be cyclic and `n` has to divide its order `phi(q)`.
+    /// For a negacyclic NTT of length `n`, a primitive `2n`-th root of unity
+    /// can be computed by providing `2n`.
+    ///
+    /// Parameters:
+    /// - `n`: the order of the root of unity
+    /// - `modulus`: the modulus of the multiplicative group
+    ///
+    /// Returns a primitive `n`-th root of unity as a [`Zq`] or an error
+    /// if no such root exists.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::Z;
+    /// use qfall_math::integer_mod_q::{Modulus, Zq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = Modulus::from_str("17").unwrap();
+    ///
+    /// let root = Zq::primitive_root_of_unity(8, &modulus).unwrap();
+    ///
+    /// assert_eq!(Z::from(8), root.order().unwrap());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NonPositive`](MathError::NonPositive)
+    /// if `n` is not positive.
+    /// - Returns a [`MathError`] of type [`NoPrimitiveRoot`](MathError::NoPrimitiveRoot)
+    /// if the multiplicative group modulo `modulus` is not cyclic or
+    /// `n` does not divide `phi(q)`.
+    pub fn primitive_root_of_unity(n: impl Into<Z>, modulus: &Modulus) -> Result<Zq, MathError> {
+        let n: Z = n.into();
+        if n < Z::ONE {
+            return Err(MathError::NonPositive(format!(
+                "{}. The order of a root of unity has to be positive",
+                n
+            )));
+        }
+
+        let generator = Zq::generator(modulus)?;
+        let (phi, _) = euler_phi_factorization(modulus);
+        if 0 == unsafe { fmpz_divisible(&phi.value, &n.value) } {
+            return Err(MathError::NoPrimitiveRoot(format!(
+                "No primitive {}-th root of unity exists modulo {}, as {} does not divide phi(q) = {}.",
+                n, modulus, n, phi
+            )));
+        }
+
+        let mut exponent = Z::default();
+        unsafe { fmpz_divexact(&mut exponent.value, &phi.value, &n.value) };
+        Ok(generator.pow(&exponent).unwrap())
+    }
+
+    /// Checks whether `self` is coprime to the modulus.
+    fn is_unit(&self) -> bool {
+        let mut gcd = Z::default();
+        unsafe {
+            fmpz_gcd(
+                &mut gcd.value,
+                &self.value.value,
+                &self.modulus.get_fmpz_mod_ctx_struct().n[0],
+            );
+            1 == fmpz_is_one(&gcd.value)
+        }
+    }
+
+    /// Checks whether `self` is equal to `1 mod q`.
+    fn is_one(&self) -> bool {
+        self == &Zq::from_z_modulus(&Z::ONE, &self.modulus)
+    }
+}
+
+/// Computes Euler's totient function `phi(q)` of `modulus` together with
+/// the prime factorization of `phi(q)`.
+///
+/// Parameters:
+/// - `modulus`: the modulus `q` for which `phi(q)` is computed
+///
+/// Returns `phi(q)` and its prime factors with their exponents.
+fn euler_phi_factorization(modulus: &Modulus) -> (Z, Vec<(Z, u64)>) {
+    let mut phi = Z::ONE;
+    let mut phi_factors: Vec<(Z, u64)> = Vec::new();
+    let mut add_factor = |prime: Z, exponent: u64| {
+        match phi_factors.iter_mut().find(|(p, _)| p == &prime) {
+            Some((_, e)) => *e += exponent,
+            None => phi_factors.push((prime, exponent)),
+        };
+    };
+
+    // `phi(p^k) = p^(k-1) * (p - 1)`
+    for (prime, exponent) in factor(&modulus.get_fmpz_mod_ctx_struct().n[0]) {
+        let prime_minus_one = &prime - Z::ONE;
+        let mut power = Z::default();
+        unsafe {
+            fmpz_pow_ui(&mut power.value, &prime.value, exponent - 1);
+            fmpz_mul(&mut phi.value, &phi.value, &power.value);
+            fmpz_mul(&mut phi.value, &phi.value, &prime_minus_one.value);
+        }
+
+        for (factor_prime, factor_exponent) in factor(&prime_minus_one.value) {
+            add_factor(factor_prime, factor_exponent);
+        }
+        if exponent > 1 {
+            add_factor(prime, exponent - 1);
+        }
+    }
+    (phi, phi_factors)
+}
+
+#[cfg(test)]
+mod test_order {
+    use super::Zq;
+    use crate::{error::MathError, integer::Z};
+
+    /// Computes the order of `value` by repeated multiplication
+    fn brute_force_order(value: &Zq) -> Z {
+        let mut power = value.clone();
+        let mut order = Z::ONE;
+        while !power.is_one() {
+            power = &power * value;
+            order = &order + Z::ONE;
+        }
+        order
+    }
+
+    /// Compares the order with the order computed by brute force
+    /// for all units of small moduli
+    #[test]
+    fn brute_force_small_moduli() {
+        for modulus in 1..80_u64 {
+            for value in 0..modulus {
+                let zq = Zq::try_from((value, modulus)).unwrap();
+                if zq.is_unit() {
+                    assert_eq!(brute_force_order(&zq), zq.order().unwrap());
+                }
+            }
+        }
+    }
+
+    /// Ensure that the order is computed correctly for large moduli
+    #[test]
+    fn large_modulus() {
+        // 2^64 - 59 is prime and `-1` has order `2`
+        let minus_one = Zq::try_from((-1, u64::MAX - 58)).unwrap();
+        let one = Zq::try_from((1, u64::MAX - 58)).unwrap();
+        let power = Zq::try_from((2, 3_u64.pow(40))).unwrap();
+
+        assert_eq!(Z::from(2), minus_one.order().unwrap());
+        assert_eq!(Z::ONE, one.order().unwrap());
+        assert_eq!(Z::from(2 * 3_u64.pow(39)), power.order().unwrap());
+    }
+
+    /// Ensure that an error is returned for values that are not coprime to the modulus
+    #[test]
+    fn not_invertible() {
+        let value = Zq::try_from((6, 15)).unwrap();
+
+        assert!(matches!(value.order(), Err(MathError::NotInvertible(_))));
+    }
+
+    /// Ensure that `element_order` is an alias of `order`
+    #[test]
+    fn element_order() {
+        let value = Zq::try_from((3, 17)).unwrap();
+        let not_invertible = Zq::try_from((6, 15)).unwrap();
+
+        assert_eq!(value.order().unwrap(), value.element_order().unwrap());
+        assert!(matches!(
+            not_invertible.element_order(),
+            Err(MathError::NotInvertible(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_generator {
+    use super::{euler_phi_factorization, Zq};
+    use crate::{error::MathError, integer_mod_q::Modulus};
+    use std::str::FromStr;
+
+    /// Ensure that the generator has order `phi(q)` for all small moduli
+    /// with cyclic groups and that an error is returned otherwise
+    #[test]
+    fn small_moduli() {
+        for q in 1..200_u64 {
+            let modulus = Modulus::from_str(&q.to_string()).unwrap();
+            let (phi, _) = euler_phi_factorization(&modulus);
+            let is_cyclic = (0..q).any(|value| {
+                let value = Zq::try_from((value, q)).unwrap();
+                value.is_unit() && value.order().unwrap() == phi
+            });
+
+            match Zq::generator(&modulus) {
+                Ok(generator) => {
+                    assert!(is_cyclic);
+                    assert_eq!(phi, generator.order().unwrap());
+                }
+                Err(MathError::NoPrimitiveRoot(_)) => assert!(!is_cyclic),
+                Err(_) => panic!("unexpected error"),
+            }
+        }
+    }
+
+    /// Ensure that a generator is found for large prime and prime power moduli
+    #[test]
+    fn large_moduli() {
+        for value in [
+            (u64::MAX - 58).to_string(),
+            3_u64.pow(40).to_string(),
+            (2 * 3_u64.pow(39)).to_string(),
+        ] {
+            let modulus = Modulus::from_str(&value).unwrap();
+            let (phi, _) = euler_phi_factorization(&modulus);
+
+            let generator = Zq::generator(&modulus).unwrap();
+
+            assert_eq!(phi, generator.order().unwrap());
+        }
+    }
+
+    /// Ensure that an error is returned for non-cyclic groups
+    #[test]
+    fn not_cyclic() {
+        for value in ["8", "15", "1024", "18446744073709551615"] {
+            let modulus = Modulus::from_str(value).unwrap();
+
+            assert!(matches!(
+                Zq::generator(&modulus),
+                Err(MathError::NoPrimitiveRoot(_))
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_primitive_root_of_unity {
+    use super::Zq;
+    use crate::{error::MathError, integer::Z, integer_mod_q::Modulus};
+    use std::str::FromStr;
+
+    /// Ensure that the roots have the requested order for all divisors of `q - 1`
+    #[test]
+    fn small_prime() {
+        let modulus = Modulus::from_str("97").unwrap();
+
+        for n in [1, 2, 3, 4, 6, 8, 12, 16, 24, 32, 48, 96] {
+            let root = Zq::primitive_root_of_unity(n, &modulus).unwrap();
+
+            assert_eq!(Z::from(n), root.order().unwrap());
+        }
+    }
+
+    /// Ensure that roots of unity are found for NTT-friendly moduli
+    #[test]
+    fn ntt_friendly_modulus() {
+        // `12289 = 3 * 2^12 + 1`
+        let modulus = Modulus::from_str("12289").unwrap();
+
+        let root = Zq::primitive_root_of_unity(2048, &modulus).unwrap();
+
+        assert_eq!(Z::from(2048), root.order().unwrap());
+    }
+
+    /// Ensure that roots of unity are found for large moduli
+    #[test]
+    fn large_modulus() {
+        // `2^64 - 59` is prime and `2^64 - 60` is divisible by `4`
+        let modulus = Modulus::from_str(&(u64::MAX - 58).to_string()).unwrap();
+
+        let root = Zq::primitive_root_of_unity(4, &modulus).unwrap();
+
+        assert_eq!(Z::from(4), root.order().unwrap());
+    }
+
+    /// Ensure that an error is returned if `n` does not divide `phi(q)`
+    /// or the group is not cyclic
+    #[test]
+    fn no_root() {
+        let prime = Modulus::from_str("17").unwrap();
+        let not_cyclic = Modulus::from_str("15").unwrap();
+
+        assert!(matches!(
+            Zq::primitive_root_of_unity(3, &prime),
+            Err(MathError::NoPrimitiveRoot(_))
+        ));
+        assert!(matches!(
+            Zq::primitive_root_of_unity(2, &not_cyclic),
+            Err(MathError::NoPrimitiveRoot(_))
+        ));
+    }
+
+    /// Ensure that an error is returned for non-positive orders
+    #[test]
+    fn non_positive_order() {
+        let modulus = Modulus::from_str("17").unwrap();
+
+        assert!(matches!(
+            Zq::primitive_root_of_unity(0, &modulus),
+            Err(MathError::NonPositive(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_is_generator {
+    use super::{euler_phi_factorization, Zq};
+    use crate::integer_mod_q::Modulus;
+    use std::str::FromStr;
+
+    /// Ensure that exactly the elements of order `phi(q)` are generators
+    #[test]
+    fn brute_force_small_moduli() {
+        for q in 1..100_u64 {
+            let modulus = Modulus::from_str(&q.to_string()).unwrap();
+            let (phi, _) = euler_phi_factorization(&modulus);
+
+            for value in 0..q {
+                let value = Zq::try_from((value, q)).unwrap();
+                let expected = value.is_unit() && value.order().unwrap() == phi;
+
+                assert_eq!(expected, value.is_generator());
+            }
+        }
+    }
+
+    /// Ensure that generators of large moduli are recognized
+    #[test]
+    fn large_modulus() {
+        let modulus = Modulus::from_str(&(u64::MAX - 58).to_string()).unwrap();
+        let generator = Zq::generator(&modulus).unwrap();
+
+        assert!(generator.is_generator());
+        assert!(!(&generator * &generator).is_generator());
+        assert!(!Zq::try_from((0, u64::MAX - 58)).unwrap().is_generator());
+    }
+
+    /// Ensure that no value is a generator of a non-cyclic group
+    #[test]
+    fn not_cyclic() {
+        for value in 0..8 {
+            assert!(!Zq::try_from((value, 8)).unwrap().is_generator());
+        }
+    }
+}
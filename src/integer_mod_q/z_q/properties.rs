@@ -9,7 +9,12 @@
 //! This module includes functionality about properties of [`Zq`] instances.
 
 use super::Zq;
-use crate::traits::Pow;
+use crate::{
+    error::MathError,
+    integer::{fmpz_helpers::factor, Z},
+    traits::Pow,
+};
+use flint_sys::fmpz::{fmpz_jacobi, fmpz_mod, fmpz_pow_ui, fmpz_remove, fmpz_tstbit};
 
 impl Zq {
     /// Returns the inverse of `self` as a fresh [`Zq`] instance.
@@ -25,7 +30,158 @@ impl Zq {
     /// assert_eq!(Zq::try_from((2, 7)).unwrap(), inverse);
     /// ```
     pub fn inv(&self) -> Option<Zq> {
-        self.pow(-1).ok()
+        self.inverse().ok()
+    }
+
+    /// Returns the multiplicative inverse of `self` as a fresh [`Zq`] instance.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::Zq;
+    /// let value = Zq::try_from((4, 7)).unwrap();
+    ///
+    /// let inverse = value.inverse().unwrap();
+    ///
+    /// assert_eq!(Zq::try_from((2, 7)).unwrap(), inverse);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NotInvertible`](MathError::NotInvertible)
+    /// if `self` and the modulus are not coprime, i.e. no inverse exists.
+    pub fn inverse(&self) -> Result<Zq, MathError> {
+        self.pow(-1).map_err(|_| {
+            MathError::NotInvertible(format!(
+                "{}, as the value and the modulus are not coprime",
+                self
+            ))
+        })
+    }
+
+    /// Replaces each value in `values` by its multiplicative inverse.
+    /// Montgomery's trick is used, i.e. only one inversion and `3n`
+    /// multiplications are performed for `n` values.
+    ///
+    /// Parameters:
+    /// - `values`: the values that are inverted in place
+    ///
+    /// Returns an empty `Ok` if all values were inverted or an error otherwise.
+    /// If an error is returned, `values` is left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::Zq;
+    ///
+    /// let mut values = [
+    ///     Zq::try_from((4, 7)).unwrap(),
+    ///     Zq::try_from((3, 7)).unwrap(),
+    /// ];
+    ///
+    /// Zq::batch_invert(&mut values).unwrap();
+    ///
+    /// assert_eq!(Zq::try_from((2, 7)).unwrap(), values[0]);
+    /// assert_eq!(Zq::try_from((5, 7)).unwrap(), values[1]);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingModulus`](MathError::MismatchingModulus) if the values
+    /// do not share the same modulus.
+    /// - Returns a [`MathError`] of type [`NotInvertible`](MathError::NotInvertible)
+    /// if one of the values is not invertible.
+    pub fn batch_invert(values: &mut [Zq]) -> Result<(), MathError> {
+        let first = match values.first() {
+            Some(first) => first,
+            None => return Ok(()),
+        };
+
+        // `prefix_products[i]` is the product of all values before index `i`
+        let mut prefix_products = Vec::with_capacity(values.len());
+        let mut product = Zq::from_z_modulus(&Z::ONE, &first.modulus);
+        for value in values.iter() {
+            let next_product = product.mul_safe(value)?;
+            prefix_products.push(product);
+            product = next_product;
+        }
+
+        let mut inverse = product.inverse().map_err(|_| {
+            MathError::NotInvertible(format!(
+                "{}, as at least one of the values is not coprime to the modulus",
+                product
+            ))
+        })?;
+
+        // `inverse` is the inverse of the product of all values up to index `i`
+        for (value, prefix_product) in values.iter_mut().zip(prefix_products.iter()).rev() {
+            let value_inverse = &inverse * prefix_product;
+            inverse = &inverse * &*value;
+            *value = value_inverse;
+        }
+        Ok(())
+    }
+
+    /// Checks whether `self` is a quadratic residue, i.e. whether a value `r`
+    /// with `r^2 = self mod q` exists.
+    /// For odd prime moduli the Legendre symbol is evaluated. Otherwise,
+    /// the modulus is factorized and `self` is checked modulo each prime power.
+    ///
+    /// Returns `true` if `self` is a square modulo `q`, otherwise `false`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::Zq;
+    ///
+    /// let square = Zq::try_from((2, 7)).unwrap();
+    /// let non_square = Zq::try_from((3, 7)).unwrap();
+    ///
+    /// assert!(square.is_quadratic_residue());
+    /// assert!(!non_square.is_quadratic_residue());
+    /// ```
+    pub fn is_quadratic_residue(&self) -> bool {
+        let modulus = &self.modulus.get_fmpz_mod_ctx_struct().n[0];
+
+        if self.modulus.is_prime() && Z::from_fmpz(modulus) != Z::from(2) {
+            // the Legendre symbol is `0` for `0`, which is a square as well
+            return -1 != unsafe { fmpz_jacobi(&self.value.value, modulus) };
+        }
+
+        factor(modulus).iter().all(|(prime, exponent)| {
+            is_quadratic_residue_prime_power(&self.value, prime, *exponent)
+        })
+    }
+}
+
+/// Checks whether `value` is a square modulo `prime^exponent`.
+///
+/// Parameters:
+/// - `value`: the value that is checked
+/// - `prime`: the prime of the modulus
+/// - `exponent`: the exponent of the modulus
+///
+/// Returns `true` if `value` is a square modulo `prime^exponent`, otherwise `false`.
+fn is_quadratic_residue_prime_power(value: &Z, prime: &Z, exponent: u64) -> bool {
+    let mut modulus = Z::default();
+    let mut reduced = Z::default();
+    unsafe {
+        fmpz_pow_ui(&mut modulus.value, &prime.value, exponent);
+        fmpz_mod(&mut reduced.value, &value.value, &modulus.value);
+    }
+    if reduced == Z::ZERO {
+        return true;
+    }
+
+    // write `value = prime^valuation * unit`, where `unit` is coprime to `prime`
+    let mut unit = Z::default();
+    let valuation = unsafe { fmpz_remove(&mut unit.value, &reduced.value, &prime.value) } as u64;
+    if valuation % 2 == 1 {
+        return false;
+    }
+
+    if prime == &Z::from(2) {
+        // odd squares are `1 mod 8`, which is only relevant up to the remaining exponent
+        let relevant_bits = (exponent - valuation).min(3);
+        (1..relevant_bits).all(|bit| 0 == unsafe { fmpz_tstbit(&unit.value, bit) })
+    } else {
+        1 == unsafe { fmpz_jacobi(&unit.value, &prime.value) }
     }
 }
 
@@ -77,3 +233,147 @@ mod test_inv {
         assert!(val_2.inv().is_none());
     }
 }
+
+#[cfg(test)]
+mod test_inverse {
+    use super::Zq;
+    use crate::error::MathError;
+
+    /// Checks whether the inverse is correctly computed for small and large values
+    #[test]
+    fn correct_values() {
+        let val_0 = Zq::try_from((4, 7)).unwrap();
+        let val_1 = Zq::try_from((i64::MAX, u64::MAX)).unwrap();
+
+        assert_eq!(Zq::try_from((2, 7)).unwrap(), val_0.inverse().unwrap());
+        assert_eq!(
+            Zq::try_from((18446744073709551613_u64, u64::MAX)).unwrap(),
+            val_1.inverse().unwrap()
+        );
+    }
+
+    /// Checks whether `inverse` returns an error for values without an inverse
+    #[test]
+    fn no_inverse_returns_error() {
+        let val_0 = Zq::try_from((4, 8)).unwrap();
+        let val_1 = Zq::try_from((0, 7)).unwrap();
+
+        assert!(matches!(val_0.inverse(), Err(MathError::NotInvertible(_))));
+        assert!(matches!(val_1.inverse(), Err(MathError::NotInvertible(_))));
+    }
+}
+
+#[cfg(test)]
+mod test_batch_invert {
+    use super::Zq;
+    use crate::error::MathError;
+
+    /// Ensure that all values are replaced by their inverses
+    #[test]
+    fn correct_values() {
+        let originals: Vec<Zq> = (1..50)
+            .map(|value| Zq::try_from((value, 53)).unwrap())
+            .collect();
+        let mut values = originals.clone();
+
+        Zq::batch_invert(&mut values).unwrap();
+
+        for (original, inverse) in originals.iter().zip(values.iter()) {
+            assert_eq!(original.inverse().unwrap(), *inverse);
+        }
+    }
+
+    /// Ensure that large values are inverted correctly
+    #[test]
+    fn large_values() {
+        let mut values = [
+            Zq::try_from((i64::MAX, u64::MAX)).unwrap(),
+            Zq::try_from((u64::MAX - 2, u64::MAX)).unwrap(),
+        ];
+
+        Zq::batch_invert(&mut values).unwrap();
+
+        assert_eq!(
+            Zq::try_from((18446744073709551613_u64, u64::MAX)).unwrap(),
+            values[0]
+        );
+        assert_eq!(Zq::try_from((i64::MAX, u64::MAX)).unwrap(), values[1]);
+    }
+
+    /// Ensure that an empty slice is accepted
+    #[test]
+    fn empty_slice() {
+        let mut values: [Zq; 0] = [];
+
+        assert!(Zq::batch_invert(&mut values).is_ok());
+    }
+
+    /// Ensure that an error is returned and the values are unchanged
+    /// if one value is not invertible
+    #[test]
+    fn not_invertible() {
+        let originals = [
+            Zq::try_from((3, 8)).unwrap(),
+            Zq::try_from((4, 8)).unwrap(),
+            Zq::try_from((5, 8)).unwrap(),
+        ];
+        let mut values = originals.clone();
+
+        assert!(matches!(
+            Zq::batch_invert(&mut values),
+            Err(MathError::NotInvertible(_))
+        ));
+        assert_eq!(originals, values);
+    }
+
+    /// Ensure that an error is returned for mismatching moduli
+    #[test]
+    fn mismatching_moduli() {
+        let mut values = [Zq::try_from((3, 8)).unwrap(), Zq::try_from((3, 7)).unwrap()];
+
+        assert!(matches!(
+            Zq::batch_invert(&mut values),
+            Err(MathError::MismatchingModulus(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_is_quadratic_residue {
+    use super::Zq;
+
+    /// Compares the result with the squares computed by brute force
+    /// for all small moduli
+    #[test]
+    fn brute_force_small_moduli() {
+        for modulus in 1..100_u64 {
+            let squares: Vec<u64> = (0..modulus).map(|r| r * r % modulus).collect();
+
+            for value in 0..modulus {
+                let zq = Zq::try_from((value, modulus)).unwrap();
+
+                assert_eq!(
+                    squares.contains(&value),
+                    zq.is_quadratic_residue(),
+                    "{value} mod {modulus}"
+                );
+            }
+        }
+    }
+
+    /// Ensure that large prime and prime power moduli are supported
+    #[test]
+    fn large_moduli() {
+        // 2^64 - 59 is prime
+        let root = Zq::try_from((i64::MAX, u64::MAX - 58)).unwrap();
+        let square = &root * &root;
+        let root_power = Zq::try_from((u64::MAX, 3_u64.pow(40))).unwrap();
+        let square_power = &root_power * &root_power;
+
+        assert!(square.is_quadratic_residue());
+        assert!(square_power.is_quadratic_residue());
+        assert!(!Zq::try_from((2, 3_u64.pow(40)))
+            .unwrap()
+            .is_quadratic_residue());
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains algorithms for sampling [`Zq`] values
+//! uniformly at random.
+
+use super::Zq;
+use crate::{
+    error::MathError, integer::Z, integer_mod_q::Modulus, utils::sample::sample_uniform_rejection,
+};
+
+impl Zq {
+    /// Samples a value uniformly at random from `[0, q)` using a
+    /// cryptographically secure pseudorandom number generator.
+    ///
+    /// Parameters:
+    /// - `modulus`: the modulus `q` of the sampled value
+    ///
+    /// Returns a uniformly random [`Zq`] modulo `modulus`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{Modulus, Zq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = Modulus::from_str("17").unwrap();
+    ///
+    /// let sample = Zq::sample_uniform(&modulus);
+    /// ```
+    pub fn sample_uniform(modulus: &Modulus) -> Zq {
+        let sample = sample_uniform_rejection(&Z::from(modulus)).unwrap();
+        Zq::from_z_modulus(&sample, modulus)
+    }
+
+    /// Samples a non-zero value uniformly at random from `[1, q)` using a
+    /// cryptographically secure pseudorandom number generator.
+    ///
+    /// Parameters:
+    /// - `modulus`: the modulus `q` of the sampled value
+    ///
+    /// Returns a uniformly random non-zero [`Zq`] modulo `modulus` or an error
+    /// if `modulus` is `1`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::{Modulus, Zq};
+    /// use std::str::FromStr;
+    ///
+    /// let modulus = Modulus::from_str("17").unwrap();
+    ///
+    /// let sample = Zq::sample_uniform_nonzero(&modulus).unwrap();
+    ///
+    /// assert_ne!(Zq::try_from((0, 17)).unwrap(), sample);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NonPositive`](MathError::NonPositive)
+    /// if `modulus` is `1`, i.e. no non-zero value exists.
+    pub fn sample_uniform_nonzero(modulus: &Modulus) -> Result<Zq, MathError> {
+        let sample = sample_uniform_rejection(&(Z::from(modulus) - Z::ONE))?;
+        Ok(Zq::from_z_modulus(&(sample + Z::ONE), modulus))
+    }
+}
+
+#[cfg(test)]
+mod test_sample_uniform {
+    use super::Zq;
+    use crate::integer_mod_q::Modulus;
+    use std::str::FromStr;
+
+    /// Ensure that every residue of a small modulus is sampled
+    #[test]
+    fn all_residues_sampled() {
+        let modulus = Modulus::from_str("7").unwrap();
+        let mut sampled = Vec::new();
+
+        for _ in 0..500 {
+            let sample = Zq::sample_uniform(&modulus);
+            if !sampled.contains(&sample) {
+                sampled.push(sample);
+            }
+        }
+        assert_eq!(7, sampled.len());
+    }
+
+    /// Ensure that large moduli are supported
+    #[test]
+    fn large_modulus() {
+        let modulus = Modulus::from_str(&"1".repeat(65)).unwrap();
+
+        let sample = Zq::sample_uniform(&modulus);
+
+        assert_eq!(modulus, sample.modulus);
+    }
+
+    /// Ensure that the only value modulo `1` is returned
+    #[test]
+    fn modulus_one() {
+        let modulus = Modulus::from_str("1").unwrap();
+
+        assert_eq!(Zq::try_from((0, 1)).unwrap(), Zq::sample_uniform(&modulus));
+    }
+}
+
+#[cfg(test)]
+mod test_sample_uniform_nonzero {
+    use super::Zq;
+    use crate::{error::MathError, integer_mod_q::Modulus};
+    use std::str::FromStr;
+
+    /// Ensure that zero is never sampled and all non-zero residues are
+    #[test]
+    fn all_nonzero_residues_sampled() {
+        let modulus = Modulus::from_str("5").unwrap();
+        let zero = Zq::try_from((0, 5)).unwrap();
+        let mut sampled = Vec::new();
+
+        for _ in 0..500 {
+            let sample = Zq::sample_uniform_nonzero(&modulus).unwrap();
+            assert_ne!(zero, sample);
+            if !sampled.contains(&sample) {
+                sampled.push(sample);
+            }
+        }
+        assert_eq!(4, sampled.len());
+    }
+
+    /// Ensure that the only non-zero value modulo `2` is returned
+    #[test]
+    fn modulus_two() {
+        let modulus = Modulus::from_str("2").unwrap();
+
+        assert_eq!(
+            Zq::try_from((1, 2)).unwrap(),
+            Zq::sample_uniform_nonzero(&modulus).unwrap()
+        );
+    }
+
+    /// Ensure that an error is returned if no non-zero value exists
+    #[test]
+    fn modulus_one() {
+        let modulus = Modulus::from_str("1").unwrap();
+
+        assert!(matches!(
+            Zq::sample_uniform_nonzero(&modulus),
+            Err(MathError::NonPositive(_))
+        ));
+    }
+}
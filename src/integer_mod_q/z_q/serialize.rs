@@ -0,0 +1,209 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains implementations of functions
+//! important for serialization such as the [`Serialize`] and [`Deserialize`] trait.
+//!
+//! The explicit functions contain the documentation.
+
+use super::Zq;
+use crate::{integer::Z, integer_mod_q::Modulus};
+use core::fmt;
+use serde::{
+    de::{Error, MapAccess, Unexpected, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Serialize,
+};
+use std::str::FromStr;
+
+impl Serialize for Zq {
+    /// Implements the serialize option. This allows to create a Json-object
+    /// from a given [`Zq`] containing its value and its modulus,
+    /// e.g. `{"value":"13","modulus":"17"}`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("Zq", 2)?;
+        state.serialize_field("value", &self.value.to_string())?;
+        state.serialize_field("modulus", &self.modulus.to_string())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Zq {
+    /// Implements the deserialize option. This allows to create a [`Zq`]
+    /// from a given Json-object containing its value and its modulus.
+    /// The value has to be reduced, i.e. lie in `[0, modulus)`.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        /// This enum defines the content of the struct to be generated using [`Deserialize`]
+        const FIELDS: &[&str] = &["value", "modulus"];
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field {
+            Value,
+            Modulus,
+        }
+
+        /// This visitor iterates over the strings content and collects all possible fields.
+        /// It sets the corresponding values of the struct based on the values found.
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Zq;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Zq with a reduced value and a positive modulus")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> Result<Zq, V::Error>
+            where
+                V: MapAccess<'de>,
+            {
+                let mut value: Option<String> = None;
+                let mut modulus: Option<String> = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::Value => {
+                            if value.is_some() {
+                                return Err(Error::duplicate_field("value"));
+                            }
+                            value = Some(map.next_value()?);
+                        }
+                        Field::Modulus => {
+                            if modulus.is_some() {
+                                return Err(Error::duplicate_field("modulus"));
+                            }
+                            modulus = Some(map.next_value()?);
+                        }
+                    }
+                }
+                let value = value.ok_or_else(|| Error::missing_field("value"))?;
+                let modulus = modulus.ok_or_else(|| Error::missing_field("modulus"))?;
+
+                let modulus = Modulus::from_str(&modulus)
+                    .map_err(|_| Error::invalid_value(Unexpected::Str(&modulus), &self))?;
+                let value = Z::from_str(&value)
+                    .map_err(|_| Error::invalid_value(Unexpected::Str(&value), &self))?;
+
+                // only reduced values are accepted s.t. each [`Zq`] has a unique encoding
+                if value < Z::ZERO || value >= Z::from(&modulus) {
+                    return Err(Error::invalid_value(
+                        Unexpected::Str(&value.to_string()),
+                        &"a value between 0 and the modulus",
+                    ));
+                }
+                Ok(Zq { value, modulus })
+            }
+        }
+
+        deserializer.deserialize_struct("Zq", FIELDS, StructVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test_serialize {
+    use crate::integer_mod_q::Zq;
+    use std::str::FromStr;
+
+    /// tests whether the serialization of a [`Zq`] contains value and modulus.
+    #[test]
+    fn serialize_output() {
+        let zq = Zq::from_str("-4 mod 17").unwrap();
+        let cmp_string = "{\"value\":\"13\",\"modulus\":\"17\"}";
+
+        assert_eq!(cmp_string, serde_json::to_string(&zq).unwrap())
+    }
+
+    /// tests whether the serialization of a large [`Zq`] works.
+    #[test]
+    fn serialize_output_large() {
+        let zq = Zq::try_from((u64::MAX - 1, u64::MAX)).unwrap();
+        let cmp_string = format!(
+            "{{\"value\":\"{}\",\"modulus\":\"{}\"}}",
+            u64::MAX - 1,
+            u64::MAX
+        );
+
+        assert_eq!(cmp_string, serde_json::to_string(&zq).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test_deserialize {
+    use crate::integer_mod_q::Zq;
+    use std::str::FromStr;
+
+    /// tests whether the deserialization of a [`Zq`] works.
+    #[test]
+    fn deserialize_small() {
+        let zq_string = "{\"value\":\"13\",\"modulus\":\"17\"}";
+
+        assert_eq!(
+            Zq::try_from((13, 17)).unwrap(),
+            serde_json::from_str::<Zq>(zq_string).unwrap()
+        )
+    }
+
+    /// tests whether the order of the fields is irrelevant.
+    #[test]
+    fn deserialize_field_order() {
+        let zq_string = "{\"modulus\":\"17\",\"value\":\"0\"}";
+
+        assert_eq!(
+            Zq::try_from((0, 17)).unwrap(),
+            serde_json::from_str::<Zq>(zq_string).unwrap()
+        )
+    }
+
+    /// tests whether serialization and deserialization of a large [`Zq`] round-trips.
+    #[test]
+    fn round_trip_large() {
+        let zq = Zq::from_str(&format!("{} mod {}", i64::MIN, u128::MAX)).unwrap();
+
+        let zq_string = serde_json::to_string(&zq).unwrap();
+
+        assert_eq!(zq, serde_json::from_str::<Zq>(&zq_string).unwrap())
+    }
+
+    /// tests whether values that are not reduced yield an error.
+    #[test]
+    fn not_reduced() {
+        for value in ["17", "-1", "100"] {
+            let zq_string = format!("{{\"value\":\"{}\",\"modulus\":\"17\"}}", value);
+
+            assert!(serde_json::from_str::<Zq>(&zq_string).is_err());
+        }
+    }
+
+    /// tests whether invalid moduli yield an error.
+    #[test]
+    fn invalid_modulus() {
+        for modulus in ["0", "-17", "a"] {
+            let zq_string = format!("{{\"value\":\"0\",\"modulus\":\"{}\"}}", modulus);
+
+            assert!(serde_json::from_str::<Zq>(&zq_string).is_err());
+        }
+    }
+
+    /// tests whether missing, duplicate and unknown fields yield an error.
+    #[test]
+    fn invalid_fields() {
+        for zq_string in [
+            "{\"value\":\"13\"}",
+            "{\"modulus\":\"17\"}",
+            "{\"value\":\"13\",\"value\":\"13\",\"modulus\":\"17\"}",
+            "{\"value\":\"13\",\"modulus\":\"17\",\"tree\":\"17\"}",
+            "{\"value\":\"13 mod 17\"}",
+        ] {
+            assert!(serde_json::from_str::<Zq>(zq_string).is_err());
+        }
+    }
+}
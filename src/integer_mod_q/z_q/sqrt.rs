@@ -0,0 +1,263 @@
+// Copyright © 2023 Marcel Luca Schmidt
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the square root of [`Zq`].
+
+use super::Zq;
+use crate::{error::MathError, integer::Z};
+use flint_sys::fmpz::{
+    fmpz_invmod, fmpz_is_zero, fmpz_mod, fmpz_mul, fmpz_pow_ui, fmpz_remove, fmpz_sqrtmod,
+    fmpz_sub, fmpz_tstbit,
+};
+
+impl Zq {
+    /// Computes a square root of `self`, i.e. a value `r` with `r^2 = self mod q`.
+    /// The modulus `q` has to be a prime or a prime power. For prime moduli,
+    /// the Tonelli-Shanks algorithm is used, which is then lifted to prime powers
+    /// via Hensel lifting.
+    ///
+    /// Returns one of the square roots of `self` or an error if the modulus
+    /// is not a prime power or `self` is not a quadratic residue.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::Zq;
+    ///
+    /// let value = Zq::try_from((2, 7)).unwrap();
+    ///
+    /// let root = value.sqrt().unwrap();
+    ///
+    /// assert_eq!(value, &root * &root);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NotPrime`](MathError::NotPrime)
+    /// if the modulus is not a prime power.
+    /// - Returns a [`MathError`] of type
+    /// [`NotQuadraticResidue`](MathError::NotQuadraticResidue) if `self` has
+    /// no square root modulo `q`.
+    pub fn sqrt(&self) -> Result<Zq, MathError> {
+        let (prime, exponent) = self
+            .modulus
+            .get_prime_power_decomposition()
+            .ok_or_else(|| {
+                MathError::NotPrime(format!(
+                    "{}. The square root is only supported for prime power moduli",
+                    self.modulus
+                ))
+            })?;
+        let modulus = Z::from_fmpz(&self.modulus.get_fmpz_mod_ctx_struct().n[0]);
+
+        if 1 == unsafe { fmpz_is_zero(&self.value.value) } {
+            return Ok(self.clone());
+        }
+
+        // write `self = prime^valuation * unit`, where `unit` is coprime to `prime`
+        let mut unit = Z::default();
+        let valuation =
+            unsafe { fmpz_remove(&mut unit.value, &self.value.value, &prime.value) } as u64;
+        if valuation % 2 == 1 {
+            return Err(MathError::NotQuadraticResidue(format!(
+                "{}, as the valuation of the value at {} is odd",
+                self, prime
+            )));
+        }
+
+        // if `s^2 = unit mod prime^(exponent - valuation)`,
+        // then `(prime^(valuation/2) * s)^2 = self mod prime^exponent`
+        let root_unit =
+            sqrt_unit_prime_power(&unit, &prime, exponent - valuation).ok_or_else(|| {
+                MathError::NotQuadraticResidue(format!(
+                    "{}, as no square root modulo {} exists",
+                    self, self.modulus
+                ))
+            })?;
+
+        let mut out = Z::default();
+        unsafe {
+            fmpz_pow_ui(&mut out.value, &prime.value, valuation / 2);
+            fmpz_mul(&mut out.value, &out.value, &root_unit.value);
+            fmpz_mod(&mut out.value, &out.value, &modulus.value);
+        }
+        Ok(Zq::from_z_modulus(&out, &self.modulus))
+    }
+}
+
+/// Computes a square root of `unit` modulo `prime^exponent`, where `unit`
+/// is coprime to `prime`.
+///
+/// Parameters:
+/// - `unit`: the value whose square root is computed
+/// - `prime`: the prime of the modulus
+/// - `exponent`: the exponent of the modulus
+///
+/// Returns a square root or `None` if `unit` is not a quadratic residue.
+fn sqrt_unit_prime_power(unit: &Z, prime: &Z, exponent: u64) -> Option<Z> {
+    let mut modulus = Z::default();
+    let mut value = Z::default();
+    unsafe {
+        fmpz_pow_ui(&mut modulus.value, &prime.value, exponent);
+        fmpz_mod(&mut value.value, &unit.value, &modulus.value);
+    }
+
+    if prime == &Z::from(2) {
+        return sqrt_unit_power_of_two(&value, exponent);
+    }
+
+    // Tonelli-Shanks modulo `prime`
+    let mut root = Z::default();
+    let mut reduced = Z::default();
+    unsafe {
+        fmpz_mod(&mut reduced.value, &value.value, &prime.value);
+        if 0 == fmpz_sqrtmod(&mut root.value, &reduced.value, &prime.value) {
+            return None;
+        }
+    }
+
+    // Hensel lifting via Newton iteration `r = r - (r^2 - value) / (2r)`,
+    // which doubles the exponent of the precision in each iteration
+    let mut precision = 1;
+    let mut difference = Z::default();
+    let mut inverse = Z::default();
+    while precision < exponent {
+        unsafe {
+            fmpz_mul(&mut difference.value, &root.value, &root.value);
+            fmpz_sub(&mut difference.value, &difference.value, &value.value);
+            fmpz_mul(&mut inverse.value, &root.value, &Z::from(2).value);
+            fmpz_invmod(&mut inverse.value, &inverse.value, &modulus.value);
+            fmpz_mul(&mut difference.value, &difference.value, &inverse.value);
+            fmpz_sub(&mut root.value, &root.value, &difference.value);
+            fmpz_mod(&mut root.value, &root.value, &modulus.value);
+        }
+        precision *= 2;
+    }
+    Some(root)
+}
+
+/// Computes a square root of an odd `value` modulo `2^exponent`.
+///
+/// Parameters:
+/// - `value`: the odd value whose square root is computed
+/// - `exponent`: the exponent of the modulus
+///
+/// Returns a square root or `None` if `value` is not a quadratic residue.
+fn sqrt_unit_power_of_two(value: &Z, exponent: u64) -> Option<Z> {
+    // the squares of odd values are `1 mod 8`
+    let residue_bits = exponent.min(3);
+    for bit in 1..residue_bits {
+        if 1 == unsafe { fmpz_tstbit(&value.value, bit) } {
+            return None;
+        }
+    }
+
+    // lift the root bit by bit: if `r^2 != value mod 2^(i+1)`, then
+    // `(r + 2^(i-1))^2 = value mod 2^(i+1)`
+    let mut root = Z::ONE;
+    let mut square = Z::default();
+    let mut step = Z::default();
+    for i in 3..exponent {
+        unsafe {
+            fmpz_mul(&mut square.value, &root.value, &root.value);
+            fmpz_sub(&mut square.value, &square.value, &value.value);
+            fmpz_pow_ui(&mut step.value, &Z::from(2).value, i + 1);
+            fmpz_mod(&mut square.value, &square.value, &step.value);
+            if 0 == fmpz_is_zero(&square.value) {
+                fmpz_pow_ui(&mut step.value, &Z::from(2).value, i - 1);
+                root = &root + &step;
+            }
+        }
+    }
+    Some(root)
+}
+
+#[cfg(test)]
+mod test_sqrt {
+    use super::Zq;
+    use crate::error::MathError;
+
+    /// Ensure that the square root is correct for prime moduli
+    #[test]
+    fn prime_modulus() {
+        for (value, modulus) in [(2, 7), (4, 17), (0, 13), (1, 2), (10, 13), (5, 41)] {
+            let value = Zq::try_from((value, modulus)).unwrap();
+
+            let root = value.sqrt().unwrap();
+
+            assert_eq!(value, &root * &root);
+        }
+    }
+
+    /// Ensure that the square root is correct for large prime moduli
+    #[test]
+    fn large_prime_modulus() {
+        // 2^64 - 59 is prime
+        let modulus = u64::MAX - 58;
+        let root = Zq::try_from((i64::MAX, modulus)).unwrap();
+        let value = &root * &root;
+
+        let res = value.sqrt().unwrap();
+
+        assert_eq!(value, &res * &res);
+    }
+
+    /// Ensure that the square root is correct for prime power moduli
+    #[test]
+    fn prime_power_modulus() {
+        let cases = [
+            (2, 49),
+            (2, 7_i64.pow(20)),
+            (9, 27),
+            (36, 3_i64.pow(5)),
+            (0, 125),
+            (25, 125),
+            (1, 4),
+            (17, 64),
+            (9, 1024),
+            (36, 1024),
+            (4, 8),
+        ];
+        for (value, modulus) in cases {
+            let value = Zq::try_from((value, modulus)).unwrap();
+
+            let root = value.sqrt().unwrap();
+
+            assert_eq!(value, &root * &root);
+        }
+    }
+
+    /// Ensure that an error is returned for non-residues
+    #[test]
+    fn not_quadratic_residue() {
+        let cases = [
+            (3, 7),
+            (3, 49),
+            (3, 9),
+            (3, 8),
+            (5, 8),
+            (3, 4),
+            (2, 32),
+            (5, 25),
+        ];
+        for (value, modulus) in cases {
+            let value = Zq::try_from((value, modulus)).unwrap();
+
+            assert!(matches!(
+                value.sqrt(),
+                Err(MathError::NotQuadraticResidue(_))
+            ));
+        }
+    }
+
+    /// Ensure that an error is returned for moduli that are no prime powers
+    #[test]
+    fn no_prime_power() {
+        let value = Zq::try_from((4, 15)).unwrap();
+
+        assert!(matches!(value.sqrt(), Err(MathError::NotPrime(_))));
+    }
+}
@@ -11,14 +11,41 @@
 //!
 //! This includes the [`Display`](std::fmt::Display) trait.
 
-use super::Zq;
+use super::{fmpz_mod_helpers::symmetric_representative, Zq};
 use core::fmt;
 
+impl Zq {
+    /// Converts an integer of type [`Zq`] into a [`String`], where the value
+    /// is represented by its representative in `(-q/2, q/2]` instead of `[0, q)`.
+    ///
+    /// Returns the integer in form of a [`String`]. For integer `3 mod 4`
+    /// the String looks like this `-1 mod 4`.
+    ///
+    /// The same representation is used by the alternate flag of the
+    /// [`Display`](std::fmt::Display) trait, i.e. `format!("{:#}", integer)`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer_mod_q::Zq;
+    /// use std::str::FromStr;
+    ///
+    /// let integer = Zq::from_str("57 mod 61").unwrap();
+    ///
+    /// assert_eq!("-4 mod 61", integer.to_string_symmetric());
+    /// assert_eq!("-4 mod 61", format!("{:#}", integer));
+    /// ```
+    pub fn to_string_symmetric(&self) -> String {
+        format!("{:#}", self)
+    }
+}
+
 impl fmt::Display for Zq {
     /// Allows to convert an integer of type [`Zq`] into a [`String`].
     ///
     /// Returns the integer in form of a [`String`]. For integer `2 mod 4`
     /// the String looks like this `2 mod 4`.
+    /// If the alternate flag `{:#}` is set, the value is represented in
+    /// `(-q/2, q/2]`, see [`Zq::to_string_symmetric`].
     ///
     /// # Examples
     /// ```
@@ -39,7 +66,15 @@ impl fmt::Display for Zq {
     /// let integer_string = integer.to_string();
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} mod {}", self.value, self.modulus)
+        if f.alternate() {
+            let value = symmetric_representative(
+                &self.value.value,
+                &self.modulus.get_fmpz_mod_ctx_struct().n[0],
+            );
+            write!(f, "{} mod {}", value, self.modulus)
+        } else {
+            write!(f, "{} mod {}", self.value, self.modulus)
+        }
     }
 }
 
@@ -93,3 +128,76 @@ mod test_to_string {
         assert!(Zq::from_str(&cmp_string).is_ok())
     }
 }
+
+#[cfg(test)]
+mod test_to_string_symmetric {
+    use crate::integer_mod_q::Zq;
+    use std::str::FromStr;
+
+    /// tests whether values larger than `q/2` are represented negatively
+    #[test]
+    fn small_values() {
+        assert_eq!(
+            "-4 mod 61",
+            Zq::from_str("57 mod 61").unwrap().to_string_symmetric()
+        );
+        assert_eq!(
+            "30 mod 61",
+            Zq::from_str("30 mod 61").unwrap().to_string_symmetric()
+        );
+        assert_eq!(
+            "-30 mod 61",
+            Zq::from_str("31 mod 61").unwrap().to_string_symmetric()
+        );
+        assert_eq!(
+            "0 mod 61",
+            Zq::from_str("61 mod 61").unwrap().to_string_symmetric()
+        );
+        assert_eq!(
+            "0 mod 1",
+            Zq::from_str("5 mod 1").unwrap().to_string_symmetric()
+        );
+    }
+
+    /// tests whether `q/2` stays positive for even moduli
+    #[test]
+    fn even_modulus_boundary() {
+        assert_eq!(
+            "2 mod 4",
+            Zq::from_str("2 mod 4").unwrap().to_string_symmetric()
+        );
+        assert_eq!(
+            "-1 mod 4",
+            Zq::from_str("3 mod 4").unwrap().to_string_symmetric()
+        );
+    }
+
+    /// tests whether large values are represented correctly
+    #[test]
+    fn large_values() {
+        let cmp = Zq::from_str(&format!("-{} mod {}", u64::MAX, u128::MAX)).unwrap();
+
+        assert_eq!(
+            format!("-{} mod {}", u64::MAX, u128::MAX),
+            cmp.to_string_symmetric()
+        )
+    }
+
+    /// tests whether the alternate flag of [`Display`](std::fmt::Display)
+    /// yields the symmetric representation and the default one is unchanged
+    #[test]
+    fn alternate_flag() {
+        let cmp = Zq::from_str("-1 mod 17").unwrap();
+
+        assert_eq!("-1 mod 17", format!("{:#}", cmp));
+        assert_eq!("16 mod 17", format!("{}", cmp));
+    }
+
+    /// tests whether the symmetric string can be used to create the same [`Zq`]
+    #[test]
+    fn round_trip() {
+        let cmp = Zq::from_str("42 mod 50").unwrap();
+
+        assert_eq!(cmp, Zq::from_str(&cmp.to_string_symmetric()).unwrap())
+    }
+}
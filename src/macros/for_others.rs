@@ -30,6 +30,8 @@
 /// `($out_type, $type, Gcd for $source_type)`
 /// - [`Lcm`](crate::traits::Lcm) with the signature
 /// `($out_type, $type, Lcm for $source_type)`
+/// - [`PartialEq`] and [`PartialOrd`] with the signature
+/// `($bridge_type, $type, PartialOrd for $source_type)`
 /// - [`Pow`](crate::traits::Pow) with the signature
 /// `($bridge_type, $type, Pow for $source_type)`
 /// - [`SetCoefficient`](crate::traits::SetCoefficient) with the signature
@@ -52,6 +54,7 @@
 /// implement_for_others!(Z, i8 i16 i32 i64 u8 u16 u32 u64, Mul Scalar for MatZ);
 /// implement_for_others!(Z, Z, Lcm for i8 i16 i32 i64 u8 u16 u32 u64);
 /// implement_for_others!(Z, Zq, Pow for u8 u16 u32 u64 i8 i16 i32 i64);
+/// implement_for_others!(Z, Q, PartialOrd for u8 u16 u32 u64 i8 i16 i32 i64);
 /// implement_for_others!(Z, Z, Gcd for u8 u16 u32 u64 i8 i16 i32 i64);
 /// implement_for_others!(Z, Z, Xgcd for u8 u16 u32 u64 i8 i16 i32 i64);
 /// ```
@@ -169,6 +172,47 @@ macro_rules! implement_for_others {
         })*
     };
 
+    // [`PartialEq`] and [`PartialOrd`] traits
+    ($bridge_type:ident, $type:ident, PartialOrd for $($source_type:ident)*) => {
+        $(impl PartialEq<$source_type> for $type {
+            paste::paste! {
+                #[doc = "Documentation can be found at [`" $type "::eq`]. Implicitly converts [`" $source_type "`] into [`" $bridge_type "`]."]
+                fn eq(&self, other: &$source_type) -> bool {
+                    self.eq(&$bridge_type::from(*other))
+                }
+            }
+        }
+
+        impl PartialEq<$type> for $source_type {
+            paste::paste! {
+                #[doc = "Documentation can be found at [`" $type "::eq`]. Implicitly converts [`" $source_type "`] into [`" $bridge_type "`]."]
+                fn eq(&self, other: &$type) -> bool {
+                    other.eq(&$bridge_type::from(*self))
+                }
+            }
+        }
+
+        impl PartialOrd<$source_type> for $type {
+            paste::paste! {
+                #[doc = "Documentation can be found at [`" $type "::partial_cmp`]. Implicitly converts [`" $source_type "`] into [`" $bridge_type "`]."]
+                fn partial_cmp(&self, other: &$source_type) -> Option<Ordering> {
+                    self.partial_cmp(&$bridge_type::from(*other))
+                }
+            }
+        }
+
+        impl PartialOrd<$type> for $source_type {
+            paste::paste! {
+                #[doc = "Documentation can be found at [`" $type "::partial_cmp`]. Implicitly converts [`" $source_type "`] into [`" $bridge_type "`]."]
+                fn partial_cmp(&self, other: &$type) -> Option<Ordering> {
+                    other
+                        .partial_cmp(&$bridge_type::from(*self))
+                        .map(Ordering::reverse)
+                }
+            }
+        })*
+    };
+
     // [`Gcd`] trait
     ($out_type:ident, $type:ident, Gcd for $($source_type:ident)*) => {
         $(impl Gcd<$source_type> for $type {
@@ -14,12 +14,28 @@ use flint_sys::fmpq_mat::fmpq_mat_struct;
 mod arithmetic;
 mod cmp;
 mod concat;
+mod determinant;
 mod from;
 mod get;
+mod gram;
+mod inplace;
+#[cfg(any(feature = "nalgebra", feature = "ndarray"))]
+mod interop;
+mod inverse;
+mod kernel;
+mod lu;
+mod manipulation;
+mod map;
+mod norm;
 mod ownership;
+mod qr;
+mod rank;
 mod serialize;
 mod set;
+mod solve;
+mod submatrix;
 mod to_string;
+mod trace;
 mod transpose;
 mod vector;
 
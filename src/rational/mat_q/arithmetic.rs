@@ -10,5 +10,6 @@
 //! such as addition or subtraction.
 
 mod add;
+mod hadamard;
 mod mul;
 mod sub;
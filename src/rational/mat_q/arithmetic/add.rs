@@ -10,6 +10,7 @@
 
 use super::super::MatQ;
 use crate::error::MathError;
+use crate::integer::MatZ;
 use crate::macros::arithmetics::{
     arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
 };
@@ -95,6 +96,51 @@ impl MatQ {
 arithmetic_trait_borrowed_to_owned!(Add, add, MatQ, MatQ, MatQ);
 arithmetic_trait_mixed_borrowed_owned!(Add, add, MatQ, MatQ, MatQ);
 
+impl Add<&MatZ> for &MatQ {
+    type Output = MatQ;
+    /// Implements the [`Add`] trait for a [`MatQ`] and a [`MatZ`].
+    /// [`Add`] is implemented for any combination of owned and borrowed
+    /// [`MatQ`] and [`MatZ`] in both orders.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the value to add to `self`
+    ///
+    /// Returns the sum of both matrices as a [`MatQ`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::MatZ, rational::MatQ};
+    /// use std::str::FromStr;
+    ///
+    /// let a = MatQ::from_str("[[1/2, 2/3],[3/4, -1]]").unwrap();
+    /// let b = MatZ::from_str("[[1, 2],[-3, 4]]").unwrap();
+    ///
+    /// let c: MatQ = &a + &b;
+    /// let d: MatQ = &b + &a;
+    /// let e: MatQ = a + b;
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if the dimensions of both matrices mismatch.
+    fn add(self, other: &MatZ) -> Self::Output {
+        self.add_safe(&MatQ::from(other)).unwrap()
+    }
+}
+
+#[doc(hidden)]
+impl Add<&MatQ> for &MatZ {
+    type Output = MatQ;
+    /// Documentation at [`MatQ::add`].
+    fn add(self, other: &MatQ) -> Self::Output {
+        MatQ::from(self).add_safe(other).unwrap()
+    }
+}
+
+arithmetic_trait_borrowed_to_owned!(Add, add, MatQ, MatZ, MatQ);
+arithmetic_trait_mixed_borrowed_owned!(Add, add, MatQ, MatZ, MatQ);
+arithmetic_trait_borrowed_to_owned!(Add, add, MatZ, MatQ, MatQ);
+arithmetic_trait_mixed_borrowed_owned!(Add, add, MatZ, MatQ, MatQ);
+
 #[cfg(test)]
 mod test_add {
 
@@ -184,3 +230,35 @@ mod test_add {
         assert!(c.add_safe(&b).is_err());
     }
 }
+
+#[cfg(test)]
+mod test_add_between_types {
+    use crate::{integer::MatZ, rational::MatQ};
+    use std::str::FromStr;
+
+    /// Ensure that adding a [`MatZ`] is the same as adding its conversion into [`MatQ`]
+    #[test]
+    fn correct_result() {
+        let a = MatQ::from_str(&format!("[[1/2, 2/3],[1/{}, -1]]", i64::MAX)).unwrap();
+        let b = MatZ::from_str(&format!("[[1, {}],[-3, 4]]", i64::MIN)).unwrap();
+        let cmp = &a + &MatQ::from(&b);
+
+        assert_eq!(cmp, &a + &b);
+        assert_eq!(cmp, &b + &a);
+        assert_eq!(cmp, a.clone() + &b);
+        assert_eq!(cmp, &b + a.clone());
+        assert_eq!(cmp, b.clone() + &a);
+        assert_eq!(cmp, &a + b.clone());
+        assert_eq!(cmp, a + b);
+    }
+
+    /// Ensure that mismatching dimensions result in a panic
+    #[test]
+    #[should_panic]
+    fn mismatching_dimensions() {
+        let a = MatQ::new(2, 3).unwrap();
+        let b = MatZ::new(3, 2).unwrap();
+
+        let _ = &a + &b;
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementation of the entrywise (Hadamard) product and division
+//! for [`MatQ`] values.
+
+use super::super::MatQ;
+use crate::error::MathError;
+use crate::traits::{GetNumColumns, GetNumRows};
+use flint_sys::{
+    fmpq::{fmpq_div, fmpq_is_zero, fmpq_mul},
+    fmpq_mat::fmpq_mat_entry,
+};
+
+impl MatQ {
+    /// Computes the entrywise (Hadamard) product of `self` and `other`,
+    /// i.e. the matrix whose entry `(i, j)` is `self[i][j] * other[i][j]`.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the matrix to multiply entrywise with `self`
+    ///
+    /// Returns the entrywise product of both matrices as a [`MatQ`] or an
+    /// error if the matrix dimensions mismatch.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let a = MatQ::from_str("[[1/2, 2],[3, -4/5]]").unwrap();
+    /// let b = MatQ::from_str("[[4, 1/3],[0, 5/2]]").unwrap();
+    ///
+    /// let c = a.hadamard(&b).unwrap();
+    ///
+    /// assert_eq!(MatQ::from_str("[[2, 2/3],[0, -2]]").unwrap(), c);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the matrix dimensions mismatch.
+    pub fn hadamard(&self, other: &Self) -> Result<Self, MathError> {
+        self.check_entrywise_dimensions(other)?;
+
+        let mut out = MatQ::new(self.get_num_rows(), self.get_num_columns()).unwrap();
+        for row in 0..self.get_num_rows() {
+            for column in 0..self.get_num_columns() {
+                unsafe {
+                    fmpq_mul(
+                        fmpq_mat_entry(&out.matrix, row, column),
+                        fmpq_mat_entry(&self.matrix, row, column),
+                        fmpq_mat_entry(&other.matrix, row, column),
+                    )
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Computes the entrywise division of `self` by `other`,
+    /// i.e. the matrix whose entry `(i, j)` is `self[i][j] / other[i][j]`.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the matrix by which `self` is divided entrywise
+    ///
+    /// Returns the entrywise quotient of both matrices as a [`MatQ`] or an
+    /// error if the matrix dimensions mismatch or `other` contains a zero entry.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let a = MatQ::from_str("[[1/2, 2],[0, -4/5]]").unwrap();
+    /// let b = MatQ::from_str("[[4, 1/3],[7, 2]]").unwrap();
+    ///
+    /// let c = a.hadamard_div(&b).unwrap();
+    ///
+    /// assert_eq!(MatQ::from_str("[[1/8, 6],[0, -2/5]]").unwrap(), c);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the matrix dimensions mismatch.
+    /// - Returns a [`MathError`] of type
+    /// [`DivisionByZeroError`](MathError::DivisionByZeroError)
+    /// if any entry of `other` is `0`.
+    pub fn hadamard_div(&self, other: &Self) -> Result<Self, MathError> {
+        self.check_entrywise_dimensions(other)?;
+
+        let mut out = MatQ::new(self.get_num_rows(), self.get_num_columns()).unwrap();
+        for row in 0..self.get_num_rows() {
+            for column in 0..self.get_num_columns() {
+                unsafe {
+                    if 1 == fmpq_is_zero(fmpq_mat_entry(&other.matrix, row, column)) {
+                        return Err(MathError::DivisionByZeroError(format!(
+                            "tried to divide entrywise by a matrix with entry 0 at ({}, {})",
+                            row, column
+                        )));
+                    }
+                    fmpq_div(
+                        fmpq_mat_entry(&out.matrix, row, column),
+                        fmpq_mat_entry(&self.matrix, row, column),
+                        fmpq_mat_entry(&other.matrix, row, column),
+                    )
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Checks whether `self` and `other` have the same dimensions,
+    /// which is required for entrywise operations.
+    fn check_entrywise_dimensions(&self, other: &Self) -> Result<(), MathError> {
+        if self.get_num_rows() != other.get_num_rows()
+            || self.get_num_columns() != other.get_num_columns()
+        {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to apply an entrywise operation to a '{}x{}' matrix and a '{}x{}' matrix.",
+                self.get_num_rows(),
+                self.get_num_columns(),
+                other.get_num_rows(),
+                other.get_num_columns()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_hadamard {
+    use super::MatQ;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that the entrywise product is computed correctly
+    #[test]
+    fn correct_result() {
+        let a = MatQ::from_str("[[1/3, -2],[0, 7/4],[3, 3]]").unwrap();
+        let b = MatQ::from_str("[[6, 1/4],[9, -8/7],[-1/3, 0]]").unwrap();
+
+        let c = a.hadamard(&b).unwrap();
+
+        assert_eq!(MatQ::from_str("[[2, -1/2],[0, -2],[-1, 0]]").unwrap(), c);
+        assert_eq!(c, b.hadamard(&a).unwrap());
+    }
+
+    /// Ensure that an error is returned if the dimensions mismatch
+    #[test]
+    fn mismatching_dimensions() {
+        let a = MatQ::new(2, 3).unwrap();
+        let b = MatQ::new(3, 2).unwrap();
+
+        assert!(matches!(
+            a.hadamard(&b),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_hadamard_div {
+    use super::MatQ;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that the entrywise quotient is computed correctly
+    #[test]
+    fn correct_result() {
+        let a = MatQ::from_str("[[1/3, -2],[0, 7/4]]").unwrap();
+        let b = MatQ::from_str("[[6, 1/4],[9, -7/8]]").unwrap();
+
+        let c = a.hadamard_div(&b).unwrap();
+
+        assert_eq!(MatQ::from_str("[[1/18, -8],[0, -2]]").unwrap(), c);
+        assert_eq!(a, c.hadamard(&b).unwrap());
+    }
+
+    /// Ensure that dividing by a matrix with a zero entry results in an error
+    #[test]
+    fn division_by_zero() {
+        let a = MatQ::from_str("[[1, 2],[3, 4]]").unwrap();
+        let b = MatQ::from_str("[[1, 2],[0, 4]]").unwrap();
+
+        assert!(matches!(
+            a.hadamard_div(&b),
+            Err(MathError::DivisionByZeroError(_))
+        ));
+    }
+
+    /// Ensure that an error is returned if the dimensions mismatch
+    #[test]
+    fn mismatching_dimensions() {
+        let a = MatQ::new(2, 3).unwrap();
+        let b = MatQ::new(2, 2).unwrap();
+
+        assert!(matches!(
+            a.hadamard_div(&b),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
@@ -10,6 +10,7 @@
 
 use super::super::MatQ;
 use crate::error::MathError;
+use crate::integer::MatZ;
 use crate::macros::arithmetics::{
     arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
 };
@@ -93,6 +94,51 @@ impl MatQ {
 arithmetic_trait_borrowed_to_owned!(Mul, mul, MatQ, MatQ, MatQ);
 arithmetic_trait_mixed_borrowed_owned!(Mul, mul, MatQ, MatQ, MatQ);
 
+impl Mul<&MatZ> for &MatQ {
+    type Output = MatQ;
+    /// Implements the [`Mul`] trait for a [`MatQ`] and a [`MatZ`].
+    /// [`Mul`] is implemented for any combination of owned and borrowed
+    /// [`MatQ`] and [`MatZ`] in both orders.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the value to multiply with `self`
+    ///
+    /// Returns the product of both matrices as a [`MatQ`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::MatZ, rational::MatQ};
+    /// use std::str::FromStr;
+    ///
+    /// let a = MatQ::from_str("[[1/2, 2/3],[3/4, -1]]").unwrap();
+    /// let b = MatZ::from_str("[[1, 2],[-3, 4]]").unwrap();
+    ///
+    /// let c: MatQ = &a * &b;
+    /// let d: MatQ = &b * &a;
+    /// let e: MatQ = a * b;
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if the dimensions of both matrices mismatch.
+    fn mul(self, other: &MatZ) -> Self::Output {
+        self.mul_safe(&MatQ::from(other)).unwrap()
+    }
+}
+
+#[doc(hidden)]
+impl Mul<&MatQ> for &MatZ {
+    type Output = MatQ;
+    /// Documentation at [`MatQ::mul`].
+    fn mul(self, other: &MatQ) -> Self::Output {
+        MatQ::from(self).mul_safe(other).unwrap()
+    }
+}
+
+arithmetic_trait_borrowed_to_owned!(Mul, mul, MatQ, MatZ, MatQ);
+arithmetic_trait_mixed_borrowed_owned!(Mul, mul, MatQ, MatZ, MatQ);
+arithmetic_trait_borrowed_to_owned!(Mul, mul, MatZ, MatQ, MatQ);
+arithmetic_trait_mixed_borrowed_owned!(Mul, mul, MatZ, MatQ, MatQ);
+
 #[cfg(test)]
 mod test_mul {
 
@@ -149,3 +195,36 @@ mod test_mul {
         assert!((mat_1.mul_safe(&mat_2)).is_err());
     }
 }
+
+#[cfg(test)]
+mod test_mul_between_types {
+    use crate::{integer::MatZ, rational::MatQ};
+    use std::str::FromStr;
+
+    /// Ensure that multiplication with a [`MatZ`] is the same as
+    /// multiplication with its conversion into [`MatQ`] in both orders
+    #[test]
+    fn correct_result() {
+        let a = MatQ::from_str(&format!("[[1/2, 2/3, 0],[1/{}, -1, 7]]", i64::MAX)).unwrap();
+        let b = MatZ::from_str(&format!("[[1, {}],[-3, 4],[0, 5]]", i64::MIN)).unwrap();
+
+        let cmp_right = &a * &MatQ::from(&b);
+        let cmp_left = &MatQ::from(&b) * &a;
+
+        assert_eq!(cmp_right, &a * &b);
+        assert_eq!(cmp_left, &b * &a);
+        assert_eq!(cmp_right, a.clone() * &b);
+        assert_eq!(cmp_left, b.clone() * &a);
+        assert_eq!(cmp_right, a * b);
+    }
+
+    /// Ensure that mismatching dimensions result in a panic
+    #[test]
+    #[should_panic]
+    fn mismatching_dimensions() {
+        let a = MatQ::new(2, 3).unwrap();
+        let b = MatZ::new(2, 3).unwrap();
+
+        let _ = &a * &b;
+    }
+}
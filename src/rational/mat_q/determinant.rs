@@ -0,0 +1,96 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the `det` function.
+
+use super::MatQ;
+use crate::{
+    error::MathError,
+    rational::Q,
+    traits::{GetNumColumns, GetNumRows},
+};
+use flint_sys::fmpq_mat::fmpq_mat_det;
+
+impl MatQ {
+    /// Returns the determinant of a square matrix.
+    ///
+    /// Returns the determinant of the matrix or an error if the matrix is not square.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{MatQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatQ::from_str("[[1/2,2],[3,1/3]]").unwrap();
+    ///
+    /// let det = mat.det().unwrap();
+    ///
+    /// assert_eq!(Q::from_str("-35/6").unwrap(), det);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the matrix is not square.
+    pub fn det(&self) -> Result<Q, MathError> {
+        if self.get_num_rows() != self.get_num_columns() {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "The determinant is only defined for square matrices, but the matrix is '{}x{}'.",
+                self.get_num_rows(),
+                self.get_num_columns()
+            )));
+        }
+
+        let mut out = Q::default();
+        unsafe { fmpq_mat_det(&mut out.value, &self.matrix) };
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_det {
+    use super::MatQ;
+    use crate::{error::MathError, rational::Q};
+    use std::str::FromStr;
+
+    /// Checks if the determinant is correctly computed
+    #[test]
+    fn correct_det() {
+        let mat = MatQ::from_str("[[1/2,0,1],[2,-1/3,0],[0,4,3/4]]").unwrap();
+
+        assert_eq!(Q::from_str("63/8").unwrap(), mat.det().unwrap());
+    }
+
+    /// Checks if the determinant of a singular matrix is zero
+    #[test]
+    fn singular() {
+        let mat = MatQ::from_str("[[1/2,1],[1/4,1/2]]").unwrap();
+
+        assert_eq!(Q::default(), mat.det().unwrap());
+    }
+
+    /// Checks if the determinant is correctly computed for large entries
+    #[test]
+    fn large_entries() {
+        let mat = MatQ::from_str(&format!("[[1/{},1],[0,{}]]", u64::MAX, i64::MIN)).unwrap();
+
+        let cmp = Q::from_str(&format!("{}/{}", i64::MIN, u64::MAX)).unwrap();
+        assert_eq!(cmp, mat.det().unwrap());
+    }
+
+    /// Checks if an error is returned for non-square matrices
+    #[test]
+    fn non_square() {
+        let mat = MatQ::new(3, 2).unwrap();
+
+        assert!(matches!(
+            mat.det(),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
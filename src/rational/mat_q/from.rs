@@ -16,13 +16,16 @@
 use super::MatQ;
 use crate::{
     error::MathError,
+    integer::MatZ,
     rational::Q,
-    traits::SetEntry,
+    traits::{GetNumColumns, GetNumRows, SetEntry},
     utils::{
-        dimensions::find_matrix_dimensions, index::evaluate_index, parse::parse_matrix_string,
+        dimensions::{find_matrix_dimensions, find_slice_matrix_dimensions},
+        index::evaluate_index,
+        parse::parse_matrix_string,
     },
 };
-use flint_sys::fmpq_mat::fmpq_mat_init;
+use flint_sys::fmpq_mat::{fmpq_mat_init, fmpq_mat_one, fmpq_mat_set_fmpz_mat};
 use std::{fmt::Display, mem::MaybeUninit, str::FromStr};
 
 impl MatQ {
@@ -75,6 +78,192 @@ impl MatQ {
             })
         }
     }
+
+    /// Generate a `num_rows` times `num_columns` matrix with `1` on the
+    /// diagonal and `0` anywhere else.
+    ///
+    /// Parameters:
+    /// - `num_rows`: the number of rows of the identity matrix
+    /// - `num_columns`: the number of columns of the identity matrix
+    ///
+    /// Returns a matrix with `1` across the diagonal and `0` anywhere else.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatQ::identity(2, 3).unwrap();
+    ///
+    /// assert_eq!(MatQ::from_str("[[1,0,0],[0,1,0]]").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix) or
+    /// [`OutOfBounds`](MathError::OutOfBounds) if the provided number of rows and columns
+    /// are not suited to create a matrix. For further information see [`MatQ::new`].
+    pub fn identity(
+        num_rows: impl TryInto<i64> + Display + Copy,
+        num_cols: impl TryInto<i64> + Display + Copy,
+    ) -> Result<Self, MathError> {
+        let mut out = MatQ::new(num_rows, num_cols)?;
+        unsafe { fmpq_mat_one(&mut out.matrix) };
+        Ok(out)
+    }
+
+    /// Generate a square matrix with the provided values on the diagonal
+    /// and `0` anywhere else.
+    ///
+    /// Parameters:
+    /// - `diagonal`: the entries of the diagonal, starting at the top left
+    ///
+    /// Returns a square matrix with `diagonal` as diagonal or an error,
+    /// if `diagonal` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{MatQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let diagonal = [Q::from_str("1/2").unwrap(), Q::from(-3)];
+    /// let matrix = MatQ::from_diagonal(&diagonal).unwrap();
+    ///
+    /// assert_eq!(MatQ::from_str("[[1/2,0],[0,-3]]").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `diagonal` is empty.
+    pub fn from_diagonal(diagonal: &[Q]) -> Result<Self, MathError> {
+        let mut out = MatQ::new(diagonal.len(), diagonal.len())?;
+        for (i, entry) in diagonal.iter().enumerate() {
+            out.set_entry(i, i, entry).unwrap();
+        }
+        Ok(out)
+    }
+
+    /// Creates a matrix from a slice of rows, where each row is a slice of
+    /// entries, which can be converted into [`Q`].
+    ///
+    /// Parameters:
+    /// - `rows`: the rows of the matrix, starting with the top row
+    ///
+    /// Returns a [`MatQ`] or an error, if `rows` is empty, contains empty rows
+    /// or rows of different length.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{MatQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let row_0 = [Q::try_from((&1, &2)).unwrap(), Q::from(2)];
+    /// let row_1 = [Q::from(-3), Q::from(0.25)];
+    /// let matrix = MatQ::from_slices(&[row_0.as_slice(), &row_1]).unwrap();
+    ///
+    /// assert_eq!(MatQ::from_str("[[1/2, 2],[-3, 1/4]]").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `rows` is empty, contains empty rows or the number of entries in rows is unequal.
+    pub fn from_slices<T: Into<Q> + Clone>(rows: &[&[T]]) -> Result<Self, MathError> {
+        let (num_rows, num_cols) = find_slice_matrix_dimensions(rows)?;
+        let mut matrix = MatQ::new(num_rows, num_cols)?;
+
+        for (row_num, row) in rows.iter().enumerate() {
+            for (col_num, entry) in row.iter().enumerate() {
+                let entry: Q = entry.clone().into();
+                matrix.set_entry(row_num, col_num, entry).unwrap();
+            }
+        }
+        Ok(matrix)
+    }
+}
+
+impl TryFrom<&[&[Q]]> for MatQ {
+    type Error = MathError;
+    /// Create a [`MatQ`] from a slice of rows using [`from_slices`](MatQ::from_slices)
+    fn try_from(rows: &[&[Q]]) -> Result<Self, Self::Error> {
+        MatQ::from_slices(rows)
+    }
+}
+
+impl From<Vec<Vec<i64>>> for MatQ {
+    /// Creates a matrix from a vector of rows, where each row is a vector of entries.
+    ///
+    /// Parameters:
+    /// - `rows`: the rows of the matrix, starting with the top row
+    ///
+    /// Returns a [`MatQ`] with the given entries.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatQ::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    ///
+    /// assert_eq!(MatQ::from_str("[[1, 2, 3],[4, 5, 6]]").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if `rows` is empty, contains empty rows or rows of different length.
+    fn from(rows: Vec<Vec<i64>>) -> Self {
+        let rows: Vec<&[i64]> = rows.iter().map(Vec::as_slice).collect();
+        MatQ::from_slices(&rows).unwrap()
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> From<[[i64; COLS]; ROWS]> for MatQ {
+    /// Creates a `ROWS x COLS` matrix from an array of rows.
+    ///
+    /// Parameters:
+    /// - `rows`: the rows of the matrix, starting with the top row
+    ///
+    /// Returns a [`MatQ`] with the given entries.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatQ::from([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(MatQ::from_str("[[1, 2, 3],[4, 5, 6]]").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Panics if `ROWS` or `COLS` is `0`.
+    fn from(rows: [[i64; COLS]; ROWS]) -> Self {
+        let rows: Vec<&[i64]> = rows.iter().map(|row| row.as_slice()).collect();
+        MatQ::from_slices(&rows).unwrap()
+    }
+}
+
+impl From<&MatZ> for MatQ {
+    /// Creates a [`MatQ`] with the same dimensions and entries as the given [`MatZ`].
+    ///
+    /// Parameters:
+    /// - `matrix`: the integer matrix that is converted
+    ///
+    /// Returns a [`MatQ`] with the entries of `matrix`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::MatZ, rational::MatQ};
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatZ::from_str("[[1, -2],[3, 4]]").unwrap();
+    ///
+    /// let matrix_q = MatQ::from(&matrix);
+    ///
+    /// assert_eq!(MatQ::from_str("[[1, -2],[3, 4]]").unwrap(), matrix_q);
+    /// ```
+    fn from(matrix: &MatZ) -> Self {
+        let mut out = MatQ::new(matrix.get_num_rows(), matrix.get_num_columns()).unwrap();
+        unsafe { fmpq_mat_set_fmpz_mat(&mut out.matrix, &matrix.matrix) };
+        out
+    }
 }
 
 impl FromStr for MatQ {
@@ -152,6 +341,80 @@ mod test_new {
     // TODO add test for `0` entries
 }
 
+#[cfg(test)]
+mod test_identity {
+    use crate::rational::MatQ;
+    use std::str::FromStr;
+
+    /// Tests if an identity matrix is correctly created
+    #[test]
+    fn square() {
+        let matrix = MatQ::identity(3, 3).unwrap();
+
+        assert_eq!(MatQ::from_str("[[1,0,0],[0,1,0],[0,0,1]]").unwrap(), matrix);
+    }
+
+    /// Tests if non-square identity matrices are correctly created
+    #[test]
+    fn non_square() {
+        let wide = MatQ::identity(2, 3).unwrap();
+        let tall = MatQ::identity(3, 2).unwrap();
+
+        assert_eq!(MatQ::from_str("[[1,0,0],[0,1,0]]").unwrap(), wide);
+        assert_eq!(MatQ::from_str("[[1,0],[0,1],[0,0]]").unwrap(), tall);
+    }
+
+    /// Tests if an error is returned for invalid dimensions
+    #[test]
+    fn invalid_dimensions() {
+        assert!(MatQ::identity(0, 3).is_err());
+        assert!(MatQ::identity(3, -1).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_from_diagonal {
+    use crate::{
+        error::MathError,
+        rational::{MatQ, Q},
+    };
+    use std::str::FromStr;
+
+    /// Tests if the diagonal is correctly set
+    #[test]
+    fn correct_diagonal() {
+        let diagonal = [Q::from_str("-1/3").unwrap(), Q::ONE, Q::ZERO];
+
+        let matrix = MatQ::from_diagonal(&diagonal).unwrap();
+
+        assert_eq!(
+            MatQ::from_str("[[-1/3,0,0],[0,1,0],[0,0,0]]").unwrap(),
+            matrix
+        );
+    }
+
+    /// Tests if large entries are correctly set
+    #[test]
+    fn large_entries() {
+        let large = Q::from_str(&format!("{}/{}", i64::MIN, u64::MAX)).unwrap();
+
+        let matrix = MatQ::from_diagonal(&[large, Q::from(i64::MAX)]).unwrap();
+
+        let cmp =
+            MatQ::from_str(&format!("[[{}/{},0],[0,{}]]", i64::MIN, u64::MAX, i64::MAX)).unwrap();
+        assert_eq!(cmp, matrix);
+    }
+
+    /// Tests if an empty diagonal returns an error
+    #[test]
+    fn empty() {
+        assert!(matches!(
+            MatQ::from_diagonal(&[]),
+            Err(MathError::InvalidMatrix(_))
+        ));
+    }
+}
+
 #[cfg(test)]
 mod test_from_str {
     use crate::{
@@ -297,3 +560,66 @@ mod test_from_str {
         assert!(MatQ::from_str(&matrix_string9).is_err());
     }
 }
+
+#[cfg(test)]
+mod test_from_slices {
+    use crate::{error::MathError, rational::MatQ};
+    use std::str::FromStr;
+
+    /// Ensure that matrices are built correctly from slices of primitive types
+    #[test]
+    fn correct_entries() {
+        let matrix_i64 = MatQ::from_slices(&[[i64::MAX, -1].as_slice(), &[0, i64::MIN]]).unwrap();
+        let matrix_f64 = MatQ::from_slices(&[[0.5_f64].as_slice(), &[-1.75]]).unwrap();
+
+        assert_eq!(
+            MatQ::from_str(&format!("[[{}, -1],[0, {}]]", i64::MAX, i64::MIN)).unwrap(),
+            matrix_i64
+        );
+        assert_eq!(MatQ::from_str("[[1/2],[-7/4]]").unwrap(), matrix_f64);
+    }
+
+    /// Ensure that vectors and arrays of [`i64`] are converted correctly
+    #[test]
+    fn from_vec_and_array() {
+        let cmp = MatQ::from_str("[[1, 0, -1],[2, 5, 3]]").unwrap();
+
+        assert_eq!(cmp, MatQ::from(vec![vec![1, 0, -1], vec![2, 5, 3]]));
+        assert_eq!(cmp, MatQ::from([[1, 0, -1], [2, 5, 3]]));
+    }
+
+    /// Ensure that rows of different length and empty matrices result in an error
+    #[test]
+    fn invalid_dimensions() {
+        assert!(matches!(
+            MatQ::from_slices(&[[1, 2].as_slice(), &[3]]),
+            Err(MathError::InvalidMatrix(_))
+        ));
+        assert!(MatQ::from_slices::<i64>(&[]).is_err());
+    }
+
+    /// Ensure that rows of different length result in a panic
+    #[test]
+    #[should_panic]
+    fn unequal_rows_panic() {
+        let _ = MatQ::from(vec![vec![1, 2], vec![3]]);
+    }
+}
+
+#[cfg(test)]
+mod test_from_mat_z {
+    use super::MatQ;
+    use crate::integer::MatZ;
+    use std::str::FromStr;
+
+    /// Ensure that small and large entries are converted correctly
+    #[test]
+    fn correct_conversion() {
+        let matrix = MatZ::from_str(&format!("[[1, {}],[0, {}]]", i64::MIN, u64::MAX)).unwrap();
+
+        let matrix_q = MatQ::from(&matrix);
+
+        let cmp = MatQ::from_str(&format!("[[1, {}],[0, {}]]", i64::MIN, u64::MAX)).unwrap();
+        assert_eq!(cmp, matrix_q);
+    }
+}
@@ -10,7 +10,7 @@
 
 use super::MatQ;
 use crate::traits::{GetEntry, GetNumColumns, GetNumRows};
-use crate::utils::index::evaluate_indices;
+use crate::utils::index::{evaluate_index, evaluate_indices};
 use crate::{error::MathError, rational::Q};
 use flint_sys::{
     fmpq::{fmpq, fmpq_set},
@@ -93,6 +93,100 @@ impl GetEntry<Q> for MatQ {
 }
 
 impl MatQ {
+    /// Outputs the row vector of the specified row.
+    ///
+    /// Parameters:
+    /// - `row`: specifies the row of the matrix
+    ///
+    /// Returns a row vector of the matrix at the position of the given
+    /// row or an error, if the number of rows is
+    /// greater than the matrix or negative.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatQ::from_str("[[1/2, 2, 3],[3, 4, 5/3]]").unwrap();
+    ///
+    /// let row0 = matrix.get_row(0).unwrap(); // first row
+    /// let row1 = matrix.get_row(1).unwrap(); // second row
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the number of the row is greater than the matrix or negative.
+    pub fn get_row(&self, row: impl TryInto<i64> + Display + Copy) -> Result<Self, MathError> {
+        let row_i64 = evaluate_index(row)?;
+
+        if self.get_num_rows() <= row_i64 {
+            return Err(MathError::OutOfBounds(
+                format!("be smaller than {}", self.get_num_rows()),
+                format!("{}", row_i64),
+            ));
+        }
+
+        let out = MatQ::new(1, self.get_num_columns()).unwrap();
+        for column in 0..self.get_num_columns() {
+            unsafe {
+                fmpq_set(
+                    fmpq_mat_entry(&out.matrix, 0, column),
+                    fmpq_mat_entry(&self.matrix, row_i64, column),
+                )
+            };
+        }
+        Ok(out)
+    }
+
+    /// Outputs the column vector of the specified column.
+    ///
+    /// Parameters:
+    /// - `column`: specifies the column of the matrix
+    ///
+    /// Returns a column vector of the matrix at the position of the given
+    /// column or an error, if the number of columns is
+    /// greater than the matrix or negative.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatQ::from_str("[[1/2, 2, 3],[3, 4, 5/3]]").unwrap();
+    ///
+    /// let col0 = matrix.get_column(0).unwrap(); // first column
+    /// let col1 = matrix.get_column(1).unwrap(); // second column
+    /// let col2 = matrix.get_column(2).unwrap(); // third column
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the number of the column is greater than the matrix or negative.
+    pub fn get_column(
+        &self,
+        column: impl TryInto<i64> + Display + Copy,
+    ) -> Result<Self, MathError> {
+        let column_i64 = evaluate_index(column)?;
+
+        if self.get_num_columns() <= column_i64 {
+            return Err(MathError::OutOfBounds(
+                format!("be smaller than {}", self.get_num_columns()),
+                format!("{}", column_i64),
+            ));
+        }
+
+        let out = MatQ::new(self.get_num_rows(), 1).unwrap();
+        for row in 0..self.get_num_rows() {
+            unsafe {
+                fmpq_set(
+                    fmpq_mat_entry(&out.matrix, row, 0),
+                    fmpq_mat_entry(&self.matrix, row, column_i64),
+                )
+            };
+        }
+        Ok(out)
+    }
+
     #[allow(dead_code)]
     /// Efficiently collects all [`fmpq`]s in a [`MatQ`] without cloning them.
     ///
@@ -267,6 +361,57 @@ mod test_get_num {
     }
 }
 
+#[cfg(test)]
+mod test_get_vec {
+    use crate::rational::MatQ;
+    use std::str::FromStr;
+
+    /// Ensure that rows are correctly extracted
+    #[test]
+    fn get_row_works() {
+        let matrix =
+            MatQ::from_str(&format!("[[0,0,0],[4/3,{},1/{}]]", i64::MAX, i64::MIN)).unwrap();
+        let row1 = matrix.get_row(0).unwrap();
+        let row2 = matrix.get_row(1).unwrap();
+
+        let cmp1 = MatQ::from_str("[[0,0,0]]").unwrap();
+        let cmp2 = MatQ::from_str(&format!("[[4/3,{},1/{}]]", i64::MAX, i64::MIN)).unwrap();
+
+        assert_eq!(cmp1, row1);
+        assert_eq!(cmp2, row2);
+    }
+
+    /// Ensure that columns are correctly extracted
+    #[test]
+    fn get_column_works() {
+        let matrix = MatQ::from_str(&format!(
+            "[[1/2,0,3],[{},0,5],[1/{},0,7]]",
+            i64::MAX,
+            i64::MIN
+        ))
+        .unwrap();
+        let column1 = matrix.get_column(0).unwrap();
+        let column2 = matrix.get_column(1).unwrap();
+
+        let cmp1 = MatQ::from_str(&format!("[[1/2],[{}],[1/{}]]", i64::MAX, i64::MIN)).unwrap();
+        let cmp2 = MatQ::from_str("[[0],[0],[0]]").unwrap();
+
+        assert_eq!(cmp1, column1);
+        assert_eq!(cmp2, column2);
+    }
+
+    /// Ensure that indices out of bounds are rejected
+    #[test]
+    fn wrong_dim_error() {
+        let matrix = MatQ::from_str("[[1,2,3],[4,5,6]]").unwrap();
+
+        assert!(matrix.get_row(-1).is_err());
+        assert!(matrix.get_row(2).is_err());
+        assert!(matrix.get_column(-1).is_err());
+        assert!(matrix.get_column(3).is_err());
+    }
+}
+
 #[cfg(test)]
 mod test_collect_entries {
     use super::MatQ;
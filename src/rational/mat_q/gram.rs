@@ -0,0 +1,90 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the `gram` function.
+
+use super::MatQ;
+use crate::traits::{GetNumColumns, GetNumRows};
+use flint_sys::{
+    fmpq::{fmpq_addmul, fmpq_set},
+    fmpq_mat::fmpq_mat_entry,
+};
+
+impl MatQ {
+    /// Returns the Gram matrix `B * B^T` of the given matrix `B`,
+    /// i.e. the matrix of the pairwise inner products of the rows of `B`.
+    ///
+    /// As the Gram matrix is symmetric, only the entries on and above
+    /// the diagonal are computed.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let basis = MatQ::from_str("[[1/2, 2, 0],[3, -1, 4]]").unwrap();
+    ///
+    /// let gram = basis.gram();
+    ///
+    /// assert_eq!(MatQ::from_str("[[17/4, -1/2],[-1/2, 26]]").unwrap(), gram);
+    /// ```
+    pub fn gram(&self) -> Self {
+        let out = Self::new(self.get_num_rows(), self.get_num_rows()).unwrap();
+        for i in 0..self.get_num_rows() {
+            for j in i..self.get_num_rows() {
+                unsafe {
+                    let entry = fmpq_mat_entry(&out.matrix, i, j);
+                    for k in 0..self.get_num_columns() {
+                        fmpq_addmul(
+                            entry,
+                            fmpq_mat_entry(&self.matrix, i, k),
+                            fmpq_mat_entry(&self.matrix, j, k),
+                        );
+                    }
+                    fmpq_set(fmpq_mat_entry(&out.matrix, j, i), entry);
+                }
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_gram {
+    use super::MatQ;
+    use std::str::FromStr;
+
+    /// Ensure that the Gram matrix equals `B * B^T`
+    #[test]
+    fn same_as_mul_transpose() {
+        let basis = MatQ::from_str(&format!(
+            "[[1/3, {}, 0, -3],[3, -1/7, 4, -1/{}],[0, 0, 7/2, 1]]",
+            i64::MAX,
+            i64::MAX
+        ))
+        .unwrap();
+
+        let gram = basis.gram();
+
+        assert_eq!(&basis * &basis.transpose(), gram);
+        assert_eq!(gram, gram.transpose());
+    }
+
+    /// Ensure that row and column vectors result in the squared norm
+    /// and the outer product respectively
+    #[test]
+    fn vectors() {
+        let row = MatQ::from_str("[[1/2, -2, 3]]").unwrap();
+
+        assert_eq!(MatQ::from_str("[[53/4]]").unwrap(), row.gram());
+        assert_eq!(
+            MatQ::from_str("[[1/4, -1, 3/2],[-1, 4, -6],[3/2, -6, 9]]").unwrap(),
+            row.transpose().gram()
+        );
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains in-place variants of the transposition, negation and
+//! scalar multiplication of [`MatQ`], which reuse the memory of the matrix.
+
+use super::MatQ;
+use crate::rational::Q;
+use crate::traits::{GetNumColumns, GetNumRows};
+use flint_sys::fmpq_mat::{
+    fmpq_mat_neg, fmpq_mat_scalar_mul_fmpq, fmpq_mat_struct, fmpq_mat_transpose,
+};
+
+impl MatQ {
+    /// Transposes the matrix in place, i.e. rows get transformed to columns
+    /// and vice versa. Square matrices are transposed without allocating
+    /// new memory, all others are replaced by their transposed form.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{MatQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let mut mat = MatQ::from_str("[[1/2, 2, 3],[4, 5, -6]]").unwrap();
+    ///
+    /// mat.transpose_inplace();
+    ///
+    /// assert_eq!(MatQ::from_str("[[1/2, 4],[2, 5],[3, -6]]").unwrap(), mat);
+    /// ```
+    pub fn transpose_inplace(&mut self) {
+        if self.get_num_rows() == self.get_num_columns() {
+            let matrix: *mut fmpq_mat_struct = &mut self.matrix;
+            unsafe { fmpq_mat_transpose(matrix, matrix) };
+        } else {
+            *self = self.transpose();
+        }
+    }
+
+    /// Negates every entry of the matrix in place.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{MatQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let mut mat = MatQ::from_str("[[1/2, 2, 3],[4, 5, -6]]").unwrap();
+    ///
+    /// mat.negate_inplace();
+    ///
+    /// assert_eq!(MatQ::from_str("[[-1/2, -2, -3],[-4, -5, 6]]").unwrap(), mat);
+    /// ```
+    pub fn negate_inplace(&mut self) {
+        let matrix: *mut fmpq_mat_struct = &mut self.matrix;
+        unsafe { fmpq_mat_neg(matrix, matrix) };
+    }
+
+    /// Multiplies every entry of the matrix in place by the rational `scalar`.
+    ///
+    /// Parameters:
+    /// - `scalar`: specifies the scalar by which the matrix is multiplied
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{MatQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let mut mat = MatQ::from_str("[[1/2, 2, 3],[4, 5, -6]]").unwrap();
+    ///
+    /// mat.scalar_mul_assign(&Q::try_from((&-2, &3)).unwrap());
+    ///
+    /// assert_eq!(MatQ::from_str("[[-1/3, -4/3, -2],[-8/3, -10/3, 4]]").unwrap(), mat);
+    /// ```
+    pub fn scalar_mul_assign(&mut self, scalar: &Q) {
+        let matrix: *mut fmpq_mat_struct = &mut self.matrix;
+        unsafe { fmpq_mat_scalar_mul_fmpq(matrix, matrix, &scalar.value) };
+    }
+}
+
+#[cfg(test)]
+mod test_transpose_inplace {
+    use super::MatQ;
+    use std::str::FromStr;
+
+    /// Ensure that square and non-square matrices are transposed correctly
+    #[test]
+    fn correct_transpose() {
+        let mut square =
+            MatQ::from_str(&format!("[[1/2, {}],[3/{}, 4]]", i64::MAX, u64::MAX)).unwrap();
+        let mut column = MatQ::from_str("[[1/3],[2],[-7/5]]").unwrap();
+        let cmp_square = square.transpose();
+        let cmp_column = column.transpose();
+
+        square.transpose_inplace();
+        column.transpose_inplace();
+
+        assert_eq!(cmp_square, square);
+        assert_eq!(cmp_column, column);
+    }
+}
+
+#[cfg(test)]
+mod test_negate_inplace {
+    use super::MatQ;
+    use std::str::FromStr;
+
+    /// Ensure that all entries are negated
+    #[test]
+    fn correct_negation() {
+        let mut mat = MatQ::from_str(&format!("[[1/3, 0],[{}/7, -4]]", u64::MAX)).unwrap();
+
+        mat.negate_inplace();
+
+        assert_eq!(
+            MatQ::from_str(&format!("[[-1/3, 0],[-{}/7, 4]]", u64::MAX)).unwrap(),
+            mat
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_scalar_mul_assign {
+    use super::{MatQ, Q};
+    use std::str::FromStr;
+
+    /// Ensure that entries are multiplied and canceled correctly
+    #[test]
+    fn correct_product() {
+        let mut mat = MatQ::from_str(&format!("[[3/4, 0, -7],[{}, -4, 2/9]]", i64::MAX)).unwrap();
+        let scalar = Q::try_from((&-4, &3)).unwrap();
+
+        mat.scalar_mul_assign(&scalar);
+
+        let cmp = MatQ::from_str(&format!(
+            "[[-1, 0, 28/3],[-{}/3, 16/3, -8/27]]",
+            4 * i64::MAX as i128
+        ))
+        .unwrap();
+        assert_eq!(cmp, mat);
+    }
+}
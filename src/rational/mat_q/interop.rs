@@ -0,0 +1,322 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains conversions between [`MatQ`] and the matrix types of
+//! [`nalgebra`](https://docs.rs/nalgebra) and [`ndarray`](https://docs.rs/ndarray),
+//! which are available behind the features `nalgebra` and `ndarray` respectively.
+
+use super::MatQ;
+use crate::{
+    error::MathError,
+    rational::Q,
+    traits::{GetNumColumns, GetNumRows, SetEntry},
+};
+use flint_sys::{fmpq::fmpq_get_d, fmpq_mat::fmpq_mat_entry};
+
+/// Collects the entries of `matrix` in row-major order as [`f64`] values,
+/// i.e. every entry is rounded to a nearby floating-point number.
+///
+/// Parameters:
+/// - `matrix`: the matrix whose entries are collected
+///
+/// Returns the entries of `matrix` as [`f64`] values.
+fn collect_f64_entries(matrix: &MatQ) -> Vec<f64> {
+    let mut entries =
+        Vec::with_capacity((matrix.get_num_rows() * matrix.get_num_columns()) as usize);
+    for row in 0..matrix.get_num_rows() {
+        for column in 0..matrix.get_num_columns() {
+            entries.push(unsafe { fmpq_get_d(fmpq_mat_entry(&matrix.matrix, row, column)) });
+        }
+    }
+    entries
+}
+
+/// Creates a [`MatQ`] with `num_rows` rows and `num_cols` columns
+/// whose entry `(i, j)` is the exact value of `entry(i, j)`.
+///
+/// Returns the matrix or an error if `num_rows` or `num_cols` is `0`
+/// or an entry is not finite.
+///
+/// # Errors and Failures
+/// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+/// if the number of rows or columns is `0`.
+/// - Returns a [`MathError`] of type [`ConversionError`](MathError::ConversionError)
+/// if an entry is `NaN` or infinite.
+fn mat_q_from_fn(
+    num_rows: usize,
+    num_cols: usize,
+    entry: impl Fn(usize, usize) -> f64,
+) -> Result<MatQ, MathError> {
+    let mut out = MatQ::new(num_rows, num_cols)?;
+    for row in 0..num_rows {
+        for column in 0..num_cols {
+            let value = entry(row, column);
+            let exact = Q::from_f64_exact(value).ok_or_else(|| {
+                MathError::ConversionError(format!(
+                    "the entry {} at ({}, {}) can not be converted into a rational number",
+                    value, row, column
+                ))
+            })?;
+            out.set_entry(row, column, exact).unwrap();
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&MatQ> for nalgebra::DMatrix<f64> {
+    /// Converts a [`MatQ`] into a [`DMatrix`](nalgebra::DMatrix) with [`f64`] entries.
+    /// Entries, which can not be represented exactly, are rounded.
+    ///
+    /// Parameters:
+    /// - `matrix`: the matrix that is converted
+    ///
+    /// Returns the converted matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::DMatrix;
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatQ::from_str("[[1/2, 2, 3],[4, 5, -1/4]]").unwrap();
+    ///
+    /// let converted = DMatrix::<f64>::from(&matrix);
+    ///
+    /// assert_eq!(DMatrix::from_row_slice(2, 3, &[0.5, 2.0, 3.0, 4.0, 5.0, -0.25]), converted);
+    /// ```
+    fn from(matrix: &MatQ) -> Self {
+        nalgebra::DMatrix::from_row_slice(
+            matrix.get_num_rows() as usize,
+            matrix.get_num_columns() as usize,
+            &collect_f64_entries(matrix),
+        )
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl TryFrom<&nalgebra::DMatrix<f64>> for MatQ {
+    type Error = MathError;
+
+    /// Converts a [`DMatrix`](nalgebra::DMatrix) with [`f64`] entries into a [`MatQ`].
+    ///
+    /// Parameters:
+    /// - `matrix`: the matrix that is converted
+    ///
+    /// Returns the converted matrix or an error if `matrix` has no rows or columns
+    /// or contains entries that are not finite.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::DMatrix;
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = DMatrix::from_row_slice(2, 3, &[0.5, 2.0, 3.0, 4.0, 5.0, -0.25]);
+    ///
+    /// let converted = MatQ::try_from(&matrix).unwrap();
+    ///
+    /// assert_eq!(MatQ::from_str("[[1/2, 2, 3],[4, 5, -1/4]]").unwrap(), converted);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `matrix` has no rows or columns.
+    /// - Returns a [`MathError`] of type [`ConversionError`](MathError::ConversionError)
+    /// if an entry of `matrix` is `NaN` or infinite.
+    fn try_from(matrix: &nalgebra::DMatrix<f64>) -> Result<Self, Self::Error> {
+        mat_q_from_fn(matrix.nrows(), matrix.ncols(), |row, column| {
+            matrix[(row, column)]
+        })
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl From<&MatQ> for ndarray::Array2<f64> {
+    /// Converts a [`MatQ`] into an [`Array2`](ndarray::Array2) with [`f64`] entries.
+    /// Entries, which can not be represented exactly, are rounded.
+    ///
+    /// Parameters:
+    /// - `matrix`: the matrix that is converted
+    ///
+    /// Returns the converted matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use ndarray::{array, Array2};
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatQ::from_str("[[1/2, 2, 3],[4, 5, -1/4]]").unwrap();
+    ///
+    /// let converted = Array2::<f64>::from(&matrix);
+    ///
+    /// assert_eq!(array![[0.5, 2.0, 3.0], [4.0, 5.0, -0.25]], converted);
+    /// ```
+    fn from(matrix: &MatQ) -> Self {
+        let shape = (
+            matrix.get_num_rows() as usize,
+            matrix.get_num_columns() as usize,
+        );
+        // the number of collected entries always matches the shape
+        ndarray::Array2::from_shape_vec(shape, collect_f64_entries(matrix)).unwrap()
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl TryFrom<&ndarray::Array2<f64>> for MatQ {
+    type Error = MathError;
+
+    /// Converts an [`Array2`](ndarray::Array2) with [`f64`] entries into a [`MatQ`].
+    ///
+    /// Parameters:
+    /// - `matrix`: the matrix that is converted
+    ///
+    /// Returns the converted matrix or an error if `matrix` has no rows or columns
+    /// or contains entries that are not finite.
+    ///
+    /// # Example
+    /// ```
+    /// use ndarray::array;
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = array![[0.5, 2.0, 3.0], [4.0, 5.0, -0.25]];
+    ///
+    /// let converted = MatQ::try_from(&matrix).unwrap();
+    ///
+    /// assert_eq!(MatQ::from_str("[[1/2, 2, 3],[4, 5, -1/4]]").unwrap(), converted);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if `matrix` has no rows or columns.
+    /// - Returns a [`MathError`] of type [`ConversionError`](MathError::ConversionError)
+    /// if an entry of `matrix` is `NaN` or infinite.
+    fn try_from(matrix: &ndarray::Array2<f64>) -> Result<Self, Self::Error> {
+        mat_q_from_fn(matrix.nrows(), matrix.ncols(), |row, column| {
+            matrix[[row, column]]
+        })
+    }
+}
+
+#[cfg(all(test, feature = "nalgebra"))]
+mod test_nalgebra {
+    use super::MatQ;
+    use crate::{error::MathError, rational::Q, traits::GetEntry};
+    use nalgebra::DMatrix;
+    use std::str::FromStr;
+
+    /// Ensure that converting back and forth results in the same matrix
+    /// for entries, which can be represented exactly
+    #[test]
+    fn round_trip() {
+        let matrix = MatQ::from_str("[[1/2, 0],[-3/8, 1024],[3, -7/4]]").unwrap();
+
+        let converted = DMatrix::<f64>::from(&matrix);
+
+        assert_eq!((3, 2), converted.shape());
+        assert_eq!(-0.375, converted[(1, 0)]);
+        assert_eq!(matrix, MatQ::try_from(&converted).unwrap());
+    }
+
+    /// Ensure that very large and very small entries are converted exactly
+    /// and that converting back and forth results in the same matrix
+    #[test]
+    fn round_trip_extreme_values() {
+        let entries = [1e300, -1e-300, 1e30, 1e-20, f64::MAX, 0.1];
+        let matrix = DMatrix::from_row_slice(2, 3, &entries);
+
+        let converted = MatQ::try_from(&matrix).unwrap();
+        let entry: Q = converted.get_entry(1, 0).unwrap();
+
+        assert_eq!(Q::from_f64_exact(1e-20).unwrap(), entry);
+        assert_eq!(matrix, DMatrix::<f64>::from(&converted));
+    }
+
+    /// Ensure that entries, which can not be represented exactly, are rounded
+    #[test]
+    fn rounded_entries() {
+        let matrix = MatQ::from_str("[[1/3]]").unwrap();
+
+        let converted = DMatrix::<f64>::from(&matrix);
+
+        assert!((converted[(0, 0)] - 1.0 / 3.0).abs() <= f64::EPSILON);
+    }
+
+    /// Ensure that empty matrices and non-finite entries result in an error
+    #[test]
+    fn invalid_matrix() {
+        let empty = DMatrix::<f64>::zeros(0, 3);
+        let nan = DMatrix::from_row_slice(1, 2, &[1.0, f64::NAN]);
+        let infinite = DMatrix::from_row_slice(1, 2, &[f64::NEG_INFINITY, 1.0]);
+
+        assert!(matches!(
+            MatQ::try_from(&empty),
+            Err(MathError::InvalidMatrix(_))
+        ));
+        assert!(matches!(
+            MatQ::try_from(&nan),
+            Err(MathError::ConversionError(_))
+        ));
+        assert!(matches!(
+            MatQ::try_from(&infinite),
+            Err(MathError::ConversionError(_))
+        ));
+    }
+}
+
+#[cfg(all(test, feature = "ndarray"))]
+mod test_ndarray {
+    use super::MatQ;
+    use crate::{error::MathError, rational::Q, traits::GetEntry};
+    use ndarray::{array, Array2};
+    use std::str::FromStr;
+
+    /// Ensure that converting back and forth results in the same matrix
+    /// for entries, which can be represented exactly
+    #[test]
+    fn round_trip() {
+        let matrix = MatQ::from_str("[[1/2, 0],[-3/8, 1024],[3, -7/4]]").unwrap();
+
+        let converted = Array2::<f64>::from(&matrix);
+
+        assert_eq!(&[3, 2], converted.shape());
+        assert_eq!(-0.375, converted[[1, 0]]);
+        assert_eq!(matrix, MatQ::try_from(&converted).unwrap());
+    }
+
+    /// Ensure that very large and very small entries are converted exactly
+    /// and that converting back and forth results in the same matrix
+    #[test]
+    fn round_trip_extreme_values() {
+        let matrix = array![[1e300, -1e-300, 1e30], [1e-20, f64::MAX, 0.1]];
+
+        let converted = MatQ::try_from(&matrix).unwrap();
+        let entry: Q = converted.get_entry(0, 2).unwrap();
+
+        assert_eq!(Q::from_f64_exact(1e30).unwrap(), entry);
+        assert_eq!(matrix, Array2::<f64>::from(&converted));
+    }
+
+    /// Ensure that empty matrices and non-finite entries result in an error
+    #[test]
+    fn invalid_matrix() {
+        let empty = Array2::<f64>::zeros((2, 0));
+        let nan = array![[1.0, f64::NAN]];
+
+        assert!(matches!(
+            MatQ::try_from(&empty),
+            Err(MathError::InvalidMatrix(_))
+        ));
+        assert!(matches!(
+            MatQ::try_from(&nan),
+            Err(MathError::ConversionError(_))
+        ));
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the `inverse` and
+//! `pseudo_inverse` functions.
+
+use super::MatQ;
+use crate::{
+    error::MathError,
+    traits::{GetNumColumns, GetNumRows},
+};
+use flint_sys::fmpq_mat::fmpq_mat_inv;
+
+impl MatQ {
+    /// Returns the inverse of a square matrix, i.e. the matrix `B`
+    /// such that `self * B` is the identity matrix.
+    ///
+    /// Returns the inverse of the matrix or an error if the matrix
+    /// is not square or singular.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatQ::from_str("[[1/2,1],[0,4]]").unwrap();
+    ///
+    /// let inverse = mat.inverse().unwrap();
+    ///
+    /// assert_eq!(MatQ::from_str("[[2,-1/2],[0,1/4]]").unwrap(), inverse);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the matrix is not square.
+    /// - Returns a [`MathError`] of type [`NotInvertible`](MathError::NotInvertible)
+    /// if the matrix is singular.
+    pub fn inverse(&self) -> Result<MatQ, MathError> {
+        if self.get_num_rows() != self.get_num_columns() {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "The inverse is only defined for square matrices, but the matrix is '{}x{}'.",
+                self.get_num_rows(),
+                self.get_num_columns()
+            )));
+        }
+
+        let mut out = MatQ::new(self.get_num_rows(), self.get_num_columns()).unwrap();
+        if 0 == unsafe { fmpq_mat_inv(&mut out.matrix, &self.matrix) } {
+            return Err(MathError::NotInvertible(format!(
+                "{}, as the matrix is singular",
+                self
+            )));
+        }
+        Ok(out)
+    }
+
+    /// Returns the Moore-Penrose pseudo-inverse of a matrix of full rank.
+    /// For matrices with at least as many rows as columns, the pseudo-inverse
+    /// `(A^T * A)^{-1} * A^T` is a left inverse, otherwise the pseudo-inverse
+    /// `A^T * (A * A^T)^{-1}` is a right inverse of `A = self`.
+    ///
+    /// Returns the pseudo-inverse of the matrix or an error if the matrix
+    /// does not have full rank.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatQ::from_str("[[1,0],[0,1/2],[1,0]]").unwrap();
+    ///
+    /// let pseudo_inverse = mat.pseudo_inverse().unwrap();
+    ///
+    /// assert_eq!(MatQ::from_str("[[1/2,0,1/2],[0,2,0]]").unwrap(), pseudo_inverse);
+    /// assert_eq!(MatQ::identity(2, 2).unwrap(), &pseudo_inverse * &mat);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`NotInvertible`](MathError::NotInvertible)
+    /// if the matrix does not have full rank.
+    pub fn pseudo_inverse(&self) -> Result<MatQ, MathError> {
+        let transposed = self.transpose();
+        let not_full_rank = |_| {
+            MathError::NotInvertible(format!("{}, as the matrix does not have full rank", self))
+        };
+
+        if self.get_num_rows() >= self.get_num_columns() {
+            let gram_inverse = transposed.gram().inverse().map_err(not_full_rank)?;
+            Ok(&gram_inverse * &transposed)
+        } else {
+            let gram_inverse = self.gram().inverse().map_err(not_full_rank)?;
+            Ok(&transposed * &gram_inverse)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_inverse {
+    use super::MatQ;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Checks if the inverse is correctly computed
+    #[test]
+    fn correct_inverse() {
+        let mat = MatQ::from_str("[[1/2,0,1],[2,-1/3,0],[0,4,3/4]]").unwrap();
+
+        let inverse = mat.inverse().unwrap();
+
+        assert_eq!(MatQ::identity(3, 3).unwrap(), &mat * &inverse);
+        assert_eq!(MatQ::identity(3, 3).unwrap(), &inverse * &mat);
+    }
+
+    /// Checks if the inverse is correctly computed for large entries
+    #[test]
+    fn large_entries() {
+        let mat = MatQ::from_str(&format!("[[1/{},0],[0,{}]]", u64::MAX, i64::MIN)).unwrap();
+
+        let cmp = MatQ::from_str(&format!(
+            "[[{},0],[0,-1/{}]]",
+            u64::MAX,
+            i64::MIN as i128 * -1
+        ))
+        .unwrap();
+        assert_eq!(cmp, mat.inverse().unwrap());
+    }
+
+    /// Checks if an error is returned for singular matrices
+    #[test]
+    fn singular() {
+        let mat = MatQ::from_str("[[1/2,1],[1/4,1/2]]").unwrap();
+
+        assert!(matches!(mat.inverse(), Err(MathError::NotInvertible(_))));
+    }
+
+    /// Checks if an error is returned for non-square matrices
+    #[test]
+    fn non_square() {
+        let mat = MatQ::new(3, 2).unwrap();
+
+        assert!(matches!(
+            mat.inverse(),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_pseudo_inverse {
+    use super::MatQ;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Checks if the pseudo-inverse of a tall matrix is a left inverse
+    #[test]
+    fn tall() {
+        let mat = MatQ::from_str("[[1/2,1],[0,2/3],[3,-1]]").unwrap();
+
+        let pseudo_inverse = mat.pseudo_inverse().unwrap();
+
+        assert_eq!(MatQ::identity(2, 2).unwrap(), &pseudo_inverse * &mat);
+    }
+
+    /// Checks if the pseudo-inverse of a wide matrix is a right inverse
+    #[test]
+    fn wide() {
+        let mat = MatQ::from_str("[[1/2,0,3],[1,2/3,-1]]").unwrap();
+
+        let pseudo_inverse = mat.pseudo_inverse().unwrap();
+
+        assert_eq!(MatQ::identity(2, 2).unwrap(), &mat * &pseudo_inverse);
+    }
+
+    /// Checks if the pseudo-inverse of a regular matrix is its inverse
+    #[test]
+    fn square() {
+        let mat = MatQ::from_str(&format!("[[1/{},2],[0,{}]]", u64::MAX, i64::MIN)).unwrap();
+
+        assert_eq!(mat.inverse().unwrap(), mat.pseudo_inverse().unwrap());
+    }
+
+    /// Checks if an error is returned for matrices without full rank
+    #[test]
+    fn not_full_rank() {
+        let tall = MatQ::from_str("[[1,2],[2,4],[1/2,1]]").unwrap();
+        let wide = MatQ::from_str("[[1,2,3],[1/3,2/3,1]]").unwrap();
+
+        assert!(matches!(
+            tall.pseudo_inverse(),
+            Err(MathError::NotInvertible(_))
+        ));
+        assert!(matches!(
+            wide.pseudo_inverse(),
+            Err(MathError::NotInvertible(_))
+        ));
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the `kernel` function.
+
+use super::MatQ;
+use crate::traits::GetNumColumns;
+use flint_sys::{
+    fmpq::{fmpq_is_zero, fmpq_neg, fmpq_one},
+    fmpq_mat::fmpq_mat_entry,
+};
+
+impl MatQ {
+    /// Computes a basis of the right kernel of the matrix,
+    /// i.e. of the space of all vectors `x` with `self * x = 0`.
+    ///
+    /// The basis is obtained from the reduced row echelon form, where each
+    /// non-pivot column yields one basis vector.
+    ///
+    /// Returns a matrix whose columns form a basis of the right kernel.
+    /// If the kernel is trivial, the zero column vector is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatQ::from_str("[[1/2,1,3/2],[2,4,7]]").unwrap();
+    ///
+    /// let kernel = mat.kernel();
+    ///
+    /// assert_eq!(MatQ::new(2, 1).unwrap(), &mat * &kernel);
+    /// assert_eq!(1, kernel.rank());
+    /// ```
+    pub fn kernel(&self) -> Self {
+        let num_columns = self.get_num_columns();
+        let (rref, rank) = self.rref();
+        if rank == num_columns {
+            return MatQ::new(num_columns, 1).unwrap();
+        }
+
+        // the pivot of each non-zero row of the reduced row echelon form
+        let mut pivots = Vec::with_capacity(rank as usize);
+        for row in 0..rank {
+            let start = pivots.last().map_or(0, |pivot| pivot + 1);
+            let pivot = (start..num_columns)
+                .find(|column| unsafe {
+                    fmpq_is_zero(fmpq_mat_entry(&rref.matrix, row, *column)) == 0
+                })
+                .unwrap();
+            pivots.push(pivot);
+        }
+
+        // each free variable set to `1` determines the pivot variables uniquely
+        let out = MatQ::new(num_columns, num_columns - rank).unwrap();
+        let free_columns = (0..num_columns).filter(|column| !pivots.contains(column));
+        for (out_column, free_column) in free_columns.enumerate() {
+            unsafe { fmpq_one(fmpq_mat_entry(&out.matrix, free_column, out_column as i64)) };
+            for (row, pivot) in pivots.iter().enumerate() {
+                unsafe {
+                    fmpq_neg(
+                        fmpq_mat_entry(&out.matrix, *pivot, out_column as i64),
+                        fmpq_mat_entry(&rref.matrix, row as i64, free_column),
+                    )
+                };
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_kernel {
+    use super::MatQ;
+    use crate::traits::{GetNumColumns, GetNumRows};
+    use std::str::FromStr;
+
+    /// Checks if the kernel vectors are mapped to zero and span a
+    /// space of the correct dimension
+    #[test]
+    fn correct_dimension() {
+        let mat = MatQ::from_str("[[1/2,1,3/2,2],[2,4,6,8],[0,1,1,0]]").unwrap();
+
+        let kernel = mat.kernel();
+
+        assert_eq!(4, kernel.get_num_rows());
+        assert_eq!(2, kernel.get_num_columns());
+        assert_eq!(2, kernel.rank());
+        assert_eq!(MatQ::new(3, 2).unwrap(), &mat * &kernel);
+    }
+
+    /// Checks if the basis is read off the reduced row echelon form
+    #[test]
+    fn exact_basis() {
+        let mat = MatQ::from_str("[[2,4]]").unwrap();
+
+        let kernel = mat.kernel();
+
+        assert_eq!(MatQ::from_str("[[-2],[1]]").unwrap(), kernel);
+    }
+
+    /// Checks if the zero vector is returned for a trivial kernel
+    #[test]
+    fn trivial_kernel() {
+        let mat = MatQ::from_str("[[1,2],[3,4/5],[5,6]]").unwrap();
+
+        let kernel = mat.kernel();
+
+        assert_eq!(MatQ::new(2, 1).unwrap(), kernel);
+    }
+
+    /// Checks if the kernel of the zero matrix is the whole space
+    #[test]
+    fn zero_matrix() {
+        let mat = MatQ::new(2, 3).unwrap();
+
+        let kernel = mat.kernel();
+
+        assert_eq!(MatQ::identity(3, 3).unwrap(), kernel);
+    }
+
+    /// Checks if the kernel is correctly computed for large entries
+    #[test]
+    fn large_entries() {
+        let mat = MatQ::from_str(&format!("[[{},-1/{},1]]", i64::MAX, i64::MAX)).unwrap();
+
+        let kernel = mat.kernel();
+
+        assert_eq!(2, kernel.get_num_columns());
+        assert_eq!(MatQ::new(1, 2).unwrap(), &mat * &kernel);
+    }
+}
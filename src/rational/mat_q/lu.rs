@@ -0,0 +1,198 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the computation of the PLU decomposition of a [`MatQ`].
+
+use super::MatQ;
+use crate::{
+    integer::{MatZ, Z},
+    rational::Q,
+    traits::{GetNumColumns, GetNumRows, SetEntry},
+};
+use flint_sys::{
+    fmpq::{fmpq_div, fmpq_is_zero, fmpq_set, fmpq_submul, fmpq_zero},
+    fmpq_mat::fmpq_mat_entry,
+};
+
+impl MatQ {
+    /// Computes the PLU decomposition of `self` exactly over [`Q`], i.e.
+    /// a permutation matrix `P`, a lower triangular matrix `L` with ones on
+    /// its diagonal and a matrix `U` in row echelon form s.t. `P * self = L * U`.
+    /// The decomposition exists for any matrix, including singular and
+    /// non-square matrices, where `L` is square and `U` has the dimensions of `self`.
+    ///
+    /// Returns the tuple `(P, L, U)`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatQ::from_str("[[0,1/2],[2,4]]").unwrap();
+    ///
+    /// let (p, l, u) = mat.lu();
+    ///
+    /// assert_eq!(&p * &mat, &l * &u);
+    /// assert_eq!(MatQ::from_str("[[2,4],[0,1/2]]").unwrap(), u);
+    /// ```
+    pub fn lu(&self) -> (MatZ, MatQ, MatQ) {
+        let num_rows = self.get_num_rows();
+        let num_columns = self.get_num_columns();
+        let mut permutation: Vec<i64> = (0..num_rows).collect();
+        let mut lower = MatQ::identity(num_rows, num_rows).unwrap();
+        let mut upper = self.clone();
+        let mut factor = Q::default();
+
+        let mut pivot_row = 0;
+        for column in 0..num_columns {
+            if pivot_row == num_rows {
+                break;
+            }
+            let pivot = match (pivot_row..num_rows).find(|row| unsafe {
+                fmpq_is_zero(fmpq_mat_entry(&upper.matrix, *row, column)) == 0
+            }) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+
+            if pivot != pivot_row {
+                upper.swap_rows(pivot, pivot_row).unwrap();
+                permutation.swap(pivot as usize, pivot_row as usize);
+                // only the already computed multipliers of `L` belong to the swapped rows
+                for lower_column in 0..pivot_row {
+                    unsafe {
+                        fmpq_set(
+                            &mut factor.value,
+                            fmpq_mat_entry(&lower.matrix, pivot, lower_column),
+                        );
+                        fmpq_set(
+                            fmpq_mat_entry(&lower.matrix, pivot, lower_column),
+                            fmpq_mat_entry(&lower.matrix, pivot_row, lower_column),
+                        );
+                        fmpq_set(
+                            fmpq_mat_entry(&lower.matrix, pivot_row, lower_column),
+                            &factor.value,
+                        );
+                    }
+                }
+            }
+
+            for row in pivot_row + 1..num_rows {
+                unsafe {
+                    fmpq_div(
+                        &mut factor.value,
+                        fmpq_mat_entry(&upper.matrix, row, column),
+                        fmpq_mat_entry(&upper.matrix, pivot_row, column),
+                    );
+                    fmpq_set(fmpq_mat_entry(&lower.matrix, row, pivot_row), &factor.value);
+                    fmpq_zero(fmpq_mat_entry(&upper.matrix, row, column));
+                    for upper_column in column + 1..num_columns {
+                        fmpq_submul(
+                            fmpq_mat_entry(&upper.matrix, row, upper_column),
+                            &factor.value,
+                            fmpq_mat_entry(&upper.matrix, pivot_row, upper_column),
+                        );
+                    }
+                }
+            }
+            pivot_row += 1;
+        }
+
+        let mut permutation_matrix = MatZ::new(num_rows, num_rows).unwrap();
+        for (row, column) in permutation.into_iter().enumerate() {
+            permutation_matrix.set_entry(row, column, Z::ONE).unwrap();
+        }
+        (permutation_matrix, lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod test_lu {
+    use super::MatQ;
+    use crate::{
+        integer::MatZ,
+        rational::Q,
+        traits::{GetEntry, GetNumColumns, GetNumRows},
+    };
+    use std::str::FromStr;
+
+    /// Checks whether `lower` is lower triangular with ones on its diagonal,
+    /// `upper` is in row echelon form and `p * mat = lower * upper` holds
+    fn assert_decomposition(mat: &MatQ, p: &MatZ, lower: &MatQ, upper: &MatQ) {
+        assert_eq!(p * mat, lower * upper);
+
+        for row in 0..lower.get_num_rows() {
+            assert_eq!(Q::ONE, lower.get_entry(row, row).unwrap());
+            for column in row + 1..lower.get_num_columns() {
+                assert_eq!(Q::ZERO, lower.get_entry(row, column).unwrap());
+            }
+        }
+
+        let mut last_pivot = -1;
+        for row in 0..upper.get_num_rows() {
+            let pivot = (0..upper.get_num_columns())
+                .find(|column| Q::ZERO != upper.get_entry(row, *column).unwrap())
+                .unwrap_or(upper.get_num_columns());
+            assert!(pivot > last_pivot || pivot == upper.get_num_columns());
+            last_pivot = pivot;
+        }
+    }
+
+    /// Checks if the decomposition of a regular matrix is correct
+    #[test]
+    fn regular() {
+        let mat = MatQ::from_str("[[1/2,0,1],[2,-1/3,0],[0,4,3/4]]").unwrap();
+
+        let (p, l, u) = mat.lu();
+
+        assert_eq!(MatZ::identity(3, 3).unwrap(), p);
+        assert_decomposition(&mat, &p, &l, &u);
+    }
+
+    /// Checks if rows are permuted if a pivot is zero
+    #[test]
+    fn permutation() {
+        let mat = MatQ::from_str("[[0,1,2],[0,0,3],[5,1/2,0]]").unwrap();
+
+        let (p, l, u) = mat.lu();
+
+        assert_eq!(MatZ::from_str("[[0,0,1],[1,0,0],[0,1,0]]").unwrap(), p);
+        assert_decomposition(&mat, &p, &l, &u);
+    }
+
+    /// Checks if singular and non-square matrices are decomposed
+    #[test]
+    fn singular_and_non_square() {
+        let singular = MatQ::from_str("[[1,2,3],[2,4,6],[1,1/2,1/3]]").unwrap();
+        let wide = MatQ::from_str("[[0,0,1,2],[0,3/4,1,0]]").unwrap();
+        let tall = MatQ::from_str("[[0,1],[0,2],[3,4]]").unwrap();
+
+        for mat in [singular, wide, tall] {
+            let (p, l, u) = mat.lu();
+
+            assert_decomposition(&mat, &p, &l, &u);
+        }
+    }
+
+    /// Checks if the decomposition is correct for large entries
+    #[test]
+    fn large_entries() {
+        let mat = MatQ::from_str(&format!(
+            "[[1/{},{}],[{},1/{}]]",
+            u64::MAX,
+            i64::MIN,
+            i64::MAX,
+            i64::MAX
+        ))
+        .unwrap();
+
+        let (p, l, u) = mat.lu();
+
+        assert_decomposition(&mat, &p, &l, &u);
+    }
+}
@@ -0,0 +1,431 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains functions to swap, delete and insert
+//! rows and columns of a [`MatQ`].
+
+use super::MatQ;
+use crate::{
+    error::MathError,
+    traits::{GetNumColumns, GetNumRows},
+    utils::index::evaluate_bounded_index,
+};
+use flint_sys::{
+    fmpq::{fmpq_set, fmpq_swap},
+    fmpq_mat::fmpq_mat_entry,
+};
+use std::{cmp::Ordering, fmt::Display};
+
+impl MatQ {
+    /// Swaps two rows of the matrix.
+    ///
+    /// Parameters:
+    /// - `row_1`: specifies the first row that is swapped
+    /// - `row_2`: specifies the second row that is swapped
+    ///
+    /// Returns an empty `Ok` if the action could be performed successfully.
+    /// Otherwise, a [`MathError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mut matrix = MatQ::from_str("[[1/2,2,3],[4,5,6/7]]").unwrap();
+    ///
+    /// matrix.swap_rows(0, 1).unwrap();
+    ///
+    /// assert_eq!(MatQ::from_str("[[4,5,6/7],[1/2,2,3]]").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if one of the rows is greater than the matrix or negative.
+    pub fn swap_rows(
+        &mut self,
+        row_1: impl TryInto<i64> + Display + Copy,
+        row_2: impl TryInto<i64> + Display + Copy,
+    ) -> Result<(), MathError> {
+        let row_1 = evaluate_bounded_index(row_1, self.get_num_rows())?;
+        let row_2 = evaluate_bounded_index(row_2, self.get_num_rows())?;
+
+        for column in 0..self.get_num_columns() {
+            unsafe {
+                fmpq_swap(
+                    fmpq_mat_entry(&self.matrix, row_1, column),
+                    fmpq_mat_entry(&self.matrix, row_2, column),
+                )
+            };
+        }
+        Ok(())
+    }
+
+    /// Swaps two columns of the matrix.
+    ///
+    /// Parameters:
+    /// - `column_1`: specifies the first column that is swapped
+    /// - `column_2`: specifies the second column that is swapped
+    ///
+    /// Returns an empty `Ok` if the action could be performed successfully.
+    /// Otherwise, a [`MathError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mut matrix = MatQ::from_str("[[1/2,2,3],[4,5,6/7]]").unwrap();
+    ///
+    /// matrix.swap_columns(0, 1).unwrap();
+    ///
+    /// assert_eq!(MatQ::from_str("[[2,1/2,3],[5,4,6/7]]").unwrap(), matrix);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if one of the columns is greater than the matrix or negative.
+    pub fn swap_columns(
+        &mut self,
+        column_1: impl TryInto<i64> + Display + Copy,
+        column_2: impl TryInto<i64> + Display + Copy,
+    ) -> Result<(), MathError> {
+        let column_1 = evaluate_bounded_index(column_1, self.get_num_columns())?;
+        let column_2 = evaluate_bounded_index(column_2, self.get_num_columns())?;
+
+        for row in 0..self.get_num_rows() {
+            unsafe {
+                fmpq_swap(
+                    fmpq_mat_entry(&self.matrix, row, column_1),
+                    fmpq_mat_entry(&self.matrix, row, column_2),
+                )
+            };
+        }
+        Ok(())
+    }
+
+    /// Deletes a row of the matrix, i.e. all following rows are moved up by one.
+    ///
+    /// Parameters:
+    /// - `row`: specifies the row that is deleted
+    ///
+    /// Returns an empty `Ok` if the action could be performed successfully.
+    /// Otherwise, a [`MathError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mut matrix = MatQ::from_str("[[1/2,2,3],[4,5,6/7]]").unwrap();
+    ///
+    /// matrix.delete_row(0).unwrap();
+    ///
+    /// assert_eq!(matrix, MatQ::from_str("[[1/2,2,3],[4,5,6/7]]").unwrap().get_row(1).unwrap());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the row is greater than the matrix or negative.
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if the matrix has only one row, as a matrix can not contain `0` rows.
+    pub fn delete_row(&mut self, row: impl TryInto<i64> + Display + Copy) -> Result<(), MathError> {
+        let row = evaluate_bounded_index(row, self.get_num_rows())?;
+
+        let out = MatQ::new(self.get_num_rows() - 1, self.get_num_columns())?;
+        for out_row in 0..out.get_num_rows() {
+            let source_row = if out_row < row { out_row } else { out_row + 1 };
+            for column in 0..self.get_num_columns() {
+                unsafe {
+                    fmpq_set(
+                        fmpq_mat_entry(&out.matrix, out_row, column),
+                        fmpq_mat_entry(&self.matrix, source_row, column),
+                    )
+                };
+            }
+        }
+        *self = out;
+        Ok(())
+    }
+
+    /// Deletes a column of the matrix, i.e. all following columns are moved left by one.
+    ///
+    /// Parameters:
+    /// - `column`: specifies the column that is deleted
+    ///
+    /// Returns an empty `Ok` if the action could be performed successfully.
+    /// Otherwise, a [`MathError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mut matrix = MatQ::from_str("[[1/2,2,3],[4,5,6/7]]").unwrap();
+    ///
+    /// matrix.delete_column(0).unwrap();
+    ///
+    /// let cmp = MatQ::from_str("[[1/2,2,3],[4,5,6/7]]").unwrap().get_column(1).unwrap();
+    /// assert_eq!(cmp, matrix.get_column(0).unwrap());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the column is greater than the matrix or negative.
+    /// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+    /// if the matrix has only one column, as a matrix can not contain `0` columns.
+    pub fn delete_column(
+        &mut self,
+        column: impl TryInto<i64> + Display + Copy,
+    ) -> Result<(), MathError> {
+        let column = evaluate_bounded_index(column, self.get_num_columns())?;
+
+        let out = MatQ::new(self.get_num_rows(), self.get_num_columns() - 1)?;
+        for out_column in 0..out.get_num_columns() {
+            let source_column = if out_column < column {
+                out_column
+            } else {
+                out_column + 1
+            };
+            for row in 0..self.get_num_rows() {
+                unsafe {
+                    fmpq_set(
+                        fmpq_mat_entry(&out.matrix, row, out_column),
+                        fmpq_mat_entry(&self.matrix, row, source_column),
+                    )
+                };
+            }
+        }
+        *self = out;
+        Ok(())
+    }
+
+    /// Inserts a row vector into the matrix s.t. it becomes the row at position `row`
+    /// and all following rows are moved down by one.
+    ///
+    /// Parameters:
+    /// - `row`: specifies the position of the inserted row,
+    /// which may be the number of rows to append `row_vector` at the bottom
+    /// - `row_vector`: the row vector with as many entries as `self` has columns
+    ///
+    /// Returns an empty `Ok` if the action could be performed successfully.
+    /// Otherwise, a [`MathError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mut matrix = MatQ::from_str("[[1/2,2,3],[4,5,6/7]]").unwrap();
+    /// let row_vector = matrix.get_row(0).unwrap();
+    ///
+    /// matrix.insert_row(2, &row_vector).unwrap();
+    ///
+    /// assert_eq!(row_vector, matrix.get_row(2).unwrap());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the row is greater than the number of rows or negative.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if `row_vector` is not a row vector with as many entries as `self` has columns.
+    pub fn insert_row(
+        &mut self,
+        row: impl TryInto<i64> + Display + Copy,
+        row_vector: &Self,
+    ) -> Result<(), MathError> {
+        let row = evaluate_bounded_index(row, self.get_num_rows() + 1)?;
+        if row_vector.get_num_rows() != 1 || row_vector.get_num_columns() != self.get_num_columns()
+        {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to insert a '{}x{}' matrix as a row of a '{}x{}' matrix.",
+                row_vector.get_num_rows(),
+                row_vector.get_num_columns(),
+                self.get_num_rows(),
+                self.get_num_columns()
+            )));
+        }
+
+        let out = MatQ::new(self.get_num_rows() + 1, self.get_num_columns()).unwrap();
+        for out_row in 0..out.get_num_rows() {
+            for column in 0..self.get_num_columns() {
+                let source = match out_row.cmp(&row) {
+                    Ordering::Less => unsafe { fmpq_mat_entry(&self.matrix, out_row, column) },
+                    Ordering::Equal => unsafe { fmpq_mat_entry(&row_vector.matrix, 0, column) },
+                    Ordering::Greater => unsafe {
+                        fmpq_mat_entry(&self.matrix, out_row - 1, column)
+                    },
+                };
+                unsafe { fmpq_set(fmpq_mat_entry(&out.matrix, out_row, column), source) };
+            }
+        }
+        *self = out;
+        Ok(())
+    }
+
+    /// Inserts a column vector into the matrix s.t. it becomes the column at position `column`
+    /// and all following columns are moved right by one.
+    ///
+    /// Parameters:
+    /// - `column`: specifies the position of the inserted column,
+    /// which may be the number of columns to append `column_vector` on the right
+    /// - `column_vector`: the column vector with as many entries as `self` has rows
+    ///
+    /// Returns an empty `Ok` if the action could be performed successfully.
+    /// Otherwise, a [`MathError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mut matrix = MatQ::from_str("[[1/2,2,3],[4,5,6/7]]").unwrap();
+    /// let column_vector = matrix.get_column(2).unwrap();
+    ///
+    /// matrix.insert_column(0, &column_vector).unwrap();
+    ///
+    /// assert_eq!(column_vector, matrix.get_column(0).unwrap());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the column is greater than the number of columns or negative.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if `column_vector` is not a column vector with as many entries as `self` has rows.
+    pub fn insert_column(
+        &mut self,
+        column: impl TryInto<i64> + Display + Copy,
+        column_vector: &Self,
+    ) -> Result<(), MathError> {
+        let column = evaluate_bounded_index(column, self.get_num_columns() + 1)?;
+        if column_vector.get_num_columns() != 1
+            || column_vector.get_num_rows() != self.get_num_rows()
+        {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to insert a '{}x{}' matrix as a column of a '{}x{}' matrix.",
+                column_vector.get_num_rows(),
+                column_vector.get_num_columns(),
+                self.get_num_rows(),
+                self.get_num_columns()
+            )));
+        }
+
+        let out = MatQ::new(self.get_num_rows(), self.get_num_columns() + 1).unwrap();
+        for out_column in 0..out.get_num_columns() {
+            for row in 0..self.get_num_rows() {
+                let source = match out_column.cmp(&column) {
+                    Ordering::Less => unsafe { fmpq_mat_entry(&self.matrix, row, out_column) },
+                    Ordering::Equal => unsafe { fmpq_mat_entry(&column_vector.matrix, row, 0) },
+                    Ordering::Greater => unsafe {
+                        fmpq_mat_entry(&self.matrix, row, out_column - 1)
+                    },
+                };
+                unsafe { fmpq_set(fmpq_mat_entry(&out.matrix, row, out_column), source) };
+            }
+        }
+        *self = out;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_swap {
+    use crate::rational::MatQ;
+    use std::str::FromStr;
+
+    /// Ensure that rows and columns are correctly swapped
+    #[test]
+    fn swap_rows_and_columns() {
+        let mut matrix = MatQ::from_str(&format!("[[1/2,2],[3,1/{}]]", i64::MAX)).unwrap();
+
+        matrix.swap_rows(0, 1).unwrap();
+        matrix.swap_columns(1, 0).unwrap();
+
+        let cmp = MatQ::from_str(&format!("[[1/{},3],[2,1/2]]", i64::MAX)).unwrap();
+        assert_eq!(cmp, matrix);
+    }
+
+    /// Ensure that indices out of bounds are rejected
+    #[test]
+    fn out_of_bounds() {
+        let mut matrix = MatQ::new(2, 3).unwrap();
+
+        assert!(matrix.swap_rows(0, 2).is_err());
+        assert!(matrix.swap_columns(-1, 0).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_delete {
+    use crate::{error::MathError, rational::MatQ};
+    use std::str::FromStr;
+
+    /// Ensure that rows and columns are correctly deleted
+    #[test]
+    fn delete_row_and_column() {
+        let mut matrix = MatQ::from_str("[[1/2,2,3],[4,5/3,6],[7,8,9/4]]").unwrap();
+
+        matrix.delete_row(0).unwrap();
+        matrix.delete_column(1).unwrap();
+
+        assert_eq!(MatQ::from_str("[[4,6],[7,9/4]]").unwrap(), matrix);
+    }
+
+    /// Ensure that the last row or column can not be deleted
+    #[test]
+    fn last_row_or_column() {
+        let mut matrix = MatQ::new(1, 1).unwrap();
+
+        assert!(matches!(
+            matrix.delete_row(0),
+            Err(MathError::InvalidMatrix(_))
+        ));
+        assert!(matches!(
+            matrix.delete_column(0),
+            Err(MathError::InvalidMatrix(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_insert {
+    use crate::{error::MathError, rational::MatQ};
+    use std::str::FromStr;
+
+    /// Ensure that rows and columns are correctly inserted
+    #[test]
+    fn insert_row_and_column() {
+        let mut matrix = MatQ::from_str("[[1/2,2],[3,4]]").unwrap();
+
+        matrix
+            .insert_row(2, &MatQ::from_str("[[5,-6/7]]").unwrap())
+            .unwrap();
+        matrix
+            .insert_column(0, &MatQ::from_str("[[0],[1/3],[0]]").unwrap())
+            .unwrap();
+
+        let cmp = MatQ::from_str("[[0,1/2,2],[1/3,3,4],[0,5,-6/7]]").unwrap();
+        assert_eq!(cmp, matrix);
+    }
+
+    /// Ensure that vectors of wrong dimensions are rejected
+    #[test]
+    fn wrong_dimensions() {
+        let mut matrix = MatQ::new(2, 3).unwrap();
+
+        assert!(matches!(
+            matrix.insert_row(0, &MatQ::new(2, 3).unwrap()),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+        assert!(matches!(
+            matrix.insert_column(0, &MatQ::new(1, 1).unwrap()),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
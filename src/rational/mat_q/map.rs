@@ -0,0 +1,145 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains functions to apply a closure to every entry of a [`MatQ`].
+
+use super::MatQ;
+use crate::{
+    rational::Q,
+    traits::{GetEntry, GetNumColumns, GetNumRows},
+};
+use flint_sys::{fmpq::fmpq_swap, fmpq_mat::fmpq_mat_entry};
+use std::convert::Infallible;
+
+impl MatQ {
+    /// Applies `f` to every entry of `self` and returns the matrix of results.
+    ///
+    /// Parameters:
+    /// - `f`: the function that is applied to each entry
+    ///
+    /// Returns a [`MatQ`] with the same dimensions as `self`, where
+    /// each entry is `f` applied to the corresponding entry of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{MatQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatQ::from_str("[[1/2, -5/3],[7, 0]]").unwrap();
+    ///
+    /// let clamped = matrix.map(|entry| entry.clone().clamp(Q::MINUS_ONE, Q::ONE));
+    ///
+    /// assert_eq!(MatQ::from_str("[[1/2, -1],[1, 0]]").unwrap(), clamped);
+    /// ```
+    pub fn map(&self, mut f: impl FnMut(&Q) -> Q) -> Self {
+        self.try_map(|entry| Ok::<Q, Infallible>(f(entry))).unwrap()
+    }
+
+    /// Applies the fallible function `f` to every entry of `self`
+    /// in row-major order and returns the matrix of results.
+    ///
+    /// Parameters:
+    /// - `f`: the function that is applied to each entry
+    ///
+    /// Returns a [`MatQ`] with the same dimensions as `self`, where
+    /// each entry is `f` applied to the corresponding entry of `self`,
+    /// or the first error returned by `f`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{MatQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let matrix = MatQ::from_str("[[1/2, -5/3],[7, 0]]").unwrap();
+    ///
+    /// let result = matrix.try_map(|entry| Q::ONE.div_safe(entry));
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns the first error returned by `f`.
+    pub fn try_map<E>(&self, mut f: impl FnMut(&Q) -> Result<Q, E>) -> Result<Self, E> {
+        let mut out = MatQ::new(self.get_num_rows(), self.get_num_columns()).unwrap();
+        for row in 0..self.get_num_rows() {
+            for column in 0..self.get_num_columns() {
+                let entry: Q = self.get_entry(row, column).unwrap();
+                let mut value = f(&entry)?;
+                unsafe { fmpq_swap(fmpq_mat_entry(&out.matrix, row, column), &mut value.value) };
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_map {
+    use super::MatQ;
+    use crate::rational::Q;
+    use std::str::FromStr;
+
+    /// Ensure that the function is applied to small and large entries
+    #[test]
+    fn correct_entries() {
+        let matrix =
+            MatQ::from_str(&format!("[[1/2, {}],[-1/{}, 0]]", i64::MAX, i64::MAX)).unwrap();
+
+        let halved = matrix.map(|entry| entry * Q::try_from((&1, &2)).unwrap());
+
+        let cmp = MatQ::from_str(&format!(
+            "[[1/4, {}/2],[-1/{}, 0]]",
+            i64::MAX,
+            2 * i64::MAX as i128
+        ))
+        .unwrap();
+        assert_eq!(cmp, halved);
+    }
+
+    /// Ensure that the entries are visited in row-major order
+    #[test]
+    fn row_major_order() {
+        let matrix = MatQ::new(2, 3).unwrap();
+        let mut counter = 0;
+
+        let enumerated = matrix.map(|_| {
+            counter += 1;
+            Q::try_from((&1, &counter)).unwrap()
+        });
+
+        let cmp = MatQ::from_str("[[1, 1/2, 1/3],[1/4, 1/5, 1/6]]").unwrap();
+        assert_eq!(cmp, enumerated);
+    }
+}
+
+#[cfg(test)]
+mod test_try_map {
+    use super::MatQ;
+    use crate::{error::MathError, rational::Q};
+    use std::str::FromStr;
+
+    /// Ensure that the mapped matrix is returned if no error occurs
+    #[test]
+    fn success() {
+        let matrix = MatQ::from_str("[[1/2, -2],[3/7, 4]]").unwrap();
+
+        let inverted = matrix.try_map(|entry| Q::ONE.div_safe(entry));
+
+        let cmp = MatQ::from_str("[[2, -1/2],[7/3, 1/4]]").unwrap();
+        assert_eq!(cmp, inverted.unwrap());
+    }
+
+    /// Ensure that errors of `f` are returned
+    #[test]
+    fn error() {
+        let matrix = MatQ::from_str("[[1/2, 0],[3/7, 4]]").unwrap();
+
+        let inverted = matrix.try_map(|entry| Q::ONE.div_safe(entry));
+
+        assert!(matches!(inverted, Err(MathError::DivisionByZeroError(_))));
+    }
+}
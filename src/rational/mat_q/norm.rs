@@ -0,0 +1,249 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module includes functionality to compute several norms
+//! defined on matrices.
+//! The norms defined on vectors can be found in the `vector` module.
+
+use super::MatQ;
+use crate::{
+    rational::Q,
+    traits::{GetEntry, GetNumColumns, GetNumRows},
+};
+use flint_sys::fmpq::{fmpq_abs, fmpq_addmul, fmpq_cmp};
+
+impl MatQ {
+    /// Returns the 1-norm of the matrix, i.e. the operator norm induced
+    /// by the vector 1-norm, which is the maximum absolute column sum.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{MatQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatQ::from_str("[[1/2,-2],[3,4]]").unwrap();
+    ///
+    /// let norm = mat.matrix_norm_1();
+    ///
+    /// // max{|1/2| + |3|, |-2| + |4|} = 6
+    /// assert_eq!(Q::from(6), norm);
+    /// ```
+    pub fn matrix_norm_1(&self) -> Q {
+        let mut max = Q::default();
+        for column in 0..self.get_num_columns() {
+            let mut sum = Q::default();
+            for row in 0..self.get_num_rows() {
+                let entry: Q = self.get_entry(row, column).unwrap();
+                sum = sum + entry.abs();
+            }
+            if sum > max {
+                max = sum;
+            }
+        }
+        max
+    }
+
+    /// Returns the ∞-norm of the matrix, i.e. the operator norm induced
+    /// by the vector ∞-norm, which is the maximum absolute row sum.
+    ///
+    /// For the ∞-norm of a vector, see [`MatQ::norm_infty`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{MatQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatQ::from_str("[[1/2,-2],[3,4]]").unwrap();
+    ///
+    /// let norm = mat.matrix_norm_infty();
+    ///
+    /// // max{|1/2| + |-2|, |3| + |4|} = 7
+    /// assert_eq!(Q::from(7), norm);
+    /// ```
+    pub fn matrix_norm_infty(&self) -> Q {
+        self.transpose().matrix_norm_1()
+    }
+
+    /// Returns the squared Frobenius norm of the matrix,
+    /// i.e. the sum of all squared entries.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{MatQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatQ::from_str("[[1/2,-2],[3,4]]").unwrap();
+    ///
+    /// let norm = mat.norm_frobenius_sqrd();
+    ///
+    /// // 1/2*1/2 + 2*2 + 3*3 + 4*4 = 117/4
+    /// assert_eq!(Q::from_str("117/4").unwrap(), norm);
+    /// ```
+    pub fn norm_frobenius_sqrd(&self) -> Q {
+        let entries = self.collect_entries();
+
+        // sum squared entries in result
+        let mut result = Q::default();
+        for entry in entries {
+            // sets result = result + entry * entry without cloned Q element
+            unsafe { fmpq_addmul(&mut result.value, &entry, &entry) }
+        }
+
+        result
+    }
+
+    /// Returns the maximum absolute value of all entries of the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{MatQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatQ::from_str("[[1,-11/2],[3,4]]").unwrap();
+    ///
+    /// let max = mat.max_abs_entry();
+    ///
+    /// assert_eq!(Q::from_str("11/2").unwrap(), max);
+    /// ```
+    pub fn max_abs_entry(&self) -> Q {
+        let entries = self.collect_entries();
+
+        let mut max = Q::default();
+        let mut abs = Q::default();
+        for entry in entries {
+            unsafe {
+                fmpq_abs(&mut abs.value, &entry);
+                if fmpq_cmp(&abs.value, &max.value) > 0 {
+                    std::mem::swap(&mut abs, &mut max);
+                }
+            }
+        }
+        max
+    }
+}
+
+#[cfg(test)]
+mod test_matrix_norm_1 {
+    use super::{MatQ, Q};
+    use std::str::FromStr;
+
+    /// Check whether the maximum absolute column sum is computed
+    #[test]
+    fn correct_norm() {
+        let mat = MatQ::from_str("[[1/2,-2,0],[-3,4,1],[5,0,-1/3]]").unwrap();
+
+        assert_eq!(Q::from_str("17/2").unwrap(), mat.matrix_norm_1());
+    }
+
+    /// Check whether large entries are summed up correctly
+    #[test]
+    fn large_entries() {
+        let mat =
+            MatQ::from_str(&format!("[[{},1],[{},1/{}]]", i64::MIN, i64::MAX, u64::MAX)).unwrap();
+
+        let cmp = Q::from(i64::MAX) + Q::from(i64::MAX) + Q::ONE;
+        assert_eq!(cmp, mat.matrix_norm_1());
+    }
+
+    /// Check whether the norm of the zero matrix is `0`
+    #[test]
+    fn zero() {
+        let mat = MatQ::new(2, 3).unwrap();
+
+        assert_eq!(Q::ZERO, mat.matrix_norm_1());
+    }
+}
+
+#[cfg(test)]
+mod test_matrix_norm_infty {
+    use super::{MatQ, Q};
+    use std::str::FromStr;
+
+    /// Check whether the maximum absolute row sum is computed
+    #[test]
+    fn correct_norm() {
+        let mat = MatQ::from_str("[[1/2,-2,0],[-3,4,1/4],[5,0,-1]]").unwrap();
+
+        assert_eq!(Q::from_str("29/4").unwrap(), mat.matrix_norm_infty());
+    }
+
+    /// Check whether the matrix norm of a row vector differs from its vector norm
+    #[test]
+    fn row_vector() {
+        let vec = MatQ::from_str("[[1/2,-2,3]]").unwrap();
+
+        assert_eq!(Q::from_str("11/2").unwrap(), vec.matrix_norm_infty());
+        assert_eq!(Q::from(3), vec.norm_infty().unwrap());
+    }
+}
+
+#[cfg(test)]
+mod test_norm_frobenius_sqrd {
+    use super::{MatQ, Q};
+    use std::str::FromStr;
+
+    /// Check whether all squared entries are summed up
+    #[test]
+    fn correct_norm() {
+        let mat = MatQ::from_str("[[1/2,-2,0],[-3,4,1/3]]").unwrap();
+
+        assert_eq!(Q::from_str("1057/36").unwrap(), mat.norm_frobenius_sqrd());
+    }
+
+    /// Check whether large entries are squared correctly
+    #[test]
+    fn large_entries() {
+        let mat = MatQ::from_str(&format!("[[{},0],[0,1/{}]]", i64::MIN, i64::MAX)).unwrap();
+
+        let min = Q::from(i64::MIN);
+        let max = Q::from_str(&format!("1/{}", i64::MAX)).unwrap();
+        assert_eq!(&min * &min + &max * &max, mat.norm_frobenius_sqrd());
+    }
+
+    /// Check whether the squared Frobenius norm of a vector
+    /// equals its squared Euclidean norm
+    #[test]
+    fn vector() {
+        let vec = MatQ::from_str("[[1/2],[-2],[3]]").unwrap();
+
+        assert_eq!(vec.norm_eucl_sqrd().unwrap(), vec.norm_frobenius_sqrd());
+    }
+}
+
+#[cfg(test)]
+mod test_max_abs_entry {
+    use super::{MatQ, Q};
+    use std::str::FromStr;
+
+    /// Check whether the largest absolute value is found for small entries
+    #[test]
+    fn small_entries() {
+        let positive = MatQ::from_str("[[1/2,3/4],[2,-1]]").unwrap();
+        let negative = MatQ::from_str("[[1/2,3/4],[-9/4,2]]").unwrap();
+
+        assert_eq!(Q::from(2), positive.max_abs_entry());
+        assert_eq!(Q::from_str("9/4").unwrap(), negative.max_abs_entry());
+    }
+
+    /// Check whether the largest absolute value is found for large entries
+    #[test]
+    fn large_entries() {
+        let mat =
+            MatQ::from_str(&format!("[[{},1/{}],[{},0]]", i64::MAX, u64::MAX, i64::MIN)).unwrap();
+
+        assert_eq!(Q::from(i64::MIN).abs(), mat.max_abs_entry());
+    }
+
+    /// Check whether the zero matrix has maximum absolute entry `0`
+    #[test]
+    fn zero() {
+        let mat = MatQ::new(2, 2).unwrap();
+
+        assert_eq!(Q::ZERO, mat.max_abs_entry());
+    }
+}
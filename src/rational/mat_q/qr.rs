@@ -0,0 +1,187 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the computation of an exact QR-like decomposition
+//! of a [`MatQ`] based on the Gram-Schmidt orthogonalization.
+
+use super::MatQ;
+use crate::{
+    rational::Q,
+    traits::{GetNumColumns, GetNumRows},
+};
+use flint_sys::{
+    fmpq::{fmpq_addmul, fmpq_div, fmpq_is_zero, fmpq_set, fmpq_submul, fmpq_zero},
+    fmpq_mat::fmpq_mat_entry,
+};
+
+impl MatQ {
+    /// Computes an exact QR-like decomposition of `self` over [`Q`] by
+    /// applying the Gram-Schmidt orthogonalization to the columns of `self`.
+    /// The result is a matrix `Q` with pairwise orthogonal, but not normalized
+    /// columns and an upper triangular matrix `R` with ones on its diagonal
+    /// s.t. `self = Q * R`.
+    ///
+    /// The columns of `Q` are the Gram-Schmidt vectors `b*_j` of the columns `b_j`
+    /// of `self` and the entry of `R` in row `i` and column `j > i` is the
+    /// Gram-Schmidt coefficient `<b_j, b*_i> / <b*_i, b*_i>`.
+    /// If the columns of `self` are linearly dependent, the corresponding
+    /// columns of `Q` are zero and their coefficients are set to `0`.
+    ///
+    /// Returns the tuple `(Q, R)`, where `Q` has the dimensions of `self`
+    /// and `R` is square with as many rows as `self` has columns.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatQ::from_str("[[1,1],[1,0]]").unwrap();
+    ///
+    /// let (q, r) = mat.qr();
+    ///
+    /// assert_eq!(mat, &q * &r);
+    /// assert_eq!(MatQ::from_str("[[1,1/2],[1,-1/2]]").unwrap(), q);
+    /// assert_eq!(MatQ::from_str("[[1,1/2],[0,1]]").unwrap(), r);
+    /// ```
+    pub fn qr(&self) -> (MatQ, MatQ) {
+        let num_rows = self.get_num_rows();
+        let num_columns = self.get_num_columns();
+        let orthogonal = self.clone();
+        let upper = MatQ::identity(num_columns, num_columns).unwrap();
+        let mut norms_sqrd = vec![Q::default(); num_columns as usize];
+        let mut inner_product = Q::default();
+        let mut factor = Q::default();
+
+        for column in 0..num_columns {
+            for previous in 0..column {
+                // linearly dependent columns yield a zero Gram-Schmidt vector
+                if unsafe { fmpq_is_zero(&norms_sqrd[previous as usize].value) } != 0 {
+                    continue;
+                }
+                unsafe {
+                    // the already subtracted parts of `column` are orthogonal to `previous`,
+                    // hence the coefficient equals the one of the original column
+                    fmpq_zero(&mut inner_product.value);
+                    for row in 0..num_rows {
+                        fmpq_addmul(
+                            &mut inner_product.value,
+                            fmpq_mat_entry(&orthogonal.matrix, row, column),
+                            fmpq_mat_entry(&orthogonal.matrix, row, previous),
+                        );
+                    }
+                    fmpq_div(
+                        &mut factor.value,
+                        &inner_product.value,
+                        &norms_sqrd[previous as usize].value,
+                    );
+                    fmpq_set(
+                        fmpq_mat_entry(&upper.matrix, previous, column),
+                        &factor.value,
+                    );
+                    for row in 0..num_rows {
+                        fmpq_submul(
+                            fmpq_mat_entry(&orthogonal.matrix, row, column),
+                            &factor.value,
+                            fmpq_mat_entry(&orthogonal.matrix, row, previous),
+                        );
+                    }
+                }
+            }
+
+            for row in 0..num_rows {
+                unsafe {
+                    fmpq_addmul(
+                        &mut norms_sqrd[column as usize].value,
+                        fmpq_mat_entry(&orthogonal.matrix, row, column),
+                        fmpq_mat_entry(&orthogonal.matrix, row, column),
+                    )
+                };
+            }
+        }
+
+        (orthogonal, upper)
+    }
+}
+
+#[cfg(test)]
+mod test_qr {
+    use super::MatQ;
+    use crate::{
+        rational::Q,
+        traits::{GetEntry, GetNumColumns},
+    };
+    use std::str::FromStr;
+
+    /// Checks whether `q` has pairwise orthogonal columns, `r` is upper triangular
+    /// with ones on its diagonal and `mat = q * r` holds
+    fn assert_decomposition(mat: &MatQ, q: &MatQ, r: &MatQ) {
+        assert_eq!(mat, &(q * r));
+
+        let gram = q.transpose().gram();
+        for row in 0..gram.get_num_columns() {
+            for column in 0..gram.get_num_columns() {
+                if row != column {
+                    assert_eq!(Q::ZERO, gram.get_entry(row, column).unwrap());
+                }
+            }
+        }
+
+        for row in 0..r.get_num_columns() {
+            assert_eq!(Q::ONE, r.get_entry(row, row).unwrap());
+            for column in 0..row {
+                assert_eq!(Q::ZERO, r.get_entry(row, column).unwrap());
+            }
+        }
+    }
+
+    /// Ensure that the decomposition of a square matrix is correct
+    #[test]
+    fn square() {
+        let mat = MatQ::from_str("[[1/2,2,-1],[3,0,4/3],[1,-5,2]]").unwrap();
+
+        let (q, r) = mat.qr();
+
+        assert_decomposition(&mat, &q, &r);
+    }
+
+    /// Ensure that the decomposition of non-square matrices is correct
+    #[test]
+    fn non_square() {
+        let tall = MatQ::from_str("[[1,2],[-3,1/4],[2,7]]").unwrap();
+        let wide = MatQ::from_str("[[1,2,-1/3],[-3,1/4,5]]").unwrap();
+
+        let (q_tall, r_tall) = tall.qr();
+        let (q_wide, r_wide) = wide.qr();
+
+        assert_decomposition(&tall, &q_tall, &r_tall);
+        assert_decomposition(&wide, &q_wide, &r_wide);
+    }
+
+    /// Ensure that linearly dependent columns yield zero columns in `Q`
+    #[test]
+    fn linearly_dependent() {
+        let mat = MatQ::from_str("[[1,2,0],[2,4,0]]").unwrap();
+
+        let (q, r) = mat.qr();
+
+        assert_decomposition(&mat, &q, &r);
+        assert_eq!(MatQ::from_str("[[1,0,0],[2,0,0]]").unwrap(), q);
+        assert_eq!(MatQ::from_str("[[1,2,0],[0,1,0],[0,0,1]]").unwrap(), r);
+    }
+
+    /// Ensure that large entries are handled exactly
+    #[test]
+    fn large_entries() {
+        let mat =
+            MatQ::from_str(&format!("[[{},1],[1/{},{}]]", i64::MAX, i64::MAX, i64::MIN)).unwrap();
+
+        let (q, r) = mat.qr();
+
+        assert_decomposition(&mat, &q, &r);
+    }
+}
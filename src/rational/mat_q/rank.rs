@@ -0,0 +1,85 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the `rank` function.
+
+use super::MatQ;
+use crate::traits::{GetNumColumns, GetNumRows};
+use flint_sys::fmpq_mat::fmpq_mat_rref;
+
+impl MatQ {
+    /// Returns the rank of the matrix, i.e. the maximal number of linearly
+    /// independent rows (or columns).
+    /// The rank is computed using the reduced row echelon form.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatQ::from_str("[[1/2,1,3/2],[2,4,6],[0,1,1]]").unwrap();
+    ///
+    /// assert_eq!(2, mat.rank());
+    /// ```
+    pub fn rank(&self) -> i64 {
+        self.rref().1
+    }
+
+    /// Computes the reduced row echelon form of the matrix.
+    ///
+    /// Returns the reduced row echelon form together with the rank of the matrix.
+    pub(crate) fn rref(&self) -> (MatQ, i64) {
+        let mut out = MatQ::new(self.get_num_rows(), self.get_num_columns()).unwrap();
+        let rank = unsafe { fmpq_mat_rref(&mut out.matrix, &self.matrix) };
+        (out, rank)
+    }
+}
+
+#[cfg(test)]
+mod test_rank {
+    use super::MatQ;
+    use std::str::FromStr;
+
+    /// Checks if matrices of full rank are correctly recognized
+    #[test]
+    fn full_rank() {
+        let square = MatQ::from_str("[[2/3,1],[1,2]]").unwrap();
+        let wide = MatQ::from_str("[[1,0,5/2],[0,1,7]]").unwrap();
+        let tall = MatQ::from_str("[[1,0],[0,1/3],[5,7]]").unwrap();
+
+        assert_eq!(2, square.rank());
+        assert_eq!(2, wide.rank());
+        assert_eq!(2, tall.rank());
+    }
+
+    /// Checks if linearly dependent rows reduce the rank
+    #[test]
+    fn dependent_rows() {
+        let mat = MatQ::from_str("[[1/7,2/7,3/7],[4,5,6],[7,8,9]]").unwrap();
+
+        assert_eq!(2, mat.rank());
+    }
+
+    /// Checks if the rank is correctly computed for large entries
+    #[test]
+    fn large_entries() {
+        let mat = MatQ::from_str(&format!("[[1/{},1],[1,{}]]", u64::MAX, i64::MIN)).unwrap();
+        let dependent = MatQ::from_str(&format!("[[1/{},1],[1,{}]]", u64::MAX, u64::MAX)).unwrap();
+
+        assert_eq!(2, mat.rank());
+        assert_eq!(1, dependent.rank());
+    }
+
+    /// Checks if the zero matrix has rank `0`
+    #[test]
+    fn zero() {
+        let mat = MatQ::new(3, 4).unwrap();
+
+        assert_eq!(0, mat.rank());
+    }
+}
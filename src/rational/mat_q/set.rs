@@ -10,8 +10,8 @@
 
 use super::MatQ;
 use crate::macros::for_others::implement_for_owned;
-use crate::traits::SetEntry;
-use crate::utils::index::evaluate_indices;
+use crate::traits::{GetNumColumns, GetNumRows, SetEntry};
+use crate::utils::index::{evaluate_index, evaluate_indices};
 use crate::{error::MathError, rational::Q};
 use flint_sys::{fmpq::fmpq_set, fmpq_mat::fmpq_mat_entry};
 use std::fmt::Display;
@@ -62,6 +62,135 @@ impl SetEntry<&Q> for MatQ {
 
 implement_for_owned!(Q, MatQ, SetEntry);
 
+impl MatQ {
+    /// Sets the specified row of the matrix to the given row vector.
+    ///
+    /// Parameters:
+    /// - `row`: specifies the row of the matrix that is set
+    /// - `row_vector`: the row vector with as many entries as `self` has columns
+    ///
+    /// Returns an empty `Ok` if the action could be performed successfully.
+    /// Otherwise, a [`MathError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mut matrix = MatQ::from_str("[[1/2,2,3],[3,4,5/3]]").unwrap();
+    /// let row_vector = MatQ::from_str("[[6,7/2,8]]").unwrap();
+    ///
+    /// matrix.set_row(1, &row_vector).unwrap();
+    ///
+    /// assert_eq!(row_vector, matrix.get_row(1).unwrap());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the number of the row is greater than the matrix or negative.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if `row_vector` is not a row vector with as many entries as `self` has columns.
+    pub fn set_row(
+        &mut self,
+        row: impl TryInto<i64> + Display + Copy,
+        row_vector: &Self,
+    ) -> Result<(), MathError> {
+        let row_i64 = evaluate_index(row)?;
+
+        if self.get_num_rows() <= row_i64 {
+            return Err(MathError::OutOfBounds(
+                format!("be smaller than {}", self.get_num_rows()),
+                format!("{}", row_i64),
+            ));
+        }
+        if row_vector.get_num_rows() != 1 || row_vector.get_num_columns() != self.get_num_columns()
+        {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to set a row of a '{}x{}' matrix to a '{}x{}' matrix.",
+                self.get_num_rows(),
+                self.get_num_columns(),
+                row_vector.get_num_rows(),
+                row_vector.get_num_columns()
+            )));
+        }
+
+        for column in 0..self.get_num_columns() {
+            unsafe {
+                fmpq_set(
+                    fmpq_mat_entry(&self.matrix, row_i64, column),
+                    fmpq_mat_entry(&row_vector.matrix, 0, column),
+                )
+            };
+        }
+        Ok(())
+    }
+
+    /// Sets the specified column of the matrix to the given column vector.
+    ///
+    /// Parameters:
+    /// - `column`: specifies the column of the matrix that is set
+    /// - `column_vector`: the column vector with as many entries as `self` has rows
+    ///
+    /// Returns an empty `Ok` if the action could be performed successfully.
+    /// Otherwise, a [`MathError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mut matrix = MatQ::from_str("[[1/2,2,3],[3,4,5/3]]").unwrap();
+    /// let column_vector = MatQ::from_str("[[9],[10/7]]").unwrap();
+    ///
+    /// matrix.set_column(2, &column_vector).unwrap();
+    ///
+    /// assert_eq!(column_vector, matrix.get_column(2).unwrap());
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds)
+    /// if the number of the column is greater than the matrix or negative.
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if `column_vector` is not a column vector with as many entries as `self` has rows.
+    pub fn set_column(
+        &mut self,
+        column: impl TryInto<i64> + Display + Copy,
+        column_vector: &Self,
+    ) -> Result<(), MathError> {
+        let column_i64 = evaluate_index(column)?;
+
+        if self.get_num_columns() <= column_i64 {
+            return Err(MathError::OutOfBounds(
+                format!("be smaller than {}", self.get_num_columns()),
+                format!("{}", column_i64),
+            ));
+        }
+        if column_vector.get_num_columns() != 1
+            || column_vector.get_num_rows() != self.get_num_rows()
+        {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to set a column of a '{}x{}' matrix to a '{}x{}' matrix.",
+                self.get_num_rows(),
+                self.get_num_columns(),
+                column_vector.get_num_rows(),
+                column_vector.get_num_columns()
+            )));
+        }
+
+        for row in 0..self.get_num_rows() {
+            unsafe {
+                fmpq_set(
+                    fmpq_mat_entry(&self.matrix, row, column_i64),
+                    fmpq_mat_entry(&column_vector.matrix, row, 0),
+                )
+            };
+        }
+        Ok(())
+    }
+}
+
 // TODO add implementation for other types as well
 
 #[cfg(test)]
@@ -181,3 +310,56 @@ mod test_setter {
         assert!(matrix.get_entry(1, 100).is_err());
     }
 }
+
+#[cfg(test)]
+mod test_set_vec {
+    use crate::{error::MathError, rational::MatQ};
+    use std::str::FromStr;
+
+    /// Ensure that a row is correctly set
+    #[test]
+    fn set_row_works() {
+        let mut matrix = MatQ::new(3, 2).unwrap();
+        let row = MatQ::from_str(&format!("[[1/{},{}]]", i64::MAX, i64::MIN)).unwrap();
+
+        matrix.set_row(2, &row).unwrap();
+
+        let cmp = MatQ::from_str(&format!("[[0,0],[0,0],[1/{},{}]]", i64::MAX, i64::MIN)).unwrap();
+        assert_eq!(cmp, matrix);
+    }
+
+    /// Ensure that a column is correctly set
+    #[test]
+    fn set_column_works() {
+        let mut matrix = MatQ::from_str("[[1,2],[3,4]]").unwrap();
+        let column = MatQ::from_str("[[-1/2],[7/3]]").unwrap();
+
+        matrix.set_column(1, &column).unwrap();
+
+        assert_eq!(MatQ::from_str("[[1,-1/2],[3,7/3]]").unwrap(), matrix);
+    }
+
+    /// Ensure that vectors of wrong dimensions are rejected
+    #[test]
+    fn wrong_dimensions() {
+        let mut matrix = MatQ::new(2, 3).unwrap();
+
+        assert!(matches!(
+            matrix.set_row(0, &MatQ::new(1, 2).unwrap()),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+        assert!(matches!(
+            matrix.set_column(0, &MatQ::new(3, 1).unwrap()),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+
+    /// Ensure that indices out of bounds are rejected
+    #[test]
+    fn out_of_bounds() {
+        let mut matrix = MatQ::new(2, 3).unwrap();
+
+        assert!(matrix.set_row(2, &MatQ::new(1, 3).unwrap()).is_err());
+        assert!(matrix.set_column(-1, &MatQ::new(2, 1).unwrap()).is_err());
+    }
+}
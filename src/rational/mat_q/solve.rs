@@ -0,0 +1,195 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains functions to solve systems of linear equations
+//! given by a [`MatQ`] exactly over [`Q`](crate::rational::Q).
+
+use super::MatQ;
+use crate::{
+    error::MathError,
+    traits::{Concatenate, GetNumColumns, GetNumRows},
+};
+use flint_sys::{
+    fmpq::{fmpq_is_zero, fmpq_set},
+    fmpq_mat::{fmpq_mat_entry, fmpq_mat_rref, fmpq_mat_solve},
+};
+
+impl MatQ {
+    /// Computes a solution `x` of the system `self * x = b`.
+    /// If the system has several solutions, all free variables are set to `0`.
+    /// If `b` has several columns, each column of `x` solves the system
+    /// for the corresponding column of `b`.
+    ///
+    /// Regular systems are solved using fraction-free or Dixon's p-adic
+    /// solving, all other systems using the reduced row echelon form.
+    ///
+    /// Parameters:
+    /// - `b`: the right-hand side of the system
+    ///
+    /// Returns a matrix `x` with `self * x = b` or an error if the system
+    /// has no solution or the dimensions do not match.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::MatQ;
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatQ::from_str("[[1/2,0],[1,4]]").unwrap();
+    /// let b = MatQ::from_str("[[1],[1/3]]").unwrap();
+    ///
+    /// let x = mat.solve(&b).unwrap();
+    ///
+    /// assert_eq!(MatQ::from_str("[[2],[-5/12]]").unwrap(), x);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the number of rows of `self` and `b` differ.
+    /// - Returns a [`MathError`] of type [`NoSolution`](MathError::NoSolution)
+    /// if the system has no solution.
+    pub fn solve(&self, b: &MatQ) -> Result<MatQ, MathError> {
+        if self.get_num_rows() != b.get_num_rows() {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "Tried to solve a system with a '{}x{}' matrix and a '{}x{}' right-hand side.",
+                self.get_num_rows(),
+                self.get_num_columns(),
+                b.get_num_rows(),
+                b.get_num_columns()
+            )));
+        }
+        let num_columns = self.get_num_columns();
+
+        // `fmpq_mat_solve` returns `0` if `self` is singular and aborts if it is not square
+        if self.get_num_rows() == num_columns {
+            let mut out = MatQ::new(num_columns, b.get_num_columns()).unwrap();
+            if 0 != unsafe { fmpq_mat_solve(&mut out.matrix, &self.matrix, &b.matrix) } {
+                return Ok(out);
+            }
+        }
+
+        let augmented = self.concat_horizontal(b)?;
+        let mut rref = MatQ::new(augmented.get_num_rows(), augmented.get_num_columns()).unwrap();
+        let rank = unsafe { fmpq_mat_rref(&mut rref.matrix, &augmented.matrix) };
+
+        // every non-zero row of the reduced row echelon form determines
+        // the variable at its pivot, all other variables are free
+        let out = MatQ::new(num_columns, b.get_num_columns()).unwrap();
+        for row in 0..rank {
+            let pivot = (0..augmented.get_num_columns())
+                .find(|column| unsafe {
+                    fmpq_is_zero(fmpq_mat_entry(&rref.matrix, row, *column)) == 0
+                })
+                .unwrap();
+            if pivot >= num_columns {
+                return Err(MathError::NoSolution(format!(
+                    "The system given by {} and {} is inconsistent.",
+                    self, b
+                )));
+            }
+            for column in 0..b.get_num_columns() {
+                unsafe {
+                    fmpq_set(
+                        fmpq_mat_entry(&out.matrix, pivot, column),
+                        fmpq_mat_entry(&rref.matrix, row, num_columns + column),
+                    )
+                };
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_solve {
+    use super::MatQ;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Checks if a regular system is correctly solved
+    #[test]
+    fn regular() {
+        let mat = MatQ::from_str("[[2/3,0],[0,4]]").unwrap();
+        let b = MatQ::from_str("[[1],[-1/2]]").unwrap();
+
+        let x = mat.solve(&b).unwrap();
+
+        assert_eq!(MatQ::from_str("[[3/2],[-1/8]]").unwrap(), x);
+    }
+
+    /// Checks if free variables are set to `0`
+    #[test]
+    fn underdetermined() {
+        let mat = MatQ::from_str("[[1,2,3],[0,0,1/2]]").unwrap();
+        let b = MatQ::from_str("[[6],[1/4]]").unwrap();
+
+        let x = mat.solve(&b).unwrap();
+
+        assert_eq!(MatQ::from_str("[[9/2],[0],[1/2]]").unwrap(), x);
+    }
+
+    /// Checks if singular but consistent square systems are solved
+    #[test]
+    fn singular() {
+        let mat = MatQ::from_str("[[1,1],[2,2]]").unwrap();
+        let b = MatQ::from_str("[[1/2],[1]]").unwrap();
+
+        let x = mat.solve(&b).unwrap();
+
+        assert_eq!(MatQ::from_str("[[1/2],[0]]").unwrap(), x);
+    }
+
+    /// Checks if each column of the right-hand side is solved
+    #[test]
+    fn several_columns() {
+        let mat = MatQ::from_str("[[1,1],[1,-1]]").unwrap();
+        let b = MatQ::from_str("[[2,3],[0,1]]").unwrap();
+
+        let x = mat.solve(&b).unwrap();
+
+        assert_eq!(MatQ::from_str("[[1,2],[1,1]]").unwrap(), x);
+    }
+
+    /// Checks if large entries are correctly handled
+    #[test]
+    fn large_entries() {
+        let mat = MatQ::from_str(&format!("[[{},0],[0,1/{}]]", i64::MAX, u64::MAX)).unwrap();
+        let b = MatQ::from_str(&format!("[[1],[{}]]", i64::MIN)).unwrap();
+
+        let x = mat.solve(&b).unwrap();
+
+        let cmp = MatQ::from_str(&format!(
+            "[[1/{}],[{}]]",
+            i64::MAX,
+            i64::MIN as i128 * u64::MAX as i128
+        ))
+        .unwrap();
+        assert_eq!(cmp, x);
+    }
+
+    /// Checks if an error is returned for inconsistent systems
+    #[test]
+    fn inconsistent() {
+        let mat = MatQ::from_str("[[1,1],[2,2],[0,1]]").unwrap();
+        let b = MatQ::from_str("[[1],[3],[0]]").unwrap();
+
+        assert!(matches!(mat.solve(&b), Err(MathError::NoSolution(_))));
+    }
+
+    /// Checks if an error is returned for mismatching dimensions
+    #[test]
+    fn mismatching_dimensions() {
+        let mat = MatQ::from_str("[[1,1],[2,2]]").unwrap();
+        let b = MatQ::new(3, 1).unwrap();
+
+        assert!(matches!(
+            mat.solve(&b),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
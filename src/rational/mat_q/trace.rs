@@ -0,0 +1,88 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the `trace` function.
+
+use super::MatQ;
+use crate::{
+    error::MathError,
+    rational::Q,
+    traits::{GetNumColumns, GetNumRows},
+};
+use flint_sys::fmpq_mat::fmpq_mat_trace;
+
+impl MatQ {
+    /// Returns the trace of a square matrix, i.e. the sum of its diagonal entries.
+    ///
+    /// Returns the trace of the matrix or an error if the matrix is not square.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{MatQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let mat = MatQ::from_str("[[1/2,2],[3,1/3]]").unwrap();
+    ///
+    /// let trace = mat.trace().unwrap();
+    ///
+    /// assert_eq!(Q::from_str("5/6").unwrap(), trace);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`MismatchingMatrixDimension`](MathError::MismatchingMatrixDimension)
+    /// if the matrix is not square.
+    pub fn trace(&self) -> Result<Q, MathError> {
+        if self.get_num_rows() != self.get_num_columns() {
+            return Err(MathError::MismatchingMatrixDimension(format!(
+                "The trace is only defined for square matrices, but the matrix is '{}x{}'.",
+                self.get_num_rows(),
+                self.get_num_columns()
+            )));
+        }
+
+        let mut out = Q::default();
+        unsafe { fmpq_mat_trace(&mut out.value, &self.matrix) };
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test_trace {
+    use super::MatQ;
+    use crate::{error::MathError, rational::Q};
+    use std::str::FromStr;
+
+    /// Checks if the trace is correctly computed
+    #[test]
+    fn correct_trace() {
+        let mat = MatQ::from_str("[[1/4,2,3],[4,-1/2,6],[7,8,3/4]]").unwrap();
+
+        assert_eq!(Q::from_str("1/2").unwrap(), mat.trace().unwrap());
+    }
+
+    /// Checks if the trace is correctly computed for large entries
+    #[test]
+    fn large_entries() {
+        let mat = MatQ::from_str(&format!("[[1/{},1],[1,{}]]", u64::MAX, i64::MIN)).unwrap();
+
+        let cmp = Q::from_str(&format!("1/{}", u64::MAX)).unwrap() + Q::from(i64::MIN);
+        assert_eq!(cmp, mat.trace().unwrap());
+    }
+
+    /// Checks if an error is returned for non-square matrices
+    #[test]
+    fn non_square() {
+        let mat = MatQ::new(3, 2).unwrap();
+
+        assert!(matches!(
+            mat.trace(),
+            Err(MathError::MismatchingMatrixDimension(_))
+        ));
+    }
+}
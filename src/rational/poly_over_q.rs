@@ -15,10 +15,13 @@ use flint_sys::fmpq_poly::fmpq_poly_struct;
 mod arithmetic;
 mod cmp;
 mod default;
+mod derivative;
 mod evaluate;
 mod exp;
+mod factor;
 mod from;
 mod get;
+mod interpolate;
 mod ownership;
 mod serialize;
 mod set;
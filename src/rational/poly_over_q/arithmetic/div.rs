@@ -0,0 +1,201 @@
+// Copyright © 2023 Phil Milewski
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementation of the [`Div`] trait for [`PolyOverQ`] values
+//! and of the Euclidean division with remainder.
+
+use super::super::PolyOverQ;
+use crate::{
+    error::MathError,
+    macros::arithmetics::{
+        arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
+    },
+};
+use flint_sys::fmpq_poly::{fmpq_poly_divrem, fmpq_poly_is_zero};
+use std::ops::Div;
+
+impl Div for &PolyOverQ {
+    type Output = PolyOverQ;
+    /// Implements the [`Div`] trait for two [`PolyOverQ`] values.
+    /// [`Div`] is implemented for any combination of [`PolyOverQ`] and borrowed [`PolyOverQ`].
+    /// The quotient of [`PolyOverQ::div_rem`] is returned.
+    ///
+    /// Parameters:
+    /// - `other`: specifies the polynomial `self` is divided by
+    ///
+    /// Returns the quotient of both polynomials as a [`PolyOverQ`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::PolyOverQ;
+    /// use std::str::FromStr;
+    ///
+    /// let a: PolyOverQ = PolyOverQ::from_str("3  -1 0 1/2").unwrap();
+    /// let b: PolyOverQ = PolyOverQ::from_str("2  1/3 -5").unwrap();
+    ///
+    /// let c: PolyOverQ = &a / &b;
+    /// let d: PolyOverQ = a / b;
+    /// let e: PolyOverQ = &c / d;
+    /// let f: PolyOverQ = c / &e;
+    /// ```
+    ///
+    /// # Panics
+    /// - Panics if `other` is the zero polynomial.
+    fn div(self, other: Self) -> Self::Output {
+        self.div_rem(other).unwrap().0
+    }
+}
+
+arithmetic_trait_borrowed_to_owned!(Div, div, PolyOverQ, PolyOverQ, PolyOverQ);
+arithmetic_trait_mixed_borrowed_owned!(Div, div, PolyOverQ, PolyOverQ, PolyOverQ);
+
+impl PolyOverQ {
+    /// Divides `self` by `divisor` with remainder, i.e. computes `q` and `r`
+    /// s.t. `self = q * divisor + r` and the degree of `r` is smaller
+    /// than the degree of `divisor`.
+    ///
+    /// Parameters:
+    /// - `divisor`: specifies the polynomial `self` is divided by
+    ///
+    /// Returns the quotient and the remainder as a tuple of [`PolyOverQ`]
+    /// or an error if `divisor` is the zero polynomial.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::PolyOverQ;
+    /// use std::str::FromStr;
+    ///
+    /// let a = PolyOverQ::from_str("3  1 0 1").unwrap();
+    /// let b = PolyOverQ::from_str("2  1 2").unwrap();
+    ///
+    /// let (quotient, remainder) = a.div_rem(&b).unwrap();
+    ///
+    /// assert_eq!(PolyOverQ::from_str("2  -1/4 1/2").unwrap(), quotient);
+    /// assert_eq!(PolyOverQ::from_str("1  5/4").unwrap(), remainder);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`DivisionByZeroError`](MathError::DivisionByZeroError)
+    /// if `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> Result<(Self, Self), MathError> {
+        if 1 == unsafe { fmpq_poly_is_zero(&divisor.poly) } {
+            return Err(MathError::DivisionByZeroError(format!(
+                "tried to divide {} by zero",
+                self
+            )));
+        }
+
+        let mut quotient = PolyOverQ::default();
+        let mut remainder = PolyOverQ::default();
+        unsafe {
+            fmpq_poly_divrem(
+                &mut quotient.poly,
+                &mut remainder.poly,
+                &self.poly,
+                &divisor.poly,
+            )
+        };
+        Ok((quotient, remainder))
+    }
+}
+
+#[cfg(test)]
+mod test_div {
+    use super::PolyOverQ;
+    use std::str::FromStr;
+
+    /// testing division for two [`PolyOverQ`]
+    #[test]
+    fn div() {
+        let a: PolyOverQ = PolyOverQ::from_str("3  -1/4 0 1").unwrap();
+        let b: PolyOverQ = PolyOverQ::from_str("2  1 2").unwrap();
+        let c: PolyOverQ = a / b;
+        assert_eq!(c, PolyOverQ::from_str("2  -1/4 1/2").unwrap());
+    }
+
+    /// testing division for two borrowed [`PolyOverQ`]
+    #[test]
+    fn div_borrow() {
+        let a: PolyOverQ = PolyOverQ::from_str("3  -1/4 0 1").unwrap();
+        let b: PolyOverQ = PolyOverQ::from_str("2  1 2").unwrap();
+        let c: PolyOverQ = &a / &b;
+        assert_eq!(c, PolyOverQ::from_str("2  -1/4 1/2").unwrap());
+    }
+
+    /// testing division for borrowed and owned [`PolyOverQ`] in both orders
+    #[test]
+    fn div_mixed_borrowed() {
+        let a: PolyOverQ = PolyOverQ::from_str("3  -1/4 0 1").unwrap();
+        let b: PolyOverQ = PolyOverQ::from_str("2  1 2").unwrap();
+        let c: PolyOverQ = &a / b.clone();
+        let d: PolyOverQ = a / &b;
+        assert_eq!(c, PolyOverQ::from_str("2  -1/4 1/2").unwrap());
+        assert_eq!(c, d);
+    }
+
+    /// testing division with large numerators and denominators
+    #[test]
+    fn div_large_numbers() {
+        let a: PolyOverQ =
+            PolyOverQ::from_str(&format!("3  {} 0 1/{}", i64::MIN, u64::MAX)).unwrap();
+        let b: PolyOverQ =
+            PolyOverQ::from_str(&format!("2  {}/7 -1/{}", u64::MAX, i64::MAX)).unwrap();
+        let c: PolyOverQ = &a * &b;
+        assert_eq!(a, c / b);
+    }
+
+    /// testing whether division by the zero polynomial panics
+    #[test]
+    #[should_panic]
+    fn div_by_zero() {
+        let a: PolyOverQ = PolyOverQ::from_str("3  -1 0 1").unwrap();
+        let _ = a / PolyOverQ::default();
+    }
+}
+
+#[cfg(test)]
+mod test_div_rem {
+    use super::PolyOverQ;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that `self = q * divisor + r` holds for large numerators and denominators
+    #[test]
+    fn large_numbers() {
+        let a = PolyOverQ::from_str(&format!("5  {} 3 -7/2 0 1/{}", u64::MAX, i64::MIN)).unwrap();
+        let b = PolyOverQ::from_str(&format!("3  5 -2/{} 3", u64::MAX)).unwrap();
+
+        let (quotient, remainder) = a.div_rem(&b).unwrap();
+
+        assert_eq!(a, &quotient * &b + &remainder);
+        assert_eq!(PolyOverQ::default(), remainder.div_rem(&b).unwrap().0);
+    }
+
+    /// Ensure that constant divisors yield a zero remainder
+    #[test]
+    fn constant_divisor() {
+        let a = PolyOverQ::from_str("3  3 -5 2").unwrap();
+        let b = PolyOverQ::from_str("1  -2/3").unwrap();
+
+        let (quotient, remainder) = a.div_rem(&b).unwrap();
+
+        assert_eq!(PolyOverQ::from_str("3  -9/2 15/2 -3").unwrap(), quotient);
+        assert_eq!(PolyOverQ::default(), remainder);
+    }
+
+    /// Ensure that an error is returned for the zero polynomial as divisor
+    #[test]
+    fn division_by_zero() {
+        let a = PolyOverQ::from_str("2  1 3").unwrap();
+
+        assert!(matches!(
+            a.div_rem(&PolyOverQ::default()),
+            Err(MathError::DivisionByZeroError(_))
+        ));
+    }
+}
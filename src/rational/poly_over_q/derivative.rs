@@ -0,0 +1,120 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the formal derivative
+//! and the formal antiderivative of a [`PolyOverQ`].
+
+use super::PolyOverQ;
+use flint_sys::fmpq_poly::{fmpq_poly_derivative, fmpq_poly_integral};
+
+impl PolyOverQ {
+    /// Computes the formal derivative of `self`, i.e. for
+    /// `a_0 + a_1 X + ... + a_n X^n` the polynomial
+    /// `a_1 + 2 a_2 X + ... + n a_n X^(n-1)` is returned.
+    ///
+    /// Returns the formal derivative of `self` as a [`PolyOverQ`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::PolyOverQ;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverQ::from_str("4  -3 1/2 1 2/3").unwrap();
+    ///
+    /// let derivative = poly.derivative();
+    ///
+    /// assert_eq!(PolyOverQ::from_str("3  1/2 2 2").unwrap(), derivative);
+    /// ```
+    pub fn derivative(&self) -> Self {
+        let mut out = PolyOverQ::default();
+        unsafe { fmpq_poly_derivative(&mut out.poly, &self.poly) };
+        out
+    }
+
+    /// Computes the formal antiderivative of `self` with constant term `0`, i.e. for
+    /// `a_0 + a_1 X + ... + a_n X^n` the polynomial
+    /// `a_0 X + a_1/2 X^2 + ... + a_n/(n+1) X^(n+1)` is returned.
+    ///
+    /// Returns the formal antiderivative of `self` as a [`PolyOverQ`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::PolyOverQ;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverQ::from_str("3  1/2 2 2").unwrap();
+    ///
+    /// let integral = poly.integral();
+    ///
+    /// assert_eq!(PolyOverQ::from_str("4  0 1/2 1 2/3").unwrap(), integral);
+    /// ```
+    pub fn integral(&self) -> Self {
+        let mut out = PolyOverQ::default();
+        unsafe { fmpq_poly_integral(&mut out.poly, &self.poly) };
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_derivative {
+    use super::PolyOverQ;
+    use std::str::FromStr;
+
+    /// Ensure that the derivative is computed correctly for large
+    /// numerators and denominators
+    #[test]
+    fn large_numbers() {
+        let poly = PolyOverQ::from_str(&format!("3  1 {}/3 1/{}", u64::MAX, u64::MAX)).unwrap();
+
+        assert_eq!(
+            PolyOverQ::from_str(&format!("2  {}/3 2/{}", u64::MAX, u64::MAX)).unwrap(),
+            poly.derivative()
+        );
+    }
+
+    /// Ensure that the derivative of constant polynomials is zero
+    #[test]
+    fn constant() {
+        let poly = PolyOverQ::from_str("1  42/17").unwrap();
+
+        assert_eq!(PolyOverQ::default(), poly.derivative());
+        assert_eq!(PolyOverQ::default(), PolyOverQ::default().derivative());
+    }
+}
+
+#[cfg(test)]
+mod test_integral {
+    use super::PolyOverQ;
+    use std::str::FromStr;
+
+    /// Ensure that the antiderivative is computed correctly for large
+    /// numerators and denominators and is reverted by the derivative
+    #[test]
+    fn large_numbers() {
+        let poly = PolyOverQ::from_str(&format!("3  {} -1/{} 5/7", i64::MIN, u64::MAX)).unwrap();
+
+        let integral = poly.integral();
+
+        assert_eq!(
+            PolyOverQ::from_str(&format!(
+                "4  0 {} -1/{} 5/21",
+                i64::MIN,
+                2_u128 * u64::MAX as u128
+            ))
+            .unwrap(),
+            integral
+        );
+        assert_eq!(poly, integral.derivative());
+    }
+
+    /// Ensure that the antiderivative of the zero polynomial is zero
+    #[test]
+    fn zero() {
+        assert_eq!(PolyOverQ::default(), PolyOverQ::default().integral());
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the squarefree factorization of a [`PolyOverQ`].
+
+use super::PolyOverQ;
+use crate::{integer::PolyOverZ, rational::Q};
+use flint_sys::{
+    fmpq_poly::{fmpq_poly_get_coeff_fmpq, fmpq_poly_make_monic, fmpq_poly_set_fmpz_poly},
+    fmpz_poly_factor::{
+        fmpz_poly_factor_clear, fmpz_poly_factor_get_fmpz_poly, fmpz_poly_factor_init,
+        fmpz_poly_factor_squarefree, fmpz_poly_factor_struct,
+    },
+};
+use std::mem::MaybeUninit;
+
+impl PolyOverQ {
+    /// Computes the squarefree factorization of `self`, i.e. computes the leading
+    /// coefficient `c` and pairwise coprime, squarefree and monic polynomials
+    /// `f_1, ..., f_k` with pairwise distinct multiplicities `e_1, ..., e_k`
+    /// s.t. `self = c * f_1^e_1 * ... * f_k^e_k`.
+    ///
+    /// Returns the leading coefficient and a vector of all squarefree factors
+    /// together with their multiplicity. For the zero polynomial, the leading
+    /// coefficient is `0` and the vector is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{PolyOverQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// // 2 * (X - 1) * (X + 1/2)^2
+    /// let poly = PolyOverQ::from_str("4  -1/2 -3/2 0 2").unwrap();
+    ///
+    /// let (leading_coefficient, factors) = poly.squarefree_factorization();
+    ///
+    /// assert_eq!(Q::from(2), leading_coefficient);
+    /// assert!(factors.contains(&(PolyOverQ::from_str("2  -1 1").unwrap(), 1)));
+    /// assert!(factors.contains(&(PolyOverQ::from_str("2  1/2 1").unwrap(), 2)));
+    /// ```
+    pub fn squarefree_factorization(&self) -> (Q, Vec<(PolyOverQ, u64)>) {
+        let degree = self.get_degree();
+        if degree < 0 {
+            return (Q::ZERO, vec![]);
+        }
+
+        let mut leading_coefficient = Q::default();
+        unsafe { fmpq_poly_get_coeff_fmpq(&mut leading_coefficient.value, &self.poly, degree) };
+        let (numerator, _) = self.to_integer_poly();
+
+        let mut factors = MaybeUninit::<fmpz_poly_factor_struct>::uninit();
+        unsafe {
+            fmpz_poly_factor_init(factors.as_mut_ptr());
+            let mut factors = factors.assume_init();
+            fmpz_poly_factor_squarefree(&mut factors, &numerator.poly);
+
+            let out = (0..factors.num)
+                .map(|i| {
+                    let mut factor_z = PolyOverZ::default();
+                    fmpz_poly_factor_get_fmpz_poly(&mut factor_z.poly, &factors, i);
+                    let mut factor = PolyOverQ::default();
+                    fmpq_poly_set_fmpz_poly(&mut factor.poly, &factor_z.poly);
+                    fmpq_poly_make_monic(&mut factor.poly, &factor.poly);
+                    (factor, *factors.exp.add(i as usize) as u64)
+                })
+                .collect();
+
+            fmpz_poly_factor_clear(&mut factors);
+            (leading_coefficient, out)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_squarefree_factorization {
+    use super::{PolyOverQ, Q};
+    use std::str::FromStr;
+
+    /// Multiplies the leading coefficient and all factors with their multiplicities.
+    fn product(leading_coefficient: &Q, factors: &[(PolyOverQ, u64)]) -> PolyOverQ {
+        let mut out = PolyOverQ::from_str(&format!("1  {}", leading_coefficient)).unwrap();
+        for (factor, exponent) in factors {
+            for _ in 0..*exponent {
+                out = out * factor;
+            }
+        }
+        out
+    }
+
+    /// Ensure that the product of the factorization is the polynomial,
+    /// the factors are squarefree and the multiplicities are distinct
+    #[test]
+    fn product_of_factors() {
+        let factor_1 = PolyOverQ::from_str(&format!("2  1/{} 1", u64::MAX)).unwrap();
+        let factor_2 = PolyOverQ::from_str("3  -2/3 0 1").unwrap();
+        let factor_3 = PolyOverQ::from_str("2  5 1").unwrap();
+        let poly = PolyOverQ::from_str("1  -7/3").unwrap()
+            * &factor_1
+            * &factor_1
+            * &factor_1
+            * &factor_2
+            * &factor_3;
+
+        let (leading_coefficient, factors) = poly.squarefree_factorization();
+
+        assert_eq!(Q::from_str("-7/3").unwrap(), leading_coefficient);
+        assert_eq!(poly, product(&leading_coefficient, &factors));
+        assert_eq!(vec![(&factor_2 * &factor_3, 1), (factor_1, 3)], factors);
+    }
+
+    /// Ensure that squarefree polynomials are not split
+    #[test]
+    fn squarefree() {
+        let poly = PolyOverQ::from_str("3  -1 0 4").unwrap();
+
+        let (leading_coefficient, factors) = poly.squarefree_factorization();
+
+        assert_eq!(Q::from(4), leading_coefficient);
+        assert_eq!(
+            vec![(PolyOverQ::from_str("3  -1/4 0 1").unwrap(), 1)],
+            factors
+        );
+    }
+
+    /// Ensure that constant polynomials only have a leading coefficient
+    #[test]
+    fn constant() {
+        let (leading_coefficient, factors) = PolyOverQ::from_str("1  -12/5")
+            .unwrap()
+            .squarefree_factorization();
+        let (zero_coefficient, zero_factors) = PolyOverQ::default().squarefree_factorization();
+
+        assert_eq!(Q::from_str("-12/5").unwrap(), leading_coefficient);
+        assert!(factors.is_empty());
+        assert_eq!(Q::ZERO, zero_coefficient);
+        assert!(zero_factors.is_empty());
+    }
+}
@@ -13,10 +13,68 @@
 //! The explicit functions contain the documentation.
 
 use super::PolyOverQ;
-use crate::error::MathError;
-use flint_sys::fmpq_poly::{fmpq_poly_canonicalise, fmpq_poly_set_str};
+use crate::{
+    error::MathError,
+    integer::{PolyOverZ, Z},
+};
+use flint_sys::fmpq_poly::{
+    fmpq_poly_canonicalise, fmpq_poly_scalar_div_fmpz, fmpq_poly_set_fmpz_poly, fmpq_poly_set_str,
+};
 use std::{ffi::CString, str::FromStr};
 
+impl PolyOverQ {
+    /// Create a [`PolyOverQ`] from an integer polynomial and a common denominator,
+    /// i.e. computes `numerator / denominator`.
+    /// This is the inverse operation of [`PolyOverQ::to_integer_poly`].
+    ///
+    /// Parameters:
+    /// - `numerator`: the integer polynomial
+    /// - `denominator`: the common denominator of all coefficients
+    ///
+    /// Returns a [`PolyOverQ`] or an error, if `denominator` is `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use qfall_math::rational::PolyOverQ;
+    /// use std::str::FromStr;
+    ///
+    /// let numerator = PolyOverZ::from_str("3  2 0 -3").unwrap();
+    ///
+    /// let poly = PolyOverQ::from_integer_poly(&numerator, &Z::from(6)).unwrap();
+    ///
+    /// assert_eq!(PolyOverQ::from_str("3  1/3 0 -1/2").unwrap(), poly);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`DivisionByZeroError`](MathError::DivisionByZeroError)
+    /// if `denominator` is `0`.
+    pub fn from_integer_poly(numerator: &PolyOverZ, denominator: &Z) -> Result<Self, MathError> {
+        if denominator == &Z::ZERO {
+            return Err(MathError::DivisionByZeroError(format!(
+                "tried to divide {} by zero",
+                numerator
+            )));
+        }
+
+        let mut res = Self::default();
+        unsafe {
+            fmpq_poly_set_fmpz_poly(&mut res.poly, &numerator.poly);
+            fmpq_poly_scalar_div_fmpz(&mut res.poly, &res.poly, &denominator.value);
+        }
+        Ok(res)
+    }
+}
+
+impl TryFrom<(&PolyOverZ, &Z)> for PolyOverQ {
+    type Error = MathError;
+    /// Create a [`PolyOverQ`] from an integer polynomial and a common denominator
+    /// using [`from_integer_poly`](PolyOverQ::from_integer_poly)
+    fn try_from(value: (&PolyOverZ, &Z)) -> Result<Self, Self::Error> {
+        PolyOverQ::from_integer_poly(value.0, value.1)
+    }
+}
+
 impl FromStr for PolyOverQ {
     type Err = MathError;
 
@@ -72,6 +130,44 @@ impl FromStr for PolyOverQ {
     }
 }
 
+#[cfg(test)]
+mod test_from_integer_poly {
+    use super::PolyOverQ;
+    use crate::{
+        error::MathError,
+        integer::{PolyOverZ, Z},
+    };
+    use std::str::FromStr;
+
+    /// Ensure that the coefficients are reduced and negative denominators are handled
+    #[test]
+    fn reduced() {
+        let numerator = PolyOverZ::from_str(&format!("3  {} 0 -4", u64::MAX)).unwrap();
+
+        let poly = PolyOverQ::from_integer_poly(&numerator, &Z::from(-6)).unwrap();
+
+        assert_eq!(
+            PolyOverQ::from_str(&format!("3  -{}/2 0 2/3", u64::MAX / 3)).unwrap(),
+            poly
+        );
+        assert_eq!(
+            poly,
+            PolyOverQ::try_from((&numerator, &Z::from(-6))).unwrap()
+        );
+    }
+
+    /// Ensure that an error is returned if the denominator is zero
+    #[test]
+    fn zero_denominator() {
+        let numerator = PolyOverZ::from_str("2  1 2").unwrap();
+
+        assert!(matches!(
+            PolyOverQ::from_integer_poly(&numerator, &Z::ZERO),
+            Err(MathError::DivisionByZeroError(_))
+        ));
+    }
+}
+
 #[cfg(test)]
 mod test_from_str {
     use super::PolyOverQ;
@@ -10,10 +10,68 @@
 //! Each reasonable type should be allowed as a index.
 
 use super::PolyOverQ;
-use crate::{error::MathError, rational::Q, traits::GetCoefficient, utils::index::evaluate_index};
-use flint_sys::fmpq_poly::fmpq_poly_get_coeff_fmpq;
+use crate::{
+    error::MathError,
+    integer::{PolyOverZ, Z},
+    rational::Q,
+    traits::GetCoefficient,
+    utils::index::evaluate_index,
+};
+use flint_sys::fmpq_poly::{
+    fmpq_poly_degree, fmpq_poly_get_coeff_fmpq, fmpq_poly_get_denominator, fmpq_poly_get_numerator,
+};
 use std::fmt::Display;
 
+impl PolyOverQ {
+    /// Returns the degree of a polynomial [`PolyOverQ`] as a [`i64`].
+    /// The zero polynomial has degree `-1`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::PolyOverQ;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverQ::from_str("4  0 1/2 2 3/4").unwrap();
+    ///
+    /// assert_eq!(3, poly.get_degree());
+    /// assert_eq!(-1, PolyOverQ::default().get_degree());
+    /// ```
+    pub fn get_degree(&self) -> i64 {
+        unsafe { fmpq_poly_degree(&self.poly) }
+    }
+
+    /// Splits `self` into an integer polynomial and a common denominator,
+    /// s.t. `self = numerator / denominator`.
+    /// The denominator is positive and coprime to the content of the
+    /// numerator, i.e. it is the least common denominator of all coefficients.
+    /// This is the inverse operation of [`PolyOverQ::from_integer_poly`].
+    ///
+    /// Returns a tuple `(numerator, denominator)` of a [`PolyOverZ`] and a [`Z`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::integer::{PolyOverZ, Z};
+    /// use qfall_math::rational::PolyOverQ;
+    /// use std::str::FromStr;
+    ///
+    /// let poly = PolyOverQ::from_str("3  1/3 0 -1/2").unwrap();
+    ///
+    /// let (numerator, denominator) = poly.to_integer_poly();
+    ///
+    /// assert_eq!(PolyOverZ::from_str("3  2 0 -3").unwrap(), numerator);
+    /// assert_eq!(Z::from(6), denominator);
+    /// ```
+    pub fn to_integer_poly(&self) -> (PolyOverZ, Z) {
+        let mut numerator = PolyOverZ::default();
+        let mut denominator = Z::default();
+        unsafe {
+            fmpq_poly_get_numerator(&mut numerator.poly, &self.poly);
+            fmpq_poly_get_denominator(&mut denominator.value, &self.poly);
+        }
+        (numerator, denominator)
+    }
+}
+
 impl GetCoefficient<Q> for PolyOverQ {
     /// Returns the coefficient of a polynomial [`PolyOverQ`] as a [`Q`].
     ///
@@ -123,3 +181,78 @@ mod test_get_coeff {
         );
     }
 }
+
+#[cfg(test)]
+mod test_get_degree {
+    use crate::rational::PolyOverQ;
+    use std::str::FromStr;
+
+    /// Ensure that the degree is correct for polynomials with leading zeros
+    /// in their string representation and large coefficients
+    #[test]
+    fn degree() {
+        let poly_1 = PolyOverQ::from_str("5  1 2/3 3 0 0").unwrap();
+        let poly_2 = PolyOverQ::from_str(&format!("2  {} 1/{}", u64::MAX, i64::MIN)).unwrap();
+        let poly_3 = PolyOverQ::from_str("1  -7/2").unwrap();
+
+        assert_eq!(2, poly_1.get_degree());
+        assert_eq!(1, poly_2.get_degree());
+        assert_eq!(0, poly_3.get_degree());
+    }
+
+    /// Ensure that the zero polynomial has degree `-1`
+    #[test]
+    fn zero() {
+        assert_eq!(-1, PolyOverQ::default().get_degree());
+        assert_eq!(-1, PolyOverQ::from_str("3  0 0 0").unwrap().get_degree());
+    }
+}
+
+#[cfg(test)]
+mod test_to_integer_poly {
+    use crate::{
+        integer::{PolyOverZ, Z},
+        rational::PolyOverQ,
+    };
+    use std::str::FromStr;
+
+    /// Ensure that the least common denominator is returned for large values
+    /// and that the splitting can be reverted
+    #[test]
+    fn large_numbers() {
+        let poly = PolyOverQ::from_str(&format!("3  1/{} -2/3 {}", u64::MAX, i64::MIN)).unwrap();
+
+        let (numerator, denominator) = poly.to_integer_poly();
+
+        assert_eq!(Z::from(u64::MAX), denominator);
+        assert_eq!(
+            PolyOverZ::from_str(&format!(
+                "3  1 -{} {}",
+                u64::MAX / 3 * 2,
+                i64::MIN as i128 * u64::MAX as i128
+            ))
+            .unwrap(),
+            numerator
+        );
+        assert_eq!(
+            poly,
+            PolyOverQ::from_integer_poly(&numerator, &denominator).unwrap()
+        );
+    }
+
+    /// Ensure that integer polynomials have denominator `1`
+    /// and the zero polynomial is split into zero and `1`
+    #[test]
+    fn integer_coefficients() {
+        let poly = PolyOverQ::from_str("3  4 0 -2").unwrap();
+
+        assert_eq!(
+            (PolyOverZ::from_str("3  4 0 -2").unwrap(), Z::ONE),
+            poly.to_integer_poly()
+        );
+        assert_eq!(
+            (PolyOverZ::default(), Z::ONE),
+            PolyOverQ::default().to_integer_poly()
+        );
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the interpolation of a [`PolyOverQ`]
+//! through a given set of points.
+
+use super::PolyOverQ;
+use crate::{error::MathError, rational::Q, traits::SetCoefficient};
+
+impl PolyOverQ {
+    /// Computes the unique polynomial of degree smaller than the number of
+    /// points, which passes through all given points, i.e. the Lagrange interpolant.
+    /// The interpolant is computed using Newton's divided differences.
+    ///
+    /// Parameters:
+    /// - `points`: the points `(x, y)` the polynomial passes through
+    ///
+    /// Returns the interpolating polynomial as a [`PolyOverQ`] or an error if
+    /// the x-coordinates of the points are not pairwise distinct.
+    /// For an empty slice, the zero polynomial is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{PolyOverQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let points = [
+    ///     (Q::from_str("0").unwrap(), Q::from_str("1").unwrap()),
+    ///     (Q::from_str("1").unwrap(), Q::from_str("3/2").unwrap()),
+    ///     (Q::from_str("-1").unwrap(), Q::from_str("3/2").unwrap()),
+    /// ];
+    ///
+    /// let poly = PolyOverQ::interpolate(&points).unwrap();
+    ///
+    /// assert_eq!(PolyOverQ::from_str("3  1 0 1/2").unwrap(), poly);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`InvalidInterpolationPoints`](MathError::InvalidInterpolationPoints)
+    /// if two points have the same x-coordinate.
+    pub fn interpolate(points: &[(Q, Q)]) -> Result<Self, MathError> {
+        for (index, (x, _)) in points.iter().enumerate() {
+            if points[..index].iter().any(|(other_x, _)| other_x == x) {
+                return Err(MathError::InvalidInterpolationPoints(format!(
+                    "The x-coordinate {} occurs multiple times.",
+                    x
+                )));
+            }
+        }
+
+        // divided differences `[y_0], [y_0, y_1], ..., [y_0, ..., y_n]`
+        let mut differences: Vec<Q> = points.iter().map(|(_, y)| y.clone()).collect();
+        for level in 1..points.len() {
+            for index in (level..points.len()).rev() {
+                differences[index] = (&differences[index] - &differences[index - 1])
+                    / (&points[index].0 - &points[index - level].0);
+            }
+        }
+
+        // evaluate the Newton form `d_0 + (X - x_0) (d_1 + (X - x_1) (...))`
+        // using Horner's method
+        let mut out = PolyOverQ::default();
+        for (difference, (x, _)) in differences.iter().zip(points.iter()).rev() {
+            let mut linear = PolyOverQ::default();
+            linear.set_coeff(0, &(Q::ZERO - x)).unwrap();
+            linear.set_coeff(1, 1).unwrap();
+            let mut constant = PolyOverQ::default();
+            constant.set_coeff(0, difference).unwrap();
+
+            out = out * linear + constant;
+        }
+        Ok(out)
+    }
+
+    /// Computes the unique polynomial of degree smaller than the number of
+    /// points, which passes through all points `(xs[i], ys[i])`.
+    /// See [`PolyOverQ::interpolate`] for further details.
+    ///
+    /// Parameters:
+    /// - `xs`: the x-coordinates of the points
+    /// - `ys`: the y-coordinates of the points
+    ///
+    /// Returns the interpolating polynomial as a [`PolyOverQ`] or an error if
+    /// the slices have different lengths or the x-coordinates are not pairwise distinct.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::{PolyOverQ, Q};
+    /// use std::str::FromStr;
+    ///
+    /// let xs = [Q::from(0), Q::from(1), Q::from(-1)];
+    /// let ys = [Q::from(1), Q::from_str("3/2").unwrap(), Q::from_str("3/2").unwrap()];
+    ///
+    /// let poly = PolyOverQ::interpolate_from_coordinates(&xs, &ys).unwrap();
+    ///
+    /// assert_eq!(PolyOverQ::from_str("3  1 0 1/2").unwrap(), poly);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`InvalidInterpolationPoints`](MathError::InvalidInterpolationPoints)
+    /// if `xs` and `ys` have different lengths or
+    /// if an x-coordinate occurs multiple times.
+    pub fn interpolate_from_coordinates(xs: &[Q], ys: &[Q]) -> Result<Self, MathError> {
+        if xs.len() != ys.len() {
+            return Err(MathError::InvalidInterpolationPoints(format!(
+                "The number of x-coordinates {} does not match the number of y-coordinates {}.",
+                xs.len(),
+                ys.len()
+            )));
+        }
+
+        let points: Vec<(Q, Q)> = xs.iter().cloned().zip(ys.iter().cloned()).collect();
+        Self::interpolate(&points)
+    }
+}
+
+#[cfg(test)]
+mod test_interpolate {
+    use super::PolyOverQ;
+    use crate::{error::MathError, rational::Q, traits::Evaluate};
+    use std::str::FromStr;
+
+    /// Ensure that the interpolant passes through all points
+    /// and has the expected degree
+    #[test]
+    fn passes_through_points() {
+        let points: Vec<(Q, Q)> = [("1/2", "3"), ("-7", "0"), ("4", "-5/3"), ("0", "1")]
+            .iter()
+            .map(|(x, y)| (Q::from_str(x).unwrap(), Q::from_str(y).unwrap()))
+            .collect();
+
+        let poly = PolyOverQ::interpolate(&points).unwrap();
+
+        for (x, y) in points.iter() {
+            assert_eq!(*y, poly.evaluate(x));
+        }
+        assert!(poly.to_string().starts_with("4  "));
+    }
+
+    /// Ensure that a polynomial is recovered from sufficiently many evaluations
+    #[test]
+    fn recover_polynomial() {
+        let poly = PolyOverQ::from_str(&format!("4  {} -1/3 0 {}/7", i64::MIN, u64::MAX)).unwrap();
+        let points: Vec<(Q, Q)> = (-2..3)
+            .map(|x| (Q::from_str(&x.to_string()).unwrap(), poly.evaluate(x)))
+            .collect();
+
+        assert_eq!(poly, PolyOverQ::interpolate(&points).unwrap());
+    }
+
+    /// Ensure that a single point yields a constant polynomial
+    /// and no points yield the zero polynomial
+    #[test]
+    fn few_points() {
+        let points = [(Q::from_str("5").unwrap(), Q::from_str("-2/3").unwrap())];
+
+        assert_eq!(
+            PolyOverQ::from_str("1  -2/3").unwrap(),
+            PolyOverQ::interpolate(&points).unwrap()
+        );
+        assert_eq!(PolyOverQ::default(), PolyOverQ::interpolate(&[]).unwrap());
+    }
+
+    /// Ensure that an error is returned for duplicate x-coordinates
+    #[test]
+    fn duplicate_x() {
+        let points = [
+            (Q::from_str("1/2").unwrap(), Q::from_str("1").unwrap()),
+            (Q::from_str("3").unwrap(), Q::from_str("2").unwrap()),
+            (Q::from_str("2/4").unwrap(), Q::from_str("1").unwrap()),
+        ];
+
+        assert!(matches!(
+            PolyOverQ::interpolate(&points),
+            Err(MathError::InvalidInterpolationPoints(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_interpolate_from_coordinates {
+    use super::PolyOverQ;
+    use crate::{error::MathError, rational::Q, traits::Evaluate};
+    use std::str::FromStr;
+
+    /// Ensure that the interpolant passes through all points
+    /// and matches the interpolant of the corresponding pairs
+    #[test]
+    fn passes_through_points() {
+        let xs = [Q::from_str("1/2").unwrap(), Q::from(-7), Q::from(4)];
+        let ys = [Q::from(3), Q::ZERO, Q::from_str("-5/3").unwrap()];
+        let points: Vec<(Q, Q)> = xs.iter().cloned().zip(ys.iter().cloned()).collect();
+
+        let poly = PolyOverQ::interpolate_from_coordinates(&xs, &ys).unwrap();
+
+        for (x, y) in xs.iter().zip(ys.iter()) {
+            assert_eq!(*y, poly.evaluate(x));
+        }
+        assert_eq!(PolyOverQ::interpolate(&points).unwrap(), poly);
+    }
+
+    /// Ensure that an error is returned for slices of different lengths
+    #[test]
+    fn mismatching_lengths() {
+        let xs = [Q::from(1), Q::from(2)];
+        let ys = [Q::from(1)];
+
+        assert!(matches!(
+            PolyOverQ::interpolate_from_coordinates(&xs, &ys),
+            Err(MathError::InvalidInterpolationPoints(_))
+        ));
+        assert!(matches!(
+            PolyOverQ::interpolate_from_coordinates(&ys, &xs),
+            Err(MathError::InvalidInterpolationPoints(_))
+        ));
+    }
+
+    /// Ensure that an error is returned for duplicate x-coordinates
+    #[test]
+    fn duplicate_x() {
+        let xs = [
+            Q::from_str("1/2").unwrap(),
+            Q::from(3),
+            Q::from_str("2/4").unwrap(),
+        ];
+        let ys = [Q::from(1), Q::from(2), Q::from(1)];
+
+        assert!(matches!(
+            PolyOverQ::interpolate_from_coordinates(&xs, &ys),
+            Err(MathError::InvalidInterpolationPoints(_))
+        ));
+    }
+}
@@ -17,9 +17,12 @@ mod default;
 mod distance;
 mod exp;
 mod from;
+mod logarithm;
 mod ownership;
 mod properties;
+mod rounding;
 mod serialize;
+mod sqrt;
 mod to_string;
 
 /// [`Q`] represents any rational value.
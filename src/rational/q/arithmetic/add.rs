@@ -6,14 +6,14 @@
 // the terms of the Mozilla Public License Version 2.0 as published by the
 // Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
 
-//! Implementation of the [`Add`] trait for [`Q`] values.
+//! Implementation of the [`Add`] and [`Sum`] trait for [`Q`] values.
 
 use super::super::Q;
 use crate::macros::arithmetics::{
     arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
 };
 use flint_sys::fmpq::fmpq_add;
-use std::ops::Add;
+use std::{iter::Sum, ops::Add};
 
 impl Add for &Q {
     type Output = Q;
@@ -51,6 +51,64 @@ impl Add for &Q {
 arithmetic_trait_borrowed_to_owned!(Add, add, Q, Q, Q);
 arithmetic_trait_mixed_borrowed_owned!(Add, add, Q, Q, Q);
 
+impl Sum for Q {
+    /// Sums up all [`Q`] values of an iterator.
+    /// An empty iterator results in `0`.
+    ///
+    /// Parameters:
+    /// - `iter`: the iterator over the values
+    ///
+    /// Returns the sum of all values as a [`Q`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::Q;
+    /// use std::str::FromStr;
+    ///
+    /// let values = vec![Q::ONE, Q::from_str("1/2").unwrap(), Q::from_str("1/3").unwrap()];
+    ///
+    /// let sum: Q = values.into_iter().sum();
+    ///
+    /// assert_eq!(Q::from_str("11/6").unwrap(), sum);
+    /// ```
+    fn sum<I: Iterator<Item = Q>>(iter: I) -> Self {
+        let mut out = Q::default();
+        for value in iter {
+            unsafe { fmpq_add(&mut out.value, &out.value, &value.value) };
+        }
+        out
+    }
+}
+
+impl<'a> Sum<&'a Q> for Q {
+    /// Sums up all [`Q`] values of an iterator over borrowed rationals.
+    /// An empty iterator results in `0`.
+    ///
+    /// Parameters:
+    /// - `iter`: the iterator over the values
+    ///
+    /// Returns the sum of all values as a [`Q`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::Q;
+    /// use std::str::FromStr;
+    ///
+    /// let values = vec![Q::ONE, Q::from_str("1/2").unwrap(), Q::from_str("1/3").unwrap()];
+    ///
+    /// let sum: Q = values.iter().sum();
+    ///
+    /// assert_eq!(Q::from_str("11/6").unwrap(), sum);
+    /// ```
+    fn sum<I: Iterator<Item = &'a Q>>(iter: I) -> Self {
+        let mut out = Q::default();
+        for value in iter {
+            unsafe { fmpq_add(&mut out.value, &out.value, &value.value) };
+        }
+        out
+    }
+}
+
 #[cfg(test)]
 mod test_add {
     use super::Q;
@@ -112,3 +170,52 @@ mod test_add {
         );
     }
 }
+
+#[cfg(test)]
+mod test_sum {
+    use super::Q;
+    use std::str::FromStr;
+
+    /// Ensure that an empty iterator sums up to `0`
+    #[test]
+    fn empty() {
+        let values: Vec<Q> = vec![];
+
+        assert_eq!(Q::ZERO, values.iter().sum::<Q>());
+        assert_eq!(Q::ZERO, values.into_iter().sum::<Q>());
+    }
+
+    /// Ensure that owned and borrowed values are summed up correctly
+    #[test]
+    fn small_values() {
+        let values = vec![
+            Q::from_str("1/2").unwrap(),
+            Q::from_str("-1/3").unwrap(),
+            Q::from(4),
+        ];
+
+        assert_eq!(Q::from_str("25/6").unwrap(), values.iter().sum::<Q>());
+        assert_eq!(Q::from_str("25/6").unwrap(), values.into_iter().sum::<Q>());
+    }
+
+    /// Ensure that large values are summed up correctly
+    #[test]
+    fn large_values() {
+        let values = [Q::from(u64::MAX), Q::from(u64::MAX), Q::from(i64::MIN)];
+
+        assert_eq!(
+            Q::from(u64::MAX) + Q::from(u64::MAX) + Q::from(i64::MIN),
+            values.iter().sum::<Q>()
+        );
+    }
+
+    /// Ensure that computations on iterators such as squared norms compile
+    #[test]
+    fn mapped_iterator() {
+        let values = [Q::from_str("1/2").unwrap(), Q::from(-2)];
+
+        let norm: Q = values.iter().map(|c| c * c).sum();
+
+        assert_eq!(Q::from_str("17/4").unwrap(), norm);
+    }
+}
@@ -6,14 +6,14 @@
 // the terms of the Mozilla Public License Version 2.0 as published by the
 // Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
 
-//! Implementation of the [`Mul`] trait for [`Q`] values.
+//! Implementation of the [`Mul`] and [`Product`] trait for [`Q`] values.
 
 use super::super::Q;
 use crate::macros::arithmetics::{
     arithmetic_trait_borrowed_to_owned, arithmetic_trait_mixed_borrowed_owned,
 };
 use flint_sys::fmpq::fmpq_mul;
-use std::ops::Mul;
+use std::{iter::Product, ops::Mul};
 
 impl Mul for &Q {
     type Output = Q;
@@ -50,6 +50,64 @@ impl Mul for &Q {
 arithmetic_trait_borrowed_to_owned!(Mul, mul, Q, Q, Q);
 arithmetic_trait_mixed_borrowed_owned!(Mul, mul, Q, Q, Q);
 
+impl Product for Q {
+    /// Multiplies all [`Q`] values of an iterator.
+    /// An empty iterator results in `1`.
+    ///
+    /// Parameters:
+    /// - `iter`: the iterator over the values
+    ///
+    /// Returns the product of all values as a [`Q`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::Q;
+    /// use std::str::FromStr;
+    ///
+    /// let values = vec![Q::ONE, Q::from_str("1/2").unwrap(), Q::from_str("1/3").unwrap()];
+    ///
+    /// let product: Q = values.into_iter().product();
+    ///
+    /// assert_eq!(Q::from_str("1/6").unwrap(), product);
+    /// ```
+    fn product<I: Iterator<Item = Q>>(iter: I) -> Self {
+        let mut out = Q::ONE;
+        for value in iter {
+            unsafe { fmpq_mul(&mut out.value, &out.value, &value.value) };
+        }
+        out
+    }
+}
+
+impl<'a> Product<&'a Q> for Q {
+    /// Multiplies all [`Q`] values of an iterator over borrowed rationals.
+    /// An empty iterator results in `1`.
+    ///
+    /// Parameters:
+    /// - `iter`: the iterator over the values
+    ///
+    /// Returns the product of all values as a [`Q`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::Q;
+    /// use std::str::FromStr;
+    ///
+    /// let values = vec![Q::ONE, Q::from_str("1/2").unwrap(), Q::from_str("1/3").unwrap()];
+    ///
+    /// let product: Q = values.iter().product();
+    ///
+    /// assert_eq!(Q::from_str("1/6").unwrap(), product);
+    /// ```
+    fn product<I: Iterator<Item = &'a Q>>(iter: I) -> Self {
+        let mut out = Q::ONE;
+        for value in iter {
+            unsafe { fmpq_mul(&mut out.value, &out.value, &value.value) };
+        }
+        out
+    }
+}
+
 #[cfg(test)]
 mod test_mul {
     use super::Q;
@@ -110,3 +168,51 @@ mod test_mul {
         );
     }
 }
+
+#[cfg(test)]
+mod test_product {
+    use super::Q;
+    use std::str::FromStr;
+
+    /// Ensure that the product of an empty iterator is `1`
+    #[test]
+    fn empty() {
+        let values: Vec<Q> = vec![];
+
+        assert_eq!(Q::ONE, values.iter().product::<Q>());
+        assert_eq!(Q::ONE, values.into_iter().product::<Q>());
+    }
+
+    /// Ensure that owned and borrowed values are multiplied correctly
+    #[test]
+    fn small_values() {
+        let values = vec![
+            Q::from_str("1/2").unwrap(),
+            Q::from_str("-1/3").unwrap(),
+            Q::from(4),
+        ];
+
+        assert_eq!(Q::from_str("-2/3").unwrap(), values.iter().product::<Q>());
+        assert_eq!(
+            Q::from_str("-2/3").unwrap(),
+            values.into_iter().product::<Q>()
+        );
+    }
+
+    /// Ensure that large values are multiplied correctly
+    #[test]
+    fn large_values() {
+        let values = [
+            Q::from(u64::MAX),
+            Q::from_str(&format!("1/{}", i64::MAX)).unwrap(),
+            Q::from(i64::MIN),
+        ];
+
+        assert_eq!(
+            Q::from(u64::MAX)
+                * Q::from_str(&format!("1/{}", i64::MAX)).unwrap()
+                * Q::from(i64::MIN),
+            values.iter().product::<Q>()
+        );
+    }
+}
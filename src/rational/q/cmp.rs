@@ -10,7 +10,9 @@
 //! This uses the traits from [`std::cmp`].
 
 use super::Q;
-use flint_sys::fmpq::fmpq_equal;
+use crate::{integer::Z, macros::for_others::implement_for_others};
+use flint_sys::fmpq::{fmpq_cmp, fmpq_cmp_fmpz, fmpq_equal};
+use std::cmp::Ordering;
 
 impl PartialEq for Q {
     /// Checks if two rationals are equal. Used by the `==` and `!=` operators.
@@ -47,6 +49,222 @@ impl PartialEq for Q {
 // We do not allow division by zero, therefore, this is the case.
 impl Eq for Q {}
 
+impl PartialOrd for Q {
+    /// Compares two [`Q`] values. Used by the `<`, `<=`, `>`, and `>=` operators.
+    ///
+    /// Parameters:
+    /// - `other`: the other value that is used to compare the elements
+    ///
+    /// Returns the [`Ordering`] of the elements.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::Q;
+    ///
+    /// let a: Q = Q::try_from((&1, &3)).unwrap();
+    /// let b: Q = Q::try_from((&1, &2)).unwrap();
+    ///
+    /// assert!(a < b);
+    /// assert!(a <= b);
+    /// assert!(b > a);
+    /// assert!(b >= a);
+    /// ```
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Q {
+    /// Compares two [`Q`] values. As rationals are totally ordered, this enables
+    /// the use of [`Ord::max`], [`Ord::min`], [`Ord::clamp`], sorting, and
+    /// [`Iterator::max`] for [`Q`].
+    ///
+    /// Parameters:
+    /// - `other`: the other value that is used to compare the elements
+    ///
+    /// Returns the [`Ordering`] of the elements.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::Q;
+    /// use std::str::FromStr;
+    ///
+    /// let a: Q = Q::from_str("1/3").unwrap();
+    /// let b: Q = Q::from_str("1/2").unwrap();
+    /// let c: Q = Q::from_str("-1/2").unwrap();
+    ///
+    /// assert_eq!(b, a.clone().max(b.clone()));
+    /// assert_eq!(a, a.clone().min(b.clone()));
+    /// assert_eq!(Q::ZERO, c.clone().clamp(Q::ZERO, Q::ONE));
+    ///
+    /// let mut values = vec![b.clone(), c.clone(), a.clone()];
+    /// assert_eq!(Some(&b), values.iter().max());
+    /// values.sort();
+    /// assert_eq!(vec![c, a, b], values);
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        unsafe { fmpq_cmp(&self.value, &other.value).cmp(&0) }
+    }
+}
+
+impl PartialEq<Z> for Q {
+    /// Checks if a rational and an integer are equal. Used by the `==` and `!=` operators.
+    ///
+    /// Parameters:
+    /// - `other`: the other value that is used to compare the elements
+    ///
+    /// Returns `true` if the elements are equal, otherwise `false`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::Z, rational::Q};
+    /// use std::str::FromStr;
+    ///
+    /// let a: Q = Q::from_str("4/2").unwrap();
+    /// let b: Z = Z::from(2);
+    ///
+    /// assert!(a == b);
+    /// ```
+    fn eq(&self, other: &Z) -> bool {
+        unsafe { 0 == fmpq_cmp_fmpz(&self.value, &other.value) }
+    }
+}
+
+impl PartialOrd<Z> for Q {
+    /// Compares a rational with an integer. Used by the `<`, `<=`, `>`, and `>=` operators.
+    ///
+    /// Parameters:
+    /// - `other`: the other value that is used to compare the elements
+    ///
+    /// Returns the [`Ordering`] of the elements.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::{integer::Z, rational::Q};
+    /// use std::str::FromStr;
+    ///
+    /// let a: Q = Q::from_str("3/2").unwrap();
+    /// let b: Z = Z::from(2);
+    ///
+    /// assert!(a < b);
+    /// assert!(a <= b);
+    /// ```
+    fn partial_cmp(&self, other: &Z) -> Option<Ordering> {
+        unsafe { Some(fmpq_cmp_fmpz(&self.value, &other.value).cmp(&0)) }
+    }
+}
+
+impl PartialEq<f64> for Q {
+    /// Checks if a rational and a float are equal. Used by the `==` and `!=` operators.
+    /// The exact binary value of the float is compared, e.g. `0.1` is not equal to `1/10`.
+    /// `NaN` and infinite values are never equal to a [`Q`].
+    ///
+    /// Parameters:
+    /// - `other`: the other value that is used to compare the elements
+    ///
+    /// Returns `true` if the elements are equal, otherwise `false`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::Q;
+    ///
+    /// let a: Q = Q::try_from((&1, &2)).unwrap();
+    ///
+    /// assert!(a == 0.5);
+    /// assert!(0.5 == a);
+    /// assert!(!a.eq(&f64::NAN));
+    /// ```
+    fn eq(&self, other: &f64) -> bool {
+        match Q::from_f64_exact(*other) {
+            Some(other) => self.eq(&other),
+            None => false,
+        }
+    }
+}
+
+impl PartialEq<Q> for f64 {
+    /// Checks if a float and a rational are equal. Used by the `==` and `!=` operators.
+    /// `NaN` and infinite values are never equal to a [`Q`].
+    ///
+    /// Parameters:
+    /// - `other`: the other value that is used to compare the elements
+    ///
+    /// Returns `true` if the elements are equal, otherwise `false`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::Q;
+    ///
+    /// let a: Q = Q::try_from((&1, &2)).unwrap();
+    ///
+    /// assert!(0.5 == a);
+    /// ```
+    fn eq(&self, other: &Q) -> bool {
+        other.eq(self)
+    }
+}
+
+impl PartialOrd<f64> for Q {
+    /// Compares a rational with a float. Used by the `<`, `<=`, `>`, and `>=` operators.
+    /// The exact binary value of the float is compared.
+    /// Every [`Q`] is smaller than [`f64::INFINITY`] and larger than
+    /// [`f64::NEG_INFINITY`]. A comparison with `NaN` is not possible.
+    ///
+    /// Parameters:
+    /// - `other`: the other value that is used to compare the elements
+    ///
+    /// Returns the [`Ordering`] of the elements or `None` if `other` is `NaN`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::Q;
+    ///
+    /// let error: Q = Q::try_from((&1, &3)).unwrap();
+    ///
+    /// assert!(error < 0.5);
+    /// assert!(0.25 < error);
+    /// assert!(error.partial_cmp(&f64::NAN).is_none());
+    /// ```
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        if other.is_nan() {
+            None
+        } else if other.is_infinite() {
+            match other.is_sign_positive() {
+                true => Some(Ordering::Less),
+                false => Some(Ordering::Greater),
+            }
+        } else {
+            self.partial_cmp(&Q::from_f64_exact(*other).unwrap())
+        }
+    }
+}
+
+impl PartialOrd<Q> for f64 {
+    /// Compares a float with a rational. Used by the `<`, `<=`, `>`, and `>=` operators.
+    /// Every [`Q`] is smaller than [`f64::INFINITY`] and larger than
+    /// [`f64::NEG_INFINITY`]. A comparison with `NaN` is not possible.
+    ///
+    /// Parameters:
+    /// - `other`: the other value that is used to compare the elements
+    ///
+    /// Returns the [`Ordering`] of the elements or `None` if `self` is `NaN`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::Q;
+    ///
+    /// let error: Q = Q::try_from((&1, &3)).unwrap();
+    ///
+    /// assert!(0.25 < error);
+    /// ```
+    fn partial_cmp(&self, other: &Q) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+implement_for_others!(Z, Q, PartialOrd for u8 u16 u32 u64 i8 i16 i32 i64);
+implement_for_others!(f64, Q, PartialOrd for f32);
+
 /// Test that the [`PartialEq`] trait is correctly implemented.
 #[cfg(test)]
 mod test_partial_eq {
@@ -261,3 +479,196 @@ mod test_partial_eq {
         assert_eq!(a, b);
     }
 }
+
+/// Test the [`PartialOrd`] trait implementation for [`Q`]
+#[allow(clippy::neg_cmp_op_on_partial_ord)]
+#[cfg(test)]
+mod test_partial_ord {
+    use super::Q;
+    use std::str::FromStr;
+
+    /// Test comparisons between small positive and negative [`Q`]
+    /// (FLINT is not using pointers)
+    #[test]
+    fn small() {
+        let third = Q::from_str("1/3").unwrap();
+        let half = Q::from_str("1/2").unwrap();
+        let negative = Q::from_str("-1/2").unwrap();
+
+        assert!(third < half);
+        assert!(third <= half);
+        assert!(half > third);
+        assert!(half >= third);
+        assert!(negative < third);
+        assert!(half <= half);
+        assert!(!(half < half));
+        assert!(!(half > half));
+    }
+
+    /// Test comparisons between large [`Q`] (FLINT uses pointers)
+    /// and small [`Q`] (not using pointers).
+    #[test]
+    fn large_small() {
+        let max = Q::from(u64::MAX);
+        let min = Q::from(i64::MIN);
+        let large_denominator = Q::from_str(&format!("1/{}", u64::MAX)).unwrap();
+        let small = Q::from_str("1/2").unwrap();
+
+        assert!(small < max);
+        assert!(min < small);
+        assert!(min < max);
+        assert!(large_denominator < small);
+        assert!(Q::ZERO < large_denominator);
+        assert!(!(max < min));
+    }
+}
+
+/// Test the [`Ord`] trait implementation for [`Q`]
+#[cfg(test)]
+mod test_ord {
+    use super::Q;
+    use std::str::FromStr;
+
+    /// Ensure that `min`, `max`, and `clamp` work for small and large values
+    #[test]
+    fn min_max_clamp() {
+        let small = Q::from_str("-1/2").unwrap();
+        let large = Q::from_str(&format!("{}/3", u64::MAX)).unwrap();
+
+        assert_eq!(large, small.clone().max(large.clone()));
+        assert_eq!(small, small.clone().min(large.clone()));
+        assert_eq!(Q::ZERO, small.clone().clamp(Q::ZERO, Q::ONE));
+        assert_eq!(Q::ONE, large.clone().clamp(Q::ZERO, Q::ONE));
+        assert_eq!(small, small.clone().clamp(Q::MINUS_ONE, large));
+    }
+
+    /// Ensure that sorting and selecting the maximum of an iterator work
+    #[test]
+    fn sort_and_iterator() {
+        let mut values = vec![
+            Q::from_str("7/3").unwrap(),
+            Q::from(i64::MIN),
+            Q::from_str("1/2").unwrap(),
+            Q::from(u64::MAX),
+            Q::from_str("-1/2").unwrap(),
+        ];
+
+        assert_eq!(Some(&Q::from(u64::MAX)), values.iter().max());
+        assert_eq!(Some(&Q::from(i64::MIN)), values.iter().min());
+
+        values.sort();
+        assert_eq!(
+            vec![
+                Q::from(i64::MIN),
+                Q::from_str("-1/2").unwrap(),
+                Q::from_str("1/2").unwrap(),
+                Q::from_str("7/3").unwrap(),
+                Q::from(u64::MAX),
+            ],
+            values
+        );
+    }
+}
+
+/// Test the [`PartialEq`] and [`PartialOrd`] implementations between [`Q`]
+/// and other types
+#[allow(clippy::neg_cmp_op_on_partial_ord)]
+#[cfg(test)]
+mod test_cmp_other_types {
+    use super::Q;
+    use crate::integer::Z;
+    use std::str::FromStr;
+
+    /// Ensure that [`Q`] can be compared with [`Z`]
+    #[test]
+    fn integer() {
+        let half = Q::from_str("1/2").unwrap();
+        let two_q = Q::from_str("4/2").unwrap();
+        let two_z = Z::from(2);
+        let large = Z::from(u64::MAX);
+
+        assert!(two_q == two_z);
+        assert!(half != two_z);
+        assert!(half < two_z);
+        assert!(two_q <= two_z);
+        assert!(two_q >= two_z);
+        assert!(!(two_q > two_z));
+        assert!(half < large);
+    }
+
+    /// Ensure that [`Q`] can be compared with primitive integers in both directions
+    #[test]
+    fn primitive_integers() {
+        let half = Q::from_str("1/2").unwrap();
+        let max = Q::from(u64::MAX);
+        let min = Q::from(i64::MIN);
+
+        assert!(half < 1u8);
+        assert!(half < 1u16);
+        assert!(half < 1u32);
+        assert!(half < 1u64);
+        assert!(half > 0i8);
+        assert!(half > 0i16);
+        assert!(half > 0i32);
+        assert!(half > -1i64);
+        assert!(1 > half);
+        assert!(max == u64::MAX);
+        assert!(i64::MIN == min);
+        assert!(min < i64::MAX);
+        assert!(Q::from_str("6/3").unwrap() == 2);
+        assert!(Q::from_str("6/3").unwrap() != 3);
+    }
+
+    /// Ensure that [`Q`] can be compared with floats in both directions
+    #[test]
+    fn floats() {
+        let half = Q::from_str("1/2").unwrap();
+        let third = Q::from_str("1/3").unwrap();
+
+        assert!(half == 0.5);
+        assert!(0.5 == half);
+        assert!(half == 0.5f32);
+        assert!(0.5f32 == half);
+        assert!(third < 0.5);
+        assert!(0.5 > third);
+        assert!(third < 0.5f32);
+        assert!(0.25f32 < third);
+        assert!(half >= -0.5);
+        assert!(third != 0.33);
+    }
+
+    /// Ensure that infinite values and `NaN` are handled correctly
+    #[test]
+    fn floats_special_values() {
+        let half = Q::from_str("1/2").unwrap();
+
+        assert!(half < f64::INFINITY);
+        assert!(half > f64::NEG_INFINITY);
+        assert!(f32::INFINITY > half);
+        assert!(half != f64::INFINITY);
+        assert!(!half.eq(&f64::NAN));
+        assert!(!half.lt(&f64::NAN));
+        assert!(!half.ge(&f64::NAN));
+        assert!(half.partial_cmp(&f32::NAN).is_none());
+    }
+
+    /// Ensure that floats are compared with their exact binary value
+    /// without overflowing for very large or small values
+    #[test]
+    fn floats_exact() {
+        let tenth = Q::from_str("1/10").unwrap();
+        let large = Q::from_str(&format!("1{}", "0".repeat(300))).unwrap();
+        let tiny = Q::from_str(&format!("1/1{}", "0".repeat(330))).unwrap();
+
+        assert!(tenth != 0.1);
+        assert!(tenth < 0.1);
+        assert!(0.1f32 != tenth);
+        assert!(large != 1e300);
+        assert!(large < 1e301);
+        assert!(1e299 < large);
+        assert!(tiny < 5e-324);
+        assert!(Q::ZERO < 5e-324);
+        assert!(Q::ZERO != 5e-324);
+        assert!(Q::from_str("-1/2").unwrap() < -5e-324);
+    }
+}
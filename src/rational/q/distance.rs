@@ -10,6 +10,7 @@
 
 use super::Q;
 use crate::{
+    integer::Z,
     macros::for_others::{implement_for_others, implement_for_owned},
     traits::Distance,
 };
@@ -45,12 +46,21 @@ impl Distance<&Q> for Q {
     }
 }
 
+impl Distance<&Z> for Q {
+    type Output = Q;
+
+    /// Documentation can be found at [`Q::distance`]. Implicitly converts [`Z`] into [`Q`].
+    fn distance(&self, other: &Z) -> Self::Output {
+        self.distance(&Q::from(other))
+    }
+}
+
 implement_for_owned!(Q, Q, Distance);
-implement_for_others!(Q, Q, Distance for u8 u16 u32 u64 i8 i16 i32 i64 f64 f32);
+implement_for_others!(Q, Q, Distance for u8 u16 u32 u64 i8 i16 i32 i64 f64 f32 Z);
 
 #[cfg(test)]
 mod test_distance {
-    use super::{Distance, Q};
+    use super::{Distance, Q, Z};
 
     /// Checks if distance is correctly computed for small [`Q`] values
     /// and whether distance(a,b) == distance(b,a), distance(a,a) == 0
@@ -86,6 +96,19 @@ mod test_distance {
         assert_eq!(Q::ZERO, a.distance(&a));
     }
 
+    /// Checks if distance is correctly computed between [`Q`] and [`Z`] values
+    #[test]
+    fn integer_values() {
+        let a = Q::try_from((&5, &-15)).unwrap();
+        let b = Q::from(i64::MIN);
+        let large = Z::from(u64::MAX);
+
+        assert_eq!(Q::try_from((&4, &3)).unwrap(), a.distance(Z::ONE));
+        assert_eq!(Q::try_from((&1, &3)).unwrap(), a.distance(&Z::ZERO));
+        assert_eq!(Q::from(u64::MAX) - &b, b.distance(&large));
+        assert_eq!(Q::ZERO, Q::from(u64::MAX).distance(large));
+    }
+
     /// Check whether distance is available for owned [`Q`] and other types
     #[test]
     fn availability() {
@@ -19,7 +19,9 @@ use crate::{
     macros::from::{from_trait, from_type},
 };
 use flint_sys::{
-    fmpq::{fmpq, fmpq_canonicalise, fmpq_clear, fmpq_set_str},
+    fmpq::{
+        fmpq, fmpq_canonicalise, fmpq_clear, fmpq_div_2exp, fmpq_mul_2exp, fmpq_neg, fmpq_set_str,
+    },
     fmpz::{fmpz_is_zero, fmpz_set, fmpz_swap},
 };
 use fraction::Fraction;
@@ -224,6 +226,43 @@ impl Q {
         }
     }
 
+    /// Create a [`Q`] with exactly the value of a finite [`f64`], i.e.
+    /// the binary mantissa and exponent of `value` are converted without
+    /// any rounding or decimal approximation.
+    ///
+    /// Parameters:
+    /// - `value`: the value the rational number will have
+    ///
+    /// Returns a [`Q`] with the exact value of `value` or `None` if `value`
+    /// is `NaN` or infinite.
+    pub(crate) fn from_f64_exact(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+
+        // decompose the IEEE 754 representation into `(-1)^sign * mantissa * 2^exponent`
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = match biased_exponent {
+            0 => (fraction, -1074),
+            _ => (fraction | (1 << 52), biased_exponent - 1075),
+        };
+
+        let mut out = Q::from(mantissa);
+        unsafe {
+            if exponent >= 0 {
+                fmpq_mul_2exp(&mut out.value, &out.value, exponent as u64);
+            } else {
+                fmpq_div_2exp(&mut out.value, &out.value, exponent.unsigned_abs());
+            }
+            if value.is_sign_negative() {
+                fmpq_neg(&mut out.value, &out.value);
+            }
+        }
+        Some(out)
+    }
+
     from_type!(f32, f64, Q, Q::from_f64);
 }
 
@@ -646,3 +685,48 @@ mod test_from_float {
         let _ = Q::from_f32(f);
     }
 }
+
+#[cfg(test)]
+mod test_from_f64_exact {
+    use super::Q;
+    use crate::traits::Pow;
+    use std::str::FromStr;
+
+    /// Ensure that the exact binary value is used instead of a decimal approximation
+    #[test]
+    fn exact_value() {
+        // 0.1 = 3602879701896397 / 2^55
+        let cmp = Q::from_str("3602879701896397/36028797018963968").unwrap();
+
+        assert_eq!(cmp, Q::from_f64_exact(0.1).unwrap());
+        assert_eq!(cmp * Q::MINUS_ONE, Q::from_f64_exact(-0.1).unwrap());
+        assert_ne!(
+            Q::from_str("1/10").unwrap(),
+            Q::from_f64_exact(0.1).unwrap()
+        );
+        assert_eq!(Q::from(-3), Q::from_f64_exact(-3.0).unwrap());
+        assert_eq!(Q::ZERO, Q::from_f64_exact(-0.0).unwrap());
+    }
+
+    /// Ensure that very large and very small values are converted exactly
+    #[test]
+    fn extreme_values() {
+        let two = Q::from(2);
+
+        assert_eq!(two.pow(-1074).unwrap(), Q::from_f64_exact(5e-324).unwrap());
+        assert_eq!(
+            Q::from((1_u64 << 53) - 1) * two.pow(971).unwrap(),
+            Q::from_f64_exact(f64::MAX).unwrap()
+        );
+        assert!(Q::from(10).pow(299).unwrap() < Q::from_f64_exact(1e300).unwrap());
+        assert!(Q::from(10).pow(301).unwrap() > Q::from_f64_exact(1e300).unwrap());
+    }
+
+    /// Ensure that `NaN` and infinite values are not converted
+    #[test]
+    fn non_finite() {
+        assert!(Q::from_f64_exact(f64::NAN).is_none());
+        assert!(Q::from_f64_exact(f64::INFINITY).is_none());
+        assert!(Q::from_f64_exact(f64::NEG_INFINITY).is_none());
+    }
+}
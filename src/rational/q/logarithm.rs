@@ -0,0 +1,103 @@
+// Copyright © 2023 Marvin Beckmann
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! Implementations to call the logarithm on a [`Q`].
+
+use super::Q;
+use crate::error::MathError;
+use flint_sys::fmpz::fmpz_dlog;
+use std::f64::consts::LN_2;
+
+impl Q {
+    /// Computes an approximation of the logarithm to base `2` of a positive rational.
+    ///
+    /// The numerator and denominator are processed separately using double
+    /// precision. Hence, the absolute error of the result is at most
+    /// about `2^-50 * max(1, |log_2(num)| + |log_2(den)|)`.
+    ///
+    /// Returns an approximation of $log_2(self)$ as a [`Q`] instance or a [`MathError`],
+    /// if `self` is not greater than `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::Q;
+    /// use std::str::FromStr;
+    ///
+    /// let value = Q::from_str("1/8").unwrap();
+    /// let log = value.log2_approx().unwrap();
+    ///
+    /// assert_eq!(Q::from(-3), log);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type
+    /// [`NotNaturalNumber`](MathError::NotNaturalNumber) if `self` is not
+    /// greater than `0`.
+    pub fn log2_approx(&self) -> Result<Q, MathError> {
+        if self <= &Q::ZERO {
+            return Err(MathError::NotNaturalNumber(self.to_string()));
+        }
+
+        let (ln_numerator, ln_denominator) =
+            unsafe { (fmpz_dlog(&self.value.num), fmpz_dlog(&self.value.den)) };
+        Ok(Q::from((ln_numerator - ln_denominator) / LN_2))
+    }
+}
+
+#[cfg(test)]
+mod test_log2_approx {
+    use crate::error::MathError;
+    use crate::rational::Q;
+    use crate::traits::Distance;
+    use std::str::FromStr;
+
+    /// ensure that an error is returned if `self` is not positive
+    #[test]
+    fn value_too_small() {
+        assert!(matches!(
+            Q::ZERO.log2_approx(),
+            Err(MathError::NotNaturalNumber(_))
+        ));
+        assert!(Q::MINUS_ONE.log2_approx().is_err());
+        assert!(Q::from_str("-1/3").unwrap().log2_approx().is_err());
+        assert!(Q::from(i64::MIN).log2_approx().is_err());
+    }
+
+    /// ensure that powers of two yield exact results
+    #[test]
+    fn powers_of_two() {
+        assert_eq!(Q::ZERO, Q::ONE.log2_approx().unwrap());
+        assert_eq!(Q::from(10), Q::from(1024).log2_approx().unwrap());
+        assert_eq!(
+            Q::from(-3),
+            Q::from_str("1/8").unwrap().log2_approx().unwrap()
+        );
+        assert_eq!(
+            Q::from(3),
+            Q::from_str("24/3").unwrap().log2_approx().unwrap()
+        );
+    }
+
+    /// ensure that the approximation is close for other values
+    #[test]
+    fn approximation() {
+        let precision = Q::from_str("1/1000000000").unwrap();
+
+        let log = Q::from_str("1/3").unwrap().log2_approx().unwrap();
+        assert!(log.distance(-1.584962500721156) < precision);
+
+        let log = Q::from(u64::MAX).log2_approx().unwrap();
+        assert!(log.distance(64) < precision);
+
+        let log = Q::from_str(&format!("1/{}", u64::MAX))
+            .unwrap()
+            .log2_approx()
+            .unwrap();
+        assert!(log.distance(-64) < precision);
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright © 2023 Marcel Luca Schmidt
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains functions to round [`Q`] instances to a fixed grid
+//! of rational values.
+
+use super::Q;
+use crate::{error::MathError, integer::Z, utils::index::evaluate_bounded_index};
+use flint_sys::{
+    fmpq::{fmpq_div_2exp, fmpq_mul_2exp, fmpq_mul_fmpz, fmpq_set_fmpz_frac},
+    fmpz::{fmpz_add, fmpz_fdiv_q, fmpz_fdiv_q_2exp, fmpz_mul_2exp},
+};
+use std::fmt::Display;
+
+impl Q {
+    /// Rounds `self` to the nearest multiple of `step`.
+    /// If `self` lies exactly between two multiples, the larger one is chosen
+    /// for a positive `step`.
+    ///
+    /// Parameters:
+    /// - `step`: the step size whose multiples are the possible results
+    ///
+    /// Returns the multiple of `step` closest to `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::Q;
+    /// use std::str::FromStr;
+    ///
+    /// let value = Q::from_str("7/10").unwrap();
+    /// let step = Q::from_str("1/4").unwrap();
+    ///
+    /// let rounded = value.round_to_multiple(&step);
+    ///
+    /// assert_eq!(Q::from_str("3/4").unwrap(), rounded);
+    /// ```
+    ///
+    /// # Panics
+    /// - Panics if `step` is `0`.
+    pub fn round_to_multiple(&self, step: &Q) -> Q {
+        let quotient = self.div_safe(step).unwrap();
+        let factor = quotient.round();
+
+        let mut out = Q::default();
+        unsafe { fmpq_mul_fmpz(&mut out.value, &step.value, &factor.value) };
+        out
+    }
+
+    /// Rounds `self` to the nearest multiple of `1/2^k`.
+    /// If `self` lies exactly between two multiples, the larger one is chosen.
+    ///
+    /// Parameters:
+    /// - `k`: the number of binary digits after the point that are kept
+    ///
+    /// Returns the multiple of `1/2^k` closest to `self` or an error if `k` is negative
+    /// or larger than [`u32::MAX`].
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::Q;
+    /// use std::str::FromStr;
+    ///
+    /// let value = Q::from_str("1/3").unwrap();
+    ///
+    /// let rounded = value.round_to_dyadic(3).unwrap();
+    ///
+    /// assert_eq!(Q::from_str("3/8").unwrap(), rounded);
+    /// ```
+    ///
+    /// # Errors and Failures
+    /// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+    /// `k` is negative or larger than [`u32::MAX`], as `2^k` could not be allocated.
+    pub fn round_to_dyadic(&self, k: impl TryInto<i64> + Display + Copy) -> Result<Q, MathError> {
+        let k = evaluate_bounded_index(k, u32::MAX as i64 + 1)? as u64;
+
+        let mut scaled = Q::default();
+        unsafe { fmpq_mul_2exp(&mut scaled.value, &self.value, k) };
+        let rounded = scaled.round();
+
+        let mut out = Q::default();
+        unsafe {
+            fmpq_set_fmpz_frac(&mut out.value, &rounded.value, &Z::ONE.value);
+            fmpq_div_2exp(&mut out.value, &out.value, k);
+        }
+        Ok(out)
+    }
+
+    /// Rounds `self` to the closest integer, where ties are rounded up,
+    /// i.e. computes `floor(self + 1/2)`.
+    pub(crate) fn round(&self) -> Z {
+        let mut out = Z::default();
+        unsafe {
+            fmpz_mul_2exp(&mut out.value, &self.value.num, 1);
+            fmpz_add(&mut out.value, &out.value, &self.value.den);
+            fmpz_fdiv_q(&mut out.value, &out.value, &self.value.den);
+            fmpz_fdiv_q_2exp(&mut out.value, &out.value, 1);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_round_to_multiple {
+    use super::Q;
+    use crate::traits::Distance;
+    use std::str::FromStr;
+
+    /// Ensure that values are rounded to the closest multiple
+    #[test]
+    fn small_values() {
+        let step = Q::from_str("1/4").unwrap();
+
+        assert_eq!(Q::ZERO, Q::ZERO.round_to_multiple(&step));
+        assert_eq!(
+            Q::from_str("3/4").unwrap(),
+            Q::from_str("7/10").unwrap().round_to_multiple(&step)
+        );
+        assert_eq!(
+            Q::from_str("-3/4").unwrap(),
+            Q::from_str("-7/10").unwrap().round_to_multiple(&step)
+        );
+        assert_eq!(Q::from(5), Q::from(5).round_to_multiple(&step));
+        assert_eq!(Q::from(6), Q::from(7).round_to_multiple(&Q::from(3)));
+    }
+
+    /// Ensure that ties are rounded up
+    #[test]
+    fn ties() {
+        let step = Q::from(2);
+
+        assert_eq!(Q::from(2), Q::ONE.round_to_multiple(&step));
+        assert_eq!(Q::ZERO, Q::MINUS_ONE.round_to_multiple(&step));
+        assert_eq!(
+            Q::ONE,
+            Q::from_str("1/2").unwrap().round_to_multiple(&Q::ONE)
+        );
+    }
+
+    /// Ensure that large values are rounded correctly
+    #[test]
+    fn large_values() {
+        let step = Q::from_str(&format!("1/{}", u64::MAX)).unwrap();
+        let value = Q::from_str(&format!("{}/{}", u64::MAX, i64::MAX)).unwrap();
+
+        let rounded = value.round_to_multiple(&step);
+
+        assert!(value.distance(&rounded) <= step);
+        assert_eq!(
+            Q::from(u64::MAX),
+            Q::from(u64::MAX).round_to_multiple(&step)
+        );
+        assert_eq!(
+            Q::from(i64::MIN),
+            Q::from(i64::MIN).round_to_multiple(&Q::from(2))
+        );
+    }
+
+    /// Ensure that negative step sizes yield the same multiples
+    #[test]
+    fn negative_step() {
+        let step = Q::from_str("-1/4").unwrap();
+
+        assert_eq!(
+            Q::from_str("3/4").unwrap(),
+            Q::from_str("7/10").unwrap().round_to_multiple(&step)
+        );
+    }
+
+    /// Ensure that the function panics if the step size is `0`
+    #[test]
+    #[should_panic]
+    fn zero_step() {
+        let _ = Q::ONE.round_to_multiple(&Q::ZERO);
+    }
+}
+
+#[cfg(test)]
+mod test_round_to_dyadic {
+    use super::Q;
+    use crate::error::MathError;
+    use std::str::FromStr;
+
+    /// Ensure that values are rounded to the closest multiple of `1/2^k`
+    #[test]
+    fn small_values() {
+        assert_eq!(
+            Q::from_str("3/8").unwrap(),
+            Q::from_str("1/3").unwrap().round_to_dyadic(3).unwrap()
+        );
+        assert_eq!(
+            Q::from_str("-3/8").unwrap(),
+            Q::from_str("-1/3").unwrap().round_to_dyadic(3).unwrap()
+        );
+        assert_eq!(
+            Q::ZERO,
+            Q::from_str("1/3").unwrap().round_to_dyadic(0).unwrap()
+        );
+        assert_eq!(
+            Q::from_str("1/2").unwrap(),
+            Q::from_str("1/2").unwrap().round_to_dyadic(5).unwrap()
+        );
+    }
+
+    /// Ensure that ties are rounded up
+    #[test]
+    fn ties() {
+        assert_eq!(
+            Q::ONE,
+            Q::from_str("1/2").unwrap().round_to_dyadic(0).unwrap()
+        );
+        assert_eq!(
+            Q::from_str("1/4").unwrap(),
+            Q::from_str("1/8").unwrap().round_to_dyadic(2).unwrap()
+        );
+        assert_eq!(
+            Q::ZERO,
+            Q::from_str("-1/8").unwrap().round_to_dyadic(2).unwrap()
+        );
+    }
+
+    /// Ensure that large values and precisions work
+    #[test]
+    fn large_values() {
+        let value = Q::from_str(&format!("{}/3", u64::MAX)).unwrap();
+
+        assert_eq!(value, value.round_to_dyadic(0).unwrap());
+        assert_eq!(
+            Q::from_str("1/3").unwrap(),
+            Q::from_str("1/3")
+                .unwrap()
+                .round_to_dyadic(1000_u64)
+                .unwrap()
+                .round_to_multiple(&Q::from_str(&format!("1/{}", u64::MAX)).unwrap())
+        );
+    }
+
+    /// Ensure that an error is returned for negative precisions
+    #[test]
+    fn negative_precision() {
+        let value = Q::from_str("1/3").unwrap();
+
+        assert!(value.round_to_dyadic(-1).is_err());
+        assert!(value.round_to_dyadic(i64::MIN).is_err());
+    }
+
+    /// Ensure that an error is returned for precisions that can not be allocated
+    #[test]
+    fn too_large_precision() {
+        let value = Q::from_str("1/3").unwrap();
+
+        assert!(matches!(
+            value.round_to_dyadic(u32::MAX as u64 + 1),
+            Err(MathError::OutOfBounds(_, _))
+        ));
+        assert!(value.round_to_dyadic(i64::MAX).is_err());
+        assert!(value.round_to_dyadic(u64::MAX).is_err());
+    }
+}
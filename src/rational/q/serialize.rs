@@ -9,20 +9,223 @@
 //! This module contains implementations of functions
 //! important for serialization such as the [`Serialize`] and [`Deserialize`] trait.
 //!
+//! Human-readable formats like JSON store a [`Q`] as a string `{"value":"17/3"}`.
+//! All other formats, e.g. binary formats like bincode, store the numerator
+//! and denominator as compact byte arrays.
+//! The first byte of each array stores the sign, the remaining bytes
+//! store the absolute value in little-endian order.
+//!
 //! The explicit functions contain the documentation.
 
 use super::Q;
-use crate::macros::serialize::{deserialize, serialize};
+use crate::integer::{
+    fmpz_helpers::{from_bytes, to_bytes},
+    Z,
+};
 use core::fmt;
+use flint_sys::fmpq::fmpq_set_fmpz_frac;
 use serde::{
-    de::{Error, MapAccess, Unexpected, Visitor},
+    de::{Error, MapAccess, SeqAccess, Unexpected, Visitor},
     ser::SerializeStruct,
     Deserialize, Serialize,
 };
 use std::str::FromStr;
 
-serialize!("value", Q);
-deserialize!("value", Value, Q);
+impl Serialize for Q {
+    /// Implements the serialize option. This allows to create a Json-object from a given [`Q`].
+    /// Formats that are not human-readable store the numerator and denominator as byte arrays.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Q", 1)?;
+            state.serialize_field("value", &self.to_string())?;
+            state.end()
+        } else {
+            let mut state = serializer.serialize_struct("Q", 2)?;
+            state.serialize_field("numerator", &Bytes(to_bytes(&self.value.num)))?;
+            state.serialize_field("denominator", &Bytes(to_bytes(&self.value.den)))?;
+            state.end()
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Q {
+    /// Implements the deserialize option. This allows to create a [`Q`] from a given Json-object.
+    /// Formats that are not human-readable are expected to store the numerator
+    /// and denominator as byte arrays.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        /// This enum defines the content of the struct to be generated using [`Deserialize`]
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field {
+            Value,
+            Numerator,
+            Denominator,
+        }
+
+        /// This visitor iterates over the strings content and collects all possible fields.
+        /// It sets the corresponding values of the struct based on the values found.
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Q;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Q")
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<Q, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let numerator: Bytes = seq
+                    .next_element()?
+                    .ok_or_else(|| Error::invalid_length(0, &self))?;
+                let denominator: Bytes = seq
+                    .next_element()?
+                    .ok_or_else(|| Error::invalid_length(1, &self))?;
+                from_compact(&numerator.0, &denominator.0, &self)
+            }
+
+            fn visit_map<V>(self, mut map: V) -> Result<Q, V::Error>
+            where
+                V: MapAccess<'de>,
+            {
+                let mut value: Option<String> = None;
+                let mut numerator: Option<Bytes> = None;
+                let mut denominator: Option<Bytes> = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::Value => {
+                            if value.is_some() {
+                                return Err(Error::duplicate_field("value"));
+                            }
+                            value = Some(map.next_value()?);
+                        }
+                        Field::Numerator => {
+                            if numerator.is_some() {
+                                return Err(Error::duplicate_field("numerator"));
+                            }
+                            numerator = Some(map.next_value()?);
+                        }
+                        Field::Denominator => {
+                            if denominator.is_some() {
+                                return Err(Error::duplicate_field("denominator"));
+                            }
+                            denominator = Some(map.next_value()?);
+                        }
+                    }
+                }
+
+                match (value, numerator, denominator) {
+                    (Some(value), None, None) => Q::from_str(&value)
+                        .map_err(|_| Error::invalid_value(Unexpected::Str(&value), &self)),
+                    (None, Some(numerator), Some(denominator)) => {
+                        from_compact(&numerator.0, &denominator.0, &self)
+                    }
+                    (None, None, _) => Err(Error::missing_field("numerator")),
+                    (None, _, None) => Err(Error::missing_field("denominator")),
+                    _ => Err(Error::custom(
+                        "the fields `numerator` and `denominator` can not be combined with `value`",
+                    )),
+                }
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            const FIELDS: &[&str] = &["value"];
+            deserializer.deserialize_struct("Q", FIELDS, StructVisitor)
+        } else {
+            const FIELDS: &[&str] = &["numerator", "denominator"];
+            deserializer.deserialize_struct("Q", FIELDS, StructVisitor)
+        }
+    }
+}
+
+/// Creates a [`Q`] out of the byte encodings of its numerator and denominator.
+///
+/// Parameters:
+/// - `numerator`: the byte encoding of the numerator
+/// - `denominator`: the byte encoding of the denominator
+/// - `expected`: describes the expected input for error messages
+///
+/// Returns the [`Q`] instance or an error if one of the encodings is invalid
+/// or the denominator is `0`.
+fn from_compact<E: Error>(
+    numerator: &[u8],
+    denominator: &[u8],
+    expected: &dyn serde::de::Expected,
+) -> Result<Q, E> {
+    let numerator = from_bytes(numerator)
+        .ok_or_else(|| Error::invalid_value(Unexpected::Bytes(numerator), expected))?;
+    let denominator = from_bytes(denominator)
+        .ok_or_else(|| Error::invalid_value(Unexpected::Bytes(denominator), expected))?;
+    if denominator == Z::ZERO {
+        return Err(Error::invalid_value(
+            Unexpected::Other("denominator 0"),
+            expected,
+        ));
+    }
+
+    let mut out = Q::default();
+    unsafe { fmpq_set_fmpz_frac(&mut out.value, &numerator.value, &denominator.value) };
+    Ok(out)
+}
+
+/// Wraps a byte vector s.t. it is (de-)serialized as a byte array
+/// instead of a sequence of single bytes.
+struct Bytes(Vec<u8>);
+
+impl Serialize for Bytes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        /// This visitor accepts byte arrays as well as sequences of bytes.
+        struct BytesVisitor;
+        impl<'de> Visitor<'de> for BytesVisitor {
+            type Value = Bytes;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a byte array")
+            }
+
+            fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<Bytes, E> {
+                Ok(Bytes(bytes.to_vec()))
+            }
+
+            fn visit_byte_buf<E: Error>(self, bytes: Vec<u8>) -> Result<Bytes, E> {
+                Ok(Bytes(bytes))
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<Bytes, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                Ok(Bytes(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+}
 
 #[cfg(test)]
 mod test_serialize {
@@ -79,7 +282,7 @@ mod test_deserialize {
         let q_string = "{\"value\":\"17/3\"}";
         assert_eq!(
             Q::from_str("17/3").unwrap(),
-            serde_json::from_str(q_string).unwrap()
+            serde_json::from_str::<Q>(q_string).unwrap()
         )
     }
 
@@ -89,7 +292,7 @@ mod test_deserialize {
         let q_string = "{\"value\":\"-17/3\"}";
         assert_eq!(
             Q::from_str("-17/3").unwrap(),
-            serde_json::from_str(q_string).unwrap()
+            serde_json::from_str::<Q>(q_string).unwrap()
         )
     }
 
@@ -101,7 +304,7 @@ mod test_deserialize {
 
         assert_eq!(
             Q::from_str(&val_str).unwrap(),
-            serde_json::from_str(&z_string).unwrap()
+            serde_json::from_str::<Q>(&z_string).unwrap()
         )
     }
 
@@ -113,7 +316,7 @@ mod test_deserialize {
 
         assert_eq!(
             Q::from_str(&val_str).unwrap(),
-            serde_json::from_str(&z_string).unwrap()
+            serde_json::from_str::<Q>(&z_string).unwrap()
         )
     }
 
@@ -139,3 +342,93 @@ mod test_deserialize {
         assert!(b.is_err());
     }
 }
+
+#[cfg(test)]
+mod test_compact {
+    use crate::rational::Q;
+    use std::str::FromStr;
+
+    /// tests whether the compact representation is deserialized correctly
+    #[test]
+    fn deserialize_compact() {
+        let q_string = "{\"numerator\":[1,17],\"denominator\":[0,3]}";
+
+        assert_eq!(
+            Q::from_str("-17/3").unwrap(),
+            serde_json::from_str::<Q>(q_string).unwrap()
+        )
+    }
+
+    /// tests whether the compact representation of large values
+    /// is deserialized and canonicalized correctly
+    #[test]
+    fn deserialize_compact_large() {
+        let numerator = [0, 255, 255, 255, 255, 255, 255, 255, 255, 255];
+        let denominator = [0, 254, 255, 255, 255, 255, 255, 255, 255, 255];
+        let q_string = format!(
+            "{{\"numerator\":{:?},\"denominator\":{:?}}}",
+            numerator, denominator
+        );
+
+        assert_eq!(
+            Q::from_str(&format!("{}/{}", u128::MAX >> 56, (u128::MAX >> 56) - 1)).unwrap(),
+            serde_json::from_str::<Q>(&q_string).unwrap()
+        )
+    }
+
+    /// tests whether invalid compact representations yield an error
+    #[test]
+    fn invalid_compact() {
+        let zero_denominator: Result<Q, serde_json::Error> =
+            serde_json::from_str("{\"numerator\":[0,1],\"denominator\":[0]}");
+        let invalid_sign: Result<Q, serde_json::Error> =
+            serde_json::from_str("{\"numerator\":[2,1],\"denominator\":[0,1]}");
+        let missing_denominator: Result<Q, serde_json::Error> =
+            serde_json::from_str("{\"numerator\":[0,1]}");
+        let mixed: Result<Q, serde_json::Error> =
+            serde_json::from_str("{\"numerator\":[0,1],\"denominator\":[0,1],\"value\":\"1\"}");
+
+        assert!(zero_denominator.is_err());
+        assert!(invalid_sign.is_err());
+        assert!(missing_denominator.is_err());
+        assert!(mixed.is_err());
+    }
+
+    /// tests whether small, negative and multi-limb values are serialized
+    /// into the compact representation of a binary format and back
+    #[test]
+    fn roundtrip_binary() {
+        let values = [
+            Q::from_str("17/3").unwrap(),
+            Q::from_str("-17/3").unwrap(),
+            Q::default(),
+            Q::from_str(&format!("{}/{}", u128::MAX, 1_u128 << 65)).unwrap(),
+            Q::from_str(&format!("-1/{}", u128::MAX)).unwrap(),
+        ];
+
+        for value in values {
+            let bytes = bincode::serialize(&value).unwrap();
+
+            assert_eq!(value, bincode::deserialize::<Q>(&bytes).unwrap());
+        }
+    }
+
+    /// tests whether the compact representation of a binary format
+    /// stores the numerator and denominator as byte arrays
+    #[test]
+    fn serialize_binary() {
+        let q = Q::from_str("-17/3").unwrap();
+
+        // each byte array is prefixed by its length as `u64`
+        let cmp = [2, 0, 0, 0, 0, 0, 0, 0, 1, 17, 2, 0, 0, 0, 0, 0, 0, 0, 0, 3];
+        assert_eq!(cmp.to_vec(), bincode::serialize(&q).unwrap());
+    }
+
+    /// tests whether a zero denominator in a binary format yields an error
+    #[test]
+    fn zero_denominator_binary() {
+        let bytes = [2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0];
+
+        assert!(bincode::deserialize::<Q>(&bytes).is_err());
+    }
+}
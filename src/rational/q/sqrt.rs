@@ -0,0 +1,162 @@
+// Copyright © 2023 Marcel Luca Schmidt
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains the implementation of the square root approximation of [`Q`].
+
+use super::Q;
+use crate::integer::Z;
+use flint_sys::{
+    fmpq::{fmpq_div_2exp, fmpq_set_fmpz_frac},
+    fmpz::{fmpz_bits, fmpz_fdiv_q, fmpz_is_square, fmpz_mul_2exp, fmpz_sqrt},
+};
+
+impl Q {
+    /// Computes a rational approximation of the square root of `self`.
+    /// If `self` is the square of a rational, the exact square root is returned.
+    /// Otherwise, the result `r` satisfies `0 <= sqrt(self) - r < precision`.
+    ///
+    /// Parameters:
+    /// - `precision`: the maximal distance between the returned value
+    /// and the actual square root
+    ///
+    /// Returns a [`Q`] approximating the square root of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use qfall_math::rational::Q;
+    /// use std::str::FromStr;
+    ///
+    /// let value = Q::from(2);
+    /// let precision = Q::from_str("1/1000").unwrap();
+    ///
+    /// let sqrt = value.sqrt(&precision);
+    ///
+    /// assert!(&sqrt * &sqrt <= value);
+    /// assert!(Q::from_str("1414/1000").unwrap() <= sqrt);
+    /// assert_eq!(Q::from_str("3/4").unwrap(), Q::from_str("9/16").unwrap().sqrt(&precision));
+    /// ```
+    ///
+    /// # Panics
+    /// - Panics if `self` is negative.
+    /// - Panics if `precision` is not positive.
+    pub fn sqrt(&self, precision: &Q) -> Q {
+        if self < &Q::ZERO {
+            panic!("The square root of the negative value {self} is not defined over Q.");
+        }
+        if precision <= &Q::ZERO {
+            panic!("The precision {precision} of the square root has to be positive.");
+        }
+
+        let mut out = Q::default();
+        unsafe {
+            if 1 == fmpz_is_square(&self.value.num) && 1 == fmpz_is_square(&self.value.den) {
+                let mut numerator = Z::default();
+                let mut denominator = Z::default();
+                fmpz_sqrt(&mut numerator.value, &self.value.num);
+                fmpz_sqrt(&mut denominator.value, &self.value.den);
+                fmpq_set_fmpz_frac(&mut out.value, &numerator.value, &denominator.value);
+                return out;
+            }
+
+            // choose `k` such that `1/2^k <= precision`, then compute
+            // `floor(sqrt(floor(self * 4^k))) / 2^k`, which is closer than `1/2^k`
+            // to the actual square root
+            let k = (fmpz_bits(&precision.value.den) + 1)
+                .saturating_sub(fmpz_bits(&precision.value.num));
+
+            let mut scaled = Z::default();
+            fmpz_mul_2exp(&mut scaled.value, &self.value.num, 2 * k);
+            fmpz_fdiv_q(&mut scaled.value, &scaled.value, &self.value.den);
+            fmpz_sqrt(&mut scaled.value, &scaled.value);
+
+            fmpq_set_fmpz_frac(&mut out.value, &scaled.value, &Z::ONE.value);
+            fmpq_div_2exp(&mut out.value, &out.value, k);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_sqrt {
+    use super::Q;
+    use std::str::FromStr;
+
+    /// Ensure that the exact square root is returned for squares of rationals
+    #[test]
+    fn exact_squares() {
+        let precision = Q::from_str("1/2").unwrap();
+
+        assert_eq!(Q::ZERO, Q::ZERO.sqrt(&precision));
+        assert_eq!(Q::ONE, Q::ONE.sqrt(&precision));
+        assert_eq!(Q::from(12), Q::from(144).sqrt(&precision));
+        assert_eq!(
+            Q::from_str("5/7").unwrap(),
+            Q::from_str("25/49").unwrap().sqrt(&precision)
+        );
+    }
+
+    /// Ensure that the exact square root is returned for large squares
+    #[test]
+    fn exact_squares_large() {
+        let precision = Q::from_str("1/2").unwrap();
+        let root = Q::from_str(&format!("{}/{}", u64::MAX, i64::MAX)).unwrap();
+        let square = &root * &root;
+
+        assert_eq!(root, square.sqrt(&precision));
+    }
+
+    /// Ensure that the approximation is within the requested precision
+    #[test]
+    fn within_precision() {
+        let values = [
+            Q::from(2),
+            Q::from_str("1/3").unwrap(),
+            Q::from_str("17/5").unwrap(),
+            Q::from(u64::MAX),
+            Q::from_str(&format!("1/{}", u64::MAX)).unwrap(),
+        ];
+        let precisions = [
+            Q::ONE,
+            Q::from_str("1/3").unwrap(),
+            Q::from_str("1/1000000").unwrap(),
+            Q::from_str(&format!("1/{}", u64::MAX)).unwrap(),
+        ];
+
+        for value in values.iter() {
+            for precision in precisions.iter() {
+                let sqrt = value.sqrt(precision);
+                let upper = &sqrt + precision;
+
+                assert!(&sqrt * &sqrt <= *value);
+                assert!(*value < &upper * &upper);
+            }
+        }
+    }
+
+    /// Ensure that large precisions are handled correctly
+    #[test]
+    fn large_precision() {
+        let precision = Q::from(100);
+
+        assert_eq!(Q::ONE, Q::from(3).sqrt(&precision));
+    }
+
+    /// Ensure that the function panics for negative values
+    #[test]
+    #[should_panic]
+    fn negative_value() {
+        let _ = Q::MINUS_ONE.sqrt(&Q::ONE);
+    }
+
+    /// Ensure that the function panics for non-positive precisions
+    #[test]
+    #[should_panic]
+    fn zero_precision() {
+        let _ = Q::from(2).sqrt(&Q::ZERO);
+    }
+}
@@ -179,7 +179,8 @@ pub trait Pow<T> {
     fn pow(&self, exp: T) -> Result<Self::Output, MathError>;
 }
 
-/// Is implemented by [`Z`](crate::integer::Z) instances to calculate the `gcd`
+/// Is implemented by [`Z`](crate::integer::Z), [`PolyOverZ`](crate::integer::PolyOverZ),
+/// and [`PolyOverZq`](crate::integer_mod_q::PolyOverZq) instances to calculate the `gcd`
 pub trait Gcd<T = Self> {
     type Output;
 
@@ -193,8 +194,8 @@ pub trait Gcd<T = Self> {
     fn gcd(&self, other: T) -> Self::Output;
 }
 
-/// Is implemented by [`Z`](crate::integer::Z) instances to calculate the
-/// extended `gcd`
+/// Is implemented by [`Z`](crate::integer::Z), [`PolyOverZ`](crate::integer::PolyOverZ),
+/// and [`PolyOverZq`](crate::integer_mod_q::PolyOverZq) instances to calculate the extended `gcd`
 pub trait Xgcd<T = Self> {
     type Output;
 
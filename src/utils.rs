@@ -11,6 +11,8 @@
 //! This can include functions to pre-process inputs, find size of matrices
 //! and similar tasks.
 
+pub(crate) mod context_cache;
 pub mod dimensions;
 pub mod index;
 pub mod parse;
+pub(crate) mod sample;
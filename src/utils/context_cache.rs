@@ -0,0 +1,140 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains a cache for reference counted FLINT contexts.
+//!
+//! Creating a FLINT context, e.g. for a [`Modulus`](crate::integer_mod_q::Modulus),
+//! involves precomputations. The cache ensures that contexts of equal values
+//! that are in use at the same time are only initialized once and shared
+//! via [`Rc`].
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::{Rc, Weak},
+};
+
+/// The minimal number of entries before unused entries are removed from the cache.
+const MIN_PRUNE_THRESHOLD: usize = 16;
+
+/// [`ContextCache`] stores weak references to contexts, which are identified
+/// by a byte encoding of their defining value.
+/// As only weak references are stored, a context is still freed as soon as
+/// the last instance using it is dropped.
+///
+/// Attributes:
+/// - `contexts`: maps the encoding of a value to its context
+/// - `prune_threshold`: the number of entries at which unused entries are removed
+pub(crate) struct ContextCache<T> {
+    contexts: RefCell<HashMap<Vec<u8>, Weak<T>>>,
+    prune_threshold: Cell<usize>,
+}
+
+impl<T> ContextCache<T> {
+    /// Creates an empty [`ContextCache`].
+    pub(crate) fn new() -> Self {
+        Self {
+            contexts: RefCell::new(HashMap::new()),
+            prune_threshold: Cell::new(MIN_PRUNE_THRESHOLD),
+        }
+    }
+
+    /// Returns the context stored for `key` if it is still in use.
+    /// Otherwise, a new context is created with `init` and stored for `key`.
+    ///
+    /// Parameters:
+    /// - `key`: the byte encoding of the value defining the context
+    /// - `init`: initializes the context if it is not cached
+    ///
+    /// Returns a shared reference to the context or the error returned by `init`.
+    pub(crate) fn get_or_try_init<E>(
+        &self,
+        key: Vec<u8>,
+        init: impl FnOnce() -> Result<T, E>,
+    ) -> Result<Rc<T>, E> {
+        if let Some(context) = self.contexts.borrow().get(&key).and_then(Weak::upgrade) {
+            return Ok(context);
+        }
+
+        let context = Rc::new(init()?);
+        let mut contexts = self.contexts.borrow_mut();
+        // remove the entries of dropped contexts s.t. the cache does not grow
+        // indefinitely, the threshold keeps the amortized cost constant
+        if contexts.len() >= self.prune_threshold.get() {
+            contexts.retain(|_, context| context.strong_count() > 0);
+            self.prune_threshold
+                .set(MIN_PRUNE_THRESHOLD.max(2 * contexts.len()));
+        }
+        contexts.insert(key, Rc::downgrade(&context));
+        Ok(context)
+    }
+
+    /// Returns the number of entries in the cache, including the ones of dropped contexts.
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.contexts.borrow().len()
+    }
+}
+
+#[cfg(test)]
+mod test_context_cache {
+    use super::{ContextCache, MIN_PRUNE_THRESHOLD};
+    use std::rc::Rc;
+
+    /// Ensure that the same context is returned for equal keys
+    #[test]
+    fn reuse_context() {
+        let cache = ContextCache::new();
+
+        let a = cache.get_or_try_init::<()>(vec![1, 2], || Ok(12)).unwrap();
+        let b = cache.get_or_try_init::<()>(vec![1, 2], || Ok(0)).unwrap();
+        let c = cache.get_or_try_init::<()>(vec![3], || Ok(3)).unwrap();
+
+        assert!(Rc::ptr_eq(&a, &b));
+        assert_eq!(12, *b);
+        assert_eq!(3, *c);
+    }
+
+    /// Ensure that dropped contexts are initialized again
+    #[test]
+    fn dropped_context() {
+        let cache = ContextCache::new();
+
+        let a = cache.get_or_try_init::<()>(vec![1], || Ok(1)).unwrap();
+        drop(a);
+        let b = cache.get_or_try_init::<()>(vec![1], || Ok(2)).unwrap();
+
+        assert_eq!(2, *b);
+    }
+
+    /// Ensure that errors of the initialization are returned and not cached
+    #[test]
+    fn init_error() {
+        let cache = ContextCache::<i32>::new();
+
+        assert!(cache.get_or_try_init(vec![1], || Err(())).is_err());
+        assert_eq!(0, cache.len());
+    }
+
+    /// Ensure that entries of dropped contexts are removed eventually
+    #[test]
+    fn prune_dropped_contexts() {
+        let cache = ContextCache::new();
+        let kept = cache.get_or_try_init::<()>(vec![0], || Ok(0)).unwrap();
+
+        for i in 1..10 * MIN_PRUNE_THRESHOLD {
+            let _ = cache.get_or_try_init::<()>(i.to_le_bytes().to_vec(), || Ok(i));
+        }
+
+        assert!(cache.len() <= MIN_PRUNE_THRESHOLD + 1);
+        assert!(Rc::ptr_eq(
+            &kept,
+            &cache.get_or_try_init::<()>(vec![0], || Ok(1)).unwrap()
+        ));
+    }
+}
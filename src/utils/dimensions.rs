@@ -54,6 +54,36 @@ pub(crate) fn find_matrix_dimensions<T>(matrix: &Vec<Vec<T>>) -> Result<(i64, i6
     }
 }
 
+/// Returns the dimensions of a matrix given as a slice of its rows.
+/// Takes `[[1, 2, 3].as_slice(), [4, 5, 6].as_slice()]` as input and outputs `(2,3)` accordingly.
+///
+/// Parameters:
+/// - `matrix`: the rows of the matrix
+///
+/// Returns an error if the number of rows or columns is too big
+/// (must fit into [`i64`]) or if the number of entries in rows is unequal.
+///
+/// # Errors and Failures
+/// - Returns a [`MathError`] of type [`InvalidMatrix`](MathError::InvalidMatrix)
+/// if the number of rows or columns is too big (must fit into [`i64`]) or
+/// if the number of entries in rows is unequal.
+pub(crate) fn find_slice_matrix_dimensions<T>(matrix: &[&[T]]) -> Result<(i64, i64), MathError> {
+    let num_cols = matrix.first().map_or(0, |row| row.len());
+    if matrix.iter().any(|row| row.len() != num_cols) {
+        return Err(MathError::InvalidMatrix(
+            "Number of entries in rows is unequal.".to_owned(),
+        ));
+    }
+
+    let num_rows: i64 = matrix.len().try_into().map_err(|_| {
+        MathError::InvalidMatrix("Number of rows is too big (must fit into [`i64`]).".to_owned())
+    })?;
+    let num_cols: i64 = num_cols.try_into().map_err(|_| {
+        MathError::InvalidMatrix("Number of columns is too big (must fit into [`i64`]).".to_owned())
+    })?;
+    Ok((num_rows, num_cols))
+}
+
 #[cfg(test)]
 mod test_find_matrix_dimensions {
     use crate::utils::{dimensions::find_matrix_dimensions, parse::parse_matrix_string};
@@ -109,3 +139,35 @@ mod test_find_matrix_dimensions {
         assert_eq!(find_matrix_dimensions(&m2).unwrap().0, 650001);
     }
 }
+
+#[cfg(test)]
+mod test_find_slice_matrix_dimensions {
+    use crate::utils::dimensions::find_slice_matrix_dimensions;
+
+    // Ensure that the dimensions of correct matrices are found.
+    #[test]
+    fn correct_matrix_works() {
+        let row = [1, 2, 3];
+
+        assert_eq!(
+            (2, 3),
+            find_slice_matrix_dimensions(&[row.as_slice(), row.as_slice()]).unwrap()
+        );
+        assert_eq!(
+            (1, 3),
+            find_slice_matrix_dimensions(&[row.as_slice()]).unwrap()
+        );
+        assert_eq!((0, 0), find_slice_matrix_dimensions::<i64>(&[]).unwrap());
+    }
+
+    // Ensure that a matrix with an incorrect number of entries in rows is rejected.
+    #[test]
+    fn incorrect_rows_error() {
+        let row_1 = [1, 2, 3];
+        let row_2 = [1, 2];
+        let empty: [i64; 0] = [];
+
+        assert!(find_slice_matrix_dimensions(&[row_1.as_slice(), row_2.as_slice()]).is_err());
+        assert!(find_slice_matrix_dimensions(&[empty.as_slice(), row_2.as_slice()]).is_err());
+    }
+}
@@ -53,6 +53,34 @@ pub fn evaluate_index<S: TryInto<i64> + Display + Copy>(index: S) -> Result<i64,
     Ok(index)
 }
 
+/// Converts index into an [`i64`] that must be at least `0` and smaller than `bound`.
+///
+/// Parameters:
+/// - `index`: the index that has to be converted into an [`i64`]
+/// - `bound`: the exclusive upper bound of the index
+///
+/// Returns an [`i64`] representation of the index or an error, if the
+/// index does not fulfill all conditions.
+///
+/// # Errors and Failures
+/// - Returns a [`MathError`] of type [`OutOfBounds`](MathError::OutOfBounds) if
+/// either the index is negative, it does not fit into an [`i64`],
+/// or it is not smaller than `bound`.
+pub(crate) fn evaluate_bounded_index(
+    index: impl TryInto<i64> + Display + Copy,
+    bound: i64,
+) -> Result<i64, MathError> {
+    let index = evaluate_index(index)?;
+
+    if index >= bound {
+        return Err(MathError::OutOfBounds(
+            format!("be smaller than {}", bound),
+            index.to_string(),
+        ));
+    }
+    Ok(index)
+}
+
 /// Evaluates whether the provided row and column are referencing an entry in a matrix.
 ///
 /// Parameters:
@@ -118,6 +146,32 @@ mod test_eval_index {
     }
 }
 
+#[cfg(test)]
+mod test_eval_bounded_index {
+
+    use super::evaluate_bounded_index;
+
+    /// tests that indices smaller than the bound are accepted
+    #[test]
+    fn is_ok_in_bounds() {
+        assert_eq!(0, evaluate_bounded_index(0, 1).unwrap());
+        assert_eq!(4, evaluate_bounded_index(4u8, 5).unwrap());
+    }
+
+    /// tests that indices at least as large as the bound are not accepted
+    #[test]
+    fn is_err_out_of_bounds() {
+        assert!(evaluate_bounded_index(5, 5).is_err());
+        assert!(evaluate_bounded_index(u64::MAX, i64::MAX).is_err());
+    }
+
+    /// tests that negative indices are not accepted
+    #[test]
+    fn is_err_negative() {
+        assert!(evaluate_bounded_index(-1, 5).is_err());
+    }
+}
+
 #[cfg(test)]
 mod test_eval_indices {
 
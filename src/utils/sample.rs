@@ -0,0 +1,159 @@
+// Copyright © 2023 Niklas Siemer
+//
+// This file is part of qFALL-math.
+//
+// qFALL-math is free software: you can redistribute it and/or modify it under
+// the terms of the Mozilla Public License Version 2.0 as published by the
+// Mozilla Foundation. See <https://mozilla.org/en-US/MPL/2.0/>.
+
+//! This module contains helper functions to sample values uniformly at random.
+//!
+//! By default, the randomness is drawn from [`ThreadRng`](rand::rngs::ThreadRng),
+//! which is a cryptographically secure pseudorandom number generator.
+//! The `_with_rng` variants draw their randomness from a caller-supplied
+//! cryptographically secure generator, e.g. a seeded one for reproducible samples.
+
+use crate::{error::MathError, integer::Z};
+use flint_sys::fmpz::{fmpz_bits, fmpz_set_ui_array};
+use rand::{CryptoRng, RngCore};
+
+/// Samples a value uniformly at random from the interval `[0, interval_size)`
+/// using rejection sampling, i.e. uniform bit strings of the bit length of
+/// `interval_size - 1` are sampled until one of them lies in the interval.
+///
+/// Parameters:
+/// - `interval_size`: the number of values that can be sampled
+///
+/// Returns a uniformly random [`Z`] from `[0, interval_size)` or an error
+/// if `interval_size` is not positive.
+///
+/// # Example
+/// ```compile_fail
+/// use qfall_math::{integer::Z, utils::sample::sample_uniform_rejection};
+///
+/// let sample = sample_uniform_rejection(&Z::from(17)).unwrap();
+///
+/// assert!(Z::ZERO <= sample);
+/// assert!(sample < Z::from(17));
+/// ```
+///
+/// # Errors and Failures
+/// - Returns a [`MathError`] of type [`NonPositive`](MathError::NonPositive)
+/// if `interval_size` is smaller than `1`.
+pub(crate) fn sample_uniform_rejection(interval_size: &Z) -> Result<Z, MathError> {
+    sample_uniform_rejection_with_rng(interval_size, &mut rand::thread_rng())
+}
+
+/// Samples a value uniformly at random from the interval `[0, interval_size)`
+/// using rejection sampling with the randomness of the provided generator.
+/// See [`sample_uniform_rejection`] for further details.
+///
+/// Parameters:
+/// - `interval_size`: the number of values that can be sampled
+/// - `rng`: the cryptographically secure generator the randomness is drawn from
+///
+/// Returns a uniformly random [`Z`] from `[0, interval_size)` or an error
+/// if `interval_size` is not positive.
+///
+/// # Errors and Failures
+/// - Returns a [`MathError`] of type [`NonPositive`](MathError::NonPositive)
+/// if `interval_size` is smaller than `1`.
+pub(crate) fn sample_uniform_rejection_with_rng<R: CryptoRng + RngCore>(
+    interval_size: &Z,
+    rng: &mut R,
+) -> Result<Z, MathError> {
+    if interval_size < &Z::ONE {
+        return Err(MathError::NonPositive(format!(
+            "{}. The interval to sample from has to contain at least one value",
+            interval_size
+        )));
+    }
+
+    let largest_value = interval_size - Z::ONE;
+    let bit_size = unsafe { fmpz_bits(&largest_value.value) };
+    let nr_limbs = bit_size.div_ceil(64) as usize;
+    if nr_limbs == 0 {
+        return Ok(Z::ZERO);
+    }
+
+    let mut limbs = vec![0_u64; nr_limbs];
+    loop {
+        limbs.iter_mut().for_each(|limb| *limb = rng.next_u64());
+        // only keep the bits that are required to represent `largest_value`
+        if bit_size % 64 != 0 {
+            limbs[nr_limbs - 1] &= (1 << (bit_size % 64)) - 1;
+        }
+
+        let mut sample = Z::default();
+        unsafe { fmpz_set_ui_array(&mut sample.value, limbs.as_ptr(), nr_limbs as i64) };
+        if sample < *interval_size {
+            return Ok(sample);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_sample_uniform_rejection {
+    use super::{sample_uniform_rejection, sample_uniform_rejection_with_rng};
+    use crate::{error::MathError, integer::Z};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// Ensure that all samples lie in the interval and every value is sampled
+    #[test]
+    fn small_interval() {
+        let interval_size = Z::from(5);
+        let mut sampled = [false; 5];
+
+        for _ in 0..500 {
+            let sample = sample_uniform_rejection(&interval_size).unwrap();
+
+            assert!(Z::ZERO <= sample);
+            assert!(sample < interval_size);
+            sampled[i64::try_from(&sample).unwrap() as usize] = true;
+        }
+        assert!(sampled.iter().all(|value| *value));
+    }
+
+    /// Ensure that large intervals, also of limb size, are supported
+    #[test]
+    fn large_interval() {
+        for interval_size in [Z::from(u64::MAX), &Z::from(u64::MAX) * &Z::from(u64::MAX)] {
+            let sample = sample_uniform_rejection(&interval_size).unwrap();
+
+            assert!(Z::ZERO <= sample);
+            assert!(sample < interval_size);
+        }
+    }
+
+    /// Ensure that the only value of an interval of size `1` is returned
+    #[test]
+    fn interval_size_one() {
+        assert_eq!(Z::ZERO, sample_uniform_rejection(&Z::ONE).unwrap());
+    }
+
+    /// Ensure that equally seeded generators yield the same samples
+    #[test]
+    fn seeded_rng() {
+        let interval_size = &Z::from(u64::MAX) * &Z::from(u64::MAX);
+        let mut rng_1 = StdRng::seed_from_u64(42);
+        let mut rng_2 = StdRng::seed_from_u64(42);
+
+        for _ in 0..10 {
+            assert_eq!(
+                sample_uniform_rejection_with_rng(&interval_size, &mut rng_1).unwrap(),
+                sample_uniform_rejection_with_rng(&interval_size, &mut rng_2).unwrap()
+            );
+        }
+    }
+
+    /// Ensure that an error is returned for non-positive interval sizes
+    #[test]
+    fn non_positive_interval() {
+        for interval_size in [Z::ZERO, Z::MINUS_ONE] {
+            assert!(matches!(
+                sample_uniform_rejection(&interval_size),
+                Err(MathError::NonPositive(_))
+            ));
+        }
+    }
+}